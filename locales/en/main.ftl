@@ -6,14 +6,50 @@ app-description = A puzzle game with emojis and clues
 paused = PAUSED
 solve-button = Solve
 show-hint = Show Hint
+step-button = Step
+step-button-tooltip = Apply the next logical step and show which clue it came from (counts as a hint)
+check-solvable-tooltip = Check whether the puzzle can still be solved from here, without revealing anything
+obvious-consequence-label = → column { $column }
+obvious-consequence-tooltip = A clue now places this tile in column { $column }. Click to place it.
+sandbox-banner = Sandbox: these moves are tentative. Keep them, or discard them to return to where you started.
+sandbox-commit = Keep Moves
+sandbox-commit-tooltip = Keep the sandbox moves as part of your game
+sandbox-discard = Discard
+sandbox-discard-tooltip = Return to the board from before the sandbox
+check-solvable-title = Still Solvable?
+check-solvable-yes = Good news: the puzzle can still be solved from here.
+check-solvable-no = The puzzle can't be solved from here; a mistake has crept in somewhere.
+check-solvable-rewind = Rewind to Last Correct State
+board-contradiction-title = Contradiction
+board-contradiction-message = That move left the board contradicting itself, so the puzzle can no longer be finished from here.
 hints-label = Hints: 
+puzzle-rating-tooltip = Puzzle rating: { $stars } of 5 stars
+timer-penalty-tooltip = Includes { $penalty } of penalty time for incorrect submissions
+clue-panel-horizontal = Horizontal Clues
+clue-panel-vertical = Vertical Clues
+puzzles-completed-tooltip = Puzzles completed this session: { $count }
 select-difficulty = Select Difficulty
 
 # Menu items
-menu-new-game = New Game
+menu-new-game = New Game (Same Difficulty)
+menu-new-game-difficulty = New Game With Difficulty
+menu-new-game-like-current = New Game Like This One
+menu-restore-previous-game = Restore Previous Game
 menu-restart = Restart
 menu-statistics = Statistics
+menu-daily-puzzle = Daily Puzzle
+menu-puzzle-archive = Puzzle Archive
+menu-favorites = Favorites
+menu-add-favorite = Add to Favorites
 menu-seed = Seed
+menu-copy-puzzle = Copy Puzzle
+menu-begin-sandbox = What-If Sandbox
+menu-solution-walkthrough = Solution Walkthrough
+menu-toggle-clue-visibility = Hide/Show Clues
+menu-complete-satisfied-clues = Complete Satisfied Clues
+menu-keyboard-shortcuts = Keyboard Shortcuts
+menu-key-bindings = Key Bindings
+menu-clue-weights = Clue Types
 menu-settings = Settings
 menu-about = About
 
@@ -21,6 +57,61 @@ menu-about = About
 settings-show-clue-tooltips = Show Clue Tooltips
 settings-touch-screen-controls = Touch Screen Controls
 settings-auto-solve = Auto-Solve
+settings-auto-solve-off = Off
+settings-auto-solve-row = Row Only
+settings-auto-solve-board = Whole Board
+settings-progressive-clue-reveal = Reveal Clues Progressively (Tutorial)
+settings-lock-solved-cells = Lock Solved Cells (Clear Twice to Undo)
+settings-clue-nav-wraps-within-orientation = Keep Clue Navigation Within Panel
+settings-center-partial-candidate-row = Center Uneven Candidate Rows
+settings-log-hint-deductions = Log Hint Deductions
+settings-resume-last-game = Resume Last Game on Startup
+settings-preferred-difficulty = Preferred Starting Difficulty
+settings-track-elimination-reasons = Track Elimination Reasons
+settings-hide-eliminated-candidates = Hide Eliminated Candidates
+settings-generation-time-limit = Generation Time Limit
+settings-eager-clue-propagation = Apply Clues When Placing Tiles
+settings-collapse-completed-clues = Collapse Completed Clues
+settings-autosave-interval = Auto-Save
+settings-autosave-interval-off = Only on Close
+settings-autosave-interval-seconds = Every {$seconds} Seconds
+settings-cascade-delay = Auto-Solve Reveal Speed
+settings-cascade-delay-instant = Instant
+settings-cascade-delay-ms = {$ms} ms per Cell
+settings-compact-negative-tiles = Compact Negative Tiles
+settings-show-column-guides = Show Column Guides
+settings-retry-incorrect-submissions = Submit Only When Correct (Practice)
+settings-incorrect-submission-penalty = Time Penalty for Incorrect Submissions
+settings-confirm-last-candidate-elimination = Allow Eliminating the Last Candidate (Click Twice)
+settings-hint-keeps-clue-selected = Keep Hinted Clue Selected
+settings-prefer-placement-hints = Prefer Placement Hints
+settings-dim-inactive-clues = Dim Clues With Nothing to Deduce
+settings-show-row-progress = Show Row Progress
+settings-pause-on-focus-loss = Pause When Window Loses Focus
+settings-candidate-display = Candidate Layout
+settings-candidate-display-grid = Grid
+settings-candidate-display-list = List
+settings-clue-sort = Clue Order
+settings-clue-sort-type = By Type
+settings-clue-sort-position = By Row
+settings-clue-sort-tile-count = By Tile Count
+settings-featured-icon-category = Featured Emoji Category
+settings-show-timer-during-play = Show Timer While Playing
+settings-difficulty-dropdown-immediate = Difficulty Menu Starts a New Game
+settings-show-clue-panel-headers = Show Clue Panel Headers
+settings-shuffle-candidate-order = Shuffle Candidate Order
+settings-suggest-obvious-consequences = Suggest Obvious Consequences
+settings-featured-icon-category-none = Default
+icon-category-faces = Faces
+icon-category-hearts = Hearts
+icon-category-numbers = Numbers
+icon-category-food = Food
+icon-category-sports = Sports
+icon-category-letters = Letters
+icon-category-flowers = Flowers
+icon-category-animals = Animals
+settings-generation-time-limit-none = No Limit
+settings-generation-time-limit-seconds = {$seconds} Seconds
 
 # Buttons
 submit = Submit
@@ -33,16 +124,93 @@ close = Close
 
 # Dialogs
 game-seed = Game Seed
+keyboard-shortcuts = Keyboard Shortcuts
+shortcut-undo = Undo
+shortcut-redo = Redo
+history-scale-tooltip = Scrub through your moves
+shortcut-new-game = New game
+shortcut-pause = Pause or resume
+shortcut-restart = Restart puzzle
+shortcut-toggle-clue-visibility = Hide or show all clues
+shortcut-keyboard-shortcuts = Show keyboard shortcuts
+shortcut-toggle-column-guides = Show or hide column guides
+shortcut-toggle-row-progress = Show or hide row progress
+shortcut-toggle-dim-inactive-clues = Dim inactive clues on or off
+shortcut-toggle-hide-eliminated = Show or hide eliminated candidates
+shortcut-previous-clue = Focus previous clue
+shortcut-next-clue = Focus next clue
+shortcut-toggle-clue-complete = Mark focused clue complete
+shortcut-set-home-clue = Bookmark focused clue as home
+shortcut-jump-home-clue = Jump to home clue
+shortcut-clear-clue-focus = Clear clue focus
+shortcut-cursor-left = Move grid cursor left
+shortcut-cursor-right = Move grid cursor right
+shortcut-cursor-up = Move grid cursor up
+shortcut-cursor-down = Move grid cursor down
+shortcut-cursor-select = Select candidate under grid cursor
+shortcut-cursor-eliminate = Eliminate candidate under grid cursor
+shortcut-hint = Show hint
+keymap-title = Key Bindings
+keymap-help = Enter the keys for each action, separated by commas, e.g. "<Control>z, u". Leave an action empty to unbind it.
+keymap-reset = Reset to Defaults
+keymap-apply = Apply
+keymap-invalid-key = "{ $key }" is not a key GTK recognizes
+keymap-conflict = { $key } is bound to both "{ $first }" and "{ $second }"
+keymap-reserved = { $key } for "{ $action }" is already used by another shortcut
+clue-weights-title = Clue Types
+clue-weights-help = How often each type of clue is picked for new puzzles. Set a type to Off to leave it out. Daily and seeded puzzles always use the defaults.
+clue-weights-default = Default
+clue-weights-off = Off
+clue-weights-reset = Reset to Defaults
+clue-weights-apply = Apply
+copy-puzzle-code = Copy Puzzle Code
+shared-game-placeholder = Paste a shared puzzle to continue it
+clipboard-puzzle-title = Load Puzzle from Clipboard?
+clipboard-puzzle-message = Your clipboard holds a {$difficulty} puzzle code ({$code}). Load it now?
+clipboard-puzzle-load = Load Puzzle
+clipboard-puzzle-ignore = Ignore
+welcome-title = Welcome to EmojiClu!
+welcome-message = New here? The tutorial walks you through your first puzzle, one step at a time.
+welcome-start-tutorial = Start Tutorial
+welcome-skip-tutorial = Skip to Easy
 game-statistics = Game Statistics
 best-times = Best Times
 global-statistics = Global Statistics
+daily-puzzle-button = Daily
+daily-puzzle-tooltip = Play today's daily puzzle, the same board for everyone
+daily-puzzle-completed-tooltip = You solved today's daily puzzle
+archive-title = Daily Puzzle Archive
+archive-description = Replay a recent daily puzzle. Each date always gives the same {$difficulty} puzzle.
+archive-status = Status
+archive-completed = ✓ Completed
+archive-not-completed = Not completed
+archive-play = Play
+favorites-title = Favorite Puzzles
+favorites-empty = No favorites yet. Add the puzzle you're playing to come back to it later.
+favorites-puzzle = Puzzle
+favorites-remove = Remove
+favorites-add-current = Add Current Puzzle
+favorites-label-placeholder = Label (optional)
 generating-puzzle = Generating puzzle...
+generation-fallback-title = Simplified Puzzle
+generation-fallback-message = Generating a {$difficulty} puzzle took longer than your time limit, so a simplified puzzle was generated instead.
+shared-game-rejected-title = Couldn't Load Progress
+shared-game-rejected-message = The shared progress doesn't match this puzzle's clues, which can happen when it was shared from a different version of EmojiClu. The puzzle was started fresh instead.
 not-quite-right-message = Sorry, that's not quite right. Click OK to rewind to the last correct state.
+not-quite-right-keep-editing = Keep Editing
+not-quite-right-show-mistakes = Show Mistakes
+not-quite-right-show-mistakes-tooltip = Keep editing, with the tiles that are wrong flashed, for a { $penalty } hint penalty
+not-quite-right-restore-candidate = Restore a Candidate
+not-quite-right-restore-candidate-tooltip = Put back one correct candidate you eliminated, for a { $penalty } hint penalty
 
 # About dialog
 about-author = Tim Harper
 about-website-label = GitHub Repository
 about-website = https://github.com/timcharper/emojiclu
+about-locale = Language: { $locale }
+about-translations-section = Translations
+about-help-translate = Help translate EmojiClu
+about-translations-url = https://github.com/timcharper/emojiclu/tree/main/locales
 
 # Stats dialog headers
 stats-rank = Rank
@@ -56,6 +224,30 @@ stats-total-games = Total Games:
 stats-total-time = Total Time:
 stats-average-time = Average Time:
 stats-total-hints-used = Total Hints Used:
+stats-average-hints-used = Average Hints per Game:
+stats-median-hints-used = Median Hints per Game:
+stats-current-streak = Current Streak:
+stats-best-streak = Best Streak:
+stats-your-time = Your time: { $time }
+stats-puzzle-difficulty = Puzzle difficulty: { $score }
+stats-puzzle-difficulty-tooltip = Solve steps: { $simple } simple, { $converging } converging, { $hidden_sets } hidden sets
+stats-group-by = Group by:
+stats-group-difficulty = Difficulty
+stats-group-grid-size = Grid Size
+stats-more-like-this = More Like This
+stats-more-like-this-tooltip = Play a new puzzle with a similar mix of clues
+stats-no-games = No games recorded yet.
+achievements = Achievements
+achievement-not-earned = Not yet earned
+achievement-earned = Achievement earned: { $achievements }
+achievement-first-solve = First Solve
+achievement-first-solve-description = Solve any puzzle
+achievement-veteran-without-hints = Unaided Veteran
+achievement-veteran-without-hints-description = Solve a Veteran puzzle without taking a hint
+achievement-quick-hard = Hard and Fast
+achievement-quick-hard-description = Solve a Hard puzzle in under five minutes
+achievement-daily-streak = Daily Habit
+achievement-daily-streak-description = Solve the daily puzzles of ten days in a row
 
 # Timer
 timer-pause = ⏸︎
@@ -140,15 +332,38 @@ action-cannot-be = cannot be
 # Converging deduction note
 converging-note = (<i>all possible solutions for this clue overlap this cell, so it can only be one of the clue values</i>)
 
+# Deduction log
+deduction-log-title = <b>Deduction Log</b>
+deduction-log-empty = Hints you use will be listed here.
+deduction-log-hidden-set = Hidden set (no single clue)
+deduction-log-deduction = → {"{"}tile:{$tile}{"}"} {$action} in column {$column}
+
+# Solution walkthrough
+walkthrough-title = Solution Walkthrough
+walkthrough-empty = No solving steps were found from the start of this game.
+walkthrough-copy = Copy
+
+# Elimination reasons
+elimination-reason-clue = Eliminated by clue: {$clue}
+elimination-reason-hidden-set = Eliminated by a hidden set
+elimination-reason-placed = Already placed in column {$column}
+row-progress-tooltip = Left to place in this row: {$count}
+
 # Clue type titles
 clue-title-three-adjacent = Three Adjacent
 clue-title-two-apart-not-middle = Two Apart, But Not The Middle
 clue-title-left-of = Left Of
+clue-title-adjacent-left-of = Immediately Left Of
+clue-title-three-adjacent-ordered = Three In A Row, In Order
+clue-title-between = Somewhere Between
+clue-title-in-column = In Column
 clue-title-two-adjacent = Two Adjacent
 clue-title-not-adjacent = Not Adjacent
 clue-title-all-in-column = All In Column
 clue-title-two-in-column = Two In Column
+clue-title-two-in-column-non-adjacent-rows = Two In Column, Rows Apart
 clue-title-one-matches-either = One Matches Either
+clue-title-two-of-three-in-column = Two Of Three In Column
 clue-title-not-in-same-column = Not In Same Column
 clue-title-two-in-column-one-not = Two In Column, One Not
 
@@ -157,11 +372,17 @@ clue-desc-adjacent = {$tiles} are adjacent (in either direction).
 clue-desc-two-adjacent = {"{"}tile:{$tile1}{"}"} is next to {"{"}tile:{$tile2}{"}"} (in either direction).
 clue-desc-two-apart = {"{"}tile:{$tile1}{"}"} is two away from {"{"}tile:{$tile3}{"}"}, without {"{"}tile:{$tile2}{"}"} in the middle (in either direction).
 clue-desc-left-of = {"{"}tile:{$left}{"}"} is left of {"{"}tile:{$right}{"}"} (any number of tiles in between).
+clue-desc-adjacent-left-of = {"{"}tile:{$left}{"}"} is immediately left of {"{"}tile:{$right}{"}"} (next to each other, in this order).
+clue-desc-three-adjacent-ordered = {"{"}tile:{$left}{"}"}, {"{"}tile:{$middle}{"}"} and {"{"}tile:{$right}{"}"} are next to each other, in this order from left to right.
+clue-desc-between = {"{"}tile:{$middle}{"}"} is somewhere between {"{"}tile:{$tile1}{"}"} and {"{"}tile:{$tile2}{"}"}, which may be either way round.
+clue-desc-in-column = {"{"}tile:{$tile}{"}"} is in column {$column}.
 clue-desc-not-adjacent = {"{"}tile:{$tile1}{"}"} is not next to {"{"}tile:{$tile2}{"}"} (in either direction).
 clue-desc-same-column = {$tiles} are in the same column.
 clue-desc-two-in-column-without = {"{"}tile:{$tile1}{"}"} and {"{"}tile:{$tile2}{"}"} are in the same column, but {"{"}tile:{$tile3}{"}"} isn't.
+clue-desc-two-in-column-non-adjacent-rows = {"{"}tile:{$tile1}{"}"} and {"{"}tile:{$tile2}{"}"} are in the same column, from rows that aren't next to each other.
 clue-desc-not-same-column = {"{"}tile:{$tile1}{"}"} is not in the same column as {"{"}tile:{$tile2}{"}"}
 clue-desc-one-matches-either = {"{"}tile:{$tile1}{"}"} is either in the same column as {"{"}tile:{$tile2}{"}"} or {"{"}tile:{$tile3}{"}"}, but not both.
+clue-desc-two-of-three-in-column = At least two of {"{"}tile:{$tile1}{"}"}, {"{"}tile:{$tile2}{"}"} and {"{"}tile:{$tile3}{"}"} are in the same column.
 
 # Difficulty levels
 difficulty-tutorial = Tutorial
@@ -6,14 +6,50 @@ app-description = Un juego de rompecabezas con emojis y pistas
 paused = PAUSADO
 solve-button = Resolver
 show-hint = Mostrar Pista
+step-button = Paso
+step-button-tooltip = Aplicar el siguiente paso lógico y mostrar de qué pista proviene (cuenta como pista)
+check-solvable-tooltip = Comprobar si el rompecabezas aún se puede resolver desde aquí, sin revelar nada
+obvious-consequence-label = → columna { $column }
+obvious-consequence-tooltip = Una pista ahora coloca esta ficha en la columna { $column }. Haz clic para colocarla.
+sandbox-banner = Zona de pruebas: estos movimientos son provisionales. Consérvalos o descártalos para volver al punto de partida.
+sandbox-commit = Conservar movimientos
+sandbox-commit-tooltip = Conservar los movimientos de la zona de pruebas en tu partida
+sandbox-discard = Descartar
+sandbox-discard-tooltip = Volver al tablero de antes de la zona de pruebas
+check-solvable-title = ¿Aún tiene solución?
+check-solvable-yes = Buenas noticias: el rompecabezas aún se puede resolver desde aquí.
+check-solvable-no = El rompecabezas no se puede resolver desde aquí; se ha colado algún error.
+check-solvable-rewind = Volver al último estado correcto
+board-contradiction-title = Contradicción
+board-contradiction-message = Ese movimiento dejó el tablero en contradicción, así que el rompecabezas ya no se puede terminar desde aquí.
 hints-label = Pistas: 
+puzzle-rating-tooltip = Valoración del rompecabezas: { $stars } de 5 estrellas
+timer-penalty-tooltip = Incluye { $penalty } de penalización por envíos incorrectos
+clue-panel-horizontal = Pistas Horizontales
+clue-panel-vertical = Pistas Verticales
+puzzles-completed-tooltip = Rompecabezas completados en esta sesión: { $count }
 select-difficulty = Seleccionar Dificultad
 
 # Menu items
-menu-new-game = Nuevo Juego
+menu-new-game = Nuevo Juego (Misma Dificultad)
+menu-new-game-difficulty = Nuevo Juego Con Dificultad
+menu-new-game-like-current = Nuevo Juego Como Este
+menu-restore-previous-game = Restaurar Juego Anterior
 menu-restart = Reiniciar
 menu-statistics = Estadísticas
+menu-daily-puzzle = Rompecabezas Diario
+menu-puzzle-archive = Archivo de Rompecabezas
+menu-favorites = Favoritos
+menu-add-favorite = Añadir a Favoritos
 menu-seed = Semilla
+menu-copy-puzzle = Copiar Rompecabezas
+menu-begin-sandbox = Zona de pruebas
+menu-solution-walkthrough = Recorrido de la Solución
+menu-toggle-clue-visibility = Ocultar/Mostrar Pistas
+menu-complete-satisfied-clues = Completar pistas satisfechas
+menu-keyboard-shortcuts = Atajos de Teclado
+menu-key-bindings = Asignación de teclas
+menu-clue-weights = Tipos de pista
 menu-settings = Configuración
 menu-about = Acerca de
 
@@ -21,6 +57,61 @@ menu-about = Acerca de
 settings-show-clue-tooltips = Mostrar Tooltips de Pistas
 settings-touch-screen-controls = Controles de Pantalla Táctil
 settings-auto-solve = Auto-Resolver
+settings-auto-solve-off = Desactivado
+settings-auto-solve-row = Solo la fila
+settings-auto-solve-board = Todo el tablero
+settings-progressive-clue-reveal = Revelar Pistas Progresivamente (Tutorial)
+settings-lock-solved-cells = Bloquear Celdas Resueltas (Borrar Dos Veces)
+settings-clue-nav-wraps-within-orientation = Mantener la Navegación de Pistas en el Panel
+settings-center-partial-candidate-row = Centrar Filas de Candidatos Incompletas
+settings-log-hint-deductions = Registrar Deducciones de Pistas
+settings-resume-last-game = Reanudar la Última Partida al Iniciar
+settings-preferred-difficulty = Dificultad Inicial Preferida
+settings-track-elimination-reasons = Registrar Motivos de Eliminación
+settings-hide-eliminated-candidates = Ocultar Candidatos Eliminados
+settings-generation-time-limit = Límite de Tiempo de Generación
+settings-eager-clue-propagation = Aplicar Pistas al Colocar Fichas
+settings-collapse-completed-clues = Contraer Pistas Completadas
+settings-autosave-interval = Guardado Automático
+settings-autosave-interval-off = Solo al Cerrar
+settings-autosave-interval-seconds = Cada {$seconds} Segundos
+settings-cascade-delay = Velocidad de revelado del autorresuelto
+settings-cascade-delay-instant = Instantáneo
+settings-cascade-delay-ms = {$ms} ms por celda
+settings-compact-negative-tiles = Fichas Negativas Compactas
+settings-show-column-guides = Mostrar guías de columna
+settings-retry-incorrect-submissions = Enviar solo cuando sea correcto (práctica)
+settings-incorrect-submission-penalty = Penalización de Tiempo por Envíos Incorrectos
+settings-confirm-last-candidate-elimination = Permitir eliminar el último candidato (doble clic)
+settings-hint-keeps-clue-selected = Mantener seleccionada la pista sugerida
+settings-prefer-placement-hints = Preferir pistas de colocación
+settings-dim-inactive-clues = Atenuar pistas sin nada que deducir
+settings-show-row-progress = Mostrar progreso por fila
+settings-pause-on-focus-loss = Pausar al perder el foco la ventana
+settings-candidate-display = Disposición de Candidatos
+settings-candidate-display-grid = Cuadrícula
+settings-candidate-display-list = Lista
+settings-clue-sort = Orden de las pistas
+settings-clue-sort-type = Por tipo
+settings-clue-sort-position = Por fila
+settings-clue-sort-tile-count = Por número de fichas
+settings-featured-icon-category = Categoría de Emoji Destacada
+settings-show-timer-during-play = Mostrar el Temporizador Durante la Partida
+settings-difficulty-dropdown-immediate = El Menú de Dificultad Inicia una Partida Nueva
+settings-show-clue-panel-headers = Mostrar Encabezados de los Paneles de Pistas
+settings-shuffle-candidate-order = Mezclar el Orden de los Candidatos
+settings-suggest-obvious-consequences = Sugerir Consecuencias Obvias
+settings-featured-icon-category-none = Predeterminada
+icon-category-faces = Caras
+icon-category-hearts = Corazones
+icon-category-numbers = Números
+icon-category-food = Comida
+icon-category-sports = Deportes
+icon-category-letters = Letras
+icon-category-flowers = Flores
+icon-category-animals = Animales
+settings-generation-time-limit-none = Sin Límite
+settings-generation-time-limit-seconds = {$seconds} Segundos
 
 # Buttons
 submit = Enviar
@@ -33,16 +124,93 @@ close = Cerrar
 
 # Dialogs
 game-seed = Semilla del Juego
+keyboard-shortcuts = Atajos de Teclado
+shortcut-undo = Deshacer
+shortcut-redo = Rehacer
+history-scale-tooltip = Recorre tus movimientos
+shortcut-new-game = Nuevo juego
+shortcut-pause = Pausar o reanudar
+shortcut-restart = Reiniciar rompecabezas
+shortcut-toggle-clue-visibility = Ocultar o mostrar todas las pistas
+shortcut-keyboard-shortcuts = Mostrar atajos de teclado
+shortcut-toggle-column-guides = Mostrar u ocultar guías de columna
+shortcut-toggle-row-progress = Mostrar u ocultar progreso por fila
+shortcut-toggle-dim-inactive-clues = Activar o desactivar la atenuación de pistas inactivas
+shortcut-toggle-hide-eliminated = Mostrar u ocultar candidatos eliminados
+shortcut-previous-clue = Enfocar pista anterior
+shortcut-next-clue = Enfocar pista siguiente
+shortcut-toggle-clue-complete = Marcar pista enfocada como completa
+shortcut-set-home-clue = Marcar la pista enfocada como inicio
+shortcut-jump-home-clue = Ir a la pista de inicio
+shortcut-clear-clue-focus = Quitar enfoque de pista
+shortcut-cursor-left = Mover el cursor de la cuadrícula a la izquierda
+shortcut-cursor-right = Mover el cursor de la cuadrícula a la derecha
+shortcut-cursor-up = Mover el cursor de la cuadrícula arriba
+shortcut-cursor-down = Mover el cursor de la cuadrícula abajo
+shortcut-cursor-select = Seleccionar el candidato bajo el cursor
+shortcut-cursor-eliminate = Eliminar el candidato bajo el cursor
+shortcut-hint = Mostrar pista
+keymap-title = Asignación de teclas
+keymap-help = Introduce las teclas de cada acción, separadas por comas, p. ej. "<Control>z, u". Deja una acción vacía para quitarle la tecla.
+keymap-reset = Restablecer valores predeterminados
+keymap-apply = Aplicar
+keymap-invalid-key = "{ $key }" no es una tecla que GTK reconozca
+keymap-conflict = { $key } está asignada a "{ $first }" y a "{ $second }"
+keymap-reserved = { $key } para "{ $action }" ya la usa otro atajo
+clue-weights-title = Tipos de pista
+clue-weights-help = Con qué frecuencia se elige cada tipo de pista para los nuevos rompecabezas. Desactiva un tipo para excluirlo. Los rompecabezas diarios y con semilla siempre usan los valores predeterminados.
+clue-weights-default = Predeterminado
+clue-weights-off = Desactivado
+clue-weights-reset = Restablecer valores predeterminados
+clue-weights-apply = Aplicar
+copy-puzzle-code = Copiar Código del Rompecabezas
+shared-game-placeholder = Pega un rompecabezas compartido para continuarlo
+clipboard-puzzle-title = ¿Cargar Rompecabezas del Portapapeles?
+clipboard-puzzle-message = Tu portapapeles contiene un código de rompecabezas {$difficulty} ({$code}). ¿Cargarlo ahora?
+clipboard-puzzle-load = Cargar Rompecabezas
+clipboard-puzzle-ignore = Ignorar
+welcome-title = ¡Bienvenido a EmojiClu!
+welcome-message = ¿Eres nuevo? El tutorial te guía paso a paso por tu primer rompecabezas.
+welcome-start-tutorial = Empezar Tutorial
+welcome-skip-tutorial = Saltar al Nivel Fácil
 game-statistics = Estadísticas del Juego
 best-times = Mejores Tiempos
 global-statistics = Estadísticas Globales
+daily-puzzle-button = Diario
+daily-puzzle-tooltip = Juega el rompecabezas diario de hoy, el mismo tablero para todos
+daily-puzzle-completed-tooltip = Resolviste el rompecabezas diario de hoy
+archive-title = Archivo de Rompecabezas Diarios
+archive-description = Vuelve a jugar un rompecabezas diario reciente. Cada fecha da siempre el mismo rompecabezas {$difficulty}.
+archive-status = Estado
+archive-completed = ✓ Completado
+archive-not-completed = Sin completar
+archive-play = Jugar
+favorites-title = Rompecabezas Favoritos
+favorites-empty = Aún no hay favoritos. Añade el rompecabezas que estás jugando para volver a él más tarde.
+favorites-puzzle = Rompecabezas
+favorites-remove = Quitar
+favorites-add-current = Añadir Rompecabezas Actual
+favorites-label-placeholder = Etiqueta (opcional)
 generating-puzzle = Generando rompecabezas...
+generation-fallback-title = Rompecabezas Simplificado
+generation-fallback-message = Generar un rompecabezas {$difficulty} tardó más que tu límite de tiempo, así que se generó un rompecabezas simplificado.
+shared-game-rejected-title = No se pudo cargar el progreso
+shared-game-rejected-message = El progreso compartido no coincide con las pistas de este rompecabezas, lo que puede ocurrir si se compartió desde otra versión de EmojiClu. El rompecabezas se empezó desde cero.
 not-quite-right-message = Lo siento, eso no es del todo correcto. Haga clic en OK para retroceder al último estado correcto.
+not-quite-right-keep-editing = Seguir editando
+not-quite-right-show-mistakes = Mostrar errores
+not-quite-right-show-mistakes-tooltip = Seguir editando, con las fichas incorrectas resaltadas, con una penalización de { $penalty } pistas
+not-quite-right-restore-candidate = Restaurar un Candidato
+not-quite-right-restore-candidate-tooltip = Devuelve un candidato correcto que eliminaste, con una penalización de { $penalty } pistas
 
 # About dialog
 about-author = Tim Harper
 about-website-label = Repositorio de GitHub
 about-website = https://github.com/timcharper/emojiclu
+about-locale = Idioma: { $locale }
+about-translations-section = Traducciones
+about-help-translate = Ayuda a traducir EmojiClu
+about-translations-url = https://github.com/timcharper/emojiclu/tree/main/locales
 
 # Stats dialog headers
 stats-rank = Rango
@@ -55,6 +223,30 @@ stats-total-games = Total de Juegos:
 stats-total-time = Tiempo Total:
 stats-average-time = Tiempo Promedio:
 stats-total-hints-used = Total de Pistas Usadas:
+stats-average-hints-used = Promedio de Pistas por Partida:
+stats-median-hints-used = Mediana de Pistas por Partida:
+stats-current-streak = Racha Actual:
+stats-best-streak = Mejor Racha:
+stats-your-time = Tu tiempo: { $time }
+stats-puzzle-difficulty = Dificultad del puzzle: { $score }
+stats-puzzle-difficulty-tooltip = Pasos de resolución: { $simple } simples, { $converging } convergentes, { $hidden_sets } conjuntos ocultos
+stats-group-by = Agrupar por:
+stats-group-difficulty = Dificultad
+stats-group-grid-size = Tamaño de Cuadrícula
+stats-more-like-this = Más Como Este
+stats-more-like-this-tooltip = Jugar un nuevo rompecabezas con una mezcla de pistas similar
+stats-no-games = Aún no hay partidas registradas.
+achievements = Logros
+achievement-not-earned = Aún no conseguido
+achievement-earned = Logro conseguido: { $achievements }
+achievement-first-solve = Primera resolución
+achievement-first-solve-description = Resuelve cualquier puzzle
+achievement-veteran-without-hints = Veterano sin ayuda
+achievement-veteran-without-hints-description = Resuelve un puzzle Veterano sin usar pistas
+achievement-quick-hard = Difícil y rápido
+achievement-quick-hard-description = Resuelve un puzzle Difícil en menos de cinco minutos
+achievement-daily-streak = Hábito diario
+achievement-daily-streak-description = Resuelve los puzzles diarios de diez días seguidos
 stats-unknown = Desconocido
 
 # Timer
@@ -140,15 +332,38 @@ action-cannot-be = no puede estar
 # Converging deduction note
 converging-note = (<i>todas las soluciones posibles para esta pista se superponen en esta celda, por lo que solo puede ser uno de los valores de la pista</i>)
 
+# Deduction log
+deduction-log-title = <b>Registro de Deducciones</b>
+deduction-log-empty = Las pistas que uses aparecerán aquí.
+deduction-log-hidden-set = Conjunto oculto (ninguna pista individual)
+deduction-log-deduction = → {"{"}tile:{$tile}{"}"} {$action} en la columna {$column}
+
+# Solution walkthrough
+walkthrough-title = Recorrido de la Solución
+walkthrough-empty = No se encontraron pasos de resolución desde el inicio de esta partida.
+walkthrough-copy = Copiar
+
+# Elimination reasons
+elimination-reason-clue = Eliminado por la pista: {$clue}
+elimination-reason-hidden-set = Eliminado por un conjunto oculto
+elimination-reason-placed = Ya colocado en la columna {$column}
+row-progress-tooltip = Por colocar en esta fila: {$count}
+
 # Clue type titles
 clue-title-three-adjacent = Tres Adyacentes
 clue-title-two-apart-not-middle = Dos Separadas, Pero No En El Medio
 clue-title-left-of = A La Izquierda De
+clue-title-adjacent-left-of = Justo A La Izquierda De
+clue-title-three-adjacent-ordered = Tres Seguidas, En Orden
+clue-title-between = En Algún Lugar Entre
+clue-title-in-column = En La Columna
 clue-title-two-adjacent = Dos Adyacentes
 clue-title-not-adjacent = No Adyacentes
 clue-title-all-in-column = Todas En Columna
 clue-title-two-in-column = Dos En Columna
+clue-title-two-in-column-non-adjacent-rows = Dos En Columna, Filas Separadas
 clue-title-one-matches-either = Una Coincide Con Cualquiera
+clue-title-two-of-three-in-column = Dos De Tres En Columna
 clue-title-not-in-same-column = No En La Misma Columna
 clue-title-two-in-column-one-not = Dos En Columna, Una No
 
@@ -157,11 +372,17 @@ clue-desc-adjacent = {$tiles} son adyacentes (en cualquier dirección).
 clue-desc-two-adjacent = {"{"}tile:{$tile1}{"}"} está junto a {"{"}tile:{$tile2}{"}"} (en cualquier dirección).
 clue-desc-two-apart = {"{"}tile:{$tile1}{"}"} está a dos de distancia de {"{"}tile:{$tile3}{"}"}, sin {"{"}tile:{$tile2}{"}"} en el medio (en cualquier dirección).
 clue-desc-left-of = {"{"}tile:{$left}{"}"} está a la izquierda de {"{"}tile:{$right}{"}"} (cualquier número de fichas en el medio).
+clue-desc-adjacent-left-of = {"{"}tile:{$left}{"}"} está justo a la izquierda de {"{"}tile:{$right}{"}"} (una junto a la otra, en este orden).
+clue-desc-three-adjacent-ordered = {"{"}tile:{$left}{"}"}, {"{"}tile:{$middle}{"}"} y {"{"}tile:{$right}{"}"} están una junto a la otra, en este orden de izquierda a derecha.
+clue-desc-between = {"{"}tile:{$middle}{"}"} está en algún lugar entre {"{"}tile:{$tile1}{"}"} y {"{"}tile:{$tile2}{"}"}, en cualquier orden.
+clue-desc-in-column = {"{"}tile:{$tile}{"}"} está en la columna {$column}.
 clue-desc-not-adjacent = {"{"}tile:{$tile1}{"}"} no está junto a {"{"}tile:{$tile2}{"}"} (en cualquier dirección).
 clue-desc-same-column = {$tiles} están en la misma columna.
 clue-desc-two-in-column-without = {"{"}tile:{$tile1}{"}"} y {"{"}tile:{$tile2}{"}"} están en la misma columna, pero {"{"}tile:{$tile3}{"}"} no.
+clue-desc-two-in-column-non-adjacent-rows = {"{"}tile:{$tile1}{"}"} y {"{"}tile:{$tile2}{"}"} están en la misma columna, en filas no contiguas.
 clue-desc-not-same-column = {"{"}tile:{$tile1}{"}"} no está en la misma columna que {"{"}tile:{$tile2}{"}"}
 clue-desc-one-matches-either = {"{"}tile:{$tile1}{"}"} está en la misma columna que {"{"}tile:{$tile2}{"}"} o {"{"}tile:{$tile3}{"}"}, pero no ambas.
+clue-desc-two-of-three-in-column = Al menos dos de {"{"}tile:{$tile1}{"}"}, {"{"}tile:{$tile2}{"}"} y {"{"}tile:{$tile3}{"}"} están en la misma columna.
 
 # Difficulty levels
 difficulty-tutorial = Tutorial
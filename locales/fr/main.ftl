@@ -6,14 +6,50 @@ app-description = Un jeu de puzzle avec des emojis et des indices
 paused = EN PAUSE
 solve-button = Résoudre
 show-hint = Afficher l'Indice
+step-button = Étape
+step-button-tooltip = Appliquer la prochaine étape logique et montrer de quel indice elle provient (compte comme un indice)
+check-solvable-tooltip = Vérifier si le puzzle peut encore être résolu d'ici, sans rien révéler
+obvious-consequence-label = → colonne { $column }
+obvious-consequence-tooltip = Un indice place maintenant cette tuile dans la colonne { $column }. Cliquez pour la placer.
+sandbox-banner = Bac à sable : ces coups sont provisoires. Gardez-les ou annulez-les pour revenir au point de départ.
+sandbox-commit = Garder les coups
+sandbox-commit-tooltip = Garder les coups du bac à sable dans votre partie
+sandbox-discard = Annuler
+sandbox-discard-tooltip = Revenir au plateau d’avant le bac à sable
+check-solvable-title = Toujours soluble ?
+check-solvable-yes = Bonne nouvelle : le puzzle peut encore être résolu d'ici.
+check-solvable-no = Le puzzle ne peut pas être résolu d'ici ; une erreur s'est glissée quelque part.
+check-solvable-rewind = Revenir au dernier état correct
+board-contradiction-title = Contradiction
+board-contradiction-message = Ce coup a mis le plateau en contradiction : le puzzle ne peut plus être terminé d'ici.
 hints-label = Indices : 
+puzzle-rating-tooltip = Note du puzzle : { $stars } sur 5 étoiles
+timer-penalty-tooltip = Inclut { $penalty } de pénalité pour les soumissions incorrectes
+clue-panel-horizontal = Indices Horizontaux
+clue-panel-vertical = Indices Verticaux
+puzzles-completed-tooltip = Puzzles terminés pendant cette session : { $count }
 select-difficulty = Sélectionner la Difficulté
 
 # Menu items
-menu-new-game = Nouveau Jeu
+menu-new-game = Nouveau Jeu (Même Difficulté)
+menu-new-game-difficulty = Nouveau Jeu Avec Difficulté
+menu-new-game-like-current = Nouvelle Partie Similaire
+menu-restore-previous-game = Restaurer la Partie Précédente
 menu-restart = Redémarrer
 menu-statistics = Statistiques
+menu-daily-puzzle = Puzzle du Jour
+menu-puzzle-archive = Archives des Puzzles
+menu-favorites = Favoris
+menu-add-favorite = Ajouter aux Favoris
 menu-seed = Graine
+menu-copy-puzzle = Copier le Puzzle
+menu-begin-sandbox = Bac à sable
+menu-solution-walkthrough = Solution Pas à Pas
+menu-toggle-clue-visibility = Masquer/Afficher les Indices
+menu-complete-satisfied-clues = Compléter les indices satisfaits
+menu-keyboard-shortcuts = Raccourcis Clavier
+menu-key-bindings = Affectation des touches
+menu-clue-weights = Types d'indices
 menu-settings = Paramètres
 menu-about = À propos
 
@@ -21,6 +57,61 @@ menu-about = À propos
 settings-show-clue-tooltips = Afficher les Infobulles des Indices
 settings-touch-screen-controls = Contrôles d'Écran Tactile
 settings-auto-solve = Résolution Automatique
+settings-auto-solve-off = Désactivé
+settings-auto-solve-row = Ligne seulement
+settings-auto-solve-board = Grille entière
+settings-progressive-clue-reveal = Révéler les Indices Progressivement (Tutoriel)
+settings-lock-solved-cells = Verrouiller les Cases Résolues (Effacer Deux Fois)
+settings-clue-nav-wraps-within-orientation = Garder la Navigation des Indices dans le Panneau
+settings-center-partial-candidate-row = Centrer les Rangées de Candidats Incomplètes
+settings-log-hint-deductions = Journaliser les Déductions des Indices
+settings-resume-last-game = Reprendre la Dernière Partie au Démarrage
+settings-preferred-difficulty = Difficulté de Départ Préférée
+settings-track-elimination-reasons = Suivre les Raisons d'Élimination
+settings-hide-eliminated-candidates = Masquer les Candidats Éliminés
+settings-generation-time-limit = Limite de Temps de Génération
+settings-eager-clue-propagation = Appliquer les Indices en Plaçant les Tuiles
+settings-collapse-completed-clues = Réduire les Indices Terminés
+settings-autosave-interval = Sauvegarde Automatique
+settings-autosave-interval-off = Seulement à la Fermeture
+settings-autosave-interval-seconds = Toutes les {$seconds} Secondes
+settings-cascade-delay = Vitesse de révélation de la résolution auto
+settings-cascade-delay-instant = Instantané
+settings-cascade-delay-ms = {$ms} ms par case
+settings-compact-negative-tiles = Tuiles Négatives Compactes
+settings-show-column-guides = Afficher les repères de colonnes
+settings-retry-incorrect-submissions = Valider seulement si correct (entraînement)
+settings-incorrect-submission-penalty = Pénalité de Temps pour les Soumissions Incorrectes
+settings-confirm-last-candidate-elimination = Autoriser l'élimination du dernier candidat (deux clics)
+settings-hint-keeps-clue-selected = Garder l'indice suggéré sélectionné
+settings-prefer-placement-hints = Privilégier les indices de placement
+settings-dim-inactive-clues = Estomper les indices sans rien à déduire
+settings-show-row-progress = Afficher la progression par ligne
+settings-pause-on-focus-loss = Mettre en pause quand la fenêtre perd le focus
+settings-candidate-display = Disposition des Candidats
+settings-candidate-display-grid = Grille
+settings-candidate-display-list = Liste
+settings-clue-sort = Ordre des indices
+settings-clue-sort-type = Par type
+settings-clue-sort-position = Par rangée
+settings-clue-sort-tile-count = Par nombre de tuiles
+settings-featured-icon-category = Catégorie d'Émoji en Vedette
+settings-show-timer-during-play = Afficher le Chronomètre Pendant la Partie
+settings-difficulty-dropdown-immediate = Le Menu de Difficulté Lance une Nouvelle Partie
+settings-show-clue-panel-headers = Afficher les En-têtes des Panneaux d'Indices
+settings-shuffle-candidate-order = Mélanger l'Ordre des Candidats
+settings-suggest-obvious-consequences = Suggérer les Conséquences Évidentes
+settings-featured-icon-category-none = Par défaut
+icon-category-faces = Visages
+icon-category-hearts = Cœurs
+icon-category-numbers = Chiffres
+icon-category-food = Nourriture
+icon-category-sports = Sports
+icon-category-letters = Lettres
+icon-category-flowers = Fleurs
+icon-category-animals = Animaux
+settings-generation-time-limit-none = Aucune Limite
+settings-generation-time-limit-seconds = {$seconds} Secondes
 
 # Buttons
 submit = Soumettre
@@ -33,16 +124,93 @@ close = Fermer
 
 # Dialogs
 game-seed = Graine du Jeu
+keyboard-shortcuts = Raccourcis Clavier
+shortcut-undo = Annuler
+shortcut-redo = Rétablir
+history-scale-tooltip = Parcourir vos coups
+shortcut-new-game = Nouvelle partie
+shortcut-pause = Mettre en pause ou reprendre
+shortcut-restart = Recommencer le puzzle
+shortcut-toggle-clue-visibility = Masquer ou afficher tous les indices
+shortcut-keyboard-shortcuts = Afficher les raccourcis clavier
+shortcut-toggle-column-guides = Afficher ou masquer les guides de colonne
+shortcut-toggle-row-progress = Afficher ou masquer la progression par ligne
+shortcut-toggle-dim-inactive-clues = Activer ou désactiver l'atténuation des indices inactifs
+shortcut-toggle-hide-eliminated = Afficher ou masquer les candidats éliminés
+shortcut-previous-clue = Indice précédent
+shortcut-next-clue = Indice suivant
+shortcut-toggle-clue-complete = Marquer l'indice actif comme terminé
+shortcut-set-home-clue = Marquer l'indice actif comme indice d'accueil
+shortcut-jump-home-clue = Aller à l'indice d'accueil
+shortcut-clear-clue-focus = Retirer le focus de l'indice
+shortcut-cursor-left = Déplacer le curseur de la grille à gauche
+shortcut-cursor-right = Déplacer le curseur de la grille à droite
+shortcut-cursor-up = Déplacer le curseur de la grille vers le haut
+shortcut-cursor-down = Déplacer le curseur de la grille vers le bas
+shortcut-cursor-select = Sélectionner le candidat sous le curseur
+shortcut-cursor-eliminate = Éliminer le candidat sous le curseur
+shortcut-hint = Afficher un indice
+keymap-title = Affectation des touches
+keymap-help = Saisissez les touches de chaque action, séparées par des virgules, p. ex. « <Control>z, u ». Laissez une action vide pour la désaffecter.
+keymap-reset = Rétablir les valeurs par défaut
+keymap-apply = Appliquer
+keymap-invalid-key = « { $key } » n'est pas une touche reconnue par GTK
+keymap-conflict = { $key } est affectée à la fois à « { $first } » et à « { $second } »
+keymap-reserved = { $key } pour « { $action } » est déjà utilisée par un autre raccourci
+clue-weights-title = Types d'indices
+clue-weights-help = La fréquence à laquelle chaque type d'indice est choisi pour les nouveaux puzzles. Désactivez un type pour l'exclure. Les puzzles quotidiens et avec graine utilisent toujours les valeurs par défaut.
+clue-weights-default = Par défaut
+clue-weights-off = Désactivé
+clue-weights-reset = Rétablir les valeurs par défaut
+clue-weights-apply = Appliquer
+copy-puzzle-code = Copier le Code du Puzzle
+shared-game-placeholder = Collez un puzzle partagé pour le continuer
+clipboard-puzzle-title = Charger le Puzzle du Presse-papiers ?
+clipboard-puzzle-message = Votre presse-papiers contient un code de puzzle {$difficulty} ({$code}). Le charger maintenant ?
+clipboard-puzzle-load = Charger le Puzzle
+clipboard-puzzle-ignore = Ignorer
+welcome-title = Bienvenue dans EmojiClu !
+welcome-message = Nouveau ici ? Le tutoriel vous guide pas à pas dans votre premier puzzle.
+welcome-start-tutorial = Commencer le Tutoriel
+welcome-skip-tutorial = Passer au Niveau Facile
 game-statistics = Statistiques du Jeu
 best-times = Meilleurs Temps
 global-statistics = Statistiques Globales
+daily-puzzle-button = Du jour
+daily-puzzle-tooltip = Jouez au puzzle du jour, le même plateau pour tout le monde
+daily-puzzle-completed-tooltip = Vous avez résolu le puzzle du jour
+archive-title = Archives des Puzzles du Jour
+archive-description = Rejouez un puzzle du jour récent. Chaque date donne toujours le même puzzle {$difficulty}.
+archive-status = Statut
+archive-completed = ✓ Terminé
+archive-not-completed = Non terminé
+archive-play = Jouer
+favorites-title = Puzzles Favoris
+favorites-empty = Aucun favori pour l'instant. Ajoutez le puzzle en cours pour y revenir plus tard.
+favorites-puzzle = Puzzle
+favorites-remove = Retirer
+favorites-add-current = Ajouter le Puzzle Actuel
+favorites-label-placeholder = Libellé (facultatif)
 generating-puzzle = Génération du puzzle...
+generation-fallback-title = Puzzle Simplifié
+generation-fallback-message = La génération d'un puzzle {$difficulty} a dépassé votre limite de temps, un puzzle simplifié a donc été généré.
+shared-game-rejected-title = Impossible de charger la progression
+shared-game-rejected-message = La progression partagée ne correspond pas aux indices de ce puzzle, ce qui peut arriver si elle a été partagée depuis une autre version d'EmojiClu. Le puzzle a été commencé à zéro.
 not-quite-right-message = Désolé, ce n'est pas tout à fait correct. Cliquez sur OK pour revenir au dernier état correct.
+not-quite-right-keep-editing = Continuer à modifier
+not-quite-right-show-mistakes = Montrer les erreurs
+not-quite-right-show-mistakes-tooltip = Continuer à modifier, avec les tuiles incorrectes mises en évidence, pour une pénalité de { $penalty } indices
+not-quite-right-restore-candidate = Restaurer un Candidat
+not-quite-right-restore-candidate-tooltip = Remet un candidat correct que vous avez éliminé, pour une pénalité de { $penalty } indices
 
 # About dialog
 about-author = Tim Harper
 about-website-label = Dépôt GitHub
 about-website = https://github.com/timcharper/emojiclu
+about-locale = Langue : { $locale }
+about-translations-section = Traductions
+about-help-translate = Aidez à traduire EmojiClu
+about-translations-url = https://github.com/timcharper/emojiclu/tree/main/locales
 
 # Stats dialog headers
 stats-rank = Rang
@@ -55,6 +223,30 @@ stats-total-games = Total de Jeux :
 stats-total-time = Temps Total :
 stats-average-time = Temps Moyen :
 stats-total-hints-used = Total d'Indices Utilisés :
+stats-average-hints-used = Indices Moyens par Partie :
+stats-median-hints-used = Indices Médians par Partie :
+stats-current-streak = Série Actuelle :
+stats-best-streak = Meilleure Série :
+stats-your-time = Votre temps : { $time }
+stats-puzzle-difficulty = Difficulté du puzzle : { $score }
+stats-puzzle-difficulty-tooltip = Étapes de résolution : { $simple } simples, { $converging } convergentes, { $hidden_sets } ensembles cachés
+stats-group-by = Grouper par :
+stats-group-difficulty = Difficulté
+stats-group-grid-size = Taille de Grille
+stats-more-like-this = Plus Comme Celui-ci
+stats-more-like-this-tooltip = Jouer un nouveau puzzle avec un mélange d'indices similaire
+stats-no-games = Aucune partie enregistrée pour l'instant.
+achievements = Succès
+achievement-not-earned = Pas encore obtenu
+achievement-earned = Succès obtenu : { $achievements }
+achievement-first-solve = Première résolution
+achievement-first-solve-description = Résoudre n'importe quel puzzle
+achievement-veteran-without-hints = Vétéran sans aide
+achievement-veteran-without-hints-description = Résoudre un puzzle Vétéran sans utiliser d'indice
+achievement-quick-hard = Difficile et rapide
+achievement-quick-hard-description = Résoudre un puzzle Difficile en moins de cinq minutes
+achievement-daily-streak = Habitude quotidienne
+achievement-daily-streak-description = Résoudre les puzzles quotidiens de dix jours d'affilée
 stats-unknown = Inconnu
 
 # Timer
@@ -140,15 +332,38 @@ action-cannot-be = ne peut pas être
 # Converging deduction note
 converging-note = (<i>toutes les solutions possibles pour cet indice se chevauchent cette cellule, donc elle ne peut être qu'une des valeurs de l'indice</i>)
 
+# Deduction log
+deduction-log-title = <b>Journal des Déductions</b>
+deduction-log-empty = Les indices que vous utilisez apparaîtront ici.
+deduction-log-hidden-set = Ensemble caché (aucun indice seul)
+deduction-log-deduction = → {"{"}tile:{$tile}{"}"} {$action} dans la colonne {$column}
+
+# Solution walkthrough
+walkthrough-title = Solution Pas à Pas
+walkthrough-empty = Aucune étape de résolution n'a été trouvée depuis le début de cette partie.
+walkthrough-copy = Copier
+
+# Elimination reasons
+elimination-reason-clue = Éliminé par l'indice : {$clue}
+elimination-reason-hidden-set = Éliminé par un ensemble caché
+elimination-reason-placed = Déjà placé dans la colonne {$column}
+row-progress-tooltip = Reste à placer dans cette ligne : {$count}
+
 # Clue type titles
 clue-title-three-adjacent = Trois Adjacentes
 clue-title-two-apart-not-middle = Deux Séparées, Mais Pas Au Milieu
 clue-title-left-of = À Gauche De
+clue-title-adjacent-left-of = Juste À Gauche De
+clue-title-three-adjacent-ordered = Trois À La Suite, Dans L'Ordre
+clue-title-between = Quelque Part Entre
+clue-title-in-column = Dans La Colonne
 clue-title-two-adjacent = Deux Adjacentes
 clue-title-not-adjacent = Non Adjacentes
 clue-title-all-in-column = Toutes En Colonne
 clue-title-two-in-column = Deux En Colonne
+clue-title-two-in-column-non-adjacent-rows = Deux En Colonne, Lignes Écartées
 clue-title-one-matches-either = Une Correspond À L'Une Ou L'Autre
+clue-title-two-of-three-in-column = Deux Sur Trois En Colonne
 clue-title-not-in-same-column = Pas Dans La Même Colonne
 clue-title-two-in-column-one-not = Deux En Colonne, Une Pas
 
@@ -157,11 +372,17 @@ clue-desc-adjacent = {$tiles} sont adjacentes (dans les deux directions).
 clue-desc-two-adjacent = {"{"}tile:{$tile1}{"}"} est à côté de {"{"}tile:{$tile2}{"}"} (dans les deux directions).
 clue-desc-two-apart = {"{"}tile:{$tile1}{"}"} est à deux de distance de {"{"}tile:{$tile3}{"}"}, sans {"{"}tile:{$tile2}{"}"} au milieu (dans les deux directions).
 clue-desc-left-of = {"{"}tile:{$left}{"}"} est à gauche de {"{"}tile:{$right}{"}"} (n'importe quel nombre de tuiles entre).
+clue-desc-adjacent-left-of = {"{"}tile:{$left}{"}"} est juste à gauche de {"{"}tile:{$right}{"}"} (côte à côte, dans cet ordre).
+clue-desc-three-adjacent-ordered = {"{"}tile:{$left}{"}"}, {"{"}tile:{$middle}{"}"} et {"{"}tile:{$right}{"}"} sont côte à côte, dans cet ordre de gauche à droite.
+clue-desc-between = {"{"}tile:{$middle}{"}"} est quelque part entre {"{"}tile:{$tile1}{"}"} et {"{"}tile:{$tile2}{"}"}, dans un sens ou dans l'autre.
+clue-desc-in-column = {"{"}tile:{$tile}{"}"} est dans la colonne {$column}.
 clue-desc-not-adjacent = {"{"}tile:{$tile1}{"}"} n'est pas à côté de {"{"}tile:{$tile2}{"}"} (dans les deux directions).
 clue-desc-same-column = {$tiles} sont dans la même colonne.
 clue-desc-two-in-column-without = {"{"}tile:{$tile1}{"}"} et {"{"}tile:{$tile2}{"}"} sont dans la même colonne, mais {"{"}tile:{$tile3}{"}"} ne l'est pas.
+clue-desc-two-in-column-non-adjacent-rows = {"{"}tile:{$tile1}{"}"} et {"{"}tile:{$tile2}{"}"} sont dans la même colonne, sur des lignes non voisines.
 clue-desc-not-same-column = {"{"}tile:{$tile1}{"}"} n'est pas dans la même colonne que {"{"}tile:{$tile2}{"}"}
 clue-desc-one-matches-either = {"{"}tile:{$tile1}{"}"} est soit dans la même colonne que {"{"}tile:{$tile2}{"}"} ou {"{"}tile:{$tile3}{"}"}, mais pas les deux.
+clue-desc-two-of-three-in-column = Au moins deux de {"{"}tile:{$tile1}{"}"}, {"{"}tile:{$tile2}{"}"} et {"{"}tile:{$tile3}{"}"} sont dans la même colonne.
 
 # Difficulty levels
 difficulty-tutorial = Tutoriel
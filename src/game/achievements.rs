@@ -0,0 +1,154 @@
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::daily::{self, DAILY_DIFFICULTY};
+use crate::model::{Difficulty, GameStats};
+
+/// Daily puzzles that must be solved on consecutive dates for `Achievement::DailyStreak`
+pub const DAILY_STREAK_LENGTH: usize = 10;
+
+/// Time a Hard puzzle must be solved within for `Achievement::QuickHard`
+pub const QUICK_HARD_TIME: Duration = Duration::from_secs(5 * 60);
+
+/// A milestone earned by completing puzzles. Everything is computed locally from the player's own
+/// stats.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Achievement {
+    /// Solve any puzzle
+    FirstSolve,
+    /// Solve a Veteran puzzle without taking a hint
+    VeteranWithoutHints,
+    /// Solve a Hard puzzle in under five minutes
+    QuickHard,
+    /// Solve the daily puzzles of ten consecutive dates
+    DailyStreak,
+}
+
+impl Achievement {
+    pub fn all() -> Vec<Achievement> {
+        vec![
+            Achievement::FirstSolve,
+            Achievement::VeteranWithoutHints,
+            Achievement::QuickHard,
+            Achievement::DailyStreak,
+        ]
+    }
+
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Achievement::FirstSolve => "achievement-first-solve",
+            Achievement::VeteranWithoutHints => "achievement-veteran-without-hints",
+            Achievement::QuickHard => "achievement-quick-hard",
+            Achievement::DailyStreak => "achievement-daily-streak",
+        }
+    }
+
+    pub fn description_key(&self) -> &'static str {
+        match self {
+            Achievement::FirstSolve => "achievement-first-solve-description",
+            Achievement::VeteranWithoutHints => "achievement-veteran-without-hints-description",
+            Achievement::QuickHard => "achievement-quick-hard-description",
+            Achievement::DailyStreak => "achievement-daily-streak-description",
+        }
+    }
+
+    /// Whether completing the game described by `stats` earns the achievement. `is_completed`
+    /// tells whether a puzzle has ever been solved, this one included.
+    pub fn is_earned_by(
+        &self,
+        stats: &GameStats,
+        is_completed: impl Fn(Difficulty, u64) -> bool,
+    ) -> bool {
+        match self {
+            Achievement::FirstSolve => true,
+            Achievement::VeteranWithoutHints => {
+                stats.difficulty == Difficulty::Veteran && stats.hints_used == 0
+            }
+            Achievement::QuickHard => {
+                stats.difficulty == Difficulty::Hard && stats.completion_time < QUICK_HARD_TIME
+            }
+            Achievement::DailyStreak => {
+                stats.difficulty == DAILY_DIFFICULTY
+                    && stats
+                        .seed
+                        .and_then(daily::date_for_seed)
+                        .is_some_and(|date| daily_streak(date, is_completed) >= DAILY_STREAK_LENGTH)
+            }
+        }
+    }
+}
+
+/// The number of consecutive dates, counting back from `date`, whose daily puzzle is completed
+fn daily_streak(date: NaiveDate, is_completed: impl Fn(Difficulty, u64) -> bool) -> usize {
+    let mut streak = 0;
+    let mut date = Some(date);
+    while let Some(day) = date {
+        if !is_completed(DAILY_DIFFICULTY, daily::seed_for_date(day)) {
+            break;
+        }
+        streak += 1;
+        date = day.checked_sub_days(Days::new(1));
+    }
+    streak
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use uuid::Uuid;
+
+    fn game(difficulty: Difficulty, secs: u64, hints_used: u32) -> GameStats {
+        GameStats {
+            completion_time: Duration::from_secs(secs),
+            hints_used,
+            grid_size: difficulty.grid_size(),
+            difficulty,
+            timestamp: 0,
+            playthrough_id: Uuid::new_v4(),
+            clues_hidden: false,
+            seed: None,
+        }
+    }
+
+    #[test]
+    fn test_game_criteria() {
+        let never_completed = |_, _| false;
+
+        assert!(Achievement::VeteranWithoutHints
+            .is_earned_by(&game(Difficulty::Veteran, 3600, 0), never_completed));
+        assert!(!Achievement::VeteranWithoutHints
+            .is_earned_by(&game(Difficulty::Veteran, 3600, 1), never_completed));
+        assert!(!Achievement::VeteranWithoutHints
+            .is_earned_by(&game(Difficulty::Hard, 3600, 0), never_completed));
+
+        assert!(
+            Achievement::QuickHard.is_earned_by(&game(Difficulty::Hard, 299, 3), never_completed)
+        );
+        assert!(
+            !Achievement::QuickHard.is_earned_by(&game(Difficulty::Hard, 300, 0), never_completed)
+        );
+    }
+
+    #[test]
+    fn test_daily_streak_counts_consecutive_dates() {
+        let last = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+        // ten days ending on March 5th, crossing into February
+        let mut completed: HashSet<(Difficulty, u64)> = daily::archive_dates(last, 10)
+            .into_iter()
+            .map(|date| (DAILY_DIFFICULTY, daily::seed_for_date(date)))
+            .collect();
+        let mut stats = game(DAILY_DIFFICULTY, 600, 2);
+        stats.seed = Some(daily::seed_for_date(last));
+
+        let is_completed = |difficulty, seed| completed.contains(&(difficulty, seed));
+        assert!(Achievement::DailyStreak.is_earned_by(&stats, is_completed));
+
+        // a gap breaks the streak
+        completed.remove(&(DAILY_DIFFICULTY, 20250301));
+        let is_completed = |difficulty, seed| completed.contains(&(difficulty, seed));
+        assert_eq!(daily_streak(last, is_completed), 4);
+        assert!(!Achievement::DailyStreak.is_earned_by(&stats, is_completed));
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{Datelike, Days, NaiveDate, Utc};
+
+use crate::model::Difficulty;
+
+/// Every daily puzzle is generated at this difficulty so a date always maps to the same board
+pub const DAILY_DIFFICULTY: Difficulty = Difficulty::Moderate;
+
+/// The current date in UTC, so everyone plays the same daily puzzle regardless of time zone
+pub fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+/// Seed for the daily puzzle on `date`, as the number yyyymmdd
+pub fn seed_for_date(date: NaiveDate) -> u64 {
+    date.year() as u64 * 10000 + date.month() as u64 * 100 + date.day() as u64
+}
+
+/// The date whose daily puzzle has `seed`, if the seed reads as a yyyymmdd date
+pub fn date_for_seed(seed: u64) -> Option<NaiveDate> {
+    let year = i32::try_from(seed / 10000).ok()?;
+    NaiveDate::from_ymd_opt(year, (seed / 100 % 100) as u32, (seed % 100) as u32)
+}
+
+/// The date of the daily puzzle at `difficulty` with `seed`, or None if it isn't one
+pub fn daily_date(difficulty: Difficulty, seed: u64) -> Option<NaiveDate> {
+    if difficulty != DAILY_DIFFICULTY {
+        return None;
+    }
+    date_for_seed(seed)
+}
+
+/// The `n_days` dates ending with `today`, most recent first
+pub fn archive_dates(today: NaiveDate, n_days: usize) -> Vec<NaiveDate> {
+    (0..n_days as u64)
+        .map_while(|days_ago| today.checked_sub_days(Days::new(days_ago)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_for_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+        assert_eq!(seed_for_date(date), 20250307);
+        assert_eq!(date_for_seed(20250307), Some(date));
+        assert_eq!(date_for_seed(20250230), None);
+
+        assert_eq!(daily_date(DAILY_DIFFICULTY, 20250307), Some(date));
+        assert_eq!(daily_date(Difficulty::Veteran, 20250307), None);
+    }
+
+    #[test]
+    fn test_archive_dates_cross_month_boundary() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 2).unwrap();
+
+        let seeds = archive_dates(today, 4)
+            .into_iter()
+            .map(seed_for_date)
+            .collect::<Vec<_>>();
+
+        assert_eq!(seeds, vec![20250302, 20250301, 20250228, 20250227]);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::model::{Difficulty, PuzzleCode};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A puzzle the player saved to come back to; the difficulty and seed regenerate it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Favorite {
+    pub difficulty: Difficulty,
+    pub seed: u64,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+impl Favorite {
+    pub fn puzzle_code(&self) -> PuzzleCode {
+        PuzzleCode::new(self.difficulty, self.seed)
+    }
+
+    /// The label if the player gave one, otherwise the puzzle code
+    pub fn display_name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self.puzzle_code().to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Favorites {
+    data_dir: PathBuf,
+    entries: Vec<Favorite>,
+}
+
+impl Favorites {
+    pub fn new() -> Self {
+        let data_dir = glib::user_data_dir().join("emojiclu");
+        if !data_dir.exists() {
+            let _ = fs::create_dir_all(&data_dir);
+        }
+
+        let mut favorites = Self {
+            data_dir,
+            entries: Vec::new(),
+        };
+        favorites.load();
+        favorites
+    }
+
+    fn favorites_path(&self) -> PathBuf {
+        self.data_dir.join("favorites.json")
+    }
+
+    fn load(&mut self) {
+        if let Ok(contents) = fs::read_to_string(self.favorites_path()) {
+            if let Ok(entries) = serde_json::from_str(&contents) {
+                self.entries = entries;
+            }
+        }
+    }
+
+    fn save(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string(&self.entries)?;
+        fs::write(self.favorites_path(), contents)?;
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[Favorite] {
+        &self.entries
+    }
+
+    pub fn contains(&self, difficulty: Difficulty, seed: u64) -> bool {
+        self.entries
+            .iter()
+            .any(|favorite| favorite.difficulty == difficulty && favorite.seed == seed)
+    }
+
+    /// Add a puzzle to the favorites. A puzzle that is already there keeps its place in the list,
+    /// but takes the new label if one is given. Returns whether a new entry was added.
+    pub fn add(
+        &mut self,
+        difficulty: Difficulty,
+        seed: u64,
+        label: Option<String>,
+    ) -> std::io::Result<bool> {
+        let label = label
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty());
+        if let Some(existing) = self
+            .entries
+            .iter_mut()
+            .find(|favorite| favorite.difficulty == difficulty && favorite.seed == seed)
+        {
+            if label.is_some() {
+                existing.label = label;
+                self.save()?;
+            }
+            return Ok(false);
+        }
+
+        self.entries.push(Favorite {
+            difficulty,
+            seed,
+            label,
+        });
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn remove(&mut self, difficulty: Difficulty, seed: u64) -> std::io::Result<()> {
+        let n_entries = self.entries.len();
+        self.entries
+            .retain(|favorite| favorite.difficulty != difficulty || favorite.seed != seed);
+        if self.entries.len() != n_entries {
+            self.save()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TempDataDir;
+
+    fn favorites(data_dir: &TempDataDir) -> Favorites {
+        Favorites {
+            data_dir: data_dir.path.clone(),
+            entries: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_add_dedups_by_difficulty_and_seed() {
+        let data_dir = TempDataDir::new();
+        let mut favorites = favorites(&data_dir);
+
+        assert!(favorites.add(Difficulty::Easy, 42, None).unwrap());
+        assert!(favorites.add(Difficulty::Hard, 42, None).unwrap());
+        assert!(!favorites
+            .add(Difficulty::Easy, 42, Some("  tricky one ".to_string()))
+            .unwrap());
+        assert!(!favorites.add(Difficulty::Easy, 42, None).unwrap());
+
+        assert_eq!(favorites.entries().len(), 2);
+        assert_eq!(favorites.entries()[0].label.as_deref(), Some("tricky one"));
+        assert_eq!(favorites.entries()[1].display_name(), "EMOJICLU-3-42");
+    }
+
+    #[test]
+    fn test_remove_and_reload() {
+        let data_dir = TempDataDir::new();
+        let mut favorites = favorites(&data_dir);
+        favorites.add(Difficulty::Easy, 1, None).unwrap();
+        favorites
+            .add(Difficulty::Moderate, 2, Some("keep".to_string()))
+            .unwrap();
+        favorites.remove(Difficulty::Easy, 1).unwrap();
+
+        assert!(!favorites.contains(Difficulty::Easy, 1));
+        assert!(favorites.contains(Difficulty::Moderate, 2));
+
+        let mut reloaded = Favorites {
+            data_dir: data_dir.path.clone(),
+            entries: Vec::new(),
+        };
+        reloaded.load();
+        assert_eq!(reloaded.entries(), favorites.entries());
+    }
+}
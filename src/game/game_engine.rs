@@ -1,26 +1,50 @@
 use log::{error, trace};
 use std::cell::RefCell;
-use std::sync::mpsc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, TryRecvError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
+use super::daily;
 use super::settings::Settings;
 use crate::destroyable::Destroyable;
 use crate::events::{EventEmitter, EventHandler};
-use crate::model::game_state_snapshot::GameStateSnapshot;
+use crate::model::game_state_snapshot::{
+    load_game_state_snapshot_from, GameStateSnapshot, SaveSlot,
+};
 use crate::model::{
-    CandidateState, ClueAddress, ClueSelection, ClueSet, ClueWithAddress, Deduction, Difficulty,
-    GameBoard, GameBoardChangeReason, GameEngineCommand, GameEngineEvent, GameStats,
-    PuzzleCompletionState, Solution, TimerState,
+    CandidateColor, CandidateState, Clue, ClueAddress, ClueOrientation, ClueSelection, ClueSet,
+    ClueWithAddress, Contradiction, Deduction, DeductionKind, DeductionLogEntry, Difficulty,
+    EliminationReason, GameBoard, GameBoardChangeReason, GameEngineCommand, GameEngineEvent,
+    GameStats, PuzzleCode, PuzzleCompletionState, SharedGame, Solution, Tile, TileAssertion,
+    TimerState,
 };
 use crate::solver::candidate_solver::{
-    deduce_hidden_sets, perform_evaluation_step, EvaluationStepResult,
+    deduce_clue_in_context, deduce_column_exhaustion, deduce_hidden_sets, perform_evaluation_step,
+    EvaluationStepResult,
+};
+use crate::solver::clue_completion_evaluator::is_clue_fully_completed;
+use crate::solver::puzzle_metrics::{
+    find_puzzle_requiring, is_solvable_from, measure_puzzle, solution_walkthrough, PuzzleMetrics,
+    PuzzleRating,
+};
+use crate::solver::{
+    clue_weights_matching, deduce_clue, simplify_deductions, ConstraintSolver, WeightedClueType,
 };
-use crate::solver::{deduce_clue, simplify_deductions, ConstraintSolver};
 use std::rc::Weak;
 use std::{rc::Rc, sync::Arc};
 
 const HINT_LEVEL_MAX: u8 = 1;
+/// Hints charged for restoring a wrongly eliminated candidate, which is worth more than a hint
+pub const ERROR_CORRECTION_HINT_PENALTY: u32 = 3;
+/// Hints charged for filling in a cell from the solution when no deduction is left
+const REVEAL_CELL_HINT_COST: u32 = 2;
+/// Hints charged for walking through a puzzle that isn't solved yet, which gives all of it away
+const SOLUTION_WALKTHROUGH_HINT_COST: u32 = 5;
+/// Time added for submitting an incorrect board, when that setting is on
+const INCORRECT_SUBMISSION_PENALTY: Duration = Duration::from_secs(30);
+const TECHNIQUE_SEARCH_MAX_ATTEMPTS: usize = 200;
 
 struct DeductionResult {
     deductions: Vec<Deduction>,
@@ -36,6 +60,29 @@ impl std::fmt::Debug for DeductionResult {
     }
 }
 
+impl DeductionResult {
+    fn has_placement(&self) -> bool {
+        self.deductions.iter().any(Deduction::is_positive)
+    }
+
+    /// Move the placements ahead of the eliminations, keeping the order within each, so the first
+    /// deduction shown is a placement if the clue yields one
+    fn placements_first(mut self) -> Self {
+        self.deductions
+            .sort_by_key(|deduction| !deduction.is_positive());
+        self
+    }
+}
+
+/// What a hint gave the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HintKind {
+    /// A deduction from the clues: its clue first, then the cell on a second ask
+    Deduction,
+    /// A tile placed straight from the solution, when no deduction is left
+    RevealCell,
+}
+
 #[derive(Debug)]
 struct HintStatus {
     history_index: usize,
@@ -51,6 +98,16 @@ impl Default for HintStatus {
     }
 }
 
+/// The board and history as they were when the player began a sandbox, to return to if it's
+/// discarded. Hints taken in the sandbox count either way.
+#[derive(Debug)]
+struct Sandbox {
+    board: Arc<GameBoard>,
+    history_index: usize,
+    /// Redo entries from before the sandbox, which its first move would otherwise drop
+    redo: Vec<Arc<GameBoard>>,
+}
+
 pub struct GameEngine {
     clue_set: Arc<ClueSet>,
     history: Vec<Arc<GameBoard>>,
@@ -59,7 +116,18 @@ pub struct GameEngine {
     debug_mode: bool,
     history_index: usize,
     hints_used: u32,
+    deduction_log: Vec<DeductionLogEntry>,
+    home_clue: Option<ClueAddress>,
+    /// Tiles the puzzle started with; see `starting_board`
+    revealed_tiles: Vec<Tile>,
+    puzzle_rating: Option<u8>,
+    difficulty_rating: Option<PuzzleRating>,
+    clues_hidden: bool,
+    /// Whether clues have been hidden at any point this game, for stats
+    clues_were_hidden: bool,
     hint_status: HintStatus,
+    /// Set while the player's moves are tentative
+    sandbox: Option<Sandbox>,
     current_playthrough_id: Uuid,
     is_paused: bool,
     timer_state: TimerState,
@@ -68,6 +136,24 @@ pub struct GameEngine {
     current_selected_clue: Option<ClueWithAddress>,
     clue_focused: bool,
     current_clue_hint: Option<ClueWithAddress>,
+    /// The clue whose deductions were most recently applied by a solve step, hint or propagation
+    last_deduction_clue: Option<ClueWithAddress>,
+    pending_solved_clear: Option<(usize, usize)>,
+    /// Cell whose last candidate the player tried to eliminate; clearing it again confirms
+    pending_last_candidate_clear: Option<(usize, usize)>,
+    /// Set whenever the board changes; cleared once `autosave` has written it out
+    has_unsaved_changes: bool,
+    /// Which clues can yield deductions or be completed, keyed by the board's content hash so
+    /// undo/redo and repeated boards don't recompute it; cleared when the clue set changes
+    clue_actionability_cache: HashMap<u64, HashMap<ClueAddress, bool>>,
+    /// Cells auto-solve placed with the board about to be pushed, to be revealed one at a time
+    pending_cascade: Vec<(usize, Tile)>,
+    /// Auto-solved cells (column, tile) on the current board not shown yet, in the order they
+    /// were placed
+    cascade_hidden: Vec<(usize, Tile)>,
+    cascade_reveal_source: Option<glib::SourceId>,
+    /// Set to abandon the puzzle generation thread started by the latest `new_game`
+    generation_cancelled: Arc<AtomicBool>,
     self_ref: Weak<RefCell<Self>>,
 }
 
@@ -91,7 +177,15 @@ impl GameEngine {
             debug_mode: Settings::is_debug_mode(),
             history_index: 0,
             hints_used: 0,
+            deduction_log: vec![],
+            home_clue: None,
+            revealed_tiles: vec![],
+            puzzle_rating: None,
+            difficulty_rating: None,
+            clues_hidden: false,
+            clues_were_hidden: false,
             hint_status: HintStatus::default(),
+            sandbox: None,
             current_playthrough_id: Uuid::new_v4(),
             is_paused: false,
             timer_state: TimerState::default(),
@@ -100,6 +194,15 @@ impl GameEngine {
             current_selected_clue: None,
             clue_focused: false,
             current_clue_hint: None,
+            last_deduction_clue: None,
+            pending_solved_clear: None,
+            pending_last_candidate_clear: None,
+            has_unsaved_changes: false,
+            clue_actionability_cache: HashMap::new(),
+            pending_cascade: vec![],
+            cascade_hidden: vec![],
+            cascade_reveal_source: None,
+            generation_cancelled: Arc::new(AtomicBool::new(false)),
             self_ref: Weak::new(),
         };
         let refcell = Rc::new(RefCell::new(game_state));
@@ -114,6 +217,10 @@ impl GameEngine {
 impl EventHandler<GameEngineCommand> for GameEngine {
     fn handle_event(&mut self, event: &GameEngineCommand) {
         log::trace!(target: "game_state", "Handling event: {:?}", event);
+        if self.is_paused && event.is_board_interaction() {
+            trace!(target: "game_state", "Ignoring {:?} while paused", event);
+            return;
+        }
         match event {
             GameEngineCommand::CellSelect(row, col, variant) => {
                 self.handle_cell_select(*row, *col, *variant)
@@ -121,21 +228,54 @@ impl EventHandler<GameEngineCommand> for GameEngine {
             GameEngineCommand::CellClear(row, col, variant) => {
                 self.handle_cell_clear(*row, *col, *variant)
             }
+            GameEngineCommand::CellToggleCandidate(row, col, variant) => {
+                self.handle_cell_toggle_candidate(*row, *col, *variant)
+            }
+            GameEngineCommand::CellTag(row, col, variant, color) => {
+                self.handle_cell_tag(*row, *col, *variant, *color)
+            }
+            GameEngineCommand::BatchEliminate(candidates) => {
+                self.handle_batch_eliminate(candidates)
+            }
             GameEngineCommand::NewGame(difficulty, seed) => self.new_game(*difficulty, *seed),
+            GameEngineCommand::NewDailyPuzzle => self.new_game(
+                Some(daily::DAILY_DIFFICULTY),
+                Some(daily::seed_for_date(daily::today())),
+            ),
+            GameEngineCommand::NewGameLikeCurrent => self.new_game_like_current(),
+            GameEngineCommand::RestorePreviousGame => self.restore_previous_game(),
+            GameEngineCommand::LoadSharedGame(shared_game) => {
+                self.load_shared_game(shared_game.clone())
+            }
+            GameEngineCommand::NewGameRequiringTechnique(deduction_kind) => {
+                self.new_game_requiring_technique(deduction_kind.clone())
+            }
             GameEngineCommand::LoadState(save_state) => {
                 trace!(target: "game_state", "Loading saved state {:?}", save_state);
                 self.set_game_state(&save_state, GameBoardChangeReason::GameLoaded);
             }
             GameEngineCommand::Solve => self.try_solve(),
             GameEngineCommand::RewindLastGood => self.rewind_last_good(),
+            GameEngineCommand::ShowIncorrectCells => self.show_incorrect_cells(),
             GameEngineCommand::IncrementHintsUsed => self.increment_hints_used(),
             GameEngineCommand::ShowHint => {
                 self.show_hint();
             }
+            GameEngineCommand::ApplyNextDeduction => self.apply_next_deduction(),
+            GameEngineCommand::ShowSolutionWalkthrough => self.show_solution_walkthrough(),
+            GameEngineCommand::CheckSolvability => self.check_solvability(),
+            GameEngineCommand::RestoreEliminatedSolutionTile => {
+                self.restore_eliminated_solution_tile()
+            }
             GameEngineCommand::Undo => self.undo(),
             GameEngineCommand::Redo => self.redo(),
+            GameEngineCommand::GotoHistory(history_index) => self.goto_history(*history_index),
+            GameEngineCommand::BeginSandbox => self.begin_sandbox(),
+            GameEngineCommand::CommitSandbox => self.commit_sandbox(),
+            GameEngineCommand::DiscardSandbox => self.discard_sandbox(),
             GameEngineCommand::Pause => self.pause_game(),
             GameEngineCommand::Resume => self.resume_game(),
+            GameEngineCommand::FocusLost => self.handle_focus_lost(),
             GameEngineCommand::Quit => (),
             GameEngineCommand::Submit => todo!(),
             GameEngineCommand::CompletePuzzle => self.complete_puzzle(),
@@ -148,8 +288,14 @@ impl EventHandler<GameEngineCommand> for GameEngine {
             GameEngineCommand::ClueToggleSelectedComplete => {
                 self.toggle_selected_clue_complete();
             }
+            GameEngineCommand::CompleteAllSatisfiedClues => self.complete_all_satisfied_clues(),
             GameEngineCommand::ClueFocus(maybe_clue) => self.focus_clue(*maybe_clue),
             GameEngineCommand::ClueFocusNext(direction) => self.focus_next_clue(*direction),
+            GameEngineCommand::ToggleClueVisibility => {
+                self.set_clues_hidden(!self.clues_hidden);
+            }
+            GameEngineCommand::SetHomeClue => self.set_home_clue(),
+            GameEngineCommand::JumpToHomeClue => self.jump_to_home_clue(),
             GameEngineCommand::ChangeSettings(change) => {
                 self.change_settings(change);
             }
@@ -159,11 +305,9 @@ impl EventHandler<GameEngineCommand> for GameEngine {
 
 impl GameEngine {
     fn restart_game(&mut self) {
-        // Start a new game with current difficulty and seed
-        let current_seed = self.current_board.solution.seed;
-        let current_difficulty = self.current_board.solution.difficulty;
+        // Start the same puzzle over, including any clue weights it was generated with
         self.set_game_state(
-            &GameStateSnapshot::generate_new(current_difficulty, Some(current_seed)),
+            &GameStateSnapshot::regenerate(&self.current_board.solution),
             GameBoardChangeReason::NewGame,
         );
     }
@@ -184,10 +328,122 @@ impl GameEngine {
         if let Some(touch_screen_controls) = change.touch_screen_controls {
             self.settings.touch_screen_controls = touch_screen_controls;
         }
-        if let Some(auto_solve_enabled) = change.auto_solve_enabled {
-            self.settings.auto_solve_enabled = auto_solve_enabled;
+        if let Some(auto_solve_mode) = change.auto_solve_mode {
+            self.settings.auto_solve_mode = auto_solve_mode;
+        }
+        if let Some(progressive_clue_reveal) = change.progressive_clue_reveal {
+            self.settings.progressive_clue_reveal = progressive_clue_reveal;
+        }
+        if let Some(lock_solved_cells) = change.lock_solved_cells {
+            self.settings.lock_solved_cells = lock_solved_cells;
+        }
+        if let Some(wraps_within_orientation) = change.clue_nav_wraps_within_orientation {
+            self.settings.clue_nav_wraps_within_orientation = wraps_within_orientation;
+        }
+        if let Some(center_partial_candidate_row) = change.center_partial_candidate_row {
+            self.settings.center_partial_candidate_row = center_partial_candidate_row;
+        }
+        if let Some(log_hint_deductions) = change.log_hint_deductions {
+            self.settings.log_hint_deductions = log_hint_deductions;
+        }
+        if let Some(preferred_difficulty) = change.preferred_difficulty {
+            self.settings.preferred_difficulty = preferred_difficulty;
+        }
+        if let Some(resume_last_game) = change.resume_last_game {
+            self.settings.resume_last_game = resume_last_game;
+        }
+        if let Some(track_elimination_reasons) = change.track_elimination_reasons {
+            self.settings.track_elimination_reasons = track_elimination_reasons;
+        }
+        if let Some(hide_eliminated_candidates) = change.hide_eliminated_candidates {
+            self.settings.hide_eliminated_candidates = hide_eliminated_candidates;
+        }
+        if let Some(generation_time_limit_secs) = change.generation_time_limit_secs {
+            self.settings.generation_time_limit_secs = generation_time_limit_secs;
+        }
+        if let Some(eager_clue_propagation) = change.eager_clue_propagation {
+            self.settings.eager_clue_propagation = eager_clue_propagation;
+        }
+        if let Some(collapse_completed_clues) = change.collapse_completed_clues {
+            self.settings.collapse_completed_clues = collapse_completed_clues;
+        }
+        if let Some(autosave_interval_secs) = change.autosave_interval_secs {
+            self.settings.autosave_interval_secs = autosave_interval_secs;
+        }
+        if let Some(compact_negative_tiles) = change.compact_negative_tiles {
+            self.settings.compact_negative_tiles = compact_negative_tiles;
+        }
+        if let Some(show_column_guides) = change.show_column_guides {
+            self.settings.show_column_guides = show_column_guides;
+        }
+        if let Some(retry_incorrect_submissions) = change.retry_incorrect_submissions {
+            self.settings.retry_incorrect_submissions = retry_incorrect_submissions;
+        }
+        if let Some(incorrect_submission_penalty) = change.incorrect_submission_penalty {
+            self.settings.incorrect_submission_penalty = incorrect_submission_penalty;
+        }
+        if let Some(confirm_last_candidate_elimination) = change.confirm_last_candidate_elimination
+        {
+            self.settings.confirm_last_candidate_elimination = confirm_last_candidate_elimination;
+        }
+        if let Some(hint_keeps_clue_selected) = change.hint_keeps_clue_selected {
+            self.settings.hint_keeps_clue_selected = hint_keeps_clue_selected;
+        }
+        if let Some(candidate_display) = change.candidate_display {
+            self.settings.candidate_display = candidate_display;
+        }
+        if let Some(dim_inactive_clues) = change.dim_inactive_clues {
+            self.settings.dim_inactive_clues = dim_inactive_clues;
+        }
+        if let Some(show_row_progress) = change.show_row_progress {
+            self.settings.show_row_progress = show_row_progress;
+        }
+        if let Some(cascade_delay_ms) = change.cascade_delay_ms {
+            self.settings.cascade_delay_ms = cascade_delay_ms;
+        }
+        if let Some(pause_on_focus_loss) = change.pause_on_focus_loss {
+            self.settings.pause_on_focus_loss = pause_on_focus_loss;
+        }
+        if let Some(has_launched_before) = change.has_launched_before {
+            self.settings.has_launched_before = has_launched_before;
+        }
+        if let Some(featured_icon_category) = change.featured_icon_category {
+            self.settings.featured_icon_category = featured_icon_category;
+        }
+        if let Some(show_timer_during_play) = change.show_timer_during_play {
+            self.settings.show_timer_during_play = show_timer_during_play;
+        }
+        if let Some(difficulty_dropdown_immediate) = change.difficulty_dropdown_immediate {
+            self.settings.difficulty_dropdown_immediate = difficulty_dropdown_immediate;
+        }
+        if let Some(next_difficulty) = change.next_difficulty {
+            self.settings.next_difficulty = next_difficulty;
+        }
+        if let Some(show_clue_panel_headers) = change.show_clue_panel_headers {
+            self.settings.show_clue_panel_headers = show_clue_panel_headers;
+        }
+        if let Some(shuffle_candidate_order) = change.shuffle_candidate_order {
+            self.settings.shuffle_candidate_order = shuffle_candidate_order;
+        }
+        if let Some(suggest_obvious_consequences) = change.suggest_obvious_consequences {
+            self.settings.suggest_obvious_consequences = suggest_obvious_consequences;
+        }
+        if let Some(keymap) = &change.keymap {
+            self.settings.keymap = keymap.clone();
+        }
+        if let Some(prefer_placement_hints) = change.prefer_placement_hints {
+            self.settings.prefer_placement_hints = prefer_placement_hints;
+        }
+        if let Some(clue_sort) = change.clue_sort {
+            self.settings.clue_sort = clue_sort;
+        }
+        if let Some(clue_type_weights) = &change.clue_type_weights {
+            self.settings.clue_type_weights = clue_type_weights.clone();
         }
         self.update_settings();
+        if change.dim_inactive_clues == Some(true) {
+            self.sync_clue_actionability();
+        }
     }
     fn set_game_state(
         &mut self,
@@ -198,23 +454,36 @@ impl GameEngine {
             "New game; difficulty: {:?}; seed: {:?}",
             game_state_snapshot.board.solution.difficulty, game_state_snapshot.board.solution.seed
         );
-        self.current_board = Arc::new(game_state_snapshot.board.clone());
+        let (history, history_index) = game_state_snapshot.history_boards();
+        self.current_board = history[history_index].clone();
         self.clue_set = Arc::clone(&self.current_board.clue_set);
+        self.clue_actionability_cache.clear();
         self.solution = Arc::clone(&self.current_board.solution);
         self.debug_mode = Settings::is_debug_mode();
-        self.history.clear();
-        self.history.push(self.current_board.clone());
-        self.history_index = 0;
+        self.history = history;
+        self.history_index = history_index;
         self.hints_used = game_state_snapshot.hints_used;
+        self.deduction_log = game_state_snapshot.deduction_log.clone();
+        self.home_clue = game_state_snapshot.home_clue;
+        self.revealed_tiles = game_state_snapshot.revealed_tiles.clone();
+        self.puzzle_rating = game_state_snapshot.rating;
+        self.difficulty_rating = game_state_snapshot.difficulty_rating.clone();
+        self.clues_were_hidden = false;
         self.current_playthrough_id = Uuid::new_v4();
         self.is_paused = false;
         self.timer_state = game_state_snapshot.timer_state.resumed();
         self.current_selected_clue = None;
         self.clue_focused = false;
         self.hint_status = HintStatus::default();
+        self.set_sandbox(None);
+        self.set_last_deduction_clue(None);
         self.sync_board_display(change_reason);
         self.game_engine_event_emitter
             .emit(GameEngineEvent::HintUsageChanged(self.hints_used));
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::DeductionLogUpdated(
+                self.deduction_log.clone(),
+            ));
         self.game_engine_event_emitter
             .emit(GameEngineEvent::TimerStateChanged(self.timer_state.clone()));
         self.game_engine_event_emitter
@@ -223,12 +492,30 @@ impl GameEngine {
                 self.current_board.solution.difficulty,
                 self.current_board.completed_clues.clone(),
             ));
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::PuzzleRated(self.puzzle_rating));
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::PuzzleDifficultyRated(
+                self.difficulty_rating.clone(),
+            ));
+        self.set_clues_hidden(false);
         self.sync_clue_selection();
     }
 
+    fn set_clues_hidden(&mut self, clues_hidden: bool) {
+        self.clues_hidden = clues_hidden;
+        self.clues_were_hidden |= clues_hidden;
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::CluesHiddenChanged(clues_hidden));
+    }
+
     fn handle_cell_select(&mut self, row: usize, col: usize, variant: Option<char>) {
+        self.pending_solved_clear = None;
+        self.pending_last_candidate_clear = None;
         // If there's already a solution in this cell, ignore the click
         if self.current_board.get_selection(row, col).is_some() {
+            self.game_engine_event_emitter
+                .emit(GameEngineEvent::InvalidInteraction { row, col });
             return;
         }
 
@@ -241,18 +528,114 @@ impl GameEngine {
                     }
                     CandidateState::Available => {
                         current_board.select_tile_at_position(col, candidate.tile);
-                        if self.settings.auto_solve_enabled {
-                            current_board.auto_solve_row(row);
+                        if self.settings.eager_clue_propagation {
+                            if let Some(addressed_clue) =
+                                self.propagate_clues_for(&mut current_board, candidate.tile)
+                            {
+                                self.set_last_deduction_clue(Some(addressed_clue));
+                            }
                         }
+                        self.auto_solve(&mut current_board, row);
                     }
                 }
                 self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+                if candidate.state == CandidateState::Available {
+                    self.offer_obvious_consequence(candidate.tile);
+                }
+            }
+        }
+    }
+
+    /// Let the player know when a clue involving the just placed `tile` now places another tile
+    /// by itself. Eager propagation would already have applied it, so there's nothing to offer.
+    fn offer_obvious_consequence(&self, tile: Tile) {
+        if !self.settings.suggest_obvious_consequences || self.settings.eager_clue_propagation {
+            return;
+        }
+        if let Some((deduction, address)) =
+            Self::obvious_consequence(&self.current_board, &self.clue_set, tile)
+        {
+            self.game_engine_event_emitter
+                .emit(GameEngineEvent::ObviousConsequenceAvailable(
+                    deduction, address,
+                ));
+        }
+    }
+
+    /// The first tile placement a single clue involving `tile` forces on `board`, other than
+    /// placements already made
+    fn obvious_consequence(
+        board: &GameBoard,
+        clue_set: &ClueSet,
+        tile: Tile,
+    ) -> Option<(Deduction, ClueAddress)> {
+        clue_set
+            .all_clues()
+            .filter(|addressed_clue| {
+                addressed_clue
+                    .clue
+                    .concrete_tiles_iter()
+                    .any(|t| *t == tile)
+            })
+            .find_map(|addressed_clue| {
+                deduce_clue(board, &addressed_clue.clue)
+                    .into_iter()
+                    .find(|deduction| {
+                        deduction.is_positive()
+                            && !board.is_known_deduction(deduction.column, deduction.tile_assertion)
+                    })
+                    .map(|deduction| (deduction, addressed_clue.address()))
+            })
+    }
+
+    /// Apply the deductions of every clue involving `tile`, which was just placed. Runs against
+    /// `board` before it's pushed, so the placement and its consequences undo together. Returns
+    /// the last clue that contributed deductions.
+    fn propagate_clues_for(&self, board: &mut GameBoard, tile: Tile) -> Option<ClueWithAddress> {
+        let mut last_useful_clue = None;
+        for addressed_clue in self.clue_set.all_clues() {
+            if !addressed_clue
+                .clue
+                .concrete_tiles_iter()
+                .any(|t| *t == tile)
+            {
+                continue;
             }
+            let deductions = deduce_clue(board, &addressed_clue.clue);
+            if deductions.is_empty() {
+                continue;
+            }
+            trace!(
+                target: "game_state",
+                "Propagating {:?} after placing {:?}: {:?}",
+                addressed_clue.clue,
+                tile,
+                deductions
+            );
+            board.apply_deductions(&deductions);
+            self.record_elimination_reasons(board, Some(addressed_clue.address()), &deductions);
+            last_useful_clue = Some(addressed_clue.clone());
         }
+        last_useful_clue
+    }
+
+    /// The clue whose deductions were most recently applied, if any since the game started
+    pub fn last_deduction_clue(&self) -> Option<&ClueWithAddress> {
+        self.last_deduction_clue.as_ref()
+    }
+
+    fn set_last_deduction_clue(&mut self, clue: Option<ClueWithAddress>) {
+        if self.last_deduction_clue == clue {
+            return;
+        }
+        self.last_deduction_clue = clue.clone();
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::LastUsefulClueChanged(clue));
     }
 
     /// moves the GameBoard into an Rc, sets it as the current state, pushes the history
     fn push_board(&mut self, board: GameBoard, change_reason: GameBoardChangeReason) {
+        let was_contradictory = self.current_board.is_unsolvable();
         self.current_board = Arc::new(board);
         // if we're not at the end of the list, prune redo state
         if self.history_index < self.history.len() - 1 {
@@ -263,10 +646,24 @@ impl GameEngine {
 
         self.maybe_reset_clue_hint();
         self.sync_board_display(change_reason);
+        if !was_contradictory {
+            if let Some(Contradiction { row, col }) = self.current_board.contradiction() {
+                self.game_engine_event_emitter
+                    .emit(GameEngineEvent::BoardContradiction { row, col });
+            }
+        }
+    }
+
+    /// The earliest history entry undo can go back to; inside a sandbox, the board the sandbox
+    /// started from
+    fn first_undoable_index(&self) -> usize {
+        self.sandbox
+            .as_ref()
+            .map_or(0, |sandbox| sandbox.history_index)
     }
 
     fn undo(&mut self) {
-        if self.history_index > 0 {
+        if self.history_index > self.first_undoable_index() {
             self.history_index -= 1;
             self.current_board = self.history[self.history_index].clone();
             self.sync_board_display(GameBoardChangeReason::Undo);
@@ -281,35 +678,254 @@ impl GameEngine {
         }
     }
 
-    fn sync_board_display(&mut self, change_reason: GameBoardChangeReason) {
-        // Emit grid update event
+    /// Jump to `history_index`, clamped to the boards undo and redo could reach
+    fn goto_history(&mut self, history_index: usize) {
+        let history_index =
+            history_index.clamp(self.first_undoable_index(), self.history.len() - 1);
+        if history_index != self.history_index {
+            self.history_index = history_index;
+            self.current_board = self.history[self.history_index].clone();
+            self.sync_board_display(GameBoardChangeReason::HistoryJump);
+        }
+    }
+
+    fn set_sandbox(&mut self, sandbox: Option<Sandbox>) {
+        let was_active = self.sandbox.is_some();
+        self.sandbox = sandbox;
+        if was_active != self.sandbox.is_some() {
+            self.game_engine_event_emitter
+                .emit(GameEngineEvent::SandboxChanged(
+                    self.sandbox.as_ref().map(|sandbox| sandbox.history_index),
+                ));
+        }
+    }
+
+    fn begin_sandbox(&mut self) {
+        if self.sandbox.is_some() || self.current_board.is_complete() {
+            return;
+        }
+        self.set_sandbox(Some(Sandbox {
+            board: self.current_board.clone(),
+            history_index: self.history_index,
+            redo: self.history[self.history_index + 1..].to_vec(),
+        }));
+        // a full board can't be submitted until the sandbox is settled
+        self.emit_submission_ready();
+    }
+
+    /// Keep the sandbox moves; they stay in the history like any others
+    fn commit_sandbox(&mut self) {
+        if self.sandbox.is_none() {
+            return;
+        }
+        self.set_sandbox(None);
+        self.emit_submission_ready();
+    }
+
+    /// Return to the board the sandbox started from. Its moves are dropped from the history, and
+    /// any redo entries from before it come back. Hints taken meanwhile still count, and stay in
+    /// the deduction log.
+    fn discard_sandbox(&mut self) {
+        let Some(sandbox) = self.sandbox.take() else {
+            return;
+        };
+        self.history = self.history_without_sandbox_moves(&sandbox);
+        self.history_index = sandbox.history_index;
+        self.current_board = sandbox.board;
+        self.hint_status = HintStatus::default();
         self.game_engine_event_emitter
-            .emit(GameEngineEvent::GameBoardUpdated {
-                board: self.current_board.as_ref().clone(),
-                history_index: self.history_index,
-                history_length: self.history.len(),
-                change_reason,
-            });
-        // Emit completion state event
-        let all_cells_filled = self.current_board.is_complete();
+            .emit(GameEngineEvent::SandboxChanged(None));
+        self.sync_board_display(GameBoardChangeReason::Undo);
+    }
+
+    /// The history up to the board `sandbox` started from, followed by the redo entries it set
+    /// aside
+    fn history_without_sandbox_moves(&self, sandbox: &Sandbox) -> Vec<Arc<GameBoard>> {
+        self.history[..=sandbox.history_index]
+            .iter()
+            .chain(sandbox.redo.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn emit_submission_ready(&self) {
         if self.get_difficulty() != Difficulty::Tutorial {
             // we don't want to show submission screen for tutorial
             self.game_engine_event_emitter
                 .emit(GameEngineEvent::PuzzleSubmissionReadyChanged(
-                    all_cells_filled,
+                    self.current_board.is_complete() && self.sandbox.is_none(),
                 ));
         }
-        if all_cells_filled {
+    }
+
+    fn sync_board_display(&mut self, change_reason: GameBoardChangeReason) {
+        // any board change disarms a pending clear of a locked cell or a last candidate
+        self.pending_solved_clear = None;
+        self.pending_last_candidate_clear = None;
+        self.has_unsaved_changes = true;
+        // a new board (including undo mid-reveal) abandons any reveal still playing out
+        self.cancel_cascade_reveal();
+        self.cascade_hidden = std::mem::take(&mut self.pending_cascade);
+        if !self.cascade_hidden.is_empty() {
+            self.start_cascade_reveal();
+        }
+        // Emit grid update event
+        self.emit_board_updated(change_reason);
+        // Emit completion state event
+        self.emit_submission_ready();
+        if self.current_board.is_complete() {
             self.clue_focused = false;
             self.sync_clue_selection();
         }
+        self.sync_clue_actionability();
+    }
+
+    /// The current board as the player should see it, with cascaded cells still waiting to be
+    /// revealed left unsolved
+    fn emit_board_updated(&self, change_reason: GameBoardChangeReason) {
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::GameBoardUpdated {
+                board: Self::hide_cascaded_cells(&self.current_board, &self.cascade_hidden),
+                history_index: self.history_index,
+                history_length: self.history.len(),
+                change_reason,
+            });
+    }
+
+    fn hide_cascaded_cells(board: &GameBoard, hidden: &[(usize, Tile)]) -> GameBoard {
+        let mut board = board.clone();
+        for (col, tile) in hidden {
+            board.remove_selection(tile.row, *col);
+        }
+        board
+    }
+
+    /// Auto-solve `board`, about to be pushed, after a change in `row`. With a cascade delay set,
+    /// the cells it places are revealed one at a time once the board is pushed; the board itself,
+    /// and so its single history entry, holds them all at once.
+    fn auto_solve(&mut self, board: &mut GameBoard, row: usize) {
+        let selections = self.settings.auto_solve_mode.apply(board, row);
+        if self.settings.cascade_delay_ms > 0 {
+            self.pending_cascade.extend(selections);
+        }
+    }
+
+    /// Reveal the hidden cascaded cells one per `cascade_delay_ms`, in the order auto-solve
+    /// placed them. The board (and its history entry) already holds them all; only the display
+    /// lags behind.
+    fn start_cascade_reveal(&mut self) {
+        let game_engine_ref = self.self_ref.clone();
+        let source_id = glib::timeout_add_local(
+            Duration::from_millis(self.settings.cascade_delay_ms),
+            move || {
+                let Some(game_engine) = game_engine_ref.upgrade() else {
+                    return glib::ControlFlow::Break;
+                };
+                let mut game_engine = game_engine.borrow_mut();
+                if game_engine.reveal_next_cascaded_cell() {
+                    glib::ControlFlow::Continue
+                } else {
+                    // the source is removed by returning Break
+                    game_engine.cascade_reveal_source = None;
+                    glib::ControlFlow::Break
+                }
+            },
+        );
+        self.cascade_reveal_source = Some(source_id);
+    }
+
+    /// Show the next hidden cascaded cell; returns whether any are left to reveal
+    fn reveal_next_cascaded_cell(&mut self) -> bool {
+        if !self.cascade_hidden.is_empty() {
+            self.cascade_hidden.remove(0);
+            self.emit_board_updated(GameBoardChangeReason::TileStatusChanged);
+        }
+        !self.cascade_hidden.is_empty()
+    }
+
+    fn cancel_cascade_reveal(&mut self) {
+        if let Some(source_id) = self.cascade_reveal_source.take() {
+            source_id.remove();
+        }
+        self.cascade_hidden.clear();
+    }
+
+    /// Tell the clue panels which clues currently have anything to offer. Only computed while
+    /// dimming inactive clues is enabled, since it runs the clue deductions for every clue.
+    fn sync_clue_actionability(&mut self) {
+        if !self.settings.dim_inactive_clues {
+            return;
+        }
+        let board = &self.current_board;
+        let clue_set = &self.clue_set;
+        let actionability = self
+            .clue_actionability_cache
+            .entry(board.content_hash())
+            .or_insert_with(|| Self::clue_actionability(board, clue_set))
+            .clone();
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::ClueActionabilityChanged(actionability));
+    }
+
+    /// A clue is actionable when it yields a deduction on the board, or when the board already
+    /// satisfies it so it can be marked completed. Whether it has been marked completed doesn't
+    /// matter here.
+    fn clue_actionability(board: &GameBoard, clue_set: &ClueSet) -> HashMap<ClueAddress, bool> {
+        clue_set
+            .all_clues()
+            .map(|addressed_clue| {
+                let actionable = !deduce_clue(board, &addressed_clue.clue).is_empty()
+                    || is_clue_fully_completed(&addressed_clue.clue, board);
+                (addressed_clue.address(), actionable)
+            })
+            .collect()
     }
 
     fn new_game(&mut self, difficulty: Option<Difficulty>, seed: Option<u64>) {
-        let difficulty = difficulty.unwrap_or(self.settings.difficulty);
+        self.generate_new_game(difficulty, seed, None, None);
+    }
+
+    /// Regenerate the shared puzzle from its code, then put the sender's progress onto it
+    fn load_shared_game(&mut self, shared_game: SharedGame) {
+        let PuzzleCode { difficulty, seed } = shared_game.code;
+        self.generate_new_game(Some(difficulty), Some(seed), None, Some(shared_game));
+    }
+
+    /// Start a fresh puzzle at the current difficulty whose clue types come in the same
+    /// proportions as the current puzzle's
+    fn new_game_like_current(&mut self) {
+        let clue_weights = clue_weights_matching(
+            self.clue_set
+                .all_clues()
+                .map(|addressed_clue| &addressed_clue.clue),
+        );
+        trace!(target: "game_state", "Generating a puzzle like the current one: {:?}", clue_weights);
+        let clue_weights = (!clue_weights.is_empty()).then_some(clue_weights);
+        self.generate_new_game(Some(self.settings.difficulty), None, clue_weights, None);
+    }
+
+    fn generate_new_game(
+        &mut self,
+        difficulty: Option<Difficulty>,
+        seed: Option<u64>,
+        clue_weights: Option<Vec<WeightedClueType>>,
+        shared_game: Option<SharedGame>,
+    ) {
+        let difficulty = difficulty
+            .or(self.settings.next_difficulty)
+            .unwrap_or(self.settings.difficulty);
+        // starting the same puzzle over is a restart, which keeps the streak going
+        let is_same_puzzle =
+            seed == Some(self.solution.seed) && difficulty == self.solution.difficulty;
+        if self.is_game_in_progress() && !is_same_puzzle {
+            self.game_engine_event_emitter
+                .emit(GameEngineEvent::GameAbandoned(self.solution.difficulty));
+        }
+        self.back_up_current_game();
 
         // Update settings immediately (this is fast)
         self.settings.difficulty = difficulty;
+        self.settings.next_difficulty = None;
         self.update_settings();
 
         // Emit puzzle generation started event
@@ -318,25 +934,163 @@ impl GameEngine {
 
         // Option 2: True background thread with callback
         // This is more complex but shows the full pattern:
-        let (sender, receiver) = mpsc::channel::<GameStateSnapshot>();
+        let (sender, receiver) =
+            mpsc::channel::<(GameStateSnapshot, bool, Option<PuzzleMetrics>)>();
+        let measure_metrics = self.debug_mode;
+        // a seed names one puzzle, e.g. the daily, so neither the time limit's simplified fallback
+        // nor the player's clue weights apply to it
+        let time_limit = (seed.is_none() && self.settings.generation_time_limit_secs > 0)
+            .then(|| Duration::from_secs(self.settings.generation_time_limit_secs));
+        let clue_type_weights = match seed {
+            Some(_) => HashMap::new(),
+            None => self.settings.clue_type_weights.clone(),
+        };
+
+        // a newer request supersedes whatever is still generating
+        self.cancel_generation();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.generation_cancelled = Arc::clone(&cancelled);
 
         std::thread::spawn(move || {
             // Do expensive computation
-            let _result = GameStateSnapshot::generate_new(difficulty, seed);
-            let _ = sender.send(_result);
+            let Some((_result, fell_back)) = GameStateSnapshot::generate_within(
+                difficulty,
+                seed,
+                time_limit,
+                Some(cancelled),
+                clue_weights,
+                clue_type_weights,
+            ) else {
+                trace!(target: "game_state", "Puzzle generation cancelled");
+                return;
+            };
+            // metrics need a full headless solve; only pay for it when someone will look
+            let metrics = measure_metrics.then(|| measure_puzzle(&_result.board));
+            let _ = sender.send((_result, fell_back, metrics));
         });
 
         // Create a mechanism to send LoadState back to ourselves
         glib::idle_add_local({
             let game_engine_ref = self.self_ref.clone();
-            move || {
-                if let Ok(snapshot) = receiver.try_recv() {
+            move || match receiver.try_recv() {
+                Ok((mut snapshot, fell_back, metrics)) => {
+                    let rejected = shared_game
+                        .as_ref()
+                        .is_some_and(|shared_game| !shared_game.apply(&mut snapshot.board));
+                    if rejected {
+                        error!(target: "game_state", "Shared progress doesn't fit the regenerated puzzle");
+                    }
                     // Regenerate on main thread and apply
                     game_engine_ref.upgrade().map(|ge| {
-                        ge.borrow_mut()
-                            .set_game_state(&snapshot, GameBoardChangeReason::NewGame)
+                        let mut ge = ge.borrow_mut();
+                        ge.set_game_state(&snapshot, GameBoardChangeReason::NewGame);
+                        if fell_back {
+                            ge.game_engine_event_emitter
+                                .emit(GameEngineEvent::PuzzleGenerationFellBack(difficulty));
+                        }
+                        if rejected {
+                            ge.game_engine_event_emitter
+                                .emit(GameEngineEvent::SharedGameRejected);
+                        }
+                        if let Some(metrics) = metrics {
+                            trace!(target: "game_state", "Puzzle metrics: {:?}", metrics);
+                            ge.game_engine_event_emitter
+                                .emit(GameEngineEvent::PuzzleMetricsMeasured(metrics));
+                        }
                     });
                     // Send LoadState command back to GameEngine
+                    glib::ControlFlow::Break
+                }
+                // generation was cancelled; nothing is coming
+                Err(TryRecvError::Disconnected) => glib::ControlFlow::Break,
+                Err(TryRecvError::Empty) => glib::ControlFlow::Continue,
+            }
+        });
+    }
+
+    /// Whether there's an unfinished game to lose; not the empty board shown before the first game
+    fn is_game_in_progress(&self) -> bool {
+        self.timer_state.ended_timestamp.is_none() && self.clue_set.all_clues().next().is_some()
+    }
+
+    /// Keep the game about to be replaced in the previous-game slot, so an accidental new game can
+    /// be undone. Finished games and the empty board shown before the first game aren't kept.
+    fn back_up_current_game(&self) {
+        if !self.is_game_in_progress() {
+            return;
+        }
+        if !self.get_game_save_state().save_to(SaveSlot::PreviousGame) {
+            error!(target: "game_state", "Failed to back up the previous game");
+        }
+    }
+
+    /// Switch back to the game the last new game replaced. The current game takes its place in
+    /// the backup, so restoring again switches back.
+    fn restore_previous_game(&mut self) {
+        let Some(previous) = load_game_state_snapshot_from(SaveSlot::PreviousGame) else {
+            log::info!(target: "game_state", "No previous game to restore");
+            return;
+        };
+        self.cancel_generation();
+        self.back_up_current_game();
+        self.settings.difficulty = previous.board.solution.difficulty;
+        self.update_settings();
+        self.set_game_state(&previous, GameBoardChangeReason::GameLoaded);
+    }
+
+    /// Tell an in-flight `new_game` generation thread to give up
+    pub fn cancel_generation(&self) {
+        self.generation_cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Keep generating puzzles at the current difficulty until one needs `deduction_kind` to
+    /// solve, then load it
+    fn new_game_requiring_technique(&mut self, deduction_kind: DeductionKind) {
+        let difficulty = self.settings.difficulty;
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::PuzzleGenerationStarted);
+
+        let (sender, receiver) = mpsc::channel::<Option<(GameStateSnapshot, PuzzleMetrics)>>();
+        std::thread::spawn({
+            let deduction_kind = deduction_kind.clone();
+            move || {
+                let found = find_puzzle_requiring(
+                    difficulty,
+                    &deduction_kind,
+                    None,
+                    TECHNIQUE_SEARCH_MAX_ATTEMPTS,
+                );
+                let _ = sender.send(found);
+            }
+        });
+
+        glib::idle_add_local({
+            let game_engine_ref = self.self_ref.clone();
+            move || {
+                if let Ok(found) = receiver.try_recv() {
+                    game_engine_ref.upgrade().map(|ge| {
+                        let mut ge = ge.borrow_mut();
+                        let seed = found
+                            .as_ref()
+                            .map(|(snapshot, _)| snapshot.board.solution.seed);
+                        log::info!(
+                            "Technique search for {:?} finished with seed {:?}",
+                            deduction_kind,
+                            seed
+                        );
+                        ge.game_engine_event_emitter.emit(
+                            GameEngineEvent::TechniqueSearchFinished {
+                                deduction_kind: deduction_kind.clone(),
+                                max_attempts: TECHNIQUE_SEARCH_MAX_ATTEMPTS,
+                                seed,
+                            },
+                        );
+                        if let Some((snapshot, metrics)) = found {
+                            ge.set_game_state(&snapshot, GameBoardChangeReason::NewGame);
+                            ge.game_engine_event_emitter
+                                .emit(GameEngineEvent::PuzzleMetricsMeasured(metrics));
+                        }
+                    });
                     return glib::ControlFlow::Break;
                 }
                 glib::ControlFlow::Continue
@@ -353,15 +1107,13 @@ impl GameEngine {
                 self.current_selected_clue = None;
                 // if all clues are hidden, we don't want to try forever
                 while tries > 0 {
-                    clue_idx = clue_idx + direction;
-
-                    if clue_idx < 0 {
-                        orientation = orientation.invert();
-                        clue_idx = self.clue_set.get_clue_count(orientation) as i32 - 1;
-                    } else if clue_idx >= self.clue_set.get_clue_count(orientation) as i32 {
-                        orientation = orientation.invert();
-                        clue_idx = 0;
-                    }
+                    (orientation, clue_idx) = Self::step_clue_position(
+                        orientation,
+                        clue_idx,
+                        direction,
+                        |orientation| self.clue_set.get_clue_count(orientation),
+                        self.settings.clue_nav_wraps_within_orientation,
+                    );
 
                     if !self.current_board.is_clue_completed(&ClueAddress {
                         orientation,
@@ -389,9 +1141,51 @@ impl GameEngine {
         self.sync_clue_selection();
     }
 
+    /// Move one clue in `direction`; at a panel boundary, either wrap around the same panel or
+    /// cross over into the other orientation.
+    fn step_clue_position(
+        orientation: ClueOrientation,
+        clue_idx: i32,
+        direction: i32,
+        clue_count: impl Fn(ClueOrientation) -> usize,
+        wrap_within_orientation: bool,
+    ) -> (ClueOrientation, i32) {
+        let clue_idx = clue_idx + direction;
+        let next_orientation = if wrap_within_orientation {
+            orientation
+        } else {
+            orientation.invert()
+        };
+
+        if clue_idx < 0 {
+            (next_orientation, clue_count(next_orientation) as i32 - 1)
+        } else if clue_idx >= clue_count(orientation) as i32 {
+            (next_orientation, 0)
+        } else {
+            (orientation, clue_idx)
+        }
+    }
+
     fn complete_puzzle(&mut self) {
+        if self.sandbox.is_some() {
+            return;
+        }
         if self.current_board.is_complete() {
-            if self.current_board.is_incorrect() {
+            if self.current_board.is_incorrect() && self.settings.incorrect_submission_penalty {
+                self.timer_state = self.timer_state.with_penalty(INCORRECT_SUBMISSION_PENALTY);
+                self.game_engine_event_emitter
+                    .emit(GameEngineEvent::TimerStateChanged(self.timer_state.clone()));
+            }
+            if self.current_board.is_incorrect() && self.settings.retry_incorrect_submissions {
+                // pointing out the wrong tiles is as much help as showing them on request
+                self.charge_error_correction();
+                self.game_engine_event_emitter
+                    .emit(GameEngineEvent::PuzzleCompleted(
+                        PuzzleCompletionState::TryAgain {
+                            incorrect_cells: self.current_board.incorrect_selections(),
+                        },
+                    ));
+            } else if self.current_board.is_incorrect() {
                 self.game_engine_event_emitter
                     .emit(GameEngineEvent::PuzzleCompleted(
                         PuzzleCompletionState::Incorrect,
@@ -418,6 +1212,17 @@ impl GameEngine {
         let mut current_board = self.current_board.as_ref().clone();
         // First check if there's a solution selected
         if current_board.has_selection(row, col) {
+            // Locked solved cells need a second clear on the same cell to confirm
+            if self.settings.lock_solved_cells && self.pending_solved_clear != Some((row, col)) {
+                trace!(
+                    target: "game_state",
+                    "Solved cell ({}, {}) is locked; clear again to confirm",
+                    row,
+                    col
+                );
+                self.pending_solved_clear = Some((row, col));
+                return;
+            }
             // Reset the cell back to candidates
             current_board.remove_selection(row, col);
             self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
@@ -428,30 +1233,144 @@ impl GameEngine {
         if let Some(variant) = variant {
             if let Some(candidate) = self.current_board.get_candidate(row, col, variant) {
                 if candidate.state == CandidateState::Available {
-                    current_board.remove_candidate(col, candidate.tile);
-                    if self.settings.auto_solve_enabled {
-                        current_board.auto_solve_row(row);
+                    // eliminating the last candidate leaves the cell with no options at all
+                    let is_last_candidate = self
+                        .current_board
+                        .get_available_candidates_at_cell(row, col)
+                        .len()
+                        == 1;
+                    if is_last_candidate
+                        && (!self.settings.confirm_last_candidate_elimination
+                            || self.pending_last_candidate_clear != Some((row, col)))
+                    {
+                        trace!(
+                            target: "game_state",
+                            "Refusing to eliminate the last candidate of cell ({}, {})",
+                            row,
+                            col
+                        );
+                        if self.settings.confirm_last_candidate_elimination {
+                            self.pending_last_candidate_clear = Some((row, col));
+                        }
+                        self.game_engine_event_emitter
+                            .emit(GameEngineEvent::InvalidInteraction { row, col });
+                        return;
                     }
+                    current_board.remove_candidate(col, candidate.tile);
+                    self.record_player_elimination_reason(&mut current_board, col, candidate.tile);
+                    self.auto_solve(&mut current_board, row);
                     self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
                 }
             }
         }
     }
 
-    fn try_solve(&mut self) {
-        let all_clues = self.clue_set.all_clues().map(|c| c.clue.clone()).collect();
+    /// Eliminate several candidates as a single undo step, e.g. from a drag across a cell. A
+    /// cell's last candidate is always kept, so a drag can never empty a cell.
+    fn handle_batch_eliminate(&mut self, candidates: &[(usize, usize, char)]) {
+        self.pending_solved_clear = None;
+        self.pending_last_candidate_clear = None;
         let mut current_board = self.current_board.as_ref().clone();
-        let solution = perform_evaluation_step(&mut current_board, &all_clues);
-        match solution {
-            EvaluationStepResult::Nothing => {
-                log::info!(
-                    "No solution found in seed {:?}",
-                    self.current_board.solution.seed
-                );
-                return;
+        let mut changed_rows = Vec::new();
+        for &(row, col, variant) in candidates {
+            if current_board.has_selection(row, col) {
+                continue;
+            }
+            let Some(candidate) = current_board.get_candidate(row, col, variant) else {
+                continue;
+            };
+            if candidate.state != CandidateState::Available {
+                continue;
+            }
+            if current_board
+                .get_available_candidates_at_cell(row, col)
+                .len()
+                == 1
+            {
+                trace!(
+                    target: "game_state",
+                    "Keeping the last candidate of cell ({}, {}) out of the batch",
+                    row,
+                    col
+                );
+                self.game_engine_event_emitter
+                    .emit(GameEngineEvent::InvalidInteraction { row, col });
+                continue;
+            }
+            current_board.remove_candidate(col, candidate.tile);
+            self.record_player_elimination_reason(&mut current_board, col, candidate.tile);
+            if !changed_rows.contains(&row) {
+                changed_rows.push(row);
+            }
+        }
+        if changed_rows.is_empty() {
+            return;
+        }
+        for row in changed_rows {
+            self.auto_solve(&mut current_board, row);
+        }
+        self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+    }
+
+    fn handle_cell_toggle_candidate(&mut self, row: usize, col: usize, variant: char) {
+        if self.current_board.has_selection(row, col) {
+            return;
+        }
+        if let Some(candidate) = self.current_board.get_candidate(row, col, variant) {
+            match candidate.state {
+                CandidateState::Available => self.handle_cell_clear(row, col, Some(variant)),
+                CandidateState::Eliminated => {
+                    self.pending_solved_clear = None;
+                    let mut current_board = self.current_board.as_ref().clone();
+                    current_board.show_candidate(col, candidate.tile);
+                    self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+                }
+            }
+        }
+    }
+
+    /// Color a candidate, as its own undoable step. Only the player's bookkeeping changes; the
+    /// candidate stays available or eliminated as it was.
+    fn handle_cell_tag(
+        &mut self,
+        row: usize,
+        col: usize,
+        variant: char,
+        color: Option<CandidateColor>,
+    ) {
+        let tile = Tile::new(row, variant);
+        if self.current_board.candidate_color(col, tile) == color {
+            return;
+        }
+        let mut current_board = self.current_board.as_ref().clone();
+        current_board.set_candidate_color(col, tile, color);
+        self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+    }
+
+    fn try_solve(&mut self) {
+        let all_clues: Vec<Clue> = self.clue_set.all_clues().map(|c| c.clue.clone()).collect();
+        let mut current_board = self.current_board.as_ref().clone();
+        let solution = perform_evaluation_step(&mut current_board, &all_clues);
+        match solution {
+            EvaluationStepResult::Nothing => {
+                log::info!(
+                    "No solution found in seed {:?}",
+                    self.current_board.solution.seed
+                );
+                return;
             }
             EvaluationStepResult::HiddenSetsFound => {
                 log::info!("Hidden pairs found");
+                let deductions = deduce_hidden_sets(&self.current_board);
+                self.record_elimination_reasons(&mut current_board, None, &deductions);
+                self.log_deductions(None, deductions);
+                self.game_engine_event_emitter
+                    .emit(GameEngineEvent::ClueSelected(None));
+            }
+            EvaluationStepResult::ForcedPlacementsFound => {
+                log::info!("Forced placements found");
+                let deductions = deduce_column_exhaustion(&self.current_board);
+                self.log_deductions(None, deductions);
                 self.game_engine_event_emitter
                     .emit(GameEngineEvent::ClueSelected(None));
             }
@@ -462,6 +1381,14 @@ impl GameEngine {
                     .find_clue(&clue)
                     .cloned()
                     .expect("This should have returned a clue");
+                let deductions = deduce_clue_in_context(&self.current_board, &clue, &all_clues);
+                self.record_elimination_reasons(
+                    &mut current_board,
+                    Some(addressed_clue.address()),
+                    &deductions,
+                );
+                self.log_deductions(Some(addressed_clue.address()), deductions);
+                self.set_last_deduction_clue(Some(addressed_clue.clone()));
 
                 self.game_engine_event_emitter
                     .emit(GameEngineEvent::ClueSelected(Some(ClueSelection {
@@ -475,68 +1402,166 @@ impl GameEngine {
     }
 
     fn find_deductions(&self) -> Option<DeductionResult> {
+        let mut results = self.deduction_results();
+        let result = if self.settings.prefer_placement_hints {
+            // the first result in solver order with a placement, across every pending deduction
+            let first = results.next();
+            if first.as_ref().is_some_and(DeductionResult::has_placement) {
+                first
+            } else {
+                results.find(DeductionResult::has_placement).or(first)
+            }
+            .map(DeductionResult::placements_first)
+        } else {
+            results.next()
+        };
+        if result.is_none() {
+            // Nothing found! Oof.
+            error!(
+                target: "game_state",
+                "No deductions found; seed: {:?}",
+                self.current_board.solution.seed
+            );
+        }
+        result
+    }
+
+    /// Every clue's deductions, in the order the solver would offer them as hints
+    fn deduction_results(&self) -> impl Iterator<Item = DeductionResult> + '_ {
         // First, look for obvious deductions using the simpler solver
-        for clue_grouping in self.clue_set.all_clues() {
+        let simple = self.clue_set.all_clues().filter_map(|clue_grouping| {
             let simple_deductions =
                 ConstraintSolver::deduce_clue(&self.current_board, &clue_grouping.clue);
-            if !simple_deductions.is_empty() {
-                return Some(DeductionResult {
-                    deductions: simplify_deductions(
-                        &self.current_board,
-                        simple_deductions,
-                        &clue_grouping.clue,
-                    ),
-                    clue: Some(clue_grouping.clone()),
-                });
-            }
-        }
+            (!simple_deductions.is_empty()).then(|| DeductionResult {
+                deductions: simplify_deductions(
+                    &self.current_board,
+                    simple_deductions,
+                    &clue_grouping.clue,
+                ),
+                clue: Some(clue_grouping.clone()),
+            })
+        });
 
         // Scan again using the advanced solver (which emits admittedly less obvious hints)
-        for clue_grouping in self.clue_set.all_clues() {
-            let deductions = deduce_clue(&self.current_board, &clue_grouping.clue);
-            if !deductions.is_empty() {
-                return Some(DeductionResult {
-                    deductions: simplify_deductions(
-                        &self.current_board,
-                        deductions,
-                        &clue_grouping.clue,
-                    ),
-                    clue: Some(clue_grouping.clone()),
-                });
-            }
-        }
+        let all_clues: Vec<Clue> = self.clue_set.all_clues().map(|c| c.clue.clone()).collect();
+        let advanced = self.clue_set.all_clues().filter_map(move |clue_grouping| {
+            let deductions =
+                deduce_clue_in_context(&self.current_board, &clue_grouping.clue, &all_clues);
+            (!deductions.is_empty()).then(|| DeductionResult {
+                deductions: simplify_deductions(
+                    &self.current_board,
+                    deductions,
+                    &clue_grouping.clue,
+                ),
+                clue: Some(clue_grouping.clone()),
+            })
+        });
 
         // look for hidden pairs
-        let hidden_pairs = deduce_hidden_sets(&self.current_board);
-        if !hidden_pairs.is_empty() {
-            return Some(DeductionResult {
+        let hidden_pairs = std::iter::once_with(|| {
+            let hidden_pairs = deduce_hidden_sets(&self.current_board);
+            (!hidden_pairs.is_empty()).then(|| DeductionResult {
                 deductions: hidden_pairs,
                 clue: None,
-            });
-        }
-        // Nothing found! Oof.
-        error!(
-            target: "game_state",
-            "No deductions found; seed: {:?}",
-            self.current_board.solution.seed
-        );
-        None
+            })
+        })
+        .flatten();
+
+        simple.chain(advanced).chain(hidden_pairs)
     }
 
     fn increment_hints_used(&mut self) {
         if self.hint_status.history_index != self.history_index {
             self.hint_status.history_index = self.history_index;
             self.hint_status.hint_level = 0;
-            self.hints_used += 1;
+            self.hints_used = self.hints_used.saturating_add(1);
         } else if self.hint_status.hint_level < HINT_LEVEL_MAX {
-            self.hints_used += 1;
+            self.hints_used = self.hints_used.saturating_add(1);
             self.hint_status.hint_level += 1;
         }
         self.game_engine_event_emitter
             .emit(GameEngineEvent::HintUsageChanged(self.hints_used));
     }
 
-    fn show_hint(&mut self) -> bool {
+    /// Count the cost of having a mistake pointed out or fixed
+    fn charge_error_correction(&mut self) {
+        self.hints_used = self
+            .hints_used
+            .saturating_add(ERROR_CORRECTION_HINT_PENALTY);
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::HintUsageChanged(self.hints_used));
+    }
+
+    /// Restore one solution tile the player eliminated, which ordinary hints can't recover from
+    /// since they only ever eliminate more
+    fn restore_eliminated_solution_tile(&mut self) {
+        let Some((col, tile)) = self.current_board.find_eliminated_solution_tile() else {
+            return;
+        };
+        self.charge_error_correction();
+
+        let mut current_board = self.current_board.as_ref().clone();
+        current_board.show_candidate(col, tile);
+        self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::HintSuggested(Deduction::new(
+                col,
+                TileAssertion {
+                    tile,
+                    assertion: true,
+                },
+            )));
+    }
+
+    /// Point out the placed tiles that are wrong, as a retried submission does but without
+    /// submitting. That gives away as much as restoring a candidate, so it costs the same.
+    fn show_incorrect_cells(&mut self) {
+        let incorrect_cells = self.current_board.incorrect_selections();
+        if incorrect_cells.is_empty() {
+            return;
+        }
+        self.charge_error_correction();
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::IncorrectCellsShown(incorrect_cells));
+    }
+
+    /// The puzzle as it was first shown: its clues and the tiles it starts with. The history
+    /// doesn't reach back that far once it's been resumed or capped.
+    fn starting_board(&self) -> GameBoard {
+        let mut board = GameBoard::new(Arc::clone(&self.solution));
+        board.set_clues(Arc::clone(&self.clue_set));
+        for tile in self.revealed_tiles.iter() {
+            board.select_tile_from_solution(*tile);
+        }
+        board
+    }
+
+    /// Walk through the solve from the board the game started with. Before the puzzle is solved
+    /// that counts against the player like a hint.
+    fn show_solution_walkthrough(&mut self) {
+        if self.timer_state.ended_timestamp.is_none() {
+            self.hints_used = self
+                .hints_used
+                .saturating_add(SOLUTION_WALKTHROUGH_HINT_COST);
+            self.game_engine_event_emitter
+                .emit(GameEngineEvent::HintUsageChanged(self.hints_used));
+        }
+        let walkthrough = solution_walkthrough(&self.starting_board());
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::SolutionWalkthrough(walkthrough));
+    }
+
+    /// Whether the puzzle can still be finished from the current board. Unlike a hint, this
+    /// reveals nothing and doesn't count against the player.
+    fn check_solvability(&mut self) {
+        let solvable = is_solvable_from(&self.current_board);
+        trace!(target: "game_state", "Solvability check: {}", solvable);
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::SolvabilityChecked(solvable));
+    }
+
+    fn show_hint(&mut self) -> Option<HintKind> {
         let deduction_result = self.find_deductions();
 
         if deduction_result.is_some() {
@@ -550,7 +1575,9 @@ impl GameEngine {
         );
 
         if let Some(DeductionResult { deductions, clue }) = deduction_result {
+            self.log_deductions(clue.as_ref().map(|c| c.address()), deductions.clone());
             if let Some(addressed_clue) = &clue {
+                self.set_last_deduction_clue(Some(addressed_clue.clone()));
                 self.game_engine_event_emitter
                     .emit(GameEngineEvent::ClueSelected(Some(ClueSelection {
                         clue: addressed_clue.clone(),
@@ -584,7 +1611,7 @@ impl GameEngine {
                         .emit(GameEngineEvent::HintSuggested(first_deduction.clone()));
                 }
             }
-            return true;
+            return Some(HintKind::Deduction);
         } else {
             log::error!(
                 target: "game_state",
@@ -592,11 +1619,141 @@ impl GameEngine {
                 self.current_board.solution.seed
             );
         }
-        false
+        self.reveal_cell_hint()
+    }
+
+    /// The last resort for a stuck player: place one tile straight from the solution, at
+    /// `REVEAL_CELL_HINT_COST` hints
+    fn reveal_cell_hint(&mut self) -> Option<HintKind> {
+        let deduction = Self::cell_to_reveal(&self.current_board)?;
+        self.hints_used = self.hints_used.saturating_add(REVEAL_CELL_HINT_COST);
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::HintUsageChanged(self.hints_used));
+
+        let mut current_board = self.current_board.as_ref().clone();
+        current_board.apply_deduction(&deduction);
+        self.auto_solve(&mut current_board, deduction.tile_assertion.tile.row);
+        self.log_deductions(None, vec![deduction.clone()]);
+        self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::HintSuggested(deduction));
+        Some(HintKind::RevealCell)
+    }
+
+    /// The first empty cell whose solution tile is still a candidate there and isn't already
+    /// placed elsewhere in its row, as a placement. Cells the player got wrong, by placing or by
+    /// eliminating, are left for them to find.
+    fn cell_to_reveal(board: &GameBoard) -> Option<Deduction> {
+        let solution = &board.solution;
+        (0..solution.n_rows)
+            .flat_map(|row| (0..solution.n_variants).map(move |col| (row, col)))
+            .map(|(row, col)| (col, Tile::new(row, solution.grid[row][col])))
+            .find(|(col, tile)| {
+                !board.has_selection(tile.row, *col)
+                    && !board.has_tile_selected_anywhere(tile)
+                    && board.is_candidate_available(tile.row, *col, tile.variant)
+            })
+            .map(|(col, tile)| {
+                Deduction::new(
+                    col,
+                    TileAssertion {
+                        tile,
+                        assertion: true,
+                    },
+                )
+            })
+    }
+
+    /// Apply just the first deduction of the next hint, unlike `try_solve` which applies everything
+    /// a clue yields, and select the clue it came from
+    fn apply_next_deduction(&mut self) {
+        let Some(DeductionResult { deductions, clue }) = self.find_deductions() else {
+            return;
+        };
+        let Some(deduction) = deductions.into_iter().next() else {
+            return;
+        };
+        self.increment_hints_used();
+
+        let clue_address = clue.as_ref().map(|c| c.address());
+        let mut current_board = self.current_board.as_ref().clone();
+        current_board.apply_deduction(&deduction);
+        self.record_elimination_reasons(&mut current_board, clue_address, &[deduction.clone()]);
+        self.auto_solve(&mut current_board, deduction.tile_assertion.tile.row);
+        self.log_deductions(clue_address, vec![deduction]);
+        if clue.is_some() {
+            self.set_last_deduction_clue(clue);
+        }
+
+        if clue_address.is_some() {
+            self.focus_clue(clue_address);
+        } else {
+            self.game_engine_event_emitter
+                .emit(GameEngineEvent::ClueSelected(None));
+        }
+        self.push_board(current_board, GameBoardChangeReason::TileStatusChanged);
+    }
+
+    /// Attach provenance to the negative deductions applied to `board`. Undo restores earlier
+    /// boards from history, which drops provenance along with the eliminations themselves.
+    fn record_elimination_reasons(
+        &self,
+        board: &mut GameBoard,
+        clue: Option<ClueAddress>,
+        deductions: &[Deduction],
+    ) {
+        if !self.settings.track_elimination_reasons {
+            return;
+        }
+        for deduction in deductions.iter().filter(|d| !d.is_positive()) {
+            board.record_elimination_reason(
+                deduction.column,
+                deduction.tile_assertion.tile,
+                EliminationReason::Deduction {
+                    clue,
+                    deduction_kind: deduction.deduction_kind.clone(),
+                },
+            );
+        }
+    }
+
+    /// Credit a player's elimination to the focused clue if that clue justifies it
+    fn record_player_elimination_reason(&self, board: &mut GameBoard, col: usize, tile: Tile) {
+        if !self.settings.track_elimination_reasons {
+            return;
+        }
+        let Some(addressed_clue) = &self.current_selected_clue else {
+            return;
+        };
+        let deduction = deduce_clue(&self.current_board, &addressed_clue.clue)
+            .into_iter()
+            .find(|d| !d.is_positive() && d.column == col && d.tile_assertion.tile == tile);
+        if let Some(deduction) = deduction {
+            self.record_elimination_reasons(board, Some(addressed_clue.address()), &[deduction]);
+        }
+    }
+
+    /// Record which clue a hint (or solve step) drew its deductions from, for later review
+    fn log_deductions(&mut self, clue: Option<ClueAddress>, deductions: Vec<Deduction>) {
+        if !self.settings.log_hint_deductions || deductions.is_empty() {
+            return;
+        }
+        let entry = DeductionLogEntry { clue, deductions };
+        // asking for the same hint again (e.g. the second hint level) isn't a new step
+        if self.deduction_log.last() == Some(&entry) {
+            return;
+        }
+        self.deduction_log.push(entry);
+        self.game_engine_event_emitter
+            .emit(GameEngineEvent::DeductionLogUpdated(
+                self.deduction_log.clone(),
+            ));
     }
 
     fn rewind_last_good(&mut self) {
-        while self.history_index > 0 && self.current_board.is_incorrect() {
+        while self.history_index > self.first_undoable_index() && self.current_board.is_incorrect()
+        {
             self.history_index -= 1;
             self.current_board = self.history[self.history_index].clone();
             self.sync_board_display(GameBoardChangeReason::Undo);
@@ -615,6 +1772,13 @@ impl GameEngine {
                 .unwrap()
                 .as_secs() as i64,
             playthrough_id: self.current_playthrough_id,
+            clues_hidden: self.clues_were_hidden,
+            // only a seed that regenerates the puzzle counts it as completed
+            seed: self
+                .current_board
+                .solution
+                .puzzle_code()
+                .map(|code| code.seed),
         };
         stats
     }
@@ -622,6 +1786,49 @@ impl GameEngine {
     fn handle_clue_toggle_complete(&mut self, clue_address: ClueAddress) {
         let mut current_board = self.current_board.as_ref().clone();
         current_board.toggle_clue_completed(clue_address);
+        self.drop_completed_clue_selection(&current_board);
+        self.push_board(current_board, GameBoardChangeReason::ClueStatusChanged);
+        self.sync_clue_selection();
+    }
+
+    /// Let go of the selected clue once `board` has it completed, so a greyed-out clue doesn't
+    /// keep the others dimmed. Un-completing a clue leaves it selected.
+    fn drop_completed_clue_selection(&mut self, board: &GameBoard) {
+        let selected_completed = self
+            .current_selected_clue
+            .as_ref()
+            .is_some_and(|addressed_clue| board.is_clue_completed(&addressed_clue.address()));
+        if selected_completed {
+            self.current_selected_clue = None;
+            self.clue_focused = false;
+        }
+    }
+
+    /// Grey out every clue the board fully satisfies that isn't already completed. All of them
+    /// change in a single board push, so one undo brings them back.
+    fn complete_all_satisfied_clues(&mut self) {
+        let mut current_board = self.current_board.as_ref().clone();
+        let satisfied_clues = self
+            .clue_set
+            .all_clues()
+            .filter(|addressed_clue| {
+                !current_board.is_clue_completed(&addressed_clue.address())
+                    && is_clue_fully_completed(&addressed_clue.clue, &current_board)
+            })
+            .map(|addressed_clue| addressed_clue.address())
+            .collect::<Vec<_>>();
+        if satisfied_clues.is_empty() {
+            return;
+        }
+        trace!(
+            target: "game_state",
+            "Completing {} satisfied clues",
+            satisfied_clues.len()
+        );
+        for clue_address in satisfied_clues {
+            current_board.toggle_clue_completed(clue_address);
+        }
+        self.drop_completed_clue_selection(&current_board);
         self.push_board(current_board, GameBoardChangeReason::ClueStatusChanged);
         self.sync_clue_selection();
     }
@@ -639,6 +1846,13 @@ impl GameEngine {
         }
     }
 
+    fn handle_focus_lost(&mut self) {
+        // a finished puzzle has nothing left to hide
+        if self.settings.pause_on_focus_loss && self.timer_state.ended_timestamp.is_none() {
+            self.pause_game();
+        }
+    }
+
     fn resume_game(&mut self) {
         if self.is_paused {
             self.is_paused = false;
@@ -659,6 +1873,20 @@ impl GameEngine {
         self.sync_clue_selection();
     }
 
+    fn set_home_clue(&mut self) {
+        self.home_clue = self
+            .current_selected_clue
+            .as_ref()
+            .map(|addressed_clue| addressed_clue.address());
+        trace!(target: "game_state", "Home clue set to {:?}", self.home_clue);
+    }
+
+    fn jump_to_home_clue(&mut self) {
+        if self.home_clue.is_some() {
+            self.focus_clue(self.home_clue);
+        }
+    }
+
     fn maybe_reset_clue_hint(&mut self) {
         if let Some(addressed_clue) = self.current_clue_hint.clone() {
             // different clue selected? Clear it.
@@ -697,11 +1925,596 @@ impl GameEngine {
             .emit(GameEngineEvent::SettingsChanged(self.settings.clone()));
     }
 
+    /// Save the game in progress if the board changed since the last autosave. Skipped while
+    /// paused, since nothing can change then. Returns true if the game was saved.
+    pub fn autosave(&mut self) -> bool {
+        if self.is_paused || !self.has_unsaved_changes {
+            return false;
+        }
+        if !self.get_game_save_state().save() {
+            log::error!(target: "game_state", "Autosave failed");
+            return false;
+        }
+        self.has_unsaved_changes = false;
+        true
+    }
+
+    /// The game to save. Tentative sandbox moves aren't saved; the game resumes from the board
+    /// the sandbox started from, as if it had been discarded.
     pub fn get_game_save_state(&self) -> GameStateSnapshot {
+        let (board, history, history_index) = match &self.sandbox {
+            Some(sandbox) => (
+                &sandbox.board,
+                self.history_without_sandbox_moves(sandbox),
+                sandbox.history_index,
+            ),
+            None => (
+                &self.current_board,
+                self.history.clone(),
+                self.history_index,
+            ),
+        };
         GameStateSnapshot::new(
-            self.current_board.as_ref().clone(),
+            board.as_ref().clone(),
             self.timer_state.paused(SystemTime::now()),
             self.hints_used,
         )
+        .with_deduction_log(self.deduction_log.clone())
+        .with_home_clue(self.home_clue)
+        .with_revealed_tiles(self.revealed_tiles.clone())
+        .with_rating(self.puzzle_rating)
+        .with_difficulty_rating(self.difficulty_rating.clone())
+        .with_history(&history, history_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Channel;
+    use crate::game::tests::create_test_solution;
+    use crate::model::AutoSolveMode;
+    use serial_test::serial;
+    use ClueOrientation::{Horizontal, Vertical};
+
+    fn clue_count(orientation: ClueOrientation) -> usize {
+        match orientation {
+            Horizontal => 3,
+            Vertical => 5,
+        }
+    }
+
+    #[test]
+    fn test_step_clue_position_within_panel() {
+        for wrap_within in [false, true] {
+            assert_eq!(
+                GameEngine::step_clue_position(Horizontal, 1, 1, clue_count, wrap_within),
+                (Horizontal, 2)
+            );
+            assert_eq!(
+                GameEngine::step_clue_position(Vertical, 1, -1, clue_count, wrap_within),
+                (Vertical, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_step_clue_position_crosses_over() {
+        // past the end
+        assert_eq!(
+            GameEngine::step_clue_position(Horizontal, 2, 1, clue_count, false),
+            (Vertical, 0)
+        );
+        // before the start
+        assert_eq!(
+            GameEngine::step_clue_position(Horizontal, 0, -1, clue_count, false),
+            (Vertical, 4)
+        );
+        assert_eq!(
+            GameEngine::step_clue_position(Vertical, 0, -1, clue_count, false),
+            (Horizontal, 2)
+        );
+    }
+
+    #[test]
+    fn test_step_clue_position_wraps_within_orientation() {
+        // past the end
+        assert_eq!(
+            GameEngine::step_clue_position(Horizontal, 2, 1, clue_count, true),
+            (Horizontal, 0)
+        );
+        assert_eq!(
+            GameEngine::step_clue_position(Vertical, 4, 1, clue_count, true),
+            (Vertical, 0)
+        );
+        // before the start
+        assert_eq!(
+            GameEngine::step_clue_position(Horizontal, 0, -1, clue_count, true),
+            (Horizontal, 2)
+        );
+        assert_eq!(
+            GameEngine::step_clue_position(Vertical, 0, -1, clue_count, true),
+            (Vertical, 4)
+        );
+    }
+
+    #[test]
+    fn test_clue_actionability() {
+        let clue_set = ClueSet::new(vec![Clue::parse("|+0a,+1a|")]);
+        let address = clue_set.all_clues().next().unwrap().address();
+        let mut board = GameBoard::new(create_test_solution(3, 4));
+
+        // nothing placed yet, so the clue has nothing to say
+        let actionability = GameEngine::clue_actionability(&board, &clue_set);
+        assert_eq!(actionability.get(&address), Some(&false));
+
+        board.select_tile_at_position(0, Tile::new(0, 'a'));
+        let actionability = GameEngine::clue_actionability(&board, &clue_set);
+        assert_eq!(actionability.get(&address), Some(&true));
+
+        // satisfied: no deductions left, but it can be completed
+        board.select_tile_at_position(0, Tile::new(1, 'a'));
+        let actionability = GameEngine::clue_actionability(&board, &clue_set);
+        assert_eq!(actionability.get(&address), Some(&true));
+    }
+
+    #[test]
+    fn test_obvious_consequence() {
+        let clue_set = ClueSet::new(vec![Clue::parse("|+0a,+1a|"), Clue::parse("|+0b,+2c|")]);
+        let mut board = GameBoard::new(create_test_solution(3, 4));
+
+        // 0a placed: the first clue puts 1a in the same column; the second isn't involved
+        board.select_tile_at_position(0, Tile::new(0, 'a'));
+        let (deduction, address) =
+            GameEngine::obvious_consequence(&board, &clue_set, Tile::new(0, 'a')).unwrap();
+        assert_eq!(deduction.column, 0);
+        assert_eq!(deduction.tile_assertion.tile, Tile::new(1, 'a'));
+        assert!(deduction.is_positive());
+        assert_eq!(
+            clue_set.get_clue(address).unwrap().clue,
+            Clue::parse("|+0a,+1a|")
+        );
+
+        // once 1a is placed there's nothing left to offer
+        board.select_tile_at_position(0, Tile::new(1, 'a'));
+        assert!(GameEngine::obvious_consequence(&board, &clue_set, Tile::new(0, 'a')).is_none());
+    }
+
+    #[test]
+    fn test_placements_first_keeps_relative_order() {
+        let result = DeductionResult {
+            deductions: vec![
+                Deduction::parse("1a not col 0"),
+                Deduction::parse("0b is col 2"),
+                Deduction::parse("1a not col 1"),
+                Deduction::parse("2c is col 3"),
+            ],
+            clue: None,
+        }
+        .placements_first();
+
+        assert_eq!(
+            result.deductions,
+            vec![
+                Deduction::parse("0b is col 2"),
+                Deduction::parse("2c is col 3"),
+                Deduction::parse("1a not col 0"),
+                Deduction::parse("1a not col 1"),
+            ]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_prefer_placement_hints_looks_past_the_first_clue() {
+        let mut board = GameBoard::new(create_test_solution(3, 4));
+        board.select_tile_at_position(0, Tile::new(0, 'a'));
+        board.set_clues(Arc::new(ClueSet::new(vec![
+            Clue::parse("<+0a,-0c>"),
+            Clue::parse("|+0a,+2a|"),
+        ])));
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(board, TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+
+        // in solver order, the clue that only eliminates comes first
+        let result = engine.find_deductions().unwrap();
+        assert!(!result.has_placement());
+
+        engine.settings.prefer_placement_hints = true;
+        let result = engine.find_deductions().unwrap();
+        assert_eq!(
+            result.deductions[0],
+            Deduction::parse("2a is col 0 (LastRemaining)")
+        );
+    }
+
+    #[test]
+    fn test_hide_cascaded_cells() {
+        let mut board = GameBoard::new(create_test_solution(3, 4));
+        board.select_tile_at_position(0, Tile::new(0, 'a'));
+        board.select_tile_at_position(1, Tile::new(0, 'b'));
+        board.select_tile_at_position(2, Tile::new(0, 'c'));
+
+        let hidden = vec![(1, Tile::new(0, 'b')), (2, Tile::new(0, 'c'))];
+        let displayed = GameEngine::hide_cascaded_cells(&board, &hidden);
+        assert!(displayed.has_selection(0, 0));
+        assert!(!displayed.has_selection(0, 1));
+        assert!(!displayed.has_selection(0, 2));
+        // the board itself keeps every placement
+        assert!(board.has_selection(0, 2));
+
+        let displayed = GameEngine::hide_cascaded_cells(&board, &hidden[1..]);
+        assert!(displayed.has_selection(0, 1));
+        assert!(!displayed.has_selection(0, 2));
+    }
+
+    #[test]
+    #[serial]
+    fn test_completing_focused_clue_drops_selection() {
+        let mut board = GameBoard::new(create_test_solution(3, 4));
+        board.set_clues(Arc::new(ClueSet::new(vec![
+            Clue::parse("|+0a,+1a|"),
+            Clue::parse("|+0b,+2c|"),
+        ])));
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(board, TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+        let address = engine.clue_set.all_clues().next().unwrap().address();
+
+        engine.focus_clue(Some(address));
+        engine.handle_clue_toggle_complete(address);
+        assert!(engine.current_board.is_clue_completed(&address));
+        assert!(engine.current_selected_clue.is_none());
+        assert!(!engine.clue_focused);
+
+        // un-completing a focused clue keeps it focused
+        engine.focus_clue(Some(address));
+        engine.handle_clue_toggle_complete(address);
+        assert!(!engine.current_board.is_clue_completed(&address));
+        assert_eq!(
+            engine
+                .current_selected_clue
+                .as_ref()
+                .map(|addressed_clue| addressed_clue.address()),
+            Some(address)
+        );
+        assert!(engine.clue_focused);
+    }
+
+    #[test]
+    #[serial]
+    fn test_goto_history_then_move_drops_later_boards() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+        for col in 0..3 {
+            let mut board = engine.current_board.as_ref().clone();
+            board.remove_candidate(col, Tile::new(0, solution.grid[0][3]));
+            engine.push_board(board, GameBoardChangeReason::TileStatusChanged);
+        }
+        assert_eq!(engine.history.len(), 4);
+
+        engine.goto_history(1);
+        assert_eq!(engine.history_index, 1);
+        assert!(Arc::ptr_eq(&engine.current_board, &engine.history[1]));
+
+        // out of range jumps stop at the last board
+        engine.goto_history(10);
+        assert_eq!(engine.history_index, 3);
+
+        engine.goto_history(1);
+        let mut board = engine.current_board.as_ref().clone();
+        board.remove_candidate(3, Tile::new(1, solution.grid[1][0]));
+        engine.push_board(board, GameBoardChangeReason::TileStatusChanged);
+        assert_eq!(engine.history.len(), 3);
+        assert_eq!(engine.history_index, 2);
+        assert!(Arc::ptr_eq(&engine.current_board, &engine.history[2]));
+    }
+
+    #[test]
+    #[serial]
+    fn test_discarding_sandbox_keeps_hints_and_redo() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+        for col in 0..2 {
+            let mut board = engine.current_board.as_ref().clone();
+            board.remove_candidate(col, Tile::new(0, solution.grid[0][3]));
+            engine.push_board(board, GameBoardChangeReason::TileStatusChanged);
+        }
+        engine.undo();
+        let redo = engine.history[2].clone();
+
+        engine.begin_sandbox();
+        engine.increment_hints_used();
+        let mut board = engine.current_board.as_ref().clone();
+        board.remove_candidate(3, Tile::new(1, solution.grid[1][0]));
+        engine.push_board(board, GameBoardChangeReason::TileStatusChanged);
+        assert_eq!(engine.get_game_save_state().hints_used, 1);
+        engine.discard_sandbox();
+
+        assert_eq!(engine.hints_used, 1);
+        assert_eq!(engine.history_index, 1);
+        assert_eq!(engine.history.len(), 3);
+        assert!(Arc::ptr_eq(&engine.history[2], &redo));
+        engine.redo();
+        assert!(Arc::ptr_eq(&engine.current_board, &redo));
+    }
+
+    #[test]
+    fn test_cell_to_reveal_skips_placed_tiles() {
+        let solution = create_test_solution(3, 4);
+        let mut board = GameBoard::new(solution.clone());
+        let first_tile = Tile::new(0, solution.grid[0][0]);
+        assert_eq!(
+            GameEngine::cell_to_reveal(&board),
+            Some(Deduction::new(
+                0,
+                TileAssertion {
+                    tile: first_tile,
+                    assertion: true,
+                },
+            ))
+        );
+
+        // the tile is placed, though in the wrong column: both that column and the tile are
+        // passed over
+        board.select_tile_at_position(1, first_tile);
+        let revealed = GameEngine::cell_to_reveal(&board).unwrap();
+        assert_eq!(revealed.column, 2);
+        assert_eq!(
+            revealed.tile_assertion.tile,
+            Tile::new(0, solution.grid[0][2])
+        );
+
+        // an eliminated solution tile is the player's mistake to find, not a cell to reveal
+        board.remove_candidate(2, Tile::new(0, solution.grid[0][2]));
+        assert_eq!(GameEngine::cell_to_reveal(&board).unwrap().column, 3);
+
+        // a solved board has nothing left to reveal
+        for tile in solution.all_tiles() {
+            board.select_tile_from_solution(tile);
+        }
+        assert!(GameEngine::cell_to_reveal(&board).is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn test_starting_board_survives_resuming() {
+        let snapshot = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42));
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+
+        // a save made partway through, with no history reaching back to the start
+        let mut board = snapshot.board.clone();
+        board.select_tile_from_solution(board.solution.get(0, 0));
+        board.select_tile_from_solution(board.solution.get(1, 1));
+        let resumed = GameStateSnapshot::new(board, TimerState::default(), 0)
+            .with_revealed_tiles(snapshot.revealed_tiles.clone());
+        engine.set_game_state(&resumed, GameBoardChangeReason::GameLoaded);
+
+        assert!(engine.starting_board().same_content(&snapshot.board));
+        assert_eq!(
+            engine.get_game_save_state().revealed_tiles,
+            snapshot.revealed_tiles
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_showing_incorrect_cells_costs_hints() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+
+        // nothing placed wrongly, so nothing to show or charge for
+        engine.show_incorrect_cells();
+        assert_eq!(engine.hints_used, 0);
+
+        let mut board = engine.current_board.as_ref().clone();
+        board.select_tile_at_position(0, Tile::new(0, solution.grid[0][1]));
+        engine.push_board(board, GameBoardChangeReason::TileStatusChanged);
+        engine.show_incorrect_cells();
+        assert_eq!(engine.hints_used, ERROR_CORRECTION_HINT_PENALTY);
+    }
+
+    #[test]
+    #[serial]
+    fn test_hint_reveals_a_cell_when_no_deduction_is_left() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        // no clues, so nothing to deduce
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+
+        assert_eq!(engine.show_hint(), Some(HintKind::RevealCell));
+        assert_eq!(engine.hints_used, REVEAL_CELL_HINT_COST);
+        assert!(engine.current_board.has_selection(0, 0));
+    }
+
+    #[test]
+    #[serial]
+    fn test_retried_submission_costs_hints() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.settings.retry_incorrect_submissions = true;
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+
+        // a full board with the first two tiles of the first row swapped
+        let mut board = engine.current_board.as_ref().clone();
+        for row in 0..solution.n_rows {
+            for col in 0..solution.n_variants {
+                let source_col = match (row, col) {
+                    (0, 0) => 1,
+                    (0, 1) => 0,
+                    _ => col,
+                };
+                board.select_tile_at_position(col, Tile::new(row, solution.grid[row][source_col]));
+            }
+        }
+        engine.push_board(board, GameBoardChangeReason::TileStatusChanged);
+        assert!(engine.current_board.is_complete());
+
+        engine.complete_puzzle();
+        assert_eq!(engine.hints_used, ERROR_CORRECTION_HINT_PENALTY);
+        assert_eq!(engine.timer_state.ended_timestamp, None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_solution_walkthrough_is_free_only_once_solved() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+
+        engine.show_solution_walkthrough();
+        assert_eq!(engine.hints_used, SOLUTION_WALKTHROUGH_HINT_COST);
+
+        engine.timer_state = engine.timer_state.ended(SystemTime::now());
+        engine.show_solution_walkthrough();
+        assert_eq!(engine.hints_used, SOLUTION_WALKTHROUGH_HINT_COST);
+    }
+
+    #[test]
+    #[serial]
+    fn test_undo_and_redo_bring_candidate_tags_back() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.set_game_state(
+            &GameStateSnapshot::new(GameBoard::new(solution.clone()), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+        let tile = Tile::new(0, 'b');
+
+        engine.handle_cell_tag(0, 0, 'b', Some(CandidateColor::Red));
+        engine.handle_cell_tag(0, 0, 'b', Some(CandidateColor::Blue));
+        assert_eq!(
+            engine.current_board.candidate_color(0, tile),
+            Some(CandidateColor::Blue)
+        );
+
+        engine.undo();
+        assert_eq!(
+            engine.current_board.candidate_color(0, tile),
+            Some(CandidateColor::Red)
+        );
+        engine.undo();
+        assert_eq!(engine.current_board.candidate_color(0, tile), None);
+
+        engine.redo();
+        engine.redo();
+        assert_eq!(
+            engine.current_board.candidate_color(0, tile),
+            Some(CandidateColor::Blue)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_auto_solve_cascade_undoes_in_one_step() {
+        let solution = create_test_solution(3, 4);
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.settings.auto_solve_mode = AutoSolveMode::Board;
+        engine.settings.cascade_delay_ms = 1000;
+        // every row is one tile short, and the first row two
+        let mut board = GameBoard::new(solution.clone());
+        for row in 0..3 {
+            for variant in solution.variants.iter().skip(if row == 0 { 2 } else { 1 }) {
+                board.select_tile_from_solution(Tile::new(row, *variant));
+            }
+        }
+        engine.set_game_state(
+            &GameStateSnapshot::new(board.clone(), TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+        let history_index = engine.history_index;
+
+        let tile = Tile::new(0, solution.variants[1]);
+        let (_, col) = solution.find_tile(tile);
+        engine.handle_cell_select(0, col, Some(tile.variant));
+        assert!(engine.current_board.is_complete());
+        assert_eq!(engine.history_index, history_index + 1);
+        // the cascade is still being revealed
+        assert!(!engine.cascade_hidden.is_empty());
+
+        engine.undo();
+        assert_eq!(engine.history_index, history_index);
+        assert_eq!(engine.current_board.progress(), board.progress());
+        assert!(engine.cascade_hidden.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_auto_solve_eliminations_have_reasons() {
+        let mut board = GameBoard::new(create_test_solution(3, 4));
+        board.select_tile_at_position(0, Tile::new(0, 'a'));
+        // eliminated by the player, so without a recorded reason
+        board.remove_candidate(3, Tile::new(0, 'c'));
+        board.set_clues(Arc::new(ClueSet::new(vec![Clue::parse("<+0a,-0c>")])));
+        let (emitter, _observer) = Channel::<GameEngineEvent>::new();
+        let engine = GameEngine::new(emitter, Settings::default());
+        let mut engine = engine.borrow_mut();
+        engine.settings.track_elimination_reasons = true;
+        engine.set_game_state(
+            &GameStateSnapshot::new(board, TimerState::default(), 0),
+            GameBoardChangeReason::NewGame,
+        );
+
+        // the clue rules 0c out of column 1, which leaves auto-solve to place it in column 2
+        engine.try_solve();
+        assert!(engine
+            .current_board
+            .is_selected_in_column(&Tile::new(0, 'c'), 2));
+        assert!(matches!(
+            engine.current_board.get_elimination_reason(0, 1, 'c'),
+            Some(EliminationReason::Deduction { clue: Some(_), .. })
+        ));
+        assert_eq!(
+            engine.current_board.get_elimination_reason(0, 3, 'c'),
+            Some(EliminationReason::PlacedElsewhere(2))
+        );
     }
 }
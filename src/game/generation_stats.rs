@@ -0,0 +1,100 @@
+use std::{sync::Mutex, time::Duration};
+
+use log::{info, trace};
+
+use crate::model::Difficulty;
+
+/// Wall-clock times of the puzzles generated at one difficulty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerationTimes {
+    pub count: u32,
+    pub total: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl GenerationTimes {
+    fn new(elapsed: Duration) -> Self {
+        Self {
+            count: 1,
+            total: elapsed,
+            min: elapsed,
+            max: elapsed,
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.total += elapsed;
+        self.min = self.min.min(elapsed);
+        self.max = self.max.max(elapsed);
+    }
+
+    pub fn average(&self) -> Duration {
+        self.total / self.count.max(1)
+    }
+}
+
+/// Generation runs on a spawned thread, so the times are shared behind a lock for the whole
+/// process. There are only a handful of difficulties; a Vec is plenty.
+static GENERATION_TIMES: Mutex<Vec<(Difficulty, GenerationTimes)>> = Mutex::new(Vec::new());
+
+pub fn record_generation_time(difficulty: Difficulty, elapsed: Duration) {
+    trace!(
+        target: "game_state",
+        "Generated {:?} puzzle in {:?}",
+        difficulty,
+        elapsed
+    );
+    let Ok(mut generation_times) = GENERATION_TIMES.lock() else {
+        return;
+    };
+    match generation_times.iter_mut().find(|(d, _)| *d == difficulty) {
+        Some((_, times)) => times.record(elapsed),
+        None => generation_times.push((difficulty, GenerationTimes::new(elapsed))),
+    }
+}
+
+pub fn generation_times(difficulty: Difficulty) -> Option<GenerationTimes> {
+    GENERATION_TIMES
+        .lock()
+        .ok()?
+        .iter()
+        .find(|(d, _)| *d == difficulty)
+        .map(|(_, times)| *times)
+}
+
+/// Log the min/avg/max generation time of every difficulty generated this session
+pub fn log_generation_times() {
+    let Ok(generation_times) = GENERATION_TIMES.lock() else {
+        return;
+    };
+    for (difficulty, times) in generation_times.iter() {
+        info!(
+            target: "game_state",
+            "{:?} generation times over {} puzzles: min {:?}, avg {:?}, max {:?}",
+            difficulty,
+            times.count,
+            times.min,
+            times.average(),
+            times.max
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generation_times_accumulate() {
+        let mut times = GenerationTimes::new(Duration::from_millis(300));
+        times.record(Duration::from_millis(100));
+        times.record(Duration::from_millis(500));
+
+        assert_eq!(times.count, 3);
+        assert_eq!(times.min, Duration::from_millis(100));
+        assert_eq!(times.max, Duration::from_millis(500));
+        assert_eq!(times.average(), Duration::from_millis(300));
+    }
+}
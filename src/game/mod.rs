@@ -1,4 +1,8 @@
+pub mod achievements;
+pub mod daily;
+pub mod favorites;
 pub mod game_engine;
+pub mod generation_stats;
 pub mod settings;
 pub mod stats_manager;
 
@@ -29,6 +33,7 @@ pub mod tests {
             variants_range,
             difficulty: Difficulty::Easy,
             seed: 0,
+            ..Default::default()
         })
     }
 }
@@ -1,6 +1,10 @@
-use crate::model::Difficulty;
+use crate::model::{
+    deserialize_clue_type_weights, AutoSolveMode, CandidateDisplay, ClueSort, ClueType, Difficulty,
+    Keymap,
+};
 use glib;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -21,8 +25,144 @@ pub struct Settings {
     #[serde(default)]
     pub touch_screen_controls: bool,
 
+    /// Replaced by `auto_solve_mode`; only read to migrate older settings
+    #[serde(default, skip_serializing)]
+    auto_solve_enabled: Option<bool>,
+
+    #[serde(default)]
+    pub auto_solve_mode: AutoSolveMode,
+
     #[serde(default = "default_true")]
-    pub auto_solve_enabled: bool,
+    pub progressive_clue_reveal: bool,
+
+    #[serde(default)]
+    pub lock_solved_cells: bool,
+
+    #[serde(default)]
+    pub clue_nav_wraps_within_orientation: bool,
+
+    #[serde(default)]
+    pub center_partial_candidate_row: bool,
+
+    #[serde(default)]
+    pub log_hint_deductions: bool,
+
+    /// Difficulty for the first game of a session when no saved game is resumed
+    #[serde(default)]
+    pub preferred_difficulty: Difficulty,
+
+    #[serde(default = "default_true")]
+    pub resume_last_game: bool,
+
+    #[serde(default)]
+    pub track_elimination_reasons: bool,
+
+    #[serde(default)]
+    pub hide_eliminated_candidates: bool,
+
+    /// Seconds random puzzle generation may take before falling back to a simpler configuration;
+    /// 0 means no limit. Seeded puzzles always get the full configuration.
+    #[serde(default)]
+    pub generation_time_limit_secs: u64,
+
+    /// After a tile is placed, apply the deductions of the clues involving it in the same step
+    #[serde(default)]
+    pub eager_clue_propagation: bool,
+
+    /// Hide completed horizontal clues and pack the rest together, shrinking the clue panel
+    #[serde(default)]
+    pub collapse_completed_clues: bool,
+
+    /// Seconds between automatic saves of the game in progress; 0 saves only on close
+    #[serde(default)]
+    pub autosave_interval_secs: u64,
+
+    /// Draw the negative tiles of clues small and faded behind a "¬" badge, so they don't read as
+    /// positive tiles
+    #[serde(default)]
+    pub compact_negative_tiles: bool,
+    /// Draw faint guides between the puzzle grid's columns and number the columns above it, to
+    /// make it easier to judge which cells are adjacent
+    #[serde(default)]
+    pub show_column_guides: bool,
+    /// Practice mode: submitting a filled but incorrect board highlights the wrong tiles and lets
+    /// the player keep editing, instead of counting as a mistake. The highlight costs hints.
+    #[serde(default)]
+    pub retry_incorrect_submissions: bool,
+    /// Challenge mode: submitting a filled but incorrect board adds a time penalty
+    #[serde(default)]
+    pub incorrect_submission_penalty: bool,
+    /// Eliminating a cell's last candidate is refused; with this on, it's allowed after clearing the
+    /// cell a second time to confirm
+    #[serde(default)]
+    pub confirm_last_candidate_elimination: bool,
+    /// Keep spotlighting the clue a hint came from after the hint's highlight clears, until another
+    /// clue is focused
+    #[serde(default)]
+    pub hint_keeps_clue_selected: bool,
+    /// How the candidates in each cell are arranged; a single-column list can read better on
+    /// narrow or portrait displays
+    #[serde(default)]
+    pub candidate_display: CandidateDisplay,
+    /// Dim clues that yield no deductions on the current board and can't be completed yet, as a
+    /// hint to look elsewhere
+    #[serde(default)]
+    pub dim_inactive_clues: bool,
+    /// Show how many of each row's variants are still unplaced beside the grid
+    #[serde(default)]
+    pub show_row_progress: bool,
+    /// Delay in milliseconds between revealing each cell auto-solve places after a move or a
+    /// hint, so chain reactions play out one cell at a time; 0 shows them all at once
+    #[serde(default)]
+    pub cascade_delay_ms: u64,
+    /// Pause the game whenever the window loses focus, so the board isn't left on
+    /// screen (and the clock running) while you're away
+    #[serde(default)]
+    pub pause_on_focus_loss: bool,
+    /// Set once the first-launch welcome has been answered. Settings saved before this existed
+    /// belong to returning players, so it defaults to true when missing
+    #[serde(default = "default_true")]
+    pub has_launched_before: bool,
+    /// Emoji category always shown in the grid's top row, whatever the difficulty; None keeps the
+    /// default order
+    #[serde(default)]
+    pub featured_icon_category: Option<usize>,
+    /// Show the running timer while playing; when off, the elapsed time is only revealed
+    /// once the puzzle is solved
+    #[serde(default = "default_true")]
+    pub show_timer_during_play: bool,
+    /// Picking a difficulty in the header starts a new game right away; when off, it only sets
+    /// the difficulty of the next new game
+    #[serde(default = "default_true")]
+    pub difficulty_dropdown_immediate: bool,
+    /// Difficulty picked in the header for the next new game, when that doesn't start one right
+    /// away
+    #[serde(default)]
+    pub next_difficulty: Option<Difficulty>,
+    /// Label the clue panels, for players who don't yet know which is which
+    #[serde(default = "default_true")]
+    pub show_clue_panel_headers: bool,
+    /// Challenge and accessibility testing: lay out each cell's candidates in a shuffled order, fixed
+    /// per puzzle, so they have to be told apart by their emoji rather than their position
+    #[serde(default)]
+    pub shuffle_candidate_order: bool,
+    /// After placing a tile, offer to place a tile that a single clue now forces; unlike eager
+    /// propagation, nothing is placed until the player accepts
+    #[serde(default)]
+    pub suggest_obvious_consequences: bool,
+    /// Keys bound to the gameplay actions that can be rebound, e.g. for switch access
+    #[serde(default)]
+    pub keymap: Keymap,
+    /// When a hint's clue yields both placements and eliminations, show a placement first
+    #[serde(default)]
+    pub prefer_placement_hints: bool,
+    /// Order the clues are shown in within their panels
+    #[serde(default)]
+    pub clue_sort: ClueSort,
+    /// Weights for choosing clue types in new random puzzles, over the difficulty's own; a weight
+    /// of 0 leaves the type out. Seeded puzzles ignore them, so a shared seed gives the same puzzle.
+    #[serde(default, deserialize_with = "deserialize_clue_type_weights")]
+    pub clue_type_weights: HashMap<ClueType, u32>,
 }
 
 // Helper functions for default values
@@ -40,8 +180,45 @@ impl Default for Settings {
             clue_tooltips_enabled: true,
             clue_spotlight_enabled: false,
             touch_screen_controls: false,
-            auto_solve_enabled: true,
-            version: 1,
+            auto_solve_enabled: None,
+            auto_solve_mode: AutoSolveMode::default(),
+            progressive_clue_reveal: true,
+            lock_solved_cells: false,
+            clue_nav_wraps_within_orientation: false,
+            center_partial_candidate_row: false,
+            log_hint_deductions: false,
+            preferred_difficulty: Difficulty::default(),
+            resume_last_game: true,
+            track_elimination_reasons: false,
+            hide_eliminated_candidates: false,
+            generation_time_limit_secs: 0,
+            eager_clue_propagation: false,
+            collapse_completed_clues: false,
+            autosave_interval_secs: 0,
+            compact_negative_tiles: false,
+            show_column_guides: false,
+            retry_incorrect_submissions: false,
+            incorrect_submission_penalty: false,
+            confirm_last_candidate_elimination: false,
+            hint_keeps_clue_selected: false,
+            candidate_display: CandidateDisplay::default(),
+            dim_inactive_clues: false,
+            show_row_progress: false,
+            cascade_delay_ms: 0,
+            pause_on_focus_loss: false,
+            has_launched_before: false,
+            featured_icon_category: None,
+            show_timer_during_play: true,
+            difficulty_dropdown_immediate: true,
+            next_difficulty: None,
+            show_clue_panel_headers: true,
+            shuffle_candidate_order: false,
+            suggest_obvious_consequences: false,
+            keymap: Keymap::default(),
+            prefer_placement_hints: false,
+            clue_sort: ClueSort::default(),
+            clue_type_weights: HashMap::new(),
+            version: 2,
         }
     }
 }
@@ -78,11 +255,17 @@ impl Settings {
     }
 
     fn migrate(&mut self) {
-        match self.version {
-            0 => {
-                self.version = 1;
-            }
-            _ => (),
+        if self.version == 0 {
+            self.version = 1;
+        }
+        if self.version == 1 {
+            // preferred difficulty is new; keep starting where the player left off
+            self.preferred_difficulty = self.difficulty;
+            self.version = 2;
+        }
+        // auto-solve used to be on or off; on keeps the default, row by row
+        if self.auto_solve_enabled.take() == Some(false) {
+            self.auto_solve_mode = AutoSolveMode::Off;
         }
     }
 
@@ -96,3 +279,89 @@ impl Settings {
             .ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::VerticalClueType;
+
+    #[test]
+    fn test_migrate_keeps_last_difficulty_as_preferred() {
+        let mut settings: Settings =
+            serde_json::from_str(r#"{"version": 1, "difficulty": "Hard"}"#).unwrap();
+        settings.migrate();
+
+        assert_eq!(settings.preferred_difficulty, Difficulty::Hard);
+        assert!(settings.resume_last_game);
+        assert_eq!(settings.version, 2);
+    }
+
+    #[test]
+    fn test_migrate_leaves_current_version_alone() {
+        let mut settings: Settings = serde_json::from_str(
+            r#"{"version": 2, "difficulty": "Hard", "preferred_difficulty": "Easy"}"#,
+        )
+        .unwrap();
+        settings.migrate();
+
+        assert_eq!(settings.preferred_difficulty, Difficulty::Easy);
+    }
+
+    #[test]
+    fn test_migrate_carries_over_disabled_auto_solve() {
+        let mut settings: Settings =
+            serde_json::from_str(r#"{"version": 2, "auto_solve_enabled": false}"#).unwrap();
+        settings.migrate();
+        assert_eq!(settings.auto_solve_mode, AutoSolveMode::Off);
+        assert!(!serde_json::to_string(&settings)
+            .unwrap()
+            .contains("auto_solve_enabled"));
+
+        let mut settings: Settings =
+            serde_json::from_str(r#"{"version": 2, "auto_solve_enabled": true}"#).unwrap();
+        settings.migrate();
+        assert_eq!(settings.auto_solve_mode, AutoSolveMode::Row);
+    }
+
+    #[test]
+    fn test_existing_settings_count_as_launched_before() {
+        let settings: Settings =
+            serde_json::from_str(r#"{"version": 2, "difficulty": "Hard"}"#).unwrap();
+
+        assert!(settings.has_launched_before);
+        assert!(!Settings::default().has_launched_before);
+    }
+
+    #[test]
+    fn test_new_settings_default_for_existing_files() {
+        let settings: Settings = serde_json::from_str(r#"{"version": 2}"#).unwrap();
+
+        assert!(settings.show_timer_during_play);
+        assert!(Settings::default().show_timer_during_play);
+        assert!(settings.difficulty_dropdown_immediate);
+        assert_eq!(settings.next_difficulty, None);
+        assert!(settings.show_clue_panel_headers);
+    }
+
+    #[test]
+    fn test_clue_type_weights_round_trip() {
+        let one_matches_either = ClueType::Vertical(VerticalClueType::OneMatchesEither);
+        let mut settings = Settings::default();
+        settings.clue_type_weights.insert(one_matches_either, 0);
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#""clue_type_weights":{"one-matches-either":0}"#));
+        let loaded: Settings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.clue_type_weights.get(&one_matches_either), Some(&0));
+    }
+
+    #[test]
+    fn test_unknown_clue_type_weights_are_dropped() {
+        let json = r#"{"clue_type_weights":{"one-matches-either":0,"from-the-future":3}}"#;
+        let loaded: Settings = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            loaded.clue_type_weights,
+            HashMap::from([(ClueType::Vertical(VerticalClueType::OneMatchesEither), 0)])
+        );
+    }
+}
@@ -1,13 +1,29 @@
+use super::achievements::Achievement;
+use super::daily::{self, DAILY_DIFFICULTY};
 use crate::model::{Difficulty, GameStats, GlobalStats};
-use std::collections::HashMap;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 
+/// The dimension completed games are grouped by when querying stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsGrouping {
+    Difficulty(Difficulty),
+    GridSize(usize),
+}
+
 #[derive(Debug)]
 pub struct StatsManager {
     data_dir: PathBuf,
     scores: HashMap<Difficulty, Vec<GameStats>>,
     global_stats: HashMap<Difficulty, GlobalStats>,
+    /// Every (difficulty, seed) ever completed; unlike scores, this is never truncated
+    completed_seeds: HashSet<(Difficulty, u64)>,
+    /// Achievements earned so far, with the timestamp of the game that earned each
+    achievements: BTreeMap<Achievement, i64>,
+    /// The first completion of each daily puzzle, keyed by the puzzle's date as "yyyy-mm-dd"
+    daily_completions: BTreeMap<String, GameStats>,
 }
 
 impl StatsManager {
@@ -21,6 +37,9 @@ impl StatsManager {
             data_dir,
             scores: HashMap::new(),
             global_stats: HashMap::new(),
+            completed_seeds: HashSet::new(),
+            achievements: BTreeMap::new(),
+            daily_completions: BTreeMap::new(),
         };
 
         // Load existing data
@@ -42,6 +61,22 @@ impl StatsManager {
         ))
     }
 
+    fn completed_seeds_path(&self) -> PathBuf {
+        self.data_dir.join("completed_seeds.json")
+    }
+
+    fn achievements_path(&self) -> PathBuf {
+        self.data_dir.join("achievements.json")
+    }
+
+    fn daily_completions_path(&self) -> PathBuf {
+        self.data_dir.join("daily_completions.json")
+    }
+
+    fn daily_key(date: NaiveDate) -> String {
+        date.format("%Y-%m-%d").to_string()
+    }
+
     fn load_all(&mut self) {
         // Initialize empty data for all difficulties
         for difficulty in [
@@ -73,6 +108,24 @@ impl StatsManager {
                 }
             }
         }
+
+        if let Ok(contents) = fs::read_to_string(self.completed_seeds_path()) {
+            if let Ok(completed_seeds) = serde_json::from_str(&contents) {
+                self.completed_seeds = completed_seeds;
+            }
+        }
+
+        if let Ok(contents) = fs::read_to_string(self.achievements_path()) {
+            if let Ok(achievements) = serde_json::from_str(&contents) {
+                self.achievements = achievements;
+            }
+        }
+
+        if let Ok(contents) = fs::read_to_string(self.daily_completions_path()) {
+            if let Ok(daily_completions) = serde_json::from_str(&contents) {
+                self.daily_completions = daily_completions;
+            }
+        }
     }
 
     fn save_scores(&self, difficulty: Difficulty) -> std::io::Result<()> {
@@ -91,7 +144,26 @@ impl StatsManager {
         Ok(())
     }
 
-    pub fn record_game(&mut self, stats: &GameStats) -> std::io::Result<()> {
+    fn save_completed_seeds(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string(&self.completed_seeds)?;
+        fs::write(self.completed_seeds_path(), contents)?;
+        Ok(())
+    }
+
+    fn save_achievements(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string(&self.achievements)?;
+        fs::write(self.achievements_path(), contents)?;
+        Ok(())
+    }
+
+    fn save_daily_completions(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string(&self.daily_completions)?;
+        fs::write(self.daily_completions_path(), contents)?;
+        Ok(())
+    }
+
+    /// Record a completed game, returning the achievements it newly earned
+    pub fn record_game(&mut self, stats: &GameStats) -> std::io::Result<Vec<Achievement>> {
         let difficulty = stats.difficulty;
 
         // Update scores
@@ -105,18 +177,84 @@ impl StatsManager {
         scores.truncate(20);
 
         // Update global stats
-        let global_stats = self.global_stats.entry(difficulty).or_default();
-        global_stats.total_games_played += 1;
-        global_stats.total_time_played += stats.completion_time;
-        global_stats.total_hints_used += stats.hints_used;
+        self.global_stats
+            .entry(difficulty)
+            .or_default()
+            .record_game(stats);
 
         // Save to files
         self.save_scores(difficulty)?;
         self.save_global_stats(difficulty)?;
 
+        if let Some(seed) = stats.seed {
+            if self.completed_seeds.insert((difficulty, seed)) {
+                self.save_completed_seeds()?;
+            }
+            if let Some(date) = daily::daily_date(difficulty, seed) {
+                let key = Self::daily_key(date);
+                if !self.daily_completions.contains_key(&key) {
+                    self.daily_completions.insert(key, stats.clone());
+                    self.save_daily_completions()?;
+                }
+            }
+        }
+
+        let earned: Vec<Achievement> = Achievement::all()
+            .into_iter()
+            .filter(|achievement| {
+                !self.achievements.contains_key(achievement)
+                    && achievement.is_earned_by(stats, |difficulty, seed| {
+                        self.is_seed_completed(difficulty, seed)
+                    })
+            })
+            .collect();
+        if !earned.is_empty() {
+            for achievement in earned.iter() {
+                self.achievements.insert(*achievement, stats.timestamp);
+            }
+            self.save_achievements()?;
+        }
+
+        Ok(earned)
+    }
+
+    /// A game at `difficulty` was left unsolved for a new one, ending its streak
+    pub fn record_abandoned_game(&mut self, difficulty: Difficulty) -> std::io::Result<()> {
+        let stats = self
+            .global_stats
+            .entry(difficulty)
+            .or_insert_with(|| GlobalStats {
+                difficulty,
+                ..Default::default()
+            });
+        if stats.current_streak > 0 {
+            stats.break_streak();
+            self.save_global_stats(difficulty)?;
+        }
         Ok(())
     }
 
+    /// When each earned achievement was earned, as a unix timestamp
+    pub fn earned_achievements(&self) -> &BTreeMap<Achievement, i64> {
+        &self.achievements
+    }
+
+    pub fn is_seed_completed(&self, difficulty: Difficulty, seed: u64) -> bool {
+        self.completed_seeds.contains(&(difficulty, seed))
+    }
+
+    /// The first completion of the daily puzzle for `date`
+    pub fn daily_completion(&self, date: NaiveDate) -> Option<&GameStats> {
+        self.daily_completions.get(&Self::daily_key(date))
+    }
+
+    /// Whether the daily puzzle for `date` has been solved. Dailies solved before their
+    /// completions were kept still count, through the completed seeds.
+    pub fn is_daily_completed(&self, date: NaiveDate) -> bool {
+        self.daily_completion(date).is_some()
+            || self.is_seed_completed(DAILY_DIFFICULTY, daily::seed_for_date(date))
+    }
+
     pub fn get_high_scores(&self, difficulty: Difficulty, limit: usize) -> Vec<GameStats> {
         self.scores
             .get(&difficulty)
@@ -133,4 +271,219 @@ impl StatsManager {
                 ..Default::default()
             })
     }
+
+    pub fn get_high_scores_by_grid_size(&self, grid_size: usize, limit: usize) -> Vec<GameStats> {
+        let mut scores: Vec<GameStats> = self
+            .scores
+            .values()
+            .flatten()
+            .filter(|score| score.grid_size == grid_size)
+            .cloned()
+            .collect();
+        scores.sort_by(|a, b| a.completion_time.cmp(&b.completion_time));
+        scores.truncate(limit);
+        scores
+    }
+
+    /// Global stats summed across every difficulty played at the given grid size
+    pub fn get_global_stats_by_grid_size(&self, grid_size: usize) -> GlobalStats {
+        self.global_stats
+            .iter()
+            .filter(|(difficulty, _)| difficulty.grid_size() == grid_size)
+            .fold(GlobalStats::default(), |mut acc, (_, stats)| {
+                acc.merge(stats);
+                acc
+            })
+    }
+
+    pub fn get_high_scores_for(&self, grouping: StatsGrouping, limit: usize) -> Vec<GameStats> {
+        match grouping {
+            StatsGrouping::Difficulty(difficulty) => self.get_high_scores(difficulty, limit),
+            StatsGrouping::GridSize(grid_size) => {
+                self.get_high_scores_by_grid_size(grid_size, limit)
+            }
+        }
+    }
+
+    pub fn get_global_stats_for(&self, grouping: StatsGrouping) -> GlobalStats {
+        match grouping {
+            StatsGrouping::Difficulty(difficulty) => self.get_global_stats(difficulty),
+            StatsGrouping::GridSize(grid_size) => self.get_global_stats_by_grid_size(grid_size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TempDataDir;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn game(difficulty: Difficulty, secs: u64) -> GameStats {
+        GameStats {
+            completion_time: Duration::from_secs(secs),
+            hints_used: 1,
+            grid_size: difficulty.grid_size(),
+            difficulty,
+            timestamp: 0,
+            playthrough_id: Uuid::new_v4(),
+            clues_hidden: false,
+            seed: None,
+        }
+    }
+
+    fn stats_manager(data_dir: &TempDataDir, games: &[GameStats]) -> StatsManager {
+        let mut manager = StatsManager {
+            data_dir: data_dir.path.clone(),
+            scores: HashMap::new(),
+            global_stats: HashMap::new(),
+            completed_seeds: HashSet::new(),
+            achievements: BTreeMap::new(),
+            daily_completions: BTreeMap::new(),
+        };
+        for game in games {
+            manager.record_game(game).unwrap();
+        }
+        manager
+    }
+
+    #[test]
+    fn test_high_scores_by_grid_size_merges_difficulties() {
+        let data_dir = TempDataDir::new();
+        let manager = stats_manager(
+            &data_dir,
+            &[
+                game(Difficulty::Easy, 30),
+                game(Difficulty::Tutorial, 10),
+                game(Difficulty::Moderate, 5),
+            ],
+        );
+
+        let scores = manager.get_high_scores_by_grid_size(4, 20);
+        assert_eq!(
+            scores
+                .iter()
+                .map(|s| s.completion_time.as_secs())
+                .collect::<Vec<_>>(),
+            vec![10, 30]
+        );
+
+        let global_stats = manager.get_global_stats_by_grid_size(4);
+        assert_eq!(global_stats.total_games_played, 2);
+        assert_eq!(global_stats.total_time_played, Duration::from_secs(40));
+        assert_eq!(global_stats.total_hints_used, 2);
+        assert_eq!(global_stats.median_hints_used(), Some(1));
+    }
+
+    #[test]
+    fn test_empty_grid_size_group() {
+        let data_dir = TempDataDir::new();
+        let manager = stats_manager(&data_dir, &[game(Difficulty::Easy, 30)]);
+
+        assert!(manager.get_high_scores_by_grid_size(8, 20).is_empty());
+        let global_stats = manager.get_global_stats_for(StatsGrouping::GridSize(8));
+        assert_eq!(global_stats.total_games_played, 0);
+        assert_eq!(global_stats.total_time_played, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_completed_seeds_outlive_high_scores() {
+        let data_dir = TempDataDir::new();
+        let games = (0..25)
+            .map(|seed| GameStats {
+                seed: Some(seed),
+                ..game(Difficulty::Easy, 100 - seed)
+            })
+            .collect::<Vec<_>>();
+        let manager = stats_manager(&data_dir, &games);
+
+        // the slowest games fall out of the top 20 but are still completed
+        assert_eq!(manager.get_high_scores(Difficulty::Easy, 100).len(), 20);
+        assert!(manager.is_seed_completed(Difficulty::Easy, 0));
+        assert!(manager.is_seed_completed(Difficulty::Easy, 24));
+        assert!(!manager.is_seed_completed(Difficulty::Moderate, 0));
+        assert!(!manager.is_seed_completed(Difficulty::Easy, 25));
+    }
+
+    #[test]
+    fn test_achievements_are_earned_once_and_persist() {
+        let data_dir = TempDataDir::new();
+        let mut manager = stats_manager(&data_dir, &[]);
+
+        assert_eq!(
+            manager.record_game(&game(Difficulty::Hard, 200)).unwrap(),
+            vec![Achievement::FirstSolve, Achievement::QuickHard]
+        );
+        assert!(manager
+            .record_game(&game(Difficulty::Hard, 100))
+            .unwrap()
+            .is_empty());
+
+        let mut reloaded = stats_manager(&data_dir, &[]);
+        reloaded.load_all();
+        assert_eq!(
+            reloaded.earned_achievements(),
+            manager.earned_achievements()
+        );
+    }
+
+    #[test]
+    fn test_daily_completions_are_kept_by_date() {
+        let data_dir = TempDataDir::new();
+        let date = NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+        let seed = daily::seed_for_date(date);
+        let mut manager = stats_manager(
+            &data_dir,
+            &[
+                GameStats {
+                    seed: Some(seed),
+                    ..game(DAILY_DIFFICULTY, 300)
+                },
+                // a date-like seed at another difficulty isn't a daily
+                GameStats {
+                    seed: Some(daily::seed_for_date(date.succ_opt().unwrap())),
+                    ..game(Difficulty::Veteran, 100)
+                },
+            ],
+        );
+        // solving it again keeps the first completion
+        manager
+            .record_game(&GameStats {
+                seed: Some(seed),
+                ..game(DAILY_DIFFICULTY, 200)
+            })
+            .unwrap();
+
+        assert!(manager.is_daily_completed(date));
+        assert!(!manager.is_daily_completed(date.succ_opt().unwrap()));
+        assert_eq!(
+            manager.daily_completion(date).unwrap().completion_time,
+            Duration::from_secs(300)
+        );
+
+        let mut reloaded = stats_manager(&data_dir, &[]);
+        reloaded.load_all();
+        assert!(reloaded.daily_completion(date).is_some());
+    }
+
+    #[test]
+    fn test_abandoning_a_game_breaks_only_its_streak() {
+        let data_dir = TempDataDir::new();
+        let mut manager = stats_manager(
+            &data_dir,
+            &[
+                game(Difficulty::Easy, 30),
+                game(Difficulty::Easy, 40),
+                game(Difficulty::Hard, 300),
+            ],
+        );
+        manager.record_abandoned_game(Difficulty::Easy).unwrap();
+        manager.record_game(&game(Difficulty::Easy, 50)).unwrap();
+
+        let easy = manager.get_global_stats(Difficulty::Easy);
+        assert_eq!((easy.current_streak, easy.best_streak), (1, 2));
+        let hard = manager.get_global_stats(Difficulty::Hard);
+        assert_eq!((hard.current_streak, hard.best_streak), (1, 1));
+    }
 }
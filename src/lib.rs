@@ -10,8 +10,11 @@ fluent_i18n::i18n!("locales", fallback = "en");
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
+    use std::path::PathBuf;
     use std::sync::Once;
     use test_context::TestContext;
+    use uuid::Uuid;
 
     static INIT_LOGGER: Once = Once::new();
 
@@ -34,4 +37,23 @@ mod tests {
             // Perform any teardown you wish.
         }
     }
+
+    /// A fresh directory for a test's data files, removed again when dropped
+    pub struct TempDataDir {
+        pub path: PathBuf,
+    }
+
+    impl TempDataDir {
+        pub fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("emojiclu-test-{}", Uuid::new_v4()));
+            fs::create_dir_all(&path).unwrap();
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDataDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use emojiclu::game::generation_stats::log_generation_times;
 use emojiclu::ui::build_ui;
 use fluent_i18n::set_locale;
 use gio::prelude::*;
@@ -67,5 +68,7 @@ fn main() -> ExitCode {
     app.connect_activate(|app| build_ui(app));
 
     // Run the application
-    app.run()
+    let exit_code = app.run();
+    log_generation_times();
+    exit_code
 }
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GameBoard, Tile};
+
+/// How far auto-solve reaches after the player changes a cell: placing the tiles a row is left
+/// with no choice about, or doing so for every row
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum AutoSolveMode {
+    /// Nothing is placed for the player
+    Off,
+    /// Only the row that changed
+    Row,
+    /// Every row of the board
+    Board,
+}
+
+impl Default for AutoSolveMode {
+    fn default() -> Self {
+        AutoSolveMode::Row
+    }
+}
+
+impl AutoSolveMode {
+    pub fn all() -> Vec<AutoSolveMode> {
+        vec![AutoSolveMode::Off, AutoSolveMode::Row, AutoSolveMode::Board]
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            AutoSolveMode::Off => 0,
+            AutoSolveMode::Row => 1,
+            AutoSolveMode::Board => 2,
+        }
+    }
+
+    pub fn from_index(index: usize) -> AutoSolveMode {
+        match index {
+            0 => AutoSolveMode::Off,
+            2 => AutoSolveMode::Board,
+            _ => AutoSolveMode::Row,
+        }
+    }
+
+    /// Auto-solve `board` after a change in `row`. Returns the cells placed, in order.
+    pub fn apply(&self, board: &mut GameBoard, row: usize) -> Vec<(usize, Tile)> {
+        match self {
+            AutoSolveMode::Off => Vec::new(),
+            AutoSolveMode::Row => board.auto_solve_row(row).1,
+            AutoSolveMode::Board => board.auto_solve_all().1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::model::{Difficulty, Solution};
+
+    #[test]
+    fn test_apply_reaches_only_its_scope() {
+        let solution = Arc::new(Solution::new(Difficulty::Tutorial, Some(1)));
+        let mut board = GameBoard::new(solution.clone());
+        // leave a single tile unplaced in the first two rows
+        for row in 0..2 {
+            for variant in solution.variants.iter().skip(1) {
+                board.select_tile_from_solution(Tile::new(row, *variant));
+            }
+        }
+
+        assert!(AutoSolveMode::Off.apply(&mut board.clone(), 0).is_empty());
+
+        let mut row_board = board.clone();
+        let placed = AutoSolveMode::Row.apply(&mut row_board, 0);
+        assert_eq!(placed.len(), 1);
+        assert_eq!(placed[0].1.row, 0);
+        assert_eq!(row_board.unplaced_in_row(1), 1);
+
+        let mut full_board = board.clone();
+        AutoSolveMode::Board.apply(&mut full_board, 0);
+        assert_eq!(full_board.unplaced_in_row(0), 0);
+        assert_eq!(full_board.unplaced_in_row(1), 0);
+
+        for mode in AutoSolveMode::all() {
+            assert_eq!(AutoSolveMode::from_index(mode.index()), mode);
+        }
+    }
+}
@@ -8,6 +8,40 @@ pub enum CandidateState {
     Eliminated,
 }
 
+/// A color the player tags a candidate with, to keep track of a hypothesis. Tags are only for the
+/// player; the solver never looks at them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CandidateColor {
+    Red,
+    Blue,
+    Green,
+    Orange,
+}
+
+impl CandidateColor {
+    pub fn all() -> Vec<CandidateColor> {
+        vec![
+            CandidateColor::Red,
+            CandidateColor::Blue,
+            CandidateColor::Green,
+            CandidateColor::Orange,
+        ]
+    }
+
+    /// The tag after `color` when cycling through them: untagged, each color in turn, then
+    /// untagged again
+    pub fn cycle(color: Option<CandidateColor>) -> Option<CandidateColor> {
+        let all = Self::all();
+        match color {
+            None => all.first().copied(),
+            Some(color) => all
+                .iter()
+                .position(|other| *other == color)
+                .and_then(|idx| all.get(idx + 1).copied()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Candidate {
     pub tile: Tile,
@@ -37,3 +71,19 @@ impl Candidate {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_visits_every_color_then_clears() {
+        let mut color = CandidateColor::cycle(None);
+        let mut visited = vec![];
+        while let Some(current) = color {
+            visited.push(current);
+            color = CandidateColor::cycle(color);
+        }
+        assert_eq!(visited, CandidateColor::all());
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fmt::Debug,
     hash::{Hash, Hasher},
 };
@@ -14,34 +14,46 @@ use super::{ClueOrientation, TileAssertion};
 
 // horiz sort index
 const SORT_INDEX_THREE_ADJACENT: usize = 0;
-const SORT_INDEX_TWO_APART_NOT_MIDDLE: usize = 1;
-const SORT_INDEX_LEFT_OF: usize = 2;
-const SORT_INDEX_TWO_ADJACENT: usize = 3;
-const SORT_INDEX_NOT_ADJACENT: usize = 4;
+const SORT_INDEX_THREE_ADJACENT_ORDERED: usize = 1;
+const SORT_INDEX_TWO_APART_NOT_MIDDLE: usize = 2;
+const SORT_INDEX_BETWEEN: usize = 3;
+const SORT_INDEX_LEFT_OF: usize = 4;
+const SORT_INDEX_ADJACENT_LEFT_OF: usize = 5;
+const SORT_INDEX_TWO_ADJACENT: usize = 6;
+const SORT_INDEX_NOT_ADJACENT: usize = 7;
+const SORT_INDEX_IN_COLUMN: usize = 8;
 
 // vert sort index
 const SORT_INDEX_THREE_IN_COLUMN: usize = 0;
 const SORT_INDEX_TWO_IN_COLUMN: usize = 1;
-const SORT_INDEX_TWO_IN_COLUMN_ONE_NOT: usize = 2;
-const SORT_INDEX_NOT_IN_SAME_COLUMN: usize = 3;
-const SORT_INDEX_ONE_MATCHES_EITHER: usize = 4;
+const SORT_INDEX_TWO_IN_COLUMN_NON_ADJACENT_ROWS: usize = 2;
+const SORT_INDEX_TWO_IN_COLUMN_ONE_NOT: usize = 3;
+const SORT_INDEX_NOT_IN_SAME_COLUMN: usize = 4;
+const SORT_INDEX_ONE_MATCHES_EITHER: usize = 5;
+const SORT_INDEX_TWO_OF_THREE_IN_COLUMN: usize = 6;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Copy)]
 pub enum HorizontalClueType {
-    ThreeAdjacent,     // ABC, either order
-    TwoApartNotMiddle, // A, not B, C
-    LeftOf,            // A <- B
-    TwoAdjacent,       // A next to B
-    NotAdjacent,       // A not next to B
+    ThreeAdjacent,        // ABC, either order
+    ThreeAdjacentOrdered, // A, B, C left to right
+    TwoApartNotMiddle,    // A, not B, C
+    Between,              // B somewhere between A and C, either order
+    LeftOf,               // A <- B
+    AdjacentLeftOf,       // A immediately left of B
+    TwoAdjacent,          // A next to B
+    NotAdjacent,          // A not next to B
+    InColumn,             // A is in the clue's column
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Copy)]
 pub enum VerticalClueType {
-    ThreeInColumn,      // Three tiles in same column
-    TwoInColumn,        // Two tiles in same column
-    OneMatchesEither,   // First tile matches column of either second or third, not both
-    NotInSameColumn,    // First tile not in same column as second
-    TwoInColumnWithout, // Two tiles in same column, one not
+    ThreeInColumn,              // Three tiles in same column
+    TwoInColumn,                // Two tiles in same column
+    TwoInColumnNonAdjacentRows, // Two tiles from non-adjacent rows in same column
+    OneMatchesEither,           // First tile matches column of either second or third, not both
+    NotInSameColumn,            // First tile not in same column as second
+    TwoInColumnWithout,         // Two tiles in same column, one not
+    TwoOfThreeInColumn,         // At least two of three tiles share a column
 }
 
 #[readonly::make]
@@ -56,6 +68,10 @@ pub struct Clue {
     /// DO NOT MUTATE
     #[readonly]
     pub sort_index: usize,
+    /// DO NOT MUTATE. The column an `InColumn` clue puts its tile in, which `Clue::in_column`
+    /// always sets; None for every other type.
+    #[readonly]
+    pub column: Option<usize>,
     cached_hash: u64,
 }
 
@@ -92,26 +108,141 @@ pub enum ClueType {
 }
 
 impl ClueType {
+    pub fn all() -> Vec<ClueType> {
+        vec![
+            ClueType::Horizontal(HorizontalClueType::ThreeAdjacent),
+            ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered),
+            ClueType::Horizontal(HorizontalClueType::TwoApartNotMiddle),
+            ClueType::Horizontal(HorizontalClueType::Between),
+            ClueType::Horizontal(HorizontalClueType::LeftOf),
+            ClueType::Horizontal(HorizontalClueType::AdjacentLeftOf),
+            ClueType::Horizontal(HorizontalClueType::TwoAdjacent),
+            ClueType::Horizontal(HorizontalClueType::NotAdjacent),
+            ClueType::Vertical(VerticalClueType::ThreeInColumn),
+            ClueType::Vertical(VerticalClueType::TwoInColumn),
+            ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows),
+            ClueType::Vertical(VerticalClueType::OneMatchesEither),
+            ClueType::Vertical(VerticalClueType::NotInSameColumn),
+            ClueType::Vertical(VerticalClueType::TwoInColumnWithout),
+            ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn),
+            ClueType::Horizontal(HorizontalClueType::InColumn),
+        ]
+    }
+
+    /// How many tiles the clue's constructor takes
+    pub fn n_tiles(&self) -> usize {
+        match self {
+            ClueType::Horizontal(HorizontalClueType::ThreeAdjacent)
+            | ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered)
+            | ClueType::Horizontal(HorizontalClueType::TwoApartNotMiddle)
+            | ClueType::Horizontal(HorizontalClueType::Between)
+            | ClueType::Vertical(VerticalClueType::ThreeInColumn)
+            | ClueType::Vertical(VerticalClueType::OneMatchesEither)
+            | ClueType::Vertical(VerticalClueType::TwoInColumnWithout)
+            | ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn) => 3,
+            ClueType::Horizontal(HorizontalClueType::InColumn) => 1,
+            _ => 2,
+        }
+    }
+
     pub fn get_title(&self) -> String {
         match self {
             ClueType::Horizontal(hor) => match hor {
                 HorizontalClueType::ThreeAdjacent => t!("clue-title-three-adjacent"),
+                HorizontalClueType::ThreeAdjacentOrdered => {
+                    t!("clue-title-three-adjacent-ordered")
+                }
                 HorizontalClueType::TwoApartNotMiddle => {
                     t!("clue-title-two-apart-not-middle")
                 }
+                HorizontalClueType::Between => t!("clue-title-between"),
                 HorizontalClueType::LeftOf => t!("clue-title-left-of"),
+                HorizontalClueType::AdjacentLeftOf => t!("clue-title-adjacent-left-of"),
                 HorizontalClueType::TwoAdjacent => t!("clue-title-two-adjacent"),
                 HorizontalClueType::NotAdjacent => t!("clue-title-not-adjacent"),
+                HorizontalClueType::InColumn => t!("clue-title-in-column"),
             },
             ClueType::Vertical(vert) => match vert {
                 VerticalClueType::ThreeInColumn => t!("clue-title-all-in-column"),
                 VerticalClueType::TwoInColumn => t!("clue-title-two-in-column"),
+                VerticalClueType::TwoInColumnNonAdjacentRows => {
+                    t!("clue-title-two-in-column-non-adjacent-rows")
+                }
                 VerticalClueType::OneMatchesEither => t!("clue-title-one-matches-either"),
                 VerticalClueType::NotInSameColumn => t!("clue-title-not-in-same-column"),
                 VerticalClueType::TwoInColumnWithout => t!("clue-title-two-in-column-one-not"),
+                VerticalClueType::TwoOfThreeInColumn => t!("clue-title-two-of-three-in-column"),
             },
         }
     }
+
+    /// Stable name for the clue type, used where it's saved, e.g. in the settings
+    pub fn key(&self) -> &'static str {
+        match self {
+            ClueType::Horizontal(hor) => match hor {
+                HorizontalClueType::ThreeAdjacent => "three-adjacent",
+                HorizontalClueType::ThreeAdjacentOrdered => "three-adjacent-ordered",
+                HorizontalClueType::TwoApartNotMiddle => "two-apart-not-middle",
+                HorizontalClueType::Between => "between",
+                HorizontalClueType::LeftOf => "left-of",
+                HorizontalClueType::AdjacentLeftOf => "adjacent-left-of",
+                HorizontalClueType::TwoAdjacent => "two-adjacent",
+                HorizontalClueType::NotAdjacent => "not-adjacent",
+                HorizontalClueType::InColumn => "in-column",
+            },
+            ClueType::Vertical(vert) => match vert {
+                VerticalClueType::ThreeInColumn => "all-in-column",
+                VerticalClueType::TwoInColumn => "two-in-column",
+                VerticalClueType::TwoInColumnNonAdjacentRows => "two-in-column-non-adjacent-rows",
+                VerticalClueType::OneMatchesEither => "one-matches-either",
+                VerticalClueType::NotInSameColumn => "not-in-same-column",
+                VerticalClueType::TwoInColumnWithout => "two-in-column-one-not",
+                VerticalClueType::TwoOfThreeInColumn => "two-of-three-in-column",
+            },
+        }
+    }
+
+    /// The clue type saved under `key`
+    pub fn from_key(key: &str) -> Option<ClueType> {
+        ClueType::all()
+            .into_iter()
+            .find(|clue_type| clue_type.key() == key)
+    }
+}
+
+impl Serialize for ClueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClueType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let key = String::deserialize(deserializer)?;
+        ClueType::from_key(&key)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown clue type: {}", key)))
+    }
+}
+
+/// Deserialize weights keyed by clue type, dropping keys this version doesn't know (say, saved by
+/// a newer one) rather than failing the whole file over them
+pub fn deserialize_clue_type_weights<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<ClueType, u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let weights = HashMap::<String, u32>::deserialize(deserializer)?;
+    Ok(weights
+        .into_iter()
+        .filter_map(|(key, weight)| Some((ClueType::from_key(&key)?, weight)))
+        .collect())
 }
 
 impl Clue {
@@ -127,10 +258,16 @@ impl Clue {
         assertions: Vec<TileAssertion>,
         sort_index: usize,
     ) -> Self {
+        assert_ne!(
+            clue_type,
+            ClueType::Horizontal(HorizontalClueType::InColumn),
+            "In column clues need a column; use Clue::in_column"
+        );
         let clue = Self {
             clue_type,
             assertions,
             sort_index,
+            column: None,
             cached_hash: 0, // Temporary value
         };
         let cached_hash = clue.compute_hash();
@@ -146,6 +283,7 @@ impl Clue {
         self.clue_type.hash(&mut hasher);
         self.assertions.hash(&mut hasher);
         self.sort_index.hash(&mut hasher);
+        self.column.hash(&mut hasher);
         hasher.finish()
     }
 
@@ -163,6 +301,20 @@ impl Clue {
         )
     }
 
+    pub fn three_adjacent_ordered(left: Tile, middle: Tile, right: Tile) -> Self {
+        Self::new_with_assertions(
+            ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered),
+            vec![left, middle, right]
+                .into_iter()
+                .map(|t| TileAssertion {
+                    tile: t,
+                    assertion: true,
+                })
+                .collect(),
+            SORT_INDEX_THREE_ADJACENT_ORDERED,
+        )
+    }
+
     pub fn two_apart_not_middle(t1: Tile, not_middle: Tile, t2: Tile) -> Self {
         Self::new_with_assertions(
             ClueType::Horizontal(HorizontalClueType::TwoApartNotMiddle),
@@ -184,6 +336,21 @@ impl Clue {
         )
     }
 
+    /// `middle` is somewhere between `left_or_right` and `other`, which may be either way round
+    pub fn between(left_or_right: Tile, middle: Tile, other: Tile) -> Self {
+        Self::new_with_assertions(
+            ClueType::Horizontal(HorizontalClueType::Between),
+            vec![left_or_right, middle, other]
+                .into_iter()
+                .map(|t| TileAssertion {
+                    tile: t,
+                    assertion: true,
+                })
+                .collect(),
+            SORT_INDEX_BETWEEN,
+        )
+    }
+
     pub fn left_of(left: Tile, right: Tile) -> Self {
         Self::new_with_assertions(
             ClueType::Horizontal(HorizontalClueType::LeftOf),
@@ -198,6 +365,38 @@ impl Clue {
         )
     }
 
+    pub fn adjacent_left_of(left: Tile, right: Tile) -> Self {
+        Self::new_with_assertions(
+            ClueType::Horizontal(HorizontalClueType::AdjacentLeftOf),
+            vec![left, right]
+                .into_iter()
+                .map(|t| TileAssertion {
+                    tile: t,
+                    assertion: true,
+                })
+                .collect(),
+            SORT_INDEX_ADJACENT_LEFT_OF,
+        )
+    }
+
+    pub fn in_column(tile: Tile, column: usize) -> Self {
+        let clue = Self {
+            clue_type: ClueType::Horizontal(HorizontalClueType::InColumn),
+            assertions: vec![TileAssertion {
+                tile,
+                assertion: true,
+            }],
+            sort_index: SORT_INDEX_IN_COLUMN,
+            column: Some(column),
+            cached_hash: 0, // Temporary value
+        };
+        let cached_hash = clue.compute_hash();
+        Self {
+            cached_hash,
+            ..clue
+        }
+    }
+
     pub fn adjacent(t1: Tile, t2: Tile) -> Self {
         Self::new_with_assertions(
             ClueType::Horizontal(HorizontalClueType::TwoAdjacent),
@@ -290,6 +489,29 @@ impl Clue {
         )
     }
 
+    pub fn two_in_column_non_adjacent_rows(t1: Tile, t2: Tile) -> Self {
+        assert!(
+            t1.row.abs_diff(t2.row) > 1,
+            "Tiles must be in non-adjacent rows"
+        );
+        let mut assertions = vec![
+            TileAssertion {
+                tile: t1,
+                assertion: true,
+            },
+            TileAssertion {
+                tile: t2,
+                assertion: true,
+            },
+        ];
+        assertions.sort_by(|a, b| a.tile.row.cmp(&b.tile.row));
+        Self::new_with_assertions(
+            ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows),
+            assertions,
+            SORT_INDEX_TWO_IN_COLUMN_NON_ADJACENT_ROWS,
+        )
+    }
+
     pub fn two_in_column_without(t1: Tile, not_between: Tile, t2: Tile) -> Self {
         assert_ne!(t1.row, t2.row, "Tiles must be in different rows");
         assert_ne!(t1.row, not_between.row, "Tiles must be in different rows");
@@ -350,14 +572,42 @@ impl Clue {
         )
     }
 
+    pub fn two_of_three_in_column(t1: Tile, t2: Tile, t3: Tile) -> Self {
+        assert_ne!(t1.row, t2.row, "Tiles must be in different rows");
+        assert_ne!(t1.row, t3.row, "Tiles must be in different rows");
+        assert_ne!(t2.row, t3.row, "Tiles must be in different rows");
+        let mut assertions = vec![t1, t2, t3]
+            .into_iter()
+            .map(|t| TileAssertion {
+                tile: t,
+                assertion: true,
+            })
+            .collect::<Vec<_>>();
+        // no tile is special, so the order can't give away which ones share the column
+        assertions.sort_by(|a, b| a.tile.row.cmp(&b.tile.row));
+        Self::new_with_assertions(
+            ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn),
+            assertions,
+            SORT_INDEX_TWO_OF_THREE_IN_COLUMN,
+        )
+    }
+
+    /// Clues satisfied by any of several arrangements of their tiles, so a shared tile doesn't
+    /// tell us how to combine them with another clue
+    fn is_disjunctive(&self) -> bool {
+        matches!(
+            self.clue_type,
+            ClueType::Vertical(VerticalClueType::OneMatchesEither)
+                | ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn)
+        )
+    }
+
     pub fn intersects_positive(&self, other: &Self) -> Option<Tile> {
         if self.is_vertical() != other.is_vertical() {
             return None;
         }
 
-        if self.clue_type == ClueType::Vertical(VerticalClueType::OneMatchesEither)
-            || other.clue_type == ClueType::Vertical(VerticalClueType::OneMatchesEither)
-        {
+        if self.is_disjunctive() || other.is_disjunctive() {
             return None;
         }
 
@@ -390,6 +640,31 @@ impl Clue {
                         self.assertions[1].tile.to_string()
                     )
                 }
+                HorizontalClueType::InColumn => {
+                    format!(
+                        "<@{}:{}>",
+                        self.column.expect("In column clues always have a column"),
+                        self.assertions[0].to_string()
+                    )
+                }
+                HorizontalClueType::Between => {
+                    let tiles = self
+                        .assertions
+                        .iter()
+                        .map(|a| a.tile.to_string())
+                        .collect::<Vec<String>>()
+                        .join("~");
+                    format!("<{}>", tiles)
+                }
+                HorizontalClueType::AdjacentLeftOf | HorizontalClueType::ThreeAdjacentOrdered => {
+                    let tiles = self
+                        .assertions
+                        .iter()
+                        .map(|a| a.tile.to_string())
+                        .collect::<Vec<String>>()
+                        .join("|");
+                    format!("<{}>", tiles)
+                }
                 _ => {
                     let assertions = self
                         .assertions
@@ -409,6 +684,22 @@ impl Clue {
                         self.assertions[2].tile.to_string()
                     )
                 }
+                VerticalClueType::TwoOfThreeInColumn => {
+                    let tiles = self
+                        .assertions
+                        .iter()
+                        .map(|a| format!("?{}", a.tile.to_string()))
+                        .collect::<Vec<String>>()
+                        .join(",");
+                    format!("|{}|", tiles)
+                }
+                VerticalClueType::TwoInColumnNonAdjacentRows => {
+                    format!(
+                        "|{}...{}|",
+                        self.assertions[0].tile.to_string(),
+                        self.assertions[1].tile.to_string()
+                    )
+                }
                 _ => {
                     let assertions = self
                         .assertions
@@ -424,12 +715,29 @@ impl Clue {
 
     fn parse_horizontal(s: &str) -> Self {
         let content = s.trim_matches('<').trim_matches('>');
-        if content.contains("...") {
+        if let Some(in_column) = content.strip_prefix('@') {
+            let (column, assertion) = in_column
+                .split_once(':')
+                .expect("In column clue must have a column and a tile");
+            let column = column.parse().expect("Invalid column for in column clue");
+            Clue::in_column(TileAssertion::parse(assertion).tile, column)
+        } else if content.contains("...") {
             let tiles: Vec<_> = content.split("...").collect();
             assert_eq!(tiles.len(), 2);
             let left = Tile::parse(tiles[0]);
             let right = Tile::parse(tiles[1]);
             Clue::left_of(left, right)
+        } else if content.contains('~') {
+            let tiles: Vec<_> = content.split('~').map(Tile::parse).collect();
+            assert_eq!(tiles.len(), 3, "Between clue must have exactly 3 tiles");
+            Clue::between(tiles[0], tiles[1], tiles[2])
+        } else if content.contains('|') {
+            let tiles: Vec<_> = content.split('|').map(Tile::parse).collect();
+            match tiles.len() {
+                2 => Clue::adjacent_left_of(tiles[0], tiles[1]),
+                3 => Clue::three_adjacent_ordered(tiles[0], tiles[1], tiles[2]),
+                _ => panic!("Invalid number of tiles for ordered adjacent clue"),
+            }
         } else {
             let assertions: Vec<_> = content.split(',').collect();
             let tile_assertions: Vec<TileAssertion> =
@@ -464,8 +772,30 @@ impl Clue {
 
     fn parse_vertical(s: &str) -> Self {
         let content = s.trim_matches('|');
+        if content.contains("...") {
+            let tiles: Vec<_> = content.split("...").collect();
+            assert_eq!(tiles.len(), 2);
+            return Clue::two_in_column_non_adjacent_rows(
+                Tile::parse(tiles[0]),
+                Tile::parse(tiles[1]),
+            );
+        }
         let assertions: Vec<_> = content.split(',').collect();
 
+        // two_of_three_in_column marks every tile with ?, since none has to be in the column
+        if assertions.iter().all(|a| a.starts_with('?')) {
+            assert_eq!(
+                assertions.len(),
+                3,
+                "Two of three in column must have exactly 3 assertions"
+            );
+            let tiles: Vec<_> = assertions
+                .iter()
+                .map(|a| TileAssertion::parse(a).tile)
+                .collect();
+            return Clue::two_of_three_in_column(tiles[0], tiles[1], tiles[2]);
+        }
+
         // Handle one_matches_either case which uses ? notation
         if assertions.iter().any(|a| a.starts_with('?')) {
             assert_eq!(
@@ -527,6 +857,69 @@ impl Clue {
         }
     }
 
+    /// Build a clue of the given type from its tiles, in the order its constructor takes them.
+    /// Returns None instead of panicking when the tile count is wrong, or when a vertical clue
+    /// repeats a row (or, for non-adjacent rows, uses neighbouring ones). `column` is where an
+    /// `InColumn` clue puts its tile, and None without it; other clue types ignore it.
+    pub fn build(clue_type: ClueType, tiles: &[Tile], column: Option<usize>) -> Option<Self> {
+        if tiles.len() != clue_type.n_tiles() {
+            return None;
+        }
+        if let ClueType::Vertical(vertical_clue_type) = clue_type {
+            let rows: HashSet<usize> = tiles.iter().map(|tile| tile.row).collect();
+            if rows.len() != tiles.len() {
+                return None;
+            }
+            if vertical_clue_type == VerticalClueType::TwoInColumnNonAdjacentRows
+                && tiles[0].row.abs_diff(tiles[1].row) < 2
+            {
+                return None;
+            }
+        }
+
+        let clue = match clue_type {
+            ClueType::Horizontal(hor) => match hor {
+                HorizontalClueType::ThreeAdjacent => {
+                    Clue::three_adjacent(tiles[0], tiles[1], tiles[2])
+                }
+                HorizontalClueType::ThreeAdjacentOrdered => {
+                    Clue::three_adjacent_ordered(tiles[0], tiles[1], tiles[2])
+                }
+                HorizontalClueType::TwoApartNotMiddle => {
+                    Clue::two_apart_not_middle(tiles[0], tiles[1], tiles[2])
+                }
+                HorizontalClueType::Between => Clue::between(tiles[0], tiles[1], tiles[2]),
+                HorizontalClueType::LeftOf => Clue::left_of(tiles[0], tiles[1]),
+                HorizontalClueType::AdjacentLeftOf => Clue::adjacent_left_of(tiles[0], tiles[1]),
+                HorizontalClueType::TwoAdjacent => Clue::adjacent(tiles[0], tiles[1]),
+                HorizontalClueType::NotAdjacent => Clue::not_adjacent(tiles[0], tiles[1]),
+                HorizontalClueType::InColumn => Clue::in_column(tiles[0], column?),
+            },
+            ClueType::Vertical(vert) => match vert {
+                VerticalClueType::ThreeInColumn => {
+                    Clue::three_in_column(tiles[0], tiles[1], tiles[2])
+                }
+                VerticalClueType::TwoInColumn => Clue::two_in_column(tiles[0], tiles[1]),
+                VerticalClueType::TwoInColumnNonAdjacentRows => {
+                    Clue::two_in_column_non_adjacent_rows(tiles[0], tiles[1])
+                }
+                VerticalClueType::OneMatchesEither => {
+                    Clue::one_matches_either(tiles[0], tiles[1], tiles[2])
+                }
+                VerticalClueType::NotInSameColumn => {
+                    Clue::two_not_in_same_column(tiles[0], tiles[1])
+                }
+                VerticalClueType::TwoInColumnWithout => {
+                    Clue::two_in_column_without(tiles[0], tiles[1], tiles[2])
+                }
+                VerticalClueType::TwoOfThreeInColumn => {
+                    Clue::two_of_three_in_column(tiles[0], tiles[1], tiles[2])
+                }
+            },
+        };
+        Some(clue)
+    }
+
     pub fn is_vertical(&self) -> bool {
         matches!(self.clue_type, ClueType::Vertical(_))
     }
@@ -735,18 +1128,45 @@ impl Clue {
                         "tile3" => self.assertions[2].tile.to_string()
                     })
                 }
+                HorizontalClueType::Between => {
+                    t!("clue-desc-between", {
+                        "middle" => self.assertions[1].tile.to_string(),
+                        "tile1" => self.assertions[0].tile.to_string(),
+                        "tile2" => self.assertions[2].tile.to_string()
+                    })
+                }
                 HorizontalClueType::LeftOf => {
                     t!("clue-desc-left-of", {
                         "left" => self.assertions[0].tile.to_string(),
                         "right" => self.assertions[1].tile.to_string()
                     })
                 }
+                HorizontalClueType::ThreeAdjacentOrdered => {
+                    t!("clue-desc-three-adjacent-ordered", {
+                        "left" => self.assertions[0].tile.to_string(),
+                        "middle" => self.assertions[1].tile.to_string(),
+                        "right" => self.assertions[2].tile.to_string()
+                    })
+                }
+                HorizontalClueType::AdjacentLeftOf => {
+                    t!("clue-desc-adjacent-left-of", {
+                        "left" => self.assertions[0].tile.to_string(),
+                        "right" => self.assertions[1].tile.to_string()
+                    })
+                }
                 HorizontalClueType::NotAdjacent => {
                     t!("clue-desc-not-adjacent", {
                         "tile1" => self.assertions[0].tile.to_string(),
                         "tile2" => self.assertions[1].tile.to_string()
                     })
                 }
+                HorizontalClueType::InColumn => {
+                    t!("clue-desc-in-column", {
+                        "tile" => self.assertions[0].tile.to_string(),
+                        "column" => (self.column.expect("In column clues always have a column") + 1)
+                            .to_string()
+                    })
+                }
             },
             ClueType::Vertical(vert) => match vert {
                 VerticalClueType::ThreeInColumn | VerticalClueType::TwoInColumn => {
@@ -759,6 +1179,12 @@ impl Clue {
                     }
                     t!("clue-desc-same-column", { "tiles" => tiles })
                 }
+                VerticalClueType::TwoInColumnNonAdjacentRows => {
+                    t!("clue-desc-two-in-column-non-adjacent-rows", {
+                        "tile1" => self.assertions[0].tile.to_string(),
+                        "tile2" => self.assertions[1].tile.to_string()
+                    })
+                }
                 VerticalClueType::TwoInColumnWithout => {
                     let clue_assertions: Vec<(usize, &TileAssertion)> =
                         self.assertions.iter().enumerate().collect();
@@ -791,6 +1217,13 @@ impl Clue {
                         "tile2" => self.assertions[1].tile.to_string()
                     })
                 }
+                VerticalClueType::TwoOfThreeInColumn => {
+                    t!("clue-desc-two-of-three-in-column", {
+                        "tile1" => self.assertions[0].tile.to_string(),
+                        "tile2" => self.assertions[1].tile.to_string(),
+                        "tile3" => self.assertions[2].tile.to_string()
+                    })
+                }
                 VerticalClueType::OneMatchesEither => {
                     t!("clue-desc-one-matches-either", {
                         "tile1" => self.assertions[0].tile.to_string(),
@@ -915,6 +1348,16 @@ mod tests {
         assert_eq!(merged[1].to_string(), "|+0a,-2a|");
     }
 
+    #[test]
+    fn test_merge_two_of_three_in_column() {
+        // the shared tile might be the one outside the column, so nothing can be combined
+        let clue1 = Clue::parse("|+0a,+1a|");
+        let clue2 = Clue::parse("|?0a,?2b,?3c|");
+
+        assert!(clue1.merge(&clue2).is_none());
+        assert!(clue2.merge(&clue1).is_none());
+    }
+
     #[test]
     fn test_parse_vertical() {
         // Test two_in_column
@@ -971,6 +1414,20 @@ mod tests {
         assert_eq!(clue.assertions[2].tile, Tile::new(4, 'b'));
         assert_eq!(clue.assertions[2].assertion, true);
 
+        // Test two_of_three_in_column
+        let clue = Clue::parse("|?4b,?0a,?2c|");
+        assert_eq!(
+            clue.clue_type,
+            ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn)
+        );
+        assert_eq!(clue.assertions.len(), 3);
+        assert_eq!(clue.assertions[0].tile, Tile::new(0, 'a'));
+        assert_eq!(clue.assertions[1].tile, Tile::new(2, 'c'));
+        assert_eq!(clue.assertions[2].tile, Tile::new(4, 'b'));
+        assert!(clue.assertions.iter().all(|a| a.assertion));
+        assert_eq!(clue.to_string(), "|?0a,?2c,?4b|");
+        assert_eq!(Clue::parse(&clue.to_string()), clue);
+
         // Test two_not_in_same_column
         let clue = Clue::parse("|+1a,-3f|");
         assert_eq!(
@@ -982,6 +1439,22 @@ mod tests {
         assert_eq!(clue.assertions[0].assertion, true);
         assert_eq!(clue.assertions[1].tile, Tile::new(3, 'f'));
         assert_eq!(clue.assertions[1].assertion, false);
+
+        // Test two_in_column_non_adjacent_rows
+        let clue = Clue::parse("|2b...0a|");
+        assert_eq!(
+            clue.clue_type,
+            ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows)
+        );
+        assert_eq!(clue.assertions.len(), 2);
+        assert_eq!(clue.assertions[0].tile, Tile::new(0, 'a'));
+        assert_eq!(clue.assertions[1].tile, Tile::new(2, 'b'));
+    }
+
+    #[test]
+    #[should_panic(expected = "Tiles must be in non-adjacent rows")]
+    fn test_parse_rejects_non_adjacent_rows_clue_from_adjacent_rows() {
+        Clue::parse("|0a...1b|");
     }
 
     #[test]
@@ -1033,6 +1506,44 @@ mod tests {
         assert_eq!(clue.assertions[1].assertion, false);
         assert_eq!(clue.assertions[2].tile, Tile::new(0, 'c'));
         assert_eq!(clue.assertions[2].assertion, true);
+
+        let clue = Clue::parse("<0a|1b>");
+        assert_eq!(
+            clue.clue_type,
+            ClueType::Horizontal(HorizontalClueType::AdjacentLeftOf)
+        );
+        assert_eq!(clue.assertions.len(), 2);
+        assert_eq!(clue.assertions[0].tile, Tile::new(0, 'a'));
+        assert_eq!(clue.assertions[1].tile, Tile::new(1, 'b'));
+
+        let clue = Clue::parse("<0a|1b|2c>");
+        assert_eq!(
+            clue.clue_type,
+            ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered)
+        );
+        assert_eq!(clue.assertions.len(), 3);
+        assert_eq!(clue.assertions[0].tile, Tile::new(0, 'a'));
+        assert_eq!(clue.assertions[1].tile, Tile::new(1, 'b'));
+        assert_eq!(clue.assertions[2].tile, Tile::new(2, 'c'));
+
+        let clue = Clue::parse("<2c~0a~1b>");
+        assert_eq!(
+            clue.clue_type,
+            ClueType::Horizontal(HorizontalClueType::Between)
+        );
+        assert_eq!(clue.assertions.len(), 3);
+        assert_eq!(clue.assertions[0].tile, Tile::new(2, 'c'));
+        assert_eq!(clue.assertions[1].tile, Tile::new(0, 'a'));
+        assert_eq!(clue.assertions[2].tile, Tile::new(1, 'b'));
+
+        let clue = Clue::parse("<@2:+0a>");
+        assert_eq!(
+            clue.clue_type,
+            ClueType::Horizontal(HorizontalClueType::InColumn)
+        );
+        assert_eq!(clue.column, Some(2));
+        assert_eq!(clue.assertions.len(), 1);
+        assert_eq!(clue.assertions[0].tile, Tile::new(0, 'a'));
     }
 
     #[test]
@@ -1041,9 +1552,14 @@ mod tests {
             "|+0a,+1b|",
             "|+0a,+1b,+2c|",
             "|+0a,?1b,?2b|",
+            "|0a...2b|",
             "<+0a,+1b>",
             "<+0a,-1b>",
             "<0a...1b>",
+            "<0a|1b>",
+            "<0a|1b|2c>",
+            "<2c~0a~1b>",
+            "<@2:+0a>",
             "<+0a,+1b,+2c>",
             "<+0a,-1b,+2c>",
         ] {
@@ -1085,4 +1601,57 @@ mod tests {
         assert!(clue1.intersects_positive(&clue2).is_none());
         assert!(clue2.intersects_positive(&clue1).is_none());
     }
+
+    #[test]
+    fn test_build_round_trips_through_parse() {
+        for clue_type in ClueType::all() {
+            let tiles: Vec<_> = ["0a", "2b", "4c"][..clue_type.n_tiles()]
+                .iter()
+                .map(|t| Tile::parse(t))
+                .collect();
+            let clue = Clue::build(clue_type, &tiles, Some(3)).unwrap();
+            assert_eq!(clue.clue_type, clue_type);
+            assert_eq!(Clue::parse(&clue.to_string()), clue);
+        }
+
+        let in_column = ClueType::Horizontal(HorizontalClueType::InColumn);
+        let clue = Clue::build(in_column, &[Tile::parse("0a")], Some(3)).unwrap();
+        assert_eq!(clue, Clue::in_column(Tile::parse("0a"), 3));
+        assert_ne!(clue, Clue::in_column(Tile::parse("0a"), 2));
+        // the tile alone doesn't say which column
+        assert_eq!(Clue::build(in_column, &[Tile::parse("0a")], None), None);
+    }
+
+    #[test]
+    fn test_build_rejects_invalid_tiles() {
+        let three_in_column = ClueType::Vertical(VerticalClueType::ThreeInColumn);
+        assert!(Clue::build(
+            three_in_column,
+            &[Tile::parse("0a"), Tile::parse("1a")],
+            None
+        )
+        .is_none());
+        assert!(Clue::build(
+            three_in_column,
+            &[Tile::parse("0a"), Tile::parse("1a"), Tile::parse("1b")],
+            None
+        )
+        .is_none());
+        assert!(Clue::build(
+            ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows),
+            &[Tile::parse("0a"), Tile::parse("1a")],
+            None
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_clue_type_keys_round_trip() {
+        for clue_type in ClueType::all() {
+            assert_eq!(ClueType::from_key(clue_type.key()), Some(clue_type));
+            let json = serde_json::to_string(&clue_type).unwrap();
+            assert_eq!(serde_json::from_str::<ClueType>(&json).unwrap(), clue_type);
+        }
+        assert_eq!(ClueType::from_key("no-such-clue"), None);
+    }
 }
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use super::ClueWithAddress;
+
+/// The order clues are laid out in their panels. Only where a clue is shown changes; its
+/// `ClueAddress` stays the same, so focus and completion are unaffected.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ClueSort {
+    /// Grouped by clue type, as the clue set is built
+    Type,
+    /// By the row of the clue's first tile
+    Position,
+    /// Fewest tiles first
+    TileCount,
+}
+
+impl Default for ClueSort {
+    fn default() -> Self {
+        ClueSort::Type
+    }
+}
+
+impl ClueSort {
+    pub fn all() -> Vec<ClueSort> {
+        vec![ClueSort::Type, ClueSort::Position, ClueSort::TileCount]
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            ClueSort::Type => 0,
+            ClueSort::Position => 1,
+            ClueSort::TileCount => 2,
+        }
+    }
+
+    pub fn from_index(index: usize) -> ClueSort {
+        match index {
+            1 => ClueSort::Position,
+            2 => ClueSort::TileCount,
+            _ => ClueSort::Type,
+        }
+    }
+
+    /// Compare two clues of the same panel. Ties fall back to the clue set's own order, so the
+    /// result is stable across redraws.
+    pub fn compare(&self, a: &ClueWithAddress, b: &ClueWithAddress) -> Ordering {
+        let ordering = match self {
+            ClueSort::Type => Ordering::Equal,
+            ClueSort::Position => a.clue.assertions[0].tile.cmp(&b.clue.assertions[0].tile),
+            ClueSort::TileCount => a.clue.assertions.len().cmp(&b.clue.assertions.len()),
+        };
+        ordering.then(a.index.cmp(&b.index))
+    }
+
+    /// The indexes of `clues` in display order
+    pub fn display_order<'a>(
+        &self,
+        clues: impl IntoIterator<Item = &'a ClueWithAddress>,
+    ) -> Vec<usize> {
+        let mut clues: Vec<&ClueWithAddress> = clues.into_iter().collect();
+        clues.sort_by(|a, b| self.compare(a, b));
+        clues.into_iter().map(|clue| clue.index).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Clue, ClueSet};
+
+    #[test]
+    fn test_display_order_keeps_addresses() {
+        let clue_set = ClueSet::new(vec![
+            Clue::parse("<+2a,+2b,+2c>"),
+            Clue::parse("<+1a,+0b>"),
+            Clue::parse("<+0c,-1d,+2e>"),
+        ]);
+        let clues = clue_set.horizontal_clues();
+        let first_row = |idx: usize| clues[idx].clue.assertions[0].tile.row;
+        let tile_count = |idx: usize| clues[idx].clue.assertions.len();
+
+        assert_eq!(ClueSort::Type.display_order(clues), vec![0, 1, 2]);
+
+        let by_position = ClueSort::Position.display_order(clues);
+        let rows: Vec<usize> = by_position.iter().map(|&idx| first_row(idx)).collect();
+        assert_eq!(rows, vec![0, 1, 2]);
+
+        let by_tile_count = ClueSort::TileCount.display_order(clues);
+        assert_eq!(tile_count(by_tile_count[0]), 2);
+
+        // every clue is shown exactly once, under its original address
+        let mut addresses = by_tile_count.clone();
+        addresses.sort();
+        assert_eq!(addresses, vec![0, 1, 2]);
+    }
+}
@@ -5,7 +5,7 @@ use super::tile::Tile;
 
 use crate::model::tile_assertion::TileAssertion;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum DeductionKind {
     // Simple deduction where a tile simply isn't possible
     Constraint,
@@ -17,6 +17,15 @@ pub enum DeductionKind {
 }
 
 impl DeductionKind {
+    pub fn all() -> [DeductionKind; 4] {
+        [
+            Self::Constraint,
+            Self::Converging,
+            Self::LastRemaining,
+            Self::HiddenSet,
+        ]
+    }
+
     #[cfg(test)]
     pub fn from_str(input: &str) -> Option<Self> {
         match input {
@@ -39,7 +48,7 @@ impl DeductionKind {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Deduction {
     pub column: usize,
     pub tile_assertion: TileAssertion,
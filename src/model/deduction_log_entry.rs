@@ -0,0 +1,8 @@
+use super::{ClueAddress, Deduction};
+
+/// A clue (or a hidden set, when `clue` is None) and the deductions a hint derived from it
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeductionLogEntry {
+    pub clue: Option<ClueAddress>,
+    pub deductions: Vec<Deduction>,
+}
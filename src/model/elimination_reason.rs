@@ -0,0 +1,13 @@
+use super::{ClueAddress, DeductionKind};
+
+/// Why a candidate is no longer available in a cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EliminationReason {
+    /// Removed by a deduction from a clue; `clue` is None for hidden sets
+    Deduction {
+        clue: Option<ClueAddress>,
+        deduction_kind: Option<DeductionKind>,
+    },
+    /// The tile is already placed in this column of the row
+    PlacedElsewhere(usize),
+}
@@ -1,12 +1,16 @@
 use super::{
     solution::{Solution, MAX_GRID_SIZE},
-    ClueAddress, ClueSet,
+    ClueAddress, ClueSet, EliminationReason,
 };
 use crate::model::tile_assertion::TileAssertion;
-use crate::model::{Candidate, Deduction, PartialSolution, Tile};
-use std::{collections::HashSet, sync::Arc};
+use crate::model::{Candidate, CandidateColor, Deduction, PartialSolution, Tile};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(from = "GameBoardFields")]
 pub struct GameBoard {
     candidates: [[u8; MAX_GRID_SIZE]; MAX_GRID_SIZE],
     resolved_candidates: [[u8; MAX_GRID_SIZE]; MAX_GRID_SIZE],
@@ -14,6 +18,85 @@ pub struct GameBoard {
     pub solution: Arc<Solution>,
     pub clue_set: Arc<ClueSet>,
     pub completed_clues: HashSet<ClueAddress>,
+    /// Provenance of eliminated candidates, keyed by (column, tile); only populated when tracking
+    /// is enabled. Not persisted.
+    #[serde(skip)]
+    elimination_reasons: HashMap<(usize, Tile), EliminationReason>,
+    /// Colors the player tagged candidates with, keyed by (column, tile). They're left out of the
+    /// content hash, so the solver and its caches never see them.
+    #[serde(serialize_with = "serialize_candidate_colors")]
+    candidate_colors: BTreeMap<(usize, Tile), CandidateColor>,
+    /// XOR of every cell's hash, kept up to date as cells change; see `content_hash`. Recomputed
+    /// on load rather than persisted.
+    #[serde(skip)]
+    content_hash: u64,
+}
+
+/// The persisted fields of a `GameBoard`; loading goes through here so the content hash can be
+/// rebuilt
+#[derive(serde::Deserialize)]
+struct GameBoardFields {
+    candidates: [[u8; MAX_GRID_SIZE]; MAX_GRID_SIZE],
+    resolved_candidates: [[u8; MAX_GRID_SIZE]; MAX_GRID_SIZE],
+    selected: [[Option<char>; MAX_GRID_SIZE]; MAX_GRID_SIZE],
+    solution: Arc<Solution>,
+    clue_set: Arc<ClueSet>,
+    completed_clues: HashSet<ClueAddress>,
+    #[serde(default)]
+    candidate_colors: Vec<((usize, Tile), CandidateColor)>,
+}
+
+/// JSON map keys have to be strings, so candidate colors are saved as a list of entries
+fn serialize_candidate_colors<S: serde::Serializer>(
+    candidate_colors: &BTreeMap<(usize, Tile), CandidateColor>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(candidate_colors.iter())
+}
+
+impl From<GameBoardFields> for GameBoard {
+    fn from(fields: GameBoardFields) -> Self {
+        let mut board = Self {
+            candidates: fields.candidates,
+            resolved_candidates: fields.resolved_candidates,
+            selected: fields.selected,
+            solution: fields.solution,
+            clue_set: fields.clue_set,
+            completed_clues: fields.completed_clues,
+            elimination_reasons: HashMap::new(),
+            candidate_colors: fields.candidate_colors.into_iter().collect(),
+            content_hash: 0,
+        };
+        board.rehash();
+        board
+    }
+}
+
+/// The parts of a board that change from move to move. Saved undo history keeps these instead of
+/// whole boards, so the solution and clues aren't repeated in every entry.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BoardProgress {
+    candidates: [[u8; MAX_GRID_SIZE]; MAX_GRID_SIZE],
+    selected: [[Option<char>; MAX_GRID_SIZE]; MAX_GRID_SIZE],
+    completed_clues: HashSet<ClueAddress>,
+    #[serde(default)]
+    candidate_colors: Vec<((usize, Tile), CandidateColor)>,
+}
+
+/// A row of a board that can't be completed any more
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contradiction {
+    pub row: usize,
+    /// The cell left with no candidates, or None when some variant has no column left instead
+    pub col: Option<usize>,
+}
+
+/// splitmix64 finalizer; spreads a cell's packed contents over the whole hash
+fn mix_cell_hash(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
 }
 
 impl std::fmt::Debug for GameBoard {
@@ -63,14 +146,19 @@ impl Default for GameBoard {
         let clue_set = Arc::new(ClueSet::new(vec![]));
         let completed_clues = HashSet::new();
 
-        Self {
+        let mut board = Self {
             candidates,
             resolved_candidates,
             selected,
             solution,
             clue_set,
             completed_clues,
-        }
+            elimination_reasons: HashMap::new(),
+            candidate_colors: BTreeMap::new(),
+            content_hash: 0,
+        };
+        board.rehash();
+        board
     }
 }
 
@@ -87,23 +175,139 @@ impl GameBoard {
             solution,
             clue_set: Arc::new(ClueSet::new(vec![])),
             completed_clues: HashSet::new(),
+            elimination_reasons: HashMap::new(),
+            candidate_colors: BTreeMap::new(),
+            content_hash: 0,
         };
         board.recompute_resolved();
+        board.rehash();
+        board
+    }
+
+    fn cell_hash(&self, row: usize, col: usize) -> u64 {
+        let selected = self.selected[row][col].map_or(0, |variant| variant as u64);
+        let position = (row * MAX_GRID_SIZE + col) as u64;
+        mix_cell_hash((position << 40) | (selected << 8) | self.candidates[row][col] as u64)
+    }
+
+    fn rehash(&mut self) {
+        self.content_hash = 0;
+        for row in 0..MAX_GRID_SIZE {
+            for col in 0..MAX_GRID_SIZE {
+                self.content_hash ^= self.cell_hash(row, col);
+            }
+        }
+    }
+
+    /// Apply a change to one cell's candidates or selection, keeping the content hash current
+    fn update_cell(&mut self, row: usize, col: usize, change: impl FnOnce(&mut Self)) {
+        let before = self.cell_hash(row, col);
+        change(self);
+        self.content_hash ^= before ^ self.cell_hash(row, col);
+    }
+
+    /// Hash of the candidates and selections, maintained incrementally so it costs nothing to
+    /// read. Clues, completed clues, and elimination reasons aren't part of the content.
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash
+    }
+
+    /// Whether both boards have the same candidates and selections. Boards that differ almost
+    /// always differ in hash, so the cell-by-cell comparison only runs for likely matches.
+    pub fn same_content(&self, other: &GameBoard) -> bool {
+        self.content_hash == other.content_hash
+            && self.candidates == other.candidates
+            && self.selected == other.selected
+    }
+
+    pub fn progress(&self) -> BoardProgress {
+        BoardProgress {
+            candidates: self.candidates,
+            selected: self.selected,
+            completed_clues: self.completed_clues.clone(),
+            candidate_colors: self
+                .candidate_colors
+                .iter()
+                .map(|(key, color)| (*key, *color))
+                .collect(),
+        }
+    }
+
+    /// This board's puzzle with `progress` in place of its own
+    pub fn with_progress(&self, progress: &BoardProgress) -> GameBoard {
+        let mut board = Self {
+            candidates: progress.candidates,
+            resolved_candidates: [[0x00; MAX_GRID_SIZE]; MAX_GRID_SIZE],
+            selected: progress.selected,
+            solution: self.solution.clone(),
+            clue_set: self.clue_set.clone(),
+            completed_clues: progress.completed_clues.clone(),
+            elimination_reasons: HashMap::new(),
+            candidate_colors: progress.candidate_colors.iter().copied().collect(),
+            content_hash: 0,
+        };
+        board.recompute_resolved();
+        board.rehash();
         board
     }
 
     pub fn remove_candidate(&mut self, col: usize, tile: Tile) {
         let tile_idx = Tile::variant_to_usize(tile.variant);
-        self.candidates[tile.row][col] &= !(1 << tile_idx);
+        self.update_cell(tile.row, col, |board| {
+            board.candidates[tile.row][col] &= !(1 << tile_idx)
+        });
+        self.elimination_reasons.remove(&(col, tile));
         self.recompute_resolved_row(tile.row);
     }
 
     pub fn show_candidate(&mut self, col: usize, tile: Tile) {
         let tile_idx = Tile::variant_to_usize(tile.variant);
-        self.candidates[tile.row][col] |= 1 << tile_idx;
+        self.update_cell(tile.row, col, |board| {
+            board.candidates[tile.row][col] |= 1 << tile_idx
+        });
+        self.elimination_reasons.remove(&(col, tile));
         self.recompute_resolved_row(tile.row);
     }
 
+    pub fn candidate_color(&self, col: usize, tile: Tile) -> Option<CandidateColor> {
+        self.candidate_colors.get(&(col, tile)).copied()
+    }
+
+    /// Tag the candidate with `color`, or clear its tag with None
+    pub fn set_candidate_color(&mut self, col: usize, tile: Tile, color: Option<CandidateColor>) {
+        match color {
+            Some(color) => self.candidate_colors.insert((col, tile), color),
+            None => self.candidate_colors.remove(&(col, tile)),
+        };
+    }
+
+    pub fn record_elimination_reason(&mut self, col: usize, tile: Tile, reason: EliminationReason) {
+        self.elimination_reasons.insert((col, tile), reason);
+    }
+
+    /// Why the candidate is unavailable; None if it's available or was eliminated without a
+    /// recorded reason (e.g. by the player)
+    pub fn get_elimination_reason(
+        &self,
+        row: usize,
+        col: usize,
+        variant: char,
+    ) -> Option<EliminationReason> {
+        if self.is_candidate_available(row, col, variant) {
+            return None;
+        }
+        if let Some(reason) = self
+            .elimination_reasons
+            .get(&(col, Tile::new(row, variant)))
+        {
+            return Some(reason.clone());
+        }
+        self.selected[row]
+            .iter()
+            .position(|selected| *selected == Some(variant))
+            .map(EliminationReason::PlacedElsewhere)
+    }
+
     fn recompute_resolved(&mut self) {
         for row in 0..self.solution.n_rows {
             self.recompute_resolved_row(row);
@@ -158,13 +362,21 @@ impl GameBoard {
         variants
     }
 
+    /// The candidates the player hasn't eliminated at a cell, as a bitmask over variant indexes.
+    /// Unlike `is_candidate_available`, tiles placed elsewhere in the row aren't taken out.
+    pub(crate) fn candidate_mask(&self, row: usize, col: usize) -> u8 {
+        self.candidates[row][col]
+    }
+
     pub fn is_candidate_available(&self, row: usize, col: usize, variant: char) -> bool {
         let variant_idx = Tile::variant_to_usize(variant);
         (self.resolved_candidates[row][col] & (1 << variant_idx)) != 0
     }
 
     pub fn select_tile_at_position(&mut self, col: usize, tile: Tile) {
-        self.selected[tile.row][col] = Some(tile.variant);
+        self.update_cell(tile.row, col, |board| {
+            board.selected[tile.row][col] = Some(tile.variant)
+        });
         self.recompute_resolved_row(tile.row);
     }
 
@@ -174,7 +386,9 @@ impl GameBoard {
             .iter()
             .position(|t| t == &tile.variant)
             .unwrap();
-        self.selected[row][col] = Some(tile.variant);
+        self.update_cell(row, col, |board| {
+            board.selected[row][col] = Some(tile.variant)
+        });
         self.recompute_resolved_row(row);
     }
 
@@ -300,8 +514,12 @@ impl GameBoard {
             resolved_candidates,
             clue_set: Arc::new(ClueSet::new(vec![])),
             completed_clues: HashSet::new(),
+            elimination_reasons: HashMap::new(),
+            candidate_colors: BTreeMap::new(),
+            content_hash: 0,
         };
         board.recompute_resolved();
+        board.rehash();
         board
     }
 
@@ -369,29 +587,46 @@ impl GameBoard {
         }
     }
 
-    pub fn is_valid_row_possibility(&self, row: usize) -> bool {
-        for col in 0..self.solution.n_variants {
-            let mut col_has_candidate = false;
-            for variant in self.solution.variants.iter() {
-                if self.is_candidate_available(row, col, *variant) {
-                    col_has_candidate = true;
-                }
-            }
-            if !col_has_candidate {
-                return false;
-            }
+    /// The first cell of `row` left with no candidates, or failing that, a variant left with no
+    /// column in it
+    fn row_contradiction(&self, row: usize) -> Option<Contradiction> {
+        let variants = &self.solution.variants;
+        let cols = 0..self.solution.n_variants;
+        let is_available =
+            |col: usize, variant: &char| self.is_candidate_available(row, col, *variant);
+
+        let empty_col = cols
+            .clone()
+            .find(|&col| !variants.iter().any(|variant| is_available(col, variant)));
+        if empty_col.is_some() {
+            return Some(Contradiction {
+                row,
+                col: empty_col,
+            });
         }
-        true
+        // every variant needs somewhere to go, too
+        let variant_stranded = variants
+            .iter()
+            .any(|variant| !cols.clone().any(|col| is_available(col, variant)));
+        variant_stranded.then_some(Contradiction { row, col: None })
+    }
+
+    pub fn is_valid_row_possibility(&self, row: usize) -> bool {
+        self.row_contradiction(row).is_none()
     }
 
     pub fn is_valid_possibility(&self) -> bool {
-        // check if all rows have at least one candidate
-        for row in 0..self.solution.n_rows {
-            if !self.is_valid_row_possibility(row) {
-                return false;
-            }
-        }
-        true
+        !self.is_unsolvable()
+    }
+
+    /// Where the board first contradicts itself, so that no play can finish it. Unlike
+    /// `is_incorrect`, this doesn't look at the solution.
+    pub fn contradiction(&self) -> Option<Contradiction> {
+        (0..self.solution.n_rows).find_map(|row| self.row_contradiction(row))
+    }
+
+    pub fn is_unsolvable(&self) -> bool {
+        self.contradiction().is_some()
     }
 
     /// Checks if the game board is fully solved.
@@ -406,6 +641,17 @@ impl GameBoard {
         true
     }
 
+    /// How many of the row's variants haven't been placed yet. Only selections count;
+    /// eliminated candidates don't place anything.
+    pub fn unplaced_in_row(&self, row: usize) -> usize {
+        let placed = self.selected[row][..self.solution.n_variants]
+            .iter()
+            .flatten()
+            .collect::<HashSet<_>>()
+            .len();
+        self.solution.n_variants - placed
+    }
+
     pub(crate) fn apply_deductions(&mut self, deductions: &[Deduction]) {
         for deduction in deductions {
             self.apply_deduction(deduction);
@@ -452,6 +698,37 @@ impl GameBoard {
         false
     }
 
+    /// Cells (row, column) whose selected tile doesn't match the solution
+    pub(crate) fn incorrect_selections(&self) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        for row in 0..self.solution.n_rows {
+            for col in 0..self.solution.n_variants {
+                if self.selected[row][col]
+                    .is_some_and(|variant| variant != self.solution.get(row, col).variant)
+                {
+                    cells.push((row, col));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Find an undecided cell whose solution tile has been eliminated, returning its column and
+    /// the tile. No deduction can recover from such a mistake; only restoring the candidate can.
+    pub(crate) fn find_eliminated_solution_tile(&self) -> Option<(usize, Tile)> {
+        for row in 0..self.solution.n_rows {
+            for col in 0..self.solution.n_variants {
+                let solution_tile = self.solution.get(row, col);
+                if self.selected[row][col].is_none()
+                    && !self.is_candidate_available(row, col, solution_tile.variant)
+                {
+                    return Some((col, solution_tile));
+                }
+            }
+        }
+        None
+    }
+
     pub fn get_selected_tiles(&self) -> Vec<Tile> {
         let mut tiles = Vec::new();
         for row in 0..self.solution.n_rows {
@@ -477,7 +754,7 @@ impl GameBoard {
     }
 
     pub(crate) fn remove_selection(&mut self, row: usize, col: usize) {
-        self.selected[row][col] = None;
+        self.update_cell(row, col, |board| board.selected[row][col] = None);
         self.recompute_resolved_row(row);
     }
 
@@ -517,6 +794,7 @@ mod tests {
             variants_range: 'a'..='d',
             difficulty: Difficulty::Easy,
             seed: 0,
+            ..Default::default()
         })
     }
 
@@ -606,6 +884,51 @@ mod tests {
         check_cell(3, 3, 'd');
     }
 
+    #[test]
+    fn test_row_missing_a_variant_is_invalid() {
+        // every column still has a candidate, but nowhere is left for 'a' in row 1
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1| bcd| bcd| bcd| bcd|
+-----------------
+2|abcd|abcd|abcd|abcd|
+-----------------
+3|abcd|abcd|abcd|abcd|";
+
+        let board = GameBoard::parse(input, create_test_solution());
+        assert!(board.is_valid_row_possibility(0));
+        assert!(!board.is_valid_row_possibility(1));
+        assert!(!board.is_valid_possibility());
+        assert_eq!(
+            board.contradiction(),
+            Some(Contradiction { row: 1, col: None })
+        );
+    }
+
+    #[test]
+    fn test_cell_without_candidates_is_a_contradiction() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------
+2|abcd|abcd|    |abcd|
+-----------------
+3|abcd|    |abcd|abcd|";
+
+        let board = GameBoard::parse(input, create_test_solution());
+        assert!(board.is_unsolvable());
+        assert_eq!(
+            board.contradiction(),
+            Some(Contradiction {
+                row: 2,
+                col: Some(2)
+            })
+        );
+        assert!(!GameBoard::new(create_test_solution()).is_unsolvable());
+    }
+
     #[test]
     fn test_auto_solve_row_simple() {
         let input = "\
@@ -687,4 +1010,182 @@ mod tests {
         // Verify first three cells are selected
         assert!(board.is_selected_in_column(&Tile::parse("0d"), 0));
     }
+
+    #[test]
+    fn test_elimination_reasons() {
+        let mut board = GameBoard::new(create_test_solution());
+        let reason = EliminationReason::Deduction {
+            clue: None,
+            deduction_kind: Some(crate::model::DeductionKind::HiddenSet),
+        };
+
+        board.remove_candidate(1, Tile::new(0, 'b'));
+        board.record_elimination_reason(1, Tile::new(0, 'b'), reason.clone());
+        board.remove_candidate(2, Tile::new(0, 'b'));
+        board.select_tile_at_position(3, Tile::new(0, 'a'));
+
+        assert_eq!(board.get_elimination_reason(0, 1, 'b'), Some(reason));
+        // eliminated without a recorded reason
+        assert_eq!(board.get_elimination_reason(0, 2, 'b'), None);
+        // unavailable because it's placed in another column
+        assert_eq!(
+            board.get_elimination_reason(0, 0, 'a'),
+            Some(EliminationReason::PlacedElsewhere(3))
+        );
+        assert_eq!(board.get_elimination_reason(0, 0, 'b'), None);
+
+        // restoring the candidate drops its provenance
+        board.show_candidate(1, Tile::new(0, 'b'));
+        board.remove_candidate(1, Tile::new(0, 'b'));
+        assert_eq!(board.get_elimination_reason(0, 1, 'b'), None);
+    }
+
+    #[test]
+    fn test_find_eliminated_solution_tile() {
+        let mut board = GameBoard::new(create_test_solution());
+        assert_eq!(board.find_eliminated_solution_tile(), None);
+
+        // eliminating a wrong candidate isn't a mistake
+        board.remove_candidate(1, Tile::new(2, 'a'));
+        assert_eq!(board.find_eliminated_solution_tile(), None);
+
+        board.remove_candidate(2, Tile::new(1, 'c'));
+        assert_eq!(
+            board.find_eliminated_solution_tile(),
+            Some((2, Tile::new(1, 'c')))
+        );
+
+        board.show_candidate(2, Tile::new(1, 'c'));
+        assert_eq!(board.find_eliminated_solution_tile(), None);
+    }
+
+    #[test]
+    fn test_incorrect_selections() {
+        let mut board = GameBoard::new(create_test_solution());
+        assert!(board.incorrect_selections().is_empty());
+
+        board.select_tile_at_position(1, Tile::new(0, 'b'));
+        board.select_tile_at_position(3, Tile::new(2, 'c'));
+        board.select_tile_at_position(2, Tile::new(3, 'c'));
+
+        assert_eq!(board.incorrect_selections(), vec![(2, 3)]);
+    }
+
+    #[test]
+    fn test_content_hash_tracks_changes() {
+        let mut board = GameBoard::new(create_test_solution());
+        let initial = board.content_hash();
+
+        board.remove_candidate(1, Tile::new(0, 'b'));
+        let removed = board.content_hash();
+        assert_ne!(removed, initial);
+
+        // removing an already-removed candidate changes nothing
+        board.remove_candidate(1, Tile::new(0, 'b'));
+        assert_eq!(board.content_hash(), removed);
+
+        board.show_candidate(1, Tile::new(0, 'b'));
+        assert_eq!(board.content_hash(), initial);
+
+        board.select_tile_at_position(2, Tile::new(1, 'c'));
+        assert_ne!(board.content_hash(), initial);
+        board.remove_selection(1, 2);
+        assert_eq!(board.content_hash(), initial);
+    }
+
+    #[test]
+    fn test_content_hash_survives_serialization() {
+        let mut board = GameBoard::new(create_test_solution());
+        board.remove_candidate(1, Tile::new(0, 'b'));
+        board.select_tile_at_position(2, Tile::new(1, 'c'));
+
+        let json = serde_json::to_string(&board).unwrap();
+        let loaded: GameBoard = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.content_hash(), board.content_hash());
+        assert!(loaded.same_content(&board));
+    }
+
+    #[test]
+    fn test_candidate_colors_are_kept_but_not_hashed() {
+        let mut board = GameBoard::new(create_test_solution());
+        let initial = board.content_hash();
+        let tile = Tile::new(0, 'b');
+        board.set_candidate_color(1, tile, Some(CandidateColor::Blue));
+        board.set_candidate_color(3, Tile::new(2, 'a'), Some(CandidateColor::Red));
+        assert_eq!(board.candidate_color(1, tile), Some(CandidateColor::Blue));
+        assert_eq!(board.content_hash(), initial);
+
+        let json = serde_json::to_string(&board).unwrap();
+        let loaded: GameBoard = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.candidate_color(1, tile), Some(CandidateColor::Blue));
+        assert_eq!(loaded.progress(), board.progress());
+
+        let restored = GameBoard::new(create_test_solution()).with_progress(&board.progress());
+        assert_eq!(restored.candidate_color(1, tile), Some(CandidateColor::Blue));
+
+        board.set_candidate_color(1, tile, None);
+        assert_eq!(board.candidate_color(1, tile), None);
+
+        // boards saved before candidates could be colored still load
+        let mut json = serde_json::to_value(&board).unwrap();
+        json.as_object_mut().unwrap().remove("candidate_colors");
+        let loaded: GameBoard = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.candidate_color(3, Tile::new(2, 'a')), None);
+    }
+
+    #[test]
+    fn test_content_hash_matches_full_equality() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let solution = create_test_solution();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..50 {
+            let mut a = GameBoard::new(solution.clone());
+            let mut b = GameBoard::new(solution.clone());
+            for _ in 0..rng.random_range(0..12) {
+                let row = rng.random_range(0..4);
+                let col = rng.random_range(0..4);
+                let tile = Tile::new(row, Tile::usize_to_variant(rng.random_range(0..4)));
+                let op = rng.random_range(0..4);
+                // usually apply the same operation to both boards, sometimes only to one
+                let boards = if rng.random_bool(0.8) {
+                    vec![&mut a, &mut b]
+                } else {
+                    vec![&mut a]
+                };
+                for board in boards {
+                    match op {
+                        0 => board.remove_candidate(col, tile),
+                        1 => board.show_candidate(col, tile),
+                        2 => board.select_tile_at_position(col, tile),
+                        _ => board.remove_selection(row, col),
+                    }
+                }
+            }
+
+            let mut rehashed = a.clone();
+            rehashed.rehash();
+            assert_eq!(rehashed.content_hash(), a.content_hash());
+
+            let equal = a.candidates == b.candidates && a.selected == b.selected;
+            assert_eq!(a.content_hash() == b.content_hash(), equal);
+            assert_eq!(a.same_content(&b), equal);
+        }
+    }
+
+    #[test]
+    fn test_unplaced_in_row() {
+        let mut board = GameBoard::new(create_test_solution());
+        assert_eq!(board.unplaced_in_row(0), 4);
+
+        // eliminations don't count as placements
+        board.remove_candidate(1, Tile::new(0, 'a'));
+        board.remove_candidate(2, Tile::new(0, 'a'));
+        assert_eq!(board.unplaced_in_row(0), 4);
+
+        board.select_tile_at_position(0, Tile::new(0, 'a'));
+        board.select_tile_at_position(3, Tile::new(0, 'd'));
+        assert_eq!(board.unplaced_in_row(0), 2);
+        assert_eq!(board.unplaced_in_row(1), 4);
+    }
 }
@@ -1,4 +1,9 @@
-use super::{ClueAddress, Difficulty, GameStateSnapshot};
+use std::collections::HashMap;
+
+use super::{
+    AutoSolveMode, CandidateColor, CandidateDisplay, ClueAddress, ClueSort, ClueType,
+    DeductionKind, Difficulty, GameStateSnapshot, Keymap, SharedGame,
+};
 
 #[derive(Debug, Clone, Default)]
 
@@ -6,30 +11,139 @@ pub struct SettingsChange {
     pub clue_tooltips_enabled: Option<bool>,
     pub clue_spotlight_enabled: Option<bool>,
     pub touch_screen_controls: Option<bool>,
-    pub auto_solve_enabled: Option<bool>,
+    pub auto_solve_mode: Option<AutoSolveMode>,
+    pub progressive_clue_reveal: Option<bool>,
+    pub lock_solved_cells: Option<bool>,
+    pub clue_nav_wraps_within_orientation: Option<bool>,
+    pub center_partial_candidate_row: Option<bool>,
+    pub log_hint_deductions: Option<bool>,
+    pub preferred_difficulty: Option<Difficulty>,
+    pub resume_last_game: Option<bool>,
+    pub track_elimination_reasons: Option<bool>,
+    pub hide_eliminated_candidates: Option<bool>,
+    pub generation_time_limit_secs: Option<u64>,
+    pub eager_clue_propagation: Option<bool>,
+    pub collapse_completed_clues: Option<bool>,
+    pub autosave_interval_secs: Option<u64>,
+    pub compact_negative_tiles: Option<bool>,
+    pub show_column_guides: Option<bool>,
+    pub retry_incorrect_submissions: Option<bool>,
+    pub incorrect_submission_penalty: Option<bool>,
+    pub confirm_last_candidate_elimination: Option<bool>,
+    pub hint_keeps_clue_selected: Option<bool>,
+    pub candidate_display: Option<CandidateDisplay>,
+    pub dim_inactive_clues: Option<bool>,
+    pub show_row_progress: Option<bool>,
+    pub cascade_delay_ms: Option<u64>,
+    pub pause_on_focus_loss: Option<bool>,
+    pub has_launched_before: Option<bool>,
+    pub featured_icon_category: Option<Option<usize>>,
+    pub show_timer_during_play: Option<bool>,
+    pub difficulty_dropdown_immediate: Option<bool>,
+    pub next_difficulty: Option<Option<Difficulty>>,
+    pub show_clue_panel_headers: Option<bool>,
+    pub shuffle_candidate_order: Option<bool>,
+    pub suggest_obvious_consequences: Option<bool>,
+    pub keymap: Option<Keymap>,
+    pub prefer_placement_hints: Option<bool>,
+    pub clue_sort: Option<ClueSort>,
+    pub clue_type_weights: Option<HashMap<ClueType, u32>>,
 }
 
 #[derive(Debug, Clone)]
 pub enum GameEngineCommand {
     CellSelect(usize, usize, Option<char>),
     CellClear(usize, usize, Option<char>),
+    // eliminate an available candidate, or restore an eliminated one
+    CellToggleCandidate(usize, usize, char),
+    // tag a candidate (row, col, variant) with a color, or clear its tag with None
+    CellTag(usize, usize, char, Option<CandidateColor>),
+    // eliminate several candidates (row, col, variant) as one undoable step
+    BatchEliminate(Vec<(usize, usize, char)>),
     ClueToggleComplete(ClueAddress), // clue_idx
     ClueToggleSelectedComplete,
+    // mark every clue the board already satisfies as completed, in one undoable step
+    CompleteAllSatisfiedClues,
     ClueFocus(Option<ClueAddress>), // clue_idx when Some
     ClueFocusNext(i32),
+    ToggleClueVisibility, // hide or show the clue panels; solving state is unaffected
+    SetHomeClue,          // bookmark the selected clue, or clear the bookmark if none is selected
+    JumpToHomeClue,       // focus the bookmarked clue
     NewGame(Option<Difficulty>, Option<u64>), // grid rows, grid columns
+    // today's daily puzzle, the same board for everyone
+    NewDailyPuzzle,
+    // new puzzle at the current difficulty with the current puzzle's mix of clue types
+    NewGameLikeCurrent,
+    // switch back to the game the last new game replaced
+    RestorePreviousGame,
+    // regenerate a shared puzzle and pick up where the sender left off
+    LoadSharedGame(SharedGame),
+    NewGameRequiringTechnique(DeductionKind), // debug designer tool
     CompletePuzzle,
     Solve,
     RewindLastGood,
+    // flash the placed tiles that don't match the solution, as a retried submission does; costs
+    // the error correction hint penalty
+    ShowIncorrectCells,
     IncrementHintsUsed,
     ShowHint,
+    ApplyNextDeduction, // apply one deduction from the hint's clue, counting as a hint
+    ShowSolutionWalkthrough,
+    // report whether the puzzle can still be solved from the current board, changing nothing
+    CheckSolvability,
+    // restore a solution tile the player eliminated by mistake, at a higher hint cost
+    RestoreEliminatedSolutionTile,
     Undo,
     Redo,
+    // move straight to a board in the history, keeping the boards after it for redo
+    GotoHistory(usize),
+    // snapshot the board so the moves that follow can be kept or thrown away together
+    BeginSandbox,
+    // keep the sandbox moves as ordinary moves
+    CommitSandbox,
+    // return to the board from before the sandbox, forgetting its moves
+    DiscardSandbox,
     Pause,
     Resume,
+    // the window lost focus; pauses if the player asked for that in settings
+    FocusLost,
     Quit,
     Submit,
     Restart,
     LoadState(GameStateSnapshot),
     ChangeSettings(SettingsChange),
 }
+
+impl GameEngineCommand {
+    /// Commands that read or change the board. These are ignored while the game is paused, since
+    /// the board is hidden then and the clock isn't running.
+    pub fn is_board_interaction(&self) -> bool {
+        matches!(
+            self,
+            GameEngineCommand::CellSelect(..)
+                | GameEngineCommand::CellClear(..)
+                | GameEngineCommand::CellToggleCandidate(..)
+                | GameEngineCommand::CellTag(..)
+                | GameEngineCommand::BatchEliminate(..)
+                | GameEngineCommand::ClueToggleComplete(..)
+                | GameEngineCommand::ClueToggleSelectedComplete
+                | GameEngineCommand::CompleteAllSatisfiedClues
+                | GameEngineCommand::CompletePuzzle
+                | GameEngineCommand::Solve
+                | GameEngineCommand::RewindLastGood
+                | GameEngineCommand::ShowIncorrectCells
+                | GameEngineCommand::IncrementHintsUsed
+                | GameEngineCommand::ShowHint
+                | GameEngineCommand::ApplyNextDeduction
+                | GameEngineCommand::ShowSolutionWalkthrough
+                | GameEngineCommand::CheckSolvability
+                | GameEngineCommand::RestoreEliminatedSolutionTile
+                | GameEngineCommand::Undo
+                | GameEngineCommand::Redo
+                | GameEngineCommand::GotoHistory(..)
+                | GameEngineCommand::BeginSandbox
+                | GameEngineCommand::CommitSandbox
+                | GameEngineCommand::DiscardSandbox
+        )
+    }
+}
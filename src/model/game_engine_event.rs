@@ -1,7 +1,10 @@
-use super::{ClueSet, ClueWithAddress, Deduction, Difficulty, TimerState};
+use super::{
+    ClueSet, ClueWithAddress, Deduction, DeductionKind, DeductionLogEntry, Difficulty, TimerState,
+};
 use crate::game::settings::Settings;
 use crate::model::{ClueAddress, GameBoard, GameStats};
-use std::collections::HashSet;
+use crate::solver::puzzle_metrics::{PuzzleMetrics, PuzzleRating};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +18,10 @@ pub enum PuzzleCompletionState {
     Incomplete,
     Correct(GameStats),
     Incorrect,
+    /// Incorrect, but the player may keep editing; nothing is recorded or penalized
+    TryAgain {
+        incorrect_cells: Vec<(usize, usize)>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,6 +29,7 @@ pub enum GameBoardChangeReason {
     NewGame,
     Undo,
     Redo,
+    HistoryJump,
     ClueStatusChanged,
     TileStatusChanged,
     GameLoaded,
@@ -47,8 +55,58 @@ pub enum GameEngineEvent {
     TimerStateChanged(TimerState),
     PuzzleSubmissionReadyChanged(bool),
     PuzzleCompleted(PuzzleCompletionState),
+    /// an unfinished game at this difficulty was replaced by a different puzzle
+    GameAbandoned(Difficulty),
     SettingsChanged(Settings),
     PuzzleGenerationStarted,
+    PuzzleMetricsMeasured(PuzzleMetrics),
+    /// star rating (1-5) of the current puzzle, independent of its difficulty tier; None if it
+    /// isn't known, e.g. for a save from before ratings were kept
+    PuzzleRated(Option<u8>),
+    /// difficulty of the current puzzle by the solving techniques it needs; None if it isn't
+    /// known, as with `PuzzleRated`
+    PuzzleDifficultyRated(Option<PuzzleRating>),
+    /// generation hit the time limit, so a simplified puzzle at this difficulty was generated
+    PuzzleGenerationFellBack(Difficulty),
+    /// the shared progress didn't fit the regenerated puzzle, so it was started fresh instead
+    SharedGameRejected,
+    TechniqueSearchFinished {
+        deduction_kind: DeductionKind,
+        max_attempts: usize,
+        seed: Option<u64>,
+    },
+    DeductionLogUpdated(Vec<DeductionLogEntry>),
+    /// the steps a solver takes from the start of the game to the solution
+    SolutionWalkthrough(Vec<DeductionLogEntry>),
+    /// true when the clue panels should be hidden
+    CluesHiddenChanged(bool),
+    /// while the player's moves are tentative, the history index the sandbox started from, which
+    /// undo can't go back past; None once the sandbox is committed or discarded
+    SandboxChanged(Option<usize>),
+    /// the clue whose deductions were most recently applied by a solve step, hint or propagation
+    LastUsefulClueChanged(Option<ClueWithAddress>),
+    /// placed tiles that don't match the solution, pointed out on request without submitting
+    IncorrectCellsShown(Vec<(usize, usize)>),
+    /// the player tried to change a cell that doesn't accept the interaction (e.g. selecting in a
+    /// solved cell), which is otherwise ignored
+    InvalidInteraction {
+        row: usize,
+        col: usize,
+    },
+    /// whether each clue yields a deduction or can be completed on the current board; only sent
+    /// while dimming inactive clues is enabled
+    ClueActionabilityChanged(HashMap<ClueAddress, bool>),
+    /// whether a headless solve from the current board still reaches the solution
+    SolvabilityChecked(bool),
+    /// a move left the board contradicting itself: a cell with no candidates, or with `col` None,
+    /// a variant with no column left in `row`. Sent when the contradiction first appears.
+    BoardContradiction {
+        row: usize,
+        col: Option<usize>,
+    },
+    /// a tile the player just placed lets this clue alone place another tile; offered for the
+    /// player to apply, never applied automatically
+    ObviousConsequenceAvailable(Deduction, ClueAddress),
 }
 
 impl GameEngineEvent {}
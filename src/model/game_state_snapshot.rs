@@ -1,25 +1,75 @@
 use log::trace;
 
+use crate::game::generation_stats::record_generation_time;
 use crate::model::{GameBoard, Solution};
-use crate::solver::clue_generator::ClueGeneratorResult;
-use crate::solver::generate_clues;
-use std::path::PathBuf;
-use std::time::SystemTime;
+use crate::solver::clue_generator::{
+    generate_clues_with_options, ClueGeneratorOptions, ClueGeneratorResult,
+};
+use crate::solver::puzzle_metrics::{rate_difficulty, rate_puzzle, PuzzleRating};
+use crate::solver::{generate_clues, WeightedClueType};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::time::{Duration, Instant, SystemTime};
 use std::{fs, sync::Arc};
 
-use super::{Difficulty, TimerState};
+use super::{
+    BoardProgress, ClueAddress, ClueType, DeductionLogEntry, Difficulty, SharedGame, Tile,
+    TimerState,
+};
+
+/// Most undo history entries a save keeps, to bound the file size
+const MAX_SAVED_HISTORY: usize = 200;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GameStateSnapshot {
     pub board: GameBoard,
     pub timer_state: TimerState,
     pub hints_used: u32,
+    #[serde(default)]
+    pub deduction_log: Vec<DeductionLogEntry>,
+    #[serde(default)]
+    pub home_clue: Option<ClueAddress>,
+    /// Tiles the puzzle starts with, to rebuild its starting board from. Saves from before they
+    /// were kept have none.
+    #[serde(default)]
+    pub revealed_tiles: Vec<Tile>,
+    /// Star rating from `rate_puzzle`, worked out when the puzzle is generated since it takes a
+    /// full solve. Saves from before ratings were kept have none.
+    #[serde(default)]
+    pub rating: Option<u8>,
+    /// Solve steps by technique from `rate_difficulty`, worked out along with `rating`
+    #[serde(default)]
+    pub difficulty_rating: Option<PuzzleRating>,
+    /// Undo history, oldest first, with `history_index` the position of `board` in it. Saves
+    /// from before history was kept have none, and load with `board` as the only entry.
+    #[serde(default)]
+    history: Vec<BoardProgress>,
+    #[serde(default)]
+    history_index: usize,
+}
+
+/// Where a snapshot is persisted. The autosave is what's resumed on launch; the previous game is
+/// a backup of the game that a new game replaced, so an accidental new game can be undone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveSlot {
+    Autosave,
+    PreviousGame,
+}
+
+impl SaveSlot {
+    fn file_name(&self) -> &'static str {
+        match self {
+            SaveSlot::Autosave => "game_state.json",
+            SaveSlot::PreviousGame => "previous_game_state.json",
+        }
+    }
 }
 
-fn game_state_path() -> PathBuf {
+fn game_state_path(slot: SaveSlot) -> PathBuf {
     let data_dir = glib::user_data_dir();
     let mut path = data_dir.join("emojiclu");
-    path.push("game_state.json");
+    path.push(slot.file_name());
     path
 }
 
@@ -34,29 +84,200 @@ impl GameStateSnapshot {
             board,
             timer_state: paused_timer_state,
             hints_used,
+            deduction_log: vec![],
+            home_clue: None,
+            revealed_tiles: vec![],
+            rating: None,
+            difficulty_rating: None,
+            history: vec![],
+            history_index: 0,
         }
     }
 
+    pub fn with_deduction_log(mut self, deduction_log: Vec<DeductionLogEntry>) -> Self {
+        self.deduction_log = deduction_log;
+        self
+    }
+
+    pub fn with_home_clue(mut self, home_clue: Option<ClueAddress>) -> Self {
+        self.home_clue = home_clue;
+        self
+    }
+
+    pub fn with_revealed_tiles(mut self, revealed_tiles: Vec<Tile>) -> Self {
+        self.revealed_tiles = revealed_tiles;
+        self
+    }
+
+    pub fn with_rating(mut self, rating: Option<u8>) -> Self {
+        self.rating = rating;
+        self
+    }
+
+    pub fn with_difficulty_rating(mut self, difficulty_rating: Option<PuzzleRating>) -> Self {
+        self.difficulty_rating = difficulty_rating;
+        self
+    }
+
+    /// Rate a newly generated puzzle from its starting board. Both ratings take a full solve, so
+    /// they're worked out here, off the main thread.
+    fn rated(self) -> Self {
+        let rating = rate_puzzle(&self.board.clue_set, &self.board.solution);
+        let clues = self
+            .board
+            .clue_set
+            .all_clues()
+            .map(|clue| clue.clue.clone())
+            .collect();
+        let difficulty_rating = rate_difficulty(&self.board, &clues);
+        self.with_rating(Some(rating))
+            .with_difficulty_rating(Some(difficulty_rating))
+    }
+
+    /// Keep undo history, `history[history_index]` being the snapshot's board. Only the last
+    /// `MAX_SAVED_HISTORY` entries up to the current one are kept, along with as many redo entries
+    /// as still fit.
+    pub fn with_history(mut self, history: &[Arc<GameBoard>], history_index: usize) -> Self {
+        let start = history_index.saturating_sub(MAX_SAVED_HISTORY - 1);
+        let end = history.len().min(start + MAX_SAVED_HISTORY);
+        self.history = history[start..end]
+            .iter()
+            .map(|board| board.progress())
+            .collect();
+        self.history_index = history_index - start;
+        self
+    }
+
+    /// The undo history to resume with, and the index of `board` in it. Falls back to `board`
+    /// alone when there's no history, or it doesn't match the board.
+    pub fn history_boards(&self) -> (Vec<Arc<GameBoard>>, usize) {
+        let matches_board = self
+            .history
+            .get(self.history_index)
+            .is_some_and(|progress| *progress == self.board.progress());
+        if !matches_board {
+            return (vec![Arc::new(self.board.clone())], 0);
+        }
+        let history = self
+            .history
+            .iter()
+            .map(|progress| Arc::new(self.board.with_progress(progress)))
+            .collect();
+        (history, self.history_index)
+    }
+
+    /// Text to send someone the board as it stands: the puzzle code plus what's been placed and
+    /// eliminated. Timer, hints and completed clues aren't included. None if the puzzle has no
+    /// code to regenerate it from.
+    pub fn to_share_string(&self) -> Option<String> {
+        SharedGame::from_board(&self.board).map(|shared_game| shared_game.to_string())
+    }
+
+    /// Parse text from `to_share_string`. The puzzle itself has to be regenerated from the code
+    /// before the progress can be applied.
+    pub fn from_share_string(input: &str) -> Option<SharedGame> {
+        SharedGame::parse(input)
+    }
+
     pub fn generate_new(difficulty: Difficulty, seed: Option<u64>) -> Self {
-        let solution = Arc::new(Solution::new(difficulty, seed));
+        Self::regenerate(&Solution::new(difficulty, seed))
+    }
+
+    /// Generate the clues for `solution` from scratch, with the clue weights and configuration it
+    /// records, so a puzzle's own solution gives that puzzle again
+    pub fn regenerate(solution: &Solution) -> Self {
+        let started = Instant::now();
+        let solution = Arc::new(solution.clone());
         trace!(target: "game_state", "Generated solution: {:?}", solution);
         let blank_board = GameBoard::new(Arc::clone(&solution));
         let ClueGeneratorResult {
             clues: _,
             board,
-            revealed_tiles: _,
-        } = generate_clues(&blank_board);
+            revealed_tiles,
+        } = if solution.simplified {
+            generate_clues_with_options(&blank_board, &ClueGeneratorOptions::simplified())
+                .expect("generation without a deadline always finishes")
+        } else {
+            generate_clues(&blank_board)
+        };
+        record_generation_time(solution.difficulty, started.elapsed());
 
         Self::new(board, TimerState::default(), 0)
+            .with_revealed_tiles(revealed_tiles)
+            .rated()
+    }
+
+    /// Like `generate_new`, but if clue generation exceeds `time_limit` the attempt is abandoned
+    /// and the same solution is regenerated with the simplified (fast) configuration. The flag is
+    /// true when that fallback was used. Returns None if `cancelled` gets set along the way.
+    /// `time_limit` only applies to random puzzles: a seed names one puzzle, e.g. the daily, and
+    /// falling back would give a different one.
+    /// `clue_weights`, when given, replace the puzzle variant's choice of clue types; otherwise
+    /// `clue_type_weights` adjust it.
+    pub fn generate_within(
+        difficulty: Difficulty,
+        seed: Option<u64>,
+        time_limit: Option<Duration>,
+        cancelled: Option<Arc<AtomicBool>>,
+        clue_weights: Option<Vec<WeightedClueType>>,
+        clue_type_weights: HashMap<ClueType, u32>,
+    ) -> Option<(Self, bool)> {
+        let started = Instant::now();
+        let time_limit = time_limit.filter(|_| seed.is_none());
+        // the weights are kept with the solution, so the puzzle can be regenerated from it
+        let solution = Arc::new(Solution {
+            clue_weights,
+            clue_type_weights,
+            ..Solution::new(difficulty, seed)
+        });
+        trace!(target: "game_state", "Generated solution: {:?}", solution);
+        let blank_board = GameBoard::new(Arc::clone(&solution));
+        let options = ClueGeneratorOptions {
+            deadline: time_limit.map(|time_limit| Instant::now() + time_limit),
+            cancelled: cancelled.clone(),
+            ..ClueGeneratorOptions::for_difficulty(difficulty)
+        };
+
+        let (result, fell_back) = match generate_clues_with_options(&blank_board, &options) {
+            Some(result) => (result, false),
+            None if options.is_cancelled() => return None,
+            None => {
+                log::warn!(
+                    target: "game_state",
+                    "Generation exceeded {:?}; falling back to simplified generation",
+                    time_limit
+                );
+                let simplified = ClueGeneratorOptions {
+                    cancelled,
+                    ..ClueGeneratorOptions::simplified()
+                };
+                let blank_board = GameBoard::new(Arc::new(Solution {
+                    simplified: true,
+                    ..solution.as_ref().clone()
+                }));
+                // without a deadline, only cancellation stops generation
+                let result = generate_clues_with_options(&blank_board, &simplified)?;
+                (result, true)
+            }
+        };
+        record_generation_time(difficulty, started.elapsed());
+
+        let snapshot = Self::new(result.board, TimerState::default(), 0)
+            .with_revealed_tiles(result.revealed_tiles)
+            .rated();
+        Some((snapshot, fell_back))
     }
 
     pub fn save(&self) -> bool {
-        save_game_state_snapshot(self)
+        save_game_state_snapshot(self, &game_state_path(SaveSlot::Autosave))
+    }
+
+    pub fn save_to(&self, slot: SaveSlot) -> bool {
+        save_game_state_snapshot(self, &game_state_path(slot))
     }
 }
 
-fn save_game_state_snapshot(game_state: &GameStateSnapshot) -> bool {
-    let path = game_state_path();
+fn save_game_state_snapshot(game_state: &GameStateSnapshot, path: &Path) -> bool {
     if let Some(dir) = path.parent() {
         if let Err(_) = fs::create_dir_all(dir) {
             return false;
@@ -74,11 +295,370 @@ fn save_game_state_snapshot(game_state: &GameStateSnapshot) -> bool {
 }
 
 pub fn load_game_state_snapshot() -> Option<GameStateSnapshot> {
-    let path = game_state_path();
-    if let Ok(contents) = fs::read_to_string(&path) {
+    load_game_state_snapshot_from(SaveSlot::Autosave)
+}
+
+pub fn load_game_state_snapshot_from(slot: SaveSlot) -> Option<GameStateSnapshot> {
+    read_game_state_snapshot(&game_state_path(slot))
+}
+
+fn read_game_state_snapshot(path: &Path) -> Option<GameStateSnapshot> {
+    if let Ok(contents) = fs::read_to_string(path) {
         if let Ok(game_state) = serde_json::from_str::<GameStateSnapshot>(&contents) {
             return Some(game_state);
         }
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        ClueAddress, ClueOrientation, Deduction, DeductionLogEntry, PuzzleCode, VerticalClueType,
+    };
+    use crate::tests::TempDataDir;
+
+    #[test]
+    fn test_deduction_log_round_trips() {
+        let deduction_log = vec![
+            DeductionLogEntry {
+                clue: Some(ClueAddress {
+                    orientation: ClueOrientation::Horizontal,
+                    index: 2,
+                }),
+                deductions: vec![Deduction::parse("0a not col 1")],
+            },
+            DeductionLogEntry {
+                clue: None,
+                deductions: vec![Deduction::parse("1b is col 0")],
+            },
+        ];
+        let snapshot = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42))
+            .with_deduction_log(deduction_log.clone());
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: GameStateSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.deduction_log, deduction_log);
+    }
+
+    /// A game in progress: a placed tile, an eliminated candidate, a completed clue, hints used
+    /// and a paused timer
+    fn mid_game_snapshot() -> GameStateSnapshot {
+        let mut board = GameStateSnapshot::generate_new(Difficulty::Moderate, Some(7)).board;
+        let placed = board.solution.get(0, 0);
+        board.select_tile_from_solution(placed);
+        let eliminated = board.solution.get(1, 2);
+        board.remove_candidate(3, eliminated);
+        let completed = board.clue_set.all_clues().next().unwrap().address();
+        board.toggle_clue_completed(completed);
+
+        let now = SystemTime::now();
+        let timer_state = TimerState {
+            started_timestamp: now - Duration::from_secs(90),
+            paused_duration: Duration::from_secs(12),
+            paused_timestamp: None,
+            ended_timestamp: None,
+            penalty_duration: Duration::from_secs(0),
+        }
+        .paused(now);
+
+        GameStateSnapshot::new(board, timer_state, 3)
+            .with_deduction_log(vec![DeductionLogEntry {
+                clue: Some(completed),
+                deductions: vec![Deduction::parse("1c not col 3")],
+            }])
+            .with_home_clue(Some(completed))
+    }
+
+    fn unix_secs(time: SystemTime) -> u64 {
+        time.duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64()
+            .round() as u64
+    }
+
+    #[test]
+    fn test_mid_game_snapshot_round_trips() {
+        let snapshot = mid_game_snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: GameStateSnapshot = serde_json::from_str(&json).unwrap();
+
+        // board: candidates and selections (via Debug), solution, clue set and completed clues
+        assert_eq!(
+            format!("{:?}", restored.board),
+            format!("{:?}", snapshot.board)
+        );
+        assert_eq!(restored.board.solution.grid, snapshot.board.solution.grid);
+        assert_eq!(restored.board.solution.seed, snapshot.board.solution.seed);
+        assert_eq!(
+            restored.board.solution.difficulty,
+            snapshot.board.solution.difficulty
+        );
+        let clues = |snapshot: &GameStateSnapshot| {
+            snapshot
+                .board
+                .clue_set
+                .all_clues()
+                .map(|c| (c.address(), c.clue.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(clues(&restored), clues(&snapshot));
+        assert_eq!(
+            restored.board.completed_clues,
+            snapshot.board.completed_clues
+        );
+
+        // timestamps are stored rounded to the nearest second
+        let (restored_timer, timer) = (&restored.timer_state, &snapshot.timer_state);
+        assert_eq!(
+            unix_secs(restored_timer.started_timestamp),
+            unix_secs(timer.started_timestamp)
+        );
+        assert_eq!(
+            restored_timer.paused_timestamp.map(unix_secs),
+            timer.paused_timestamp.map(unix_secs)
+        );
+        assert_eq!(restored_timer.paused_duration, timer.paused_duration);
+        assert_eq!(restored_timer.penalty_duration, timer.penalty_duration);
+        assert_eq!(restored_timer.ended_timestamp, None);
+
+        assert_eq!(restored.hints_used, 3);
+        assert_eq!(restored.deduction_log, snapshot.deduction_log);
+        assert_eq!(restored.home_clue, snapshot.home_clue);
+    }
+
+    #[test]
+    fn test_snapshot_without_deduction_log_loads() {
+        let snapshot = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42));
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        json.as_object_mut().unwrap().remove("deduction_log");
+
+        let restored: GameStateSnapshot = serde_json::from_value(json).unwrap();
+
+        assert!(restored.deduction_log.is_empty());
+    }
+
+    #[test]
+    fn test_home_clue_round_trips() {
+        let home_clue = Some(ClueAddress {
+            orientation: ClueOrientation::Vertical,
+            index: 3,
+        });
+        let snapshot =
+            GameStateSnapshot::generate_new(Difficulty::Easy, Some(42)).with_home_clue(home_clue);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: GameStateSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.home_clue, home_clue);
+
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        json.as_object_mut().unwrap().remove("home_clue");
+        let restored: GameStateSnapshot = serde_json::from_value(json).unwrap();
+
+        assert_eq!(restored.home_clue, None);
+    }
+
+    #[test]
+    fn test_history_round_trips() {
+        let snapshot = mid_game_snapshot();
+        let board = snapshot.board.clone();
+        let blank = board.with_progress(&GameBoard::new(board.solution.clone()).progress());
+        let mut redo = board.clone();
+        redo.remove_candidate(4, board.solution.get(2, 1));
+        let history = [blank, board, redo].map(Arc::new);
+        let snapshot = snapshot.with_history(&history, 1);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: GameStateSnapshot = serde_json::from_str(&json).unwrap();
+        let (restored_history, history_index) = restored.history_boards();
+
+        assert_eq!(history_index, 1);
+        assert_eq!(restored_history.len(), history.len());
+        for (restored, board) in restored_history.iter().zip(history.iter()) {
+            assert!(restored.same_content(board));
+            assert_eq!(restored.completed_clues, board.completed_clues);
+            assert!(Arc::ptr_eq(
+                &restored.clue_set,
+                &restored_history[0].clue_set
+            ));
+        }
+
+        // saves from before history was kept resume with the board alone
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("history");
+        fields.remove("history_index");
+        let restored: GameStateSnapshot = serde_json::from_value(json).unwrap();
+        let (restored_history, history_index) = restored.history_boards();
+
+        assert_eq!(history_index, 0);
+        assert_eq!(restored_history.len(), 1);
+        assert!(restored_history[0].same_content(&snapshot.board));
+    }
+
+    #[test]
+    fn test_saved_history_is_capped_around_current_board() {
+        let snapshot = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42));
+        let history = vec![Arc::new(snapshot.board.clone()); MAX_SAVED_HISTORY + 50];
+
+        let (saved, history_index) = snapshot
+            .clone()
+            .with_history(&history, 230)
+            .history_boards();
+        assert_eq!(saved.len(), MAX_SAVED_HISTORY);
+        assert_eq!(history_index, MAX_SAVED_HISTORY - 1);
+
+        let (saved, history_index) = snapshot.with_history(&history, 10).history_boards();
+        assert_eq!(saved.len(), MAX_SAVED_HISTORY);
+        assert_eq!(history_index, 10);
+    }
+
+    #[test]
+    fn test_snapshot_file_round_trips() {
+        let data_dir = TempDataDir::new();
+        let path = data_dir.path.join(SaveSlot::PreviousGame.file_name());
+        let snapshot = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42));
+
+        assert!(save_game_state_snapshot(&snapshot, &path));
+        let restored = read_game_state_snapshot(&path).unwrap();
+        drop(data_dir);
+
+        assert_eq!(restored.board.solution.seed, 42);
+        assert_eq!(
+            restored.board.clue_set.all_clues().count(),
+            snapshot.board.clue_set.all_clues().count()
+        );
+        assert!(read_game_state_snapshot(&path).is_none());
+    }
+
+    #[test]
+    fn test_generate_within_falls_back_when_out_of_time() {
+        let (snapshot, fell_back) = GameStateSnapshot::generate_within(
+            Difficulty::Easy,
+            None,
+            Some(Duration::ZERO),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(fell_back);
+        assert!(snapshot.board.clue_set.all_clues().count() > 0);
+        // the seed alone would regenerate it with the normal configuration
+        assert_eq!(snapshot.board.solution.puzzle_code(), None);
+        let regenerated = GameStateSnapshot::regenerate(&snapshot.board.solution);
+        assert_eq!(
+            regenerated.board.clue_set.all_clues().count(),
+            snapshot.board.clue_set.all_clues().count()
+        );
+    }
+
+    #[test]
+    fn test_generate_within_ignores_time_limit_for_seeds() {
+        let (snapshot, fell_back) = GameStateSnapshot::generate_within(
+            Difficulty::Easy,
+            Some(42),
+            Some(Duration::ZERO),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(!fell_back);
+        assert_eq!(
+            snapshot.board.solution.puzzle_code(),
+            Some(PuzzleCode::new(Difficulty::Easy, 42))
+        );
+    }
+
+    #[test]
+    fn test_generate_within_without_limit_matches_generate_new() {
+        let (snapshot, fell_back) = GameStateSnapshot::generate_within(
+            Difficulty::Easy,
+            Some(42),
+            None,
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+        let expected = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42));
+
+        assert!(!fell_back);
+        assert_eq!(
+            snapshot.board.clue_set.all_clues().count(),
+            expected.board.clue_set.all_clues().count()
+        );
+    }
+
+    #[test]
+    fn test_generate_within_returns_none_when_cancelled() {
+        let cancelled = Arc::new(AtomicBool::new(true));
+
+        let result = GameStateSnapshot::generate_within(
+            Difficulty::Easy,
+            Some(42),
+            None,
+            Some(cancelled),
+            None,
+            HashMap::new(),
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_regenerate_keeps_clue_weights() {
+        let one_matches_either = ClueType::Vertical(VerticalClueType::OneMatchesEither);
+        let (snapshot, _) = GameStateSnapshot::generate_within(
+            Difficulty::Moderate,
+            Some(42),
+            None,
+            None,
+            None,
+            HashMap::from([(one_matches_either, 0)]),
+        )
+        .unwrap();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: GameStateSnapshot = serde_json::from_str(&json).unwrap();
+
+        let regenerated = GameStateSnapshot::regenerate(&restored.board.solution);
+
+        assert_eq!(restored.board.solution.puzzle_code(), None);
+        let clues = |snapshot: &GameStateSnapshot| {
+            snapshot
+                .board
+                .clue_set
+                .all_clues()
+                .map(|addressed_clue| addressed_clue.clue.clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(clues(&regenerated) == clues(&snapshot));
+    }
+
+    #[test]
+    fn test_generated_snapshot_carries_rating() {
+        let snapshot = GameStateSnapshot::generate_new(Difficulty::Easy, Some(42));
+        assert_eq!(
+            snapshot.rating,
+            Some(rate_puzzle(
+                &snapshot.board.clue_set,
+                &snapshot.board.solution
+            ))
+        );
+
+        let difficulty_rating = snapshot.difficulty_rating.as_ref().unwrap();
+        assert!(difficulty_rating.solved);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: GameStateSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.rating, snapshot.rating);
+        assert_eq!(restored.difficulty_rating, snapshot.difficulty_rating);
+    }
+}
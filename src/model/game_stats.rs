@@ -1,6 +1,6 @@
 use crate::model::Difficulty;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -11,6 +11,12 @@ pub struct GameStats {
     pub difficulty: Difficulty,
     pub timestamp: i64,
     pub playthrough_id: Uuid,
+    /// The clue panels were hidden at some point during the game
+    #[serde(default)]
+    pub clues_hidden: bool,
+    /// Seed of the completed puzzle; missing from games recorded before seeds were kept
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -19,4 +25,151 @@ pub struct GlobalStats {
     pub total_games_played: u32,
     pub total_time_played: Duration,
     pub total_hints_used: u32,
+    /// Number of games for each hint count; missing for games recorded before it was kept
+    #[serde(default)]
+    pub hint_counts: BTreeMap<u32, u32>,
+    /// Games solved in a row since the last one abandoned for a new game
+    #[serde(default)]
+    pub current_streak: u32,
+    #[serde(default)]
+    pub best_streak: u32,
+}
+
+/// Hint counts beyond this display as "999+"
+const MAX_DISPLAYED_HINT_COUNT: u32 = 999;
+
+/// Format a hint count the same way everywhere it's shown
+pub fn format_hint_count(hints_used: u32) -> String {
+    if hints_used > MAX_DISPLAYED_HINT_COUNT {
+        format!("{}+", MAX_DISPLAYED_HINT_COUNT)
+    } else {
+        hints_used.to_string()
+    }
+}
+
+impl GlobalStats {
+    pub fn record_game(&mut self, stats: &GameStats) {
+        self.total_games_played = self.total_games_played.saturating_add(1);
+        self.total_time_played = self.total_time_played.saturating_add(stats.completion_time);
+        self.total_hints_used = self.total_hints_used.saturating_add(stats.hints_used);
+        let n_games = self.hint_counts.entry(stats.hints_used).or_default();
+        *n_games = n_games.saturating_add(1);
+        self.current_streak = self.current_streak.saturating_add(1);
+        self.best_streak = self.best_streak.max(self.current_streak);
+    }
+
+    /// A game was left unsolved for a new one
+    pub fn break_streak(&mut self) {
+        self.current_streak = 0;
+    }
+
+    /// Add another group's totals into this one
+    pub fn merge(&mut self, other: &GlobalStats) {
+        self.total_games_played = self
+            .total_games_played
+            .saturating_add(other.total_games_played);
+        self.total_time_played = self
+            .total_time_played
+            .saturating_add(other.total_time_played);
+        self.total_hints_used = self.total_hints_used.saturating_add(other.total_hints_used);
+        for (hints_used, n_games) in other.hint_counts.iter() {
+            let merged = self.hint_counts.entry(*hints_used).or_default();
+            *merged = merged.saturating_add(*n_games);
+        }
+        // streaks run within a single difficulty, so a group shows its longest
+        self.current_streak = self.current_streak.max(other.current_streak);
+        self.best_streak = self.best_streak.max(other.best_streak);
+    }
+
+    pub fn mean_hints_used(&self) -> Option<f64> {
+        (self.total_games_played > 0)
+            .then(|| self.total_hints_used as f64 / self.total_games_played as f64)
+    }
+
+    /// Median hints per game, which a few games with huge hint counts can't skew the way they do
+    /// the mean. Only covers games recorded since hint counts were kept.
+    pub fn median_hints_used(&self) -> Option<u32> {
+        let n_games: u64 = self.hint_counts.values().map(|n| *n as u64).sum();
+        if n_games == 0 {
+            return None;
+        }
+        let median_index = (n_games - 1) / 2;
+        let mut n_seen = 0;
+        for (hints_used, n) in self.hint_counts.iter() {
+            n_seen += *n as u64;
+            if n_seen > median_index {
+                return Some(*hints_used);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn game(hints_used: u32) -> GameStats {
+        GameStats {
+            completion_time: Duration::from_secs(60),
+            hints_used,
+            grid_size: 4,
+            difficulty: Difficulty::Easy,
+            timestamp: 0,
+            playthrough_id: Uuid::new_v4(),
+            clues_hidden: false,
+            seed: None,
+        }
+    }
+
+    #[test]
+    fn test_median_resists_outliers() {
+        let mut stats = GlobalStats::default();
+        for hints_used in [0, 1, 1, 2, u32::MAX] {
+            stats.record_game(&game(hints_used));
+        }
+
+        assert_eq!(stats.median_hints_used(), Some(1));
+        // the total saturates instead of wrapping around
+        assert_eq!(stats.total_hints_used, u32::MAX);
+        assert!(stats.mean_hints_used().unwrap() > 1000.0);
+    }
+
+    #[test]
+    fn test_no_games_have_no_hint_averages() {
+        let stats = GlobalStats::default();
+        assert_eq!(stats.median_hints_used(), None);
+        assert_eq!(stats.mean_hints_used(), None);
+    }
+
+    #[test]
+    fn test_format_hint_count() {
+        assert_eq!(format_hint_count(0), "0");
+        assert_eq!(format_hint_count(999), "999");
+        assert_eq!(format_hint_count(u32::MAX), "999+");
+    }
+
+    #[test]
+    fn test_streaks() {
+        let mut stats = GlobalStats::default();
+        for _ in 0..3 {
+            stats.record_game(&game(0));
+        }
+        stats.break_streak();
+        stats.record_game(&game(0));
+
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.best_streak, 3);
+
+        let mut merged = GlobalStats::default();
+        merged.merge(&stats);
+        merged.merge(&GlobalStats {
+            current_streak: 2,
+            best_streak: 2,
+            ..Default::default()
+        });
+        assert_eq!(merged.current_streak, 2);
+        assert_eq!(merged.best_streak, 3);
+    }
 }
@@ -1,5 +1,4 @@
-use super::ClueAddress;
-use gtk4::gdk;
+use super::{CandidateColor, ClueAddress, KeyAction};
 use std::time::Duration;
 
 pub const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
@@ -30,5 +29,11 @@ pub enum InputEvent {
     LeftClick(Clickable),
     TouchEvent(Clickable, Duration),
     RightClick(Clickable),
-    KeyPressed(gdk::Key),
+    // right-button drag across several candidates of a cell
+    RightDrag(Vec<CandidateCellTileData>),
+    MiddleClick(Clickable),
+    // shift-click on a candidate: tag it with this color, or clear its tag
+    CandidateTag(CandidateCellTileData, Option<CandidateColor>),
+    // a key bound to a gameplay action in the keymap
+    KeyAction(KeyAction),
 }
@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A gameplay action whose keys the player can rebind, e.g. to suit an accessibility switch
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum KeyAction {
+    Undo,
+    Redo,
+    Hint,
+    PreviousClue,
+    NextClue,
+    ToggleClueComplete,
+    SetHomeClue,
+    JumpToHomeClue,
+    ClearClueFocus,
+    CursorLeft,
+    CursorRight,
+    CursorUp,
+    CursorDown,
+    CursorSelect,
+    CursorEliminate,
+}
+
+impl KeyAction {
+    pub fn all() -> Vec<KeyAction> {
+        vec![
+            KeyAction::Undo,
+            KeyAction::Redo,
+            KeyAction::Hint,
+            KeyAction::PreviousClue,
+            KeyAction::NextClue,
+            KeyAction::ToggleClueComplete,
+            KeyAction::SetHomeClue,
+            KeyAction::JumpToHomeClue,
+            KeyAction::ClearClueFocus,
+            KeyAction::CursorLeft,
+            KeyAction::CursorRight,
+            KeyAction::CursorUp,
+            KeyAction::CursorDown,
+            KeyAction::CursorSelect,
+            KeyAction::CursorEliminate,
+        ]
+    }
+
+    /// GTK accelerator strings bound to the action unless the player rebinds it
+    pub fn default_accels(&self) -> &'static [&'static str] {
+        match self {
+            KeyAction::Undo => &["<Control>z"],
+            KeyAction::Redo => &["<Control><Shift>z"],
+            KeyAction::Hint => &[],
+            KeyAction::PreviousClue => &["a", "k"],
+            KeyAction::NextClue => &["d", "j"],
+            KeyAction::ToggleClueComplete => &["c"],
+            KeyAction::SetHomeClue => &["m"],
+            KeyAction::JumpToHomeClue => &["Home"],
+            KeyAction::ClearClueFocus => &["Escape"],
+            KeyAction::CursorLeft => &["Left"],
+            KeyAction::CursorRight => &["Right"],
+            KeyAction::CursorUp => &["Up"],
+            KeyAction::CursorDown => &["Down"],
+            KeyAction::CursorSelect => &["Return", "KP_Enter"],
+            KeyAction::CursorEliminate => &["Delete", "BackSpace"],
+        }
+    }
+
+    /// Window action the accelerators are registered for; None for keys sent as
+    /// `InputEvent::KeyAction`, to `InputTranslator` and the puzzle grid
+    pub fn window_action(&self) -> Option<&'static str> {
+        match self {
+            KeyAction::Undo => Some("win.undo"),
+            KeyAction::Redo => Some("win.redo"),
+            KeyAction::Hint => Some("win.hint"),
+            _ => None,
+        }
+    }
+
+    /// Fluent key describing what the action does
+    pub fn description_key(&self) -> &'static str {
+        match self {
+            KeyAction::Undo => "shortcut-undo",
+            KeyAction::Redo => "shortcut-redo",
+            KeyAction::Hint => "shortcut-hint",
+            KeyAction::PreviousClue => "shortcut-previous-clue",
+            KeyAction::NextClue => "shortcut-next-clue",
+            KeyAction::ToggleClueComplete => "shortcut-toggle-clue-complete",
+            KeyAction::SetHomeClue => "shortcut-set-home-clue",
+            KeyAction::JumpToHomeClue => "shortcut-jump-home-clue",
+            KeyAction::ClearClueFocus => "shortcut-clear-clue-focus",
+            KeyAction::CursorLeft => "shortcut-cursor-left",
+            KeyAction::CursorRight => "shortcut-cursor-right",
+            KeyAction::CursorUp => "shortcut-cursor-up",
+            KeyAction::CursorDown => "shortcut-cursor-down",
+            KeyAction::CursorSelect => "shortcut-cursor-select",
+            KeyAction::CursorEliminate => "shortcut-cursor-eliminate",
+        }
+    }
+}
+
+/// An accelerator bound to two actions at once. `other` is None when the accelerator belongs to
+/// one of the shortcuts that can't be rebound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConflict {
+    pub accel: String,
+    pub action: KeyAction,
+    pub other: Option<KeyAction>,
+}
+
+/// The player's key bindings for each `KeyAction`. Only actions rebound away from their defaults
+/// are stored, so new actions and changed defaults reach existing settings files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Keymap {
+    bindings: BTreeMap<KeyAction, Vec<String>>,
+}
+
+impl Keymap {
+    pub fn accels(&self, action: KeyAction) -> Vec<String> {
+        match self.bindings.get(&action) {
+            Some(accels) => accels.clone(),
+            None => action
+                .default_accels()
+                .iter()
+                .map(|accel| accel.to_string())
+                .collect(),
+        }
+    }
+
+    /// Bind `accels` to `action`, replacing its previous keys. An empty list leaves the action
+    /// without a key.
+    pub fn set(&mut self, action: KeyAction, accels: Vec<String>) {
+        let accels: Vec<String> = accels
+            .into_iter()
+            .map(|accel| accel.trim().to_string())
+            .filter(|accel| !accel.is_empty())
+            .collect();
+        if accels == action.default_accels() {
+            self.bindings.remove(&action);
+        } else {
+            self.bindings.insert(action, accels);
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// The first accelerator bound to more than one action, or to an action and one of the
+    /// `reserved` accelerators of the fixed shortcuts
+    pub fn find_conflict(&self, reserved: &[&str]) -> Option<KeymapConflict> {
+        let reserved: Vec<String> = reserved
+            .iter()
+            .map(|accel| canonical_accel(accel))
+            .collect();
+        let mut bound: Vec<(String, KeyAction)> = Vec::new();
+        for action in KeyAction::all() {
+            for accel in self.accels(action) {
+                let canonical = canonical_accel(&accel);
+                if reserved.contains(&canonical) {
+                    return Some(KeymapConflict {
+                        accel,
+                        action,
+                        other: None,
+                    });
+                }
+                if let Some((_, other)) = bound.iter().find(|(bound, _)| *bound == canonical) {
+                    return Some(KeymapConflict {
+                        accel,
+                        action: *other,
+                        other: Some(action),
+                    });
+                }
+                bound.push((canonical, action));
+            }
+        }
+        None
+    }
+}
+
+/// Spell an accelerator one way, so that e.g. "<Shift><Control>z" and "<Ctrl><Shift>z" compare
+/// equal
+fn canonical_accel(accel: &str) -> String {
+    let mut modifiers = Vec::new();
+    let mut rest = accel.trim();
+    while let Some(stripped) = rest.strip_prefix('<') {
+        let Some(end) = stripped.find('>') else {
+            break;
+        };
+        let modifier = stripped[..end].to_lowercase();
+        modifiers.push(match modifier.as_str() {
+            "ctrl" | "ctl" | "primary" => "control".to_string(),
+            _ => modifier,
+        });
+        rest = &stripped[end + 1..];
+    }
+    modifiers.sort();
+    modifiers.dedup();
+    modifiers
+        .iter()
+        .map(|modifier| format!("<{}>", modifier))
+        .chain(std::iter::once(rest.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(accels: &[&str]) -> Vec<String> {
+        accels.iter().map(|accel| accel.to_string()).collect()
+    }
+
+    #[test]
+    fn test_defaults_have_no_conflicts() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.accels(KeyAction::NextClue), strings(&["d", "j"]));
+        assert_eq!(keymap.find_conflict(&["space", "<Control>n"]), None);
+    }
+
+    #[test]
+    fn test_rebinding_to_defaults_stores_nothing() {
+        let mut keymap = Keymap::default();
+        keymap.set(KeyAction::Hint, strings(&[" h "]));
+        assert_eq!(keymap.accels(KeyAction::Hint), strings(&["h"]));
+        assert!(!keymap.is_default());
+
+        keymap.set(KeyAction::Hint, Vec::new());
+        assert!(keymap.is_default());
+
+        let json = serde_json::to_string(&keymap).unwrap();
+        assert_eq!(json, "{}");
+    }
+
+    #[test]
+    fn test_find_conflict() {
+        let mut keymap = Keymap::default();
+        keymap.set(KeyAction::Hint, strings(&["j"]));
+        assert_eq!(
+            keymap.find_conflict(&[]),
+            Some(KeymapConflict {
+                accel: "j".to_string(),
+                action: KeyAction::Hint,
+                other: Some(KeyAction::NextClue),
+            })
+        );
+
+        keymap.set(KeyAction::Hint, strings(&["<Ctrl>space"]));
+        assert_eq!(
+            keymap
+                .find_conflict(&["<Primary>space"])
+                .map(|conflict| conflict.other),
+            Some(None)
+        );
+
+        keymap.set(KeyAction::Hint, strings(&["<Shift><Control>z"]));
+        assert_eq!(
+            keymap.find_conflict(&[]).map(|conflict| conflict.action),
+            Some(KeyAction::Redo)
+        );
+    }
+}
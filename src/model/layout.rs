@@ -1,4 +1,40 @@
 use fixed::types::I8F8;
+use serde::{Deserialize, Serialize};
+
+/// How the candidates within a puzzle cell are arranged
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CandidateDisplay {
+    /// Two rows of candidates
+    Grid,
+    /// One candidate per row, in a single column
+    List,
+}
+
+impl Default for CandidateDisplay {
+    fn default() -> Self {
+        CandidateDisplay::Grid
+    }
+}
+
+impl CandidateDisplay {
+    pub fn all() -> Vec<CandidateDisplay> {
+        vec![CandidateDisplay::Grid, CandidateDisplay::List]
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            CandidateDisplay::Grid => 0,
+            CandidateDisplay::List => 1,
+        }
+    }
+
+    pub fn from_index(index: usize) -> CandidateDisplay {
+        match index {
+            1 => CandidateDisplay::List,
+            _ => CandidateDisplay::Grid,
+        }
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Dimensions {
     pub width: i32,
@@ -1,52 +1,68 @@
 mod addressed_clue;
+mod auto_solve_mode;
 mod candidate;
 mod clue;
 mod clue_address;
 mod clue_orientation;
 mod clue_set;
+mod clue_sort;
 mod deduction;
+mod deduction_log_entry;
 mod difficulty;
+mod elimination_reason;
 mod game_board;
 mod game_engine_command;
 mod game_engine_event;
 pub mod game_state_snapshot;
 mod game_stats;
 mod input_event;
+mod keymap;
 mod layout;
 mod layout_manager_event;
 mod partial_solution;
+mod puzzle_code;
 mod settings_projection;
+mod shared_game;
 mod solution;
 mod tile;
 pub mod tile_assertion;
 mod timer_state;
 
 pub use addressed_clue::ClueWithAddress;
-pub use candidate::{Candidate, CandidateState};
-pub use clue::{Clue, ClueType, HorizontalClueType, VerticalClueType};
+pub use auto_solve_mode::AutoSolveMode;
+pub use candidate::{Candidate, CandidateColor, CandidateState};
+pub use clue::{
+    deserialize_clue_type_weights, Clue, ClueType, HorizontalClueType, VerticalClueType,
+};
 pub use clue_address::ClueAddress;
 pub use clue_orientation::ClueOrientation;
 pub use clue_set::ClueSet;
+pub use clue_sort::ClueSort;
 pub use deduction::{Deduction, DeductionKind};
+pub use deduction_log_entry::DeductionLogEntry;
 pub use difficulty::Difficulty;
-pub use game_board::GameBoard;
+pub use elimination_reason::EliminationReason;
+pub use game_board::{BoardProgress, Contradiction, GameBoard};
 pub use game_engine_command::GameEngineCommand;
 pub use game_engine_command::SettingsChange;
 pub use game_engine_event::{
     ClueSelection, GameBoardChangeReason, GameEngineEvent, PuzzleCompletionState,
 };
 pub use game_state_snapshot::GameStateSnapshot;
-pub use game_stats::{GameStats, GlobalStats};
+pub use game_stats::{format_hint_count, GameStats, GlobalStats};
 pub use input_event::{
     CandidateCellTileData, Clickable, InputEvent, SolutionTileData, LONG_PRESS_DURATION,
 };
+pub use keymap::{KeyAction, Keymap, KeymapConflict};
 pub use layout::{
-    CluesSizing, Dimensions, GridCellSizing, GridSizing, HorizontalCluePanelSizing,
+    CandidateDisplay, CluesSizing, Dimensions, GridCellSizing, GridSizing, HorizontalCluePanelSizing,
     LayoutConfiguration, VerticalCluePanelSizing,
 };
 pub use layout_manager_event::LayoutManagerEvent;
 pub use partial_solution::PartialSolution;
+pub use puzzle_code::PuzzleCode;
 pub use settings_projection::SettingsProjection;
+pub use shared_game::SharedGame;
 pub use solution::Solution;
 pub use solution::MAX_GRID_SIZE;
 pub use tile::Tile;
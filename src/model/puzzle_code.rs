@@ -0,0 +1,79 @@
+use super::Difficulty;
+
+const PUZZLE_CODE_PREFIX: &str = "EMOJICLU";
+
+/// Shareable code for a generated puzzle; the difficulty and seed are enough to regenerate it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PuzzleCode {
+    pub difficulty: Difficulty,
+    pub seed: u64,
+}
+
+impl PuzzleCode {
+    pub fn new(difficulty: Difficulty, seed: u64) -> Self {
+        Self { difficulty, seed }
+    }
+
+    /// Parse a code of the form "EMOJICLU-2-12345". Returns None for anything else, so arbitrary
+    /// text (e.g. whatever is on the clipboard) can be passed in.
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parts = input.trim().split('-');
+        let prefix = parts.next()?;
+        if !prefix.eq_ignore_ascii_case(PUZZLE_CODE_PREFIX) {
+            return None;
+        }
+        let difficulty_index = parts.next()?.parse::<usize>().ok()?;
+        let seed = parts.next()?.parse::<u64>().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        // from_index falls back to Easy; a code naming an unknown difficulty isn't ours
+        let difficulty = *Difficulty::all().get(difficulty_index)?;
+        Some(Self { difficulty, seed })
+    }
+}
+
+impl std::fmt::Display for PuzzleCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            PUZZLE_CODE_PREFIX,
+            self.difficulty.index(),
+            self.seed
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for difficulty in Difficulty::all() {
+            let code = PuzzleCode::new(difficulty, 1234567890123);
+            assert_eq!(PuzzleCode::parse(&code.to_string()), Some(code));
+        }
+    }
+
+    #[test]
+    fn test_parse_tolerates_whitespace_and_case() {
+        assert_eq!(
+            PuzzleCode::parse("  emojiclu-3-42\n"),
+            Some(PuzzleCode::new(Difficulty::Hard, 42))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_other_text() {
+        assert_eq!(PuzzleCode::parse(""), None);
+        assert_eq!(PuzzleCode::parse("hello world"), None);
+        assert_eq!(PuzzleCode::parse("12345"), None);
+        assert_eq!(PuzzleCode::parse("EMOJICLU-2"), None);
+        assert_eq!(PuzzleCode::parse("EMOJICLU-9-42"), None);
+        assert_eq!(PuzzleCode::parse("EMOJICLU-2--42"), None);
+        assert_eq!(PuzzleCode::parse("EMOJICLU-2-42-7"), None);
+        assert_eq!(PuzzleCode::parse("OTHER-2-42"), None);
+    }
+}
@@ -0,0 +1,325 @@
+use super::{ClueSet, GameBoard, PuzzleCode, Tile};
+
+/// Base64 alphabet for the cell payload. It has no '-', so the payload can follow the puzzle code
+/// after one.
+const PAYLOAD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Bits needed for the index of a placed variant
+const VARIANT_INDEX_BITS: usize = 3;
+
+/// A cell as shared: the variant placed there, or the candidates the player hasn't eliminated as
+/// a bitmask over variant indexes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SharedCell {
+    Placed(usize),
+    Candidates(u8),
+}
+
+/// A puzzle with the player's progress, for sending someone the exact board. The puzzle code
+/// regenerates the puzzle; the cells put back what was placed and eliminated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedGame {
+    pub code: PuzzleCode,
+    /// hash of the sender's clues, so progress isn't put onto a puzzle a different version of the
+    /// generator came up with for the same code
+    clue_hash: u32,
+    cells: Vec<SharedCell>,
+}
+
+impl SharedGame {
+    /// None if the puzzle has no code, i.e. can't be regenerated on the other end
+    pub fn from_board(board: &GameBoard) -> Option<Self> {
+        let code = board.solution.puzzle_code()?;
+        let n_variants = board.solution.n_variants;
+        let cells = (0..board.solution.n_rows)
+            .flat_map(|row| (0..n_variants).map(move |col| (row, col)))
+            .map(|(row, col)| match board.get_selection(row, col) {
+                Some(tile) => SharedCell::Placed(Tile::variant_to_usize(tile.variant)),
+                None => {
+                    SharedCell::Candidates(board.candidate_mask(row, col) & full_mask(n_variants))
+                }
+            })
+            .collect();
+        Some(Self {
+            code,
+            clue_hash: clue_hash(&board.clue_set),
+            cells,
+        })
+    }
+
+    /// Parse a share string of the form "EMOJICLU-2-12345-<clue hash>-<cells>". Like
+    /// `PuzzleCode::parse`, returns None for anything else.
+    pub fn parse(input: &str) -> Option<Self> {
+        let (rest, payload) = input.trim().rsplit_once('-')?;
+        let (code, clue_hash) = rest.rsplit_once('-')?;
+        let code = PuzzleCode::parse(code)?;
+        if clue_hash.len() != 8 || !clue_hash.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let clue_hash = u32::from_str_radix(clue_hash, 16).ok()?;
+        let n_rows = code.difficulty.n_rows();
+        let n_variants = code.difficulty.n_cols();
+
+        let mut bits = BitReader::new(payload)?;
+        let mut cells = Vec::with_capacity(n_rows * n_variants);
+        for _ in 0..n_rows * n_variants {
+            let cell = if bits.read(1)? == 1 {
+                let variant_idx = bits.read(VARIANT_INDEX_BITS)? as usize;
+                if variant_idx >= n_variants {
+                    return None;
+                }
+                SharedCell::Placed(variant_idx)
+            } else {
+                SharedCell::Candidates(bits.read(n_variants)? as u8)
+            };
+            cells.push(cell);
+        }
+        if !bits.only_padding_left() {
+            return None;
+        }
+        Some(Self {
+            code,
+            clue_hash,
+            cells,
+        })
+    }
+
+    /// Put the shared progress onto `board`, freshly generated from `code`. Returns false, leaving
+    /// the board alone, if it's a different puzzle or has different clues.
+    pub fn apply(&self, board: &mut GameBoard) -> bool {
+        let n_variants = board.solution.n_variants;
+        if board.solution.puzzle_code() != Some(self.code)
+            || clue_hash(&board.clue_set) != self.clue_hash
+            || board.solution.n_rows * n_variants != self.cells.len()
+        {
+            return false;
+        }
+        for (idx, cell) in self.cells.iter().enumerate() {
+            let (row, col) = (idx / n_variants, idx % n_variants);
+            match *cell {
+                SharedCell::Placed(variant_idx) => {
+                    board.select_tile_at_position(
+                        col,
+                        Tile::new(row, Tile::usize_to_variant(variant_idx)),
+                    );
+                }
+                SharedCell::Candidates(mask) => {
+                    for variant_idx in 0..n_variants {
+                        if mask & (1 << variant_idx) == 0 {
+                            board.remove_candidate(
+                                col,
+                                Tile::new(row, Tile::usize_to_variant(variant_idx)),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn payload(&self) -> String {
+        let n_variants = self.code.difficulty.n_cols();
+        let mut bits = BitWriter::default();
+        for cell in self.cells.iter() {
+            match *cell {
+                SharedCell::Placed(variant_idx) => {
+                    bits.write(1, 1);
+                    bits.write(variant_idx as u32, VARIANT_INDEX_BITS);
+                }
+                SharedCell::Candidates(mask) => {
+                    bits.write(0, 1);
+                    bits.write(mask as u32, n_variants);
+                }
+            }
+        }
+        bits.finish()
+    }
+}
+
+impl std::fmt::Display for SharedGame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{:08x}-{}", self.code, self.clue_hash, self.payload())
+    }
+}
+
+/// FNV-1a over the clues as written, which unlike `Hash` stays the same between builds
+fn clue_hash(clue_set: &ClueSet) -> u32 {
+    clue_set
+        .all_clues()
+        .flat_map(|addressed_clue| {
+            let mut clue = addressed_clue.clue.to_string().into_bytes();
+            clue.push(b'\n');
+            clue
+        })
+        .fold(0x811c9dc5, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        })
+}
+
+fn full_mask(n_variants: usize) -> u8 {
+    ((1u16 << n_variants) - 1) as u8
+}
+
+/// Packs bits six to a character, most significant first
+#[derive(Default)]
+struct BitWriter {
+    output: String,
+    pending: u32,
+    n_pending: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, n_bits: usize) {
+        for bit in (0..n_bits).rev() {
+            self.pending = (self.pending << 1) | ((value >> bit) & 1);
+            self.n_pending += 1;
+            if self.n_pending == 6 {
+                self.output
+                    .push(PAYLOAD_ALPHABET[self.pending as usize] as char);
+                self.pending = 0;
+                self.n_pending = 0;
+            }
+        }
+    }
+
+    /// Pad the last character with zero bits
+    fn finish(mut self) -> String {
+        if self.n_pending > 0 {
+            let n_padding = 6 - self.n_pending;
+            self.write(0, n_padding);
+        }
+        self.output
+    }
+}
+
+struct BitReader {
+    bits: Vec<u8>,
+    position: usize,
+}
+
+impl BitReader {
+    fn new(payload: &str) -> Option<Self> {
+        let mut bits = Vec::with_capacity(payload.len() * 6);
+        for byte in payload.bytes() {
+            let value = PAYLOAD_ALPHABET.iter().position(|&c| c == byte)?;
+            bits.extend((0..6).rev().map(|bit| ((value >> bit) & 1) as u8));
+        }
+        Some(Self { bits, position: 0 })
+    }
+
+    fn read(&mut self, n_bits: usize) -> Option<u32> {
+        let bits = self.bits.get(self.position..self.position + n_bits)?;
+        self.position += n_bits;
+        Some(bits.iter().fold(0, |value, &bit| (value << 1) | bit as u32))
+    }
+
+    /// Whether what's left is the zero padding of the last character
+    fn only_padding_left(&self) -> bool {
+        let rest = &self.bits[self.position..];
+        rest.len() < 6 && rest.iter().all(|&bit| bit == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::model::{Clue, ClueType, Difficulty, Solution, VerticalClueType};
+
+    fn board_in_progress(difficulty: Difficulty, seed: u64) -> GameBoard {
+        let solution = Arc::new(Solution::new(difficulty, Some(seed)));
+        let mut board = GameBoard::new(solution.clone());
+        board.set_clues(Arc::new(ClueSet::new(vec![Clue::adjacent(
+            Tile::new(0, 'a'),
+            Tile::new(1, 'b'),
+        )])));
+        board.select_tile_from_solution(Tile::new(0, 'a'));
+        board.select_tile_from_solution(Tile::new(1, 'c'));
+        board.remove_candidate(0, Tile::new(2, 'b'));
+        board.remove_candidate(3, Tile::new(3, 'd'));
+        board
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for difficulty in Difficulty::all() {
+            let board = board_in_progress(difficulty, 1234567890123);
+            let shared = SharedGame::from_board(&board).unwrap();
+            let share_string = shared.to_string();
+            assert_eq!(SharedGame::parse(&share_string), Some(shared.clone()));
+
+            // a fresh board of the same puzzle ends up with the same progress
+            let mut restored = GameBoard::new(board.solution.clone());
+            restored.set_clues(board.clue_set.clone());
+            assert!(shared.apply(&mut restored));
+            assert!(restored.same_content(&board));
+        }
+    }
+
+    #[test]
+    fn test_apply_rejects_other_puzzles() {
+        let shared = SharedGame::from_board(&board_in_progress(Difficulty::Easy, 42)).unwrap();
+        let mut other = GameBoard::new(Arc::new(Solution::new(Difficulty::Easy, Some(43))));
+        assert!(!shared.apply(&mut other));
+        assert!(other.get_selected_tiles().is_empty());
+    }
+
+    #[test]
+    fn test_apply_rejects_the_same_puzzle_with_other_clues() {
+        let board = board_in_progress(Difficulty::Easy, 42);
+        let shared = SharedGame::from_board(&board).unwrap();
+        let mut other = GameBoard::new(board.solution.clone());
+        other.set_clues(Arc::new(ClueSet::new(vec![Clue::adjacent(
+            Tile::new(0, 'a'),
+            Tile::new(1, 'c'),
+        )])));
+        assert!(!shared.apply(&mut other));
+        assert!(other.get_selected_tiles().is_empty());
+    }
+
+    #[test]
+    fn test_puzzles_with_custom_clue_weights_arent_shared() {
+        let mut board = board_in_progress(Difficulty::Easy, 42);
+        board.solution = Arc::new(Solution {
+            clue_type_weights: [(ClueType::Vertical(VerticalClueType::OneMatchesEither), 0)].into(),
+            ..Solution::new(Difficulty::Easy, Some(42))
+        });
+        assert_eq!(SharedGame::from_board(&board), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_text() {
+        let share_string = SharedGame::from_board(&board_in_progress(Difficulty::Easy, 42))
+            .unwrap()
+            .to_string();
+        let (rest, payload) = share_string.rsplit_once('-').unwrap();
+        let (code, clue_hash) = rest.rsplit_once('-').unwrap();
+
+        assert_eq!(SharedGame::parse(""), None);
+        assert_eq!(SharedGame::parse(rest), None);
+        assert_eq!(SharedGame::parse(&format!("{}-", rest)), None);
+        assert_eq!(SharedGame::parse(&format!("{}-{}A", rest, payload)), None);
+        assert_eq!(
+            SharedGame::parse(&format!("{}-{}", rest, &payload[..payload.len() - 1])),
+            None
+        );
+        assert_eq!(SharedGame::parse(&format!("{}-{}!", rest, payload)), None);
+        // the clue hash is required, and is exactly eight hex digits
+        assert_eq!(SharedGame::parse(&format!("{}-{}", code, payload)), None);
+        assert_eq!(
+            SharedGame::parse(&format!("{}-{}f-{}", code, clue_hash, payload)),
+            None
+        );
+        assert_eq!(
+            SharedGame::parse(&format!("{}-{}-{}", code, &clue_hash[1..], payload)),
+            None
+        );
+        assert_eq!(
+            SharedGame::parse(&format!("OTHER-1-42-{}-{}", clue_hash, payload)),
+            None
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use std::fmt::Display;
 use std::ops::RangeInclusive;
 
-use crate::model::{Difficulty, Tile};
+use crate::model::{deserialize_clue_type_weights, ClueType, Difficulty, PuzzleCode, Tile};
+use crate::solver::WeightedClueType;
 use log::trace;
 use rand::rngs::OsRng;
 use rand::TryRngCore;
 use rand::{seq::SliceRandom, SeedableRng};
+use std::collections::HashMap;
 
 pub const MAX_GRID_SIZE: usize = 8;
 
@@ -18,6 +20,20 @@ pub struct Solution {
     pub n_variants: usize,
     pub difficulty: Difficulty,
     pub seed: u64,
+    /// Clue weights the clues were generated with in place of the puzzle variant's, e.g. to match
+    /// another puzzle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clue_weights: Option<Vec<WeightedClueType>>,
+    /// The player's clue type weights the clues were generated with
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        deserialize_with = "deserialize_clue_type_weights"
+    )]
+    pub clue_type_weights: HashMap<ClueType, u32>,
+    /// The clues came from the simplified configuration, after the normal one ran out of time
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub simplified: bool,
 }
 
 impl Default for Solution {
@@ -30,6 +46,9 @@ impl Default for Solution {
             n_variants: 0,
             difficulty: Difficulty::default(),
             seed: 0,
+            clue_weights: None,
+            clue_type_weights: HashMap::new(),
+            simplified: false,
         }
     }
 }
@@ -89,9 +108,20 @@ impl Solution {
             n_variants,
             difficulty,
             seed,
+            clue_weights: None,
+            clue_type_weights: HashMap::new(),
+            simplified: false,
         }
     }
 
+    /// The code that regenerates this puzzle. None when custom clue weights or the simplified
+    /// configuration went into it, since the difficulty and seed alone would give a different
+    /// puzzle.
+    pub fn puzzle_code(&self) -> Option<PuzzleCode> {
+        (self.clue_weights.is_none() && self.clue_type_weights.is_empty() && !self.simplified)
+            .then(|| PuzzleCode::new(self.difficulty, self.seed))
+    }
+
     pub fn get(&self, row: usize, col: usize) -> Tile {
         let variant = self.grid[row][col];
         Tile::new(row, variant)
@@ -23,6 +23,17 @@ impl Tile {
         Self { row, variant }
     }
 
+    /// Like `parse`, but returns None instead of panicking on a malformed tile
+    pub fn try_parse(s: &str) -> Option<Self> {
+        let mut chars = s.trim().chars();
+        let row = chars.next()?.to_digit(10)? as usize;
+        let variant = chars.next().filter(|c| c.is_ascii_lowercase())?;
+        if chars.next().is_some() {
+            return None;
+        }
+        Some(Self { row, variant })
+    }
+
     pub fn variant_to_u8(variant: char) -> u8 {
         variant as u8 - 'a' as u8
     }
@@ -66,4 +77,12 @@ mod tests {
         assert_eq!(tile.row, 5);
         assert_eq!(tile.variant, 'f');
     }
+
+    #[test]
+    fn test_try_parse() {
+        assert_eq!(Tile::try_parse(" 2c "), Some(Tile::new(2, 'c')));
+        assert_eq!(Tile::try_parse("a2"), None);
+        assert_eq!(Tile::try_parse("2"), None);
+        assert_eq!(Tile::try_parse("2cd"), None);
+    }
 }
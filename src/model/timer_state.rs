@@ -13,6 +13,9 @@ pub struct TimerState {
     pub started_timestamp: SystemTime,
     #[serde_as(as = "Option<TimestampSeconds>")]
     pub ended_timestamp: Option<SystemTime>,
+    /// Time added as a penalty, on top of the time spent playing
+    #[serde(default)]
+    pub penalty_duration: Duration,
 }
 
 impl Default for TimerState {
@@ -22,6 +25,7 @@ impl Default for TimerState {
             paused_duration: Duration::from_secs(0),
             started_timestamp: SystemTime::now(),
             ended_timestamp: None,
+            penalty_duration: Duration::from_secs(0),
         }
     }
 }
@@ -41,6 +45,13 @@ impl TimerState {
             .duration_since(self.started_timestamp)
             .unwrap_or(Duration::default())
             .saturating_sub(self.paused_duration)
+            .saturating_add(self.penalty_duration)
+    }
+
+    pub fn with_penalty(&self, penalty: Duration) -> TimerState {
+        let mut new_state = self.clone();
+        new_state.penalty_duration = new_state.penalty_duration.saturating_add(penalty);
+        new_state
     }
 
     pub fn paused(&self, now: SystemTime) -> TimerState {
@@ -78,6 +89,7 @@ mod tests {
             paused_timestamp: Some(now + Duration::from_secs(5)),
             paused_duration: Duration::from_secs(0),
             ended_timestamp: None,
+            penalty_duration: Duration::from_secs(0),
         };
 
         assert_eq!(timer.elapsed(), Duration::from_secs(5));
@@ -91,6 +103,7 @@ mod tests {
             paused_timestamp: None,
             paused_duration: Duration::from_secs(0),
             ended_timestamp: Some(now + Duration::from_secs(10)),
+            penalty_duration: Duration::from_secs(0),
         };
 
         assert_eq!(timer.elapsed(), Duration::from_secs(10));
@@ -104,6 +117,7 @@ mod tests {
             paused_timestamp: Some(now + Duration::from_secs(10)),
             paused_duration: Duration::from_secs(3),
             ended_timestamp: None,
+            penalty_duration: Duration::from_secs(0),
         };
 
         assert_eq!(timer.elapsed(), Duration::from_secs(7)); // 10 seconds total - 3 seconds paused
@@ -117,9 +131,58 @@ mod tests {
             paused_timestamp: None,
             paused_duration: Duration::from_secs(0),
             ended_timestamp: None,
+            penalty_duration: Duration::from_secs(0),
         };
 
         // Since we're using real time here for Instant::now(), we just verify it's at least 5 seconds
         assert!(timer.elapsed() >= Duration::from_secs(5));
     }
+
+    #[test]
+    fn test_elapsed_with_penalty() {
+        let now = SystemTime::now();
+        let timer = TimerState {
+            started_timestamp: now,
+            paused_timestamp: None,
+            paused_duration: Duration::from_secs(0),
+            ended_timestamp: Some(now + Duration::from_secs(10)),
+            penalty_duration: Duration::from_secs(0),
+        }
+        .with_penalty(Duration::from_secs(30))
+        .with_penalty(Duration::from_secs(30));
+
+        assert_eq!(timer.penalty_duration, Duration::from_secs(60));
+        assert_eq!(timer.elapsed(), Duration::from_secs(70));
+    }
+
+    #[test]
+    fn test_penalty_survives_pause_and_resume() {
+        let now = SystemTime::now();
+        let timer = TimerState {
+            started_timestamp: now - Duration::from_secs(20),
+            paused_timestamp: None,
+            paused_duration: Duration::from_secs(5),
+            ended_timestamp: None,
+            penalty_duration: Duration::from_secs(0),
+        }
+        .with_penalty(Duration::from_secs(30))
+        .paused(now);
+
+        // 20 seconds since starting, less 5 paused, plus the penalty
+        assert_eq!(timer.elapsed(), Duration::from_secs(45));
+
+        // a penalty while paused doesn't count as paused time once resumed
+        let paused = timer.with_penalty(Duration::from_secs(30));
+        assert_eq!(paused.elapsed(), Duration::from_secs(75));
+        let resumed = paused.resumed();
+        assert!(resumed.paused_timestamp.is_none());
+        assert_eq!(resumed.penalty_duration, Duration::from_secs(60));
+        assert!(resumed.elapsed() >= Duration::from_secs(75));
+        assert!(resumed.elapsed() < Duration::from_secs(80));
+
+        // the time spent resuming counts as paused, so this is a hair under 85 seconds
+        let ended = resumed.ended(now + Duration::from_secs(10));
+        assert!(ended.elapsed() > Duration::from_secs(84));
+        assert!(ended.elapsed() <= Duration::from_secs(85));
+    }
 }
@@ -9,7 +9,13 @@ use crate::{
 };
 use log::trace;
 
-use super::hidden_pair_finder::{find_hidden_pairs_in_row, find_naked_pairs_in_row};
+use super::{
+    hidden_pair_finder::{
+        find_hidden_pairs_in_row, find_hidden_triples_in_row, find_naked_pairs_in_row,
+        find_naked_triples_in_row, SubsetResult,
+    },
+    solver_helpers::sort_deductions,
+};
 
 fn is_known_deduction(board: &GameBoard, deduction: &Deduction) -> bool {
     let result = if deduction.tile_assertion.assertion {
@@ -114,10 +120,12 @@ fn synthesize_deductions(
         }
     }
 
-    deductions
+    let mut deductions: Vec<Deduction> = deductions
         .into_iter()
         .filter(|deducation| !is_known_deduction(board, deducation))
-        .collect()
+        .collect();
+    sort_deductions(&mut deductions);
+    deductions
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -197,6 +205,23 @@ fn deduce_clue_with_candidate_finder(board: &GameBoard, clue: &Clue) -> Vec<Dedu
 
         let anchored = cells_with_positive_assertions == positive_assertion_count;
 
+        // a tile that every possible solution rules out of a cell can't be there, even when the
+        // positive tiles could still go either way
+        for (coordinates, cell_solution_assertion) in solutions_by_coordinates.iter() {
+            if cell_solution_assertion.negative_count == possible_solutions.len() {
+                for variant in cell_solution_assertion.negative_variants.iter() {
+                    deductions.insert(Deduction::new_with_kind(
+                        coordinates.column,
+                        TileAssertion {
+                            tile: Tile::new(coordinates.row, *variant),
+                            assertion: false,
+                        },
+                        DeductionKind::Constraint,
+                    ));
+                }
+            }
+        }
+
         trace!(
             target: "solver",
             "Solutions by coordinates: {:?}; anchored: {}",
@@ -323,10 +348,11 @@ fn deduce_clue_with_candidate_finder(board: &GameBoard, clue: &Clue) -> Vec<Dedu
         }
     }
 
-    let filtered_deductions: Vec<Deduction> = deductions
+    let mut filtered_deductions: Vec<Deduction> = deductions
         .into_iter()
         .filter(|deduction| !is_known_deduction(board, deduction))
         .collect();
+    sort_deductions(&mut filtered_deductions);
     trace!(
         target: "solver",
         "Found {} deductions",
@@ -361,6 +387,15 @@ fn deduce_one_matches_either(board: &GameBoard, tiles: &[Tile]) -> Vec<Deduction
         return deduce_clue(board, &Clue::two_in_column(tiles[0], tiles[1]));
     }
 
+    // a branch tile that can't share any column with the first tile is ruled out as well
+    if !could_share_column(board, &tiles[0], &tiles[1]) {
+        return deduce_clue(board, &Clue::two_in_column(tiles[0], tiles[2]));
+    }
+
+    if !could_share_column(board, &tiles[0], &tiles[2]) {
+        return deduce_clue(board, &Clue::two_in_column(tiles[0], tiles[1]));
+    }
+
     // fallback behavior when junction not known
     for col in 0..board_width {
         // only add t0 if either t1 or t2 are available in this column
@@ -384,73 +419,153 @@ fn deduce_one_matches_either(board: &GameBoard, tiles: &[Tile]) -> Vec<Deduction
     synthesize_deductions(board, &possible_columns)
 }
 
-pub fn deduce_hidden_sets_in_row(board: &GameBoard, row: usize) -> Vec<Deduction> {
-    let mut deductions = Vec::new();
+/// At least two of the three tiles share a column. A tile can stay in a column if one of the other
+/// two could join it there, or if those two could share some column between themselves and leave
+/// it free. Only tiles the clue actually narrows are reported, so a tile already down to one
+/// column isn't placed on the clue's account.
+fn deduce_two_of_three_in_column(board: &GameBoard, tiles: &[Tile]) -> Vec<Deduction> {
+    let board_width = board.solution.n_variants;
+    let possible_columns = (0..3)
+        .map(|idx| {
+            let tile = tiles[idx];
+            let others = [tiles[(idx + 1) % 3], tiles[(idx + 2) % 3]];
+            let others_could_pair = could_share_column(board, &others[0], &others[1]);
+            let columns = (0..board_width)
+                .filter(|col| !board.has_negative_deduction(&tile, *col))
+                .filter(|col| {
+                    others_could_pair
+                        || others
+                            .iter()
+                            .any(|other| !board.has_negative_deduction(other, *col))
+                })
+                .collect::<HashSet<_>>();
+            (tile, columns)
+        })
+        .filter(|(tile, columns)| columns.len() < board.get_possible_cols_for_tile(*tile).count())
+        .collect::<Vec<_>>();
+
+    synthesize_deductions(board, &possible_columns)
+}
+
+fn could_share_column(board: &GameBoard, tile_a: &Tile, tile_b: &Tile) -> bool {
+    (0..board.solution.n_variants).any(|col| {
+        !board.has_negative_deduction(tile_a, col) && !board.has_negative_deduction(tile_b, col)
+    })
+}
 
-    let mut hidden_sets = Vec::new();
-    hidden_sets.extend(find_hidden_pairs_in_row(row, board));
-    if hidden_sets.len() == 0 {
-        hidden_sets.extend(find_naked_pairs_in_row(row, board));
+/// A `OneMatchesEither` clue whose branch is contradicted by a `NotInSameColumn` clue elsewhere in
+/// `clues` reduces to `TwoInColumn` with the remaining branch
+fn resolve_one_matches_either(clue: &Clue, clues: &[Clue]) -> Option<Clue> {
+    if clue.clue_type != ClueType::Vertical(VerticalClueType::OneMatchesEither) {
+        return None;
     }
-    if hidden_sets.len() == 0 {
-        return deductions;
+    let tiles = clue.assertions.iter().map(|a| a.tile).collect::<Vec<_>>();
+    let not_in_same_column = |tile_a: Tile, tile_b: Tile| {
+        clues.iter().any(|other| {
+            other.clue_type == ClueType::Vertical(VerticalClueType::NotInSameColumn) && {
+                let other_tiles = [other.assertions[0].tile, other.assertions[1].tile];
+                other_tiles == [tile_a, tile_b] || other_tiles == [tile_b, tile_a]
+            }
+        })
+    };
+
+    if not_in_same_column(tiles[0], tiles[1]) {
+        Some(Clue::two_in_column(tiles[0], tiles[2]))
+    } else if not_in_same_column(tiles[0], tiles[2]) {
+        Some(Clue::two_in_column(tiles[0], tiles[1]))
+    } else {
+        None
     }
+}
 
-    trace!(
-        target: "solver",
-        "Found {} hidden sets: {:?}",
-        hidden_sets.len(),
-        hidden_sets
-    );
+/// Like `deduce_clue`, but also considers what the other clues of the puzzle rule out
+pub fn deduce_clue_in_context(board: &GameBoard, clue: &Clue, clues: &[Clue]) -> Vec<Deduction> {
+    match resolve_one_matches_either(clue, clues) {
+        Some(resolved) => deduce_clue(board, &resolved),
+        None => deduce_clue(board, clue),
+    }
+}
 
-    // find the smallest one
-    let smallest_hidden_set = hidden_sets.iter().min_by_key(|set| set.variants.len());
-    if let Some(smallest_hidden_set) = smallest_hidden_set {
+pub fn deduce_hidden_sets_in_row(board: &GameBoard, row: usize) -> Vec<Deduction> {
+    // pairs before triples, so hints stay minimal; a bigger set is only tried when every smaller
+    // one has already been fully applied
+    let finders: [fn(usize, &GameBoard) -> Vec<SubsetResult>; 4] = [
+        find_hidden_pairs_in_row,
+        find_naked_pairs_in_row,
+        find_hidden_triples_in_row,
+        find_naked_triples_in_row,
+    ];
+    for find in finders {
+        let mut hidden_sets = find(row, board);
+        if hidden_sets.is_empty() {
+            continue;
+        }
         trace!(
             target: "solver",
-            "Smallest hidden set: {:?}",
-            smallest_hidden_set
+            "Found {} hidden sets: {:?}",
+            hidden_sets.len(),
+            hidden_sets
         );
-        // add negative deductions for the variants that are not in the smallest hidden set
-        let hidden_set_inverse = board
-            .solution
-            .variants
-            .clone()
-            .into_iter()
-            .filter(|v| !smallest_hidden_set.variants.contains(v))
-            .collect::<Vec<_>>();
-
-        for column in 0..board.solution.n_variants {
-            let col_in_set = smallest_hidden_set.columns.contains(&column);
-            if col_in_set {
-                // only the hidden set variants can go here
-                // not in hidden set, but on board? get rid of it.
-                for not_in_set_variant in hidden_set_inverse.iter() {
-                    if board.is_candidate_available(row, column, *not_in_set_variant) {
-                        deductions.push(Deduction::new_with_kind(
-                            column,
-                            TileAssertion {
-                                tile: Tile::new(row, *not_in_set_variant),
-                                assertion: false,
-                            },
-                            DeductionKind::HiddenSet,
-                        ));
-                    }
+
+        // the smallest one that still rules something out
+        hidden_sets.sort_by_key(|set| set.variants.len());
+        for hidden_set in hidden_sets.iter() {
+            let mut deductions = deduce_hidden_set(board, row, hidden_set);
+            if !deductions.is_empty() {
+                trace!(
+                    target: "solver",
+                    "Smallest hidden set: {:?}",
+                    hidden_set
+                );
+                sort_deductions(&mut deductions);
+                return deductions;
+            }
+        }
+    }
+    Vec::new()
+}
+
+fn deduce_hidden_set(board: &GameBoard, row: usize, hidden_set: &SubsetResult) -> Vec<Deduction> {
+    let mut deductions = Vec::new();
+    // add negative deductions for the variants that are not in the hidden set
+    let hidden_set_inverse = board
+        .solution
+        .variants
+        .clone()
+        .into_iter()
+        .filter(|v| !hidden_set.variants.contains(v))
+        .collect::<Vec<_>>();
+
+    for column in 0..board.solution.n_variants {
+        let col_in_set = hidden_set.columns.contains(&column);
+        if col_in_set {
+            // only the hidden set variants can go here
+            // not in hidden set, but on board? get rid of it.
+            for not_in_set_variant in hidden_set_inverse.iter() {
+                if board.is_candidate_available(row, column, *not_in_set_variant) {
+                    deductions.push(Deduction::new_with_kind(
+                        column,
+                        TileAssertion {
+                            tile: Tile::new(row, *not_in_set_variant),
+                            assertion: false,
+                        },
+                        DeductionKind::HiddenSet,
+                    ));
                 }
-            } else {
-                // hidden set variants cannot go here
-                for hidden_set_variant in smallest_hidden_set.variants.iter() {
-                    // eliminate hidden variants from columns not part of set
-                    if board.is_candidate_available(row, column, *hidden_set_variant) {
-                        // remove it! you don't belong here
-                        deductions.push(Deduction::new(
-                            column,
-                            TileAssertion {
-                                tile: Tile::new(row, *hidden_set_variant),
-                                assertion: false,
-                            },
-                        ));
-                    }
+            }
+        } else {
+            // hidden set variants cannot go here
+            for hidden_set_variant in hidden_set.variants.iter() {
+                // eliminate hidden variants from columns not part of set
+                if board.is_candidate_available(row, column, *hidden_set_variant) {
+                    // remove it! you don't belong here
+                    deductions.push(Deduction::new(
+                        column,
+                        TileAssertion {
+                            tile: Tile::new(row, *hidden_set_variant),
+                            assertion: false,
+                        },
+                    ));
                 }
             }
         }
@@ -458,12 +573,46 @@ pub fn deduce_hidden_sets_in_row(board: &GameBoard, row: usize) -> Vec<Deduction
     deductions
 }
 
+/// Hidden and naked pairs, row by row. This is also where an X-Wing would show up: each row is
+/// its own one-to-one match of variants to columns, and rows don't constrain one another without
+/// a clue, so a pair of variants confined to the same two columns can only eliminate within their
+/// own row. Tiles of different rows sharing two columns rule nothing out.
 pub fn deduce_hidden_sets(board: &GameBoard) -> Vec<Deduction> {
     (0..board.solution.n_rows)
         .flat_map(|row| deduce_hidden_sets_in_row(board, row))
         .collect()
 }
 
+/// Places every undecided cell that has been narrowed down to a single variant, sweeping column
+/// by column. Complements `deduce_hidden_sets`, which works from the variant's point of view.
+pub fn deduce_column_exhaustion(board: &GameBoard) -> Vec<Deduction> {
+    let mut deductions = Vec::new();
+    for col in 0..board.solution.n_variants {
+        for row in 0..board.solution.n_rows {
+            if board.has_selection(row, col) {
+                continue;
+            }
+            let mut available_variants = board
+                .solution
+                .variants
+                .iter()
+                .filter(|variant| board.is_candidate_available(row, col, **variant));
+            if let (Some(variant), None) = (available_variants.next(), available_variants.next()) {
+                deductions.push(Deduction::new_with_kind(
+                    col,
+                    TileAssertion {
+                        tile: Tile::new(row, *variant),
+                        assertion: true,
+                    },
+                    DeductionKind::LastRemaining,
+                ));
+            }
+        }
+    }
+    sort_deductions(&mut deductions);
+    deductions
+}
+
 pub fn deduce_clue(board: &GameBoard, clue: &Clue) -> Vec<Deduction> {
     let tiles = clue.assertions.iter().map(|a| a.tile).collect::<Vec<_>>();
     match &clue.clue_type {
@@ -479,10 +628,26 @@ pub fn deduce_clue(board: &GameBoard, clue: &Clue) -> Vec<Deduction> {
             deduce_clue_with_candidate_finder(board, &clue)
         }
 
+        ClueType::Horizontal(HorizontalClueType::Between) => {
+            deduce_clue_with_candidate_finder(board, &clue)
+        }
+
+        ClueType::Horizontal(HorizontalClueType::InColumn) => {
+            deduce_clue_with_candidate_finder(board, &clue)
+        }
+
         ClueType::Horizontal(HorizontalClueType::LeftOf) => {
             deduce_clue_with_candidate_finder(board, &clue)
         }
 
+        ClueType::Horizontal(HorizontalClueType::AdjacentLeftOf) => {
+            deduce_clue_with_candidate_finder(board, &clue)
+        }
+
+        ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered) => {
+            deduce_clue_with_candidate_finder(board, &clue)
+        }
+
         ClueType::Horizontal(HorizontalClueType::NotAdjacent) => {
             deduce_clue_with_candidate_finder(board, &clue)
         }
@@ -492,10 +657,22 @@ pub fn deduce_clue(board: &GameBoard, clue: &Clue) -> Vec<Deduction> {
             deduce_clue_with_candidate_finder(board, &clue)
         }
 
+        ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows) => {
+            // tiles from adjacent rows never satisfy the clue; don't eliminate on its account
+            if tiles[0].row.abs_diff(tiles[1].row) <= 1 {
+                return Vec::new();
+            }
+            deduce_clue_with_candidate_finder(board, &clue)
+        }
+
         ClueType::Vertical(VerticalClueType::OneMatchesEither) => {
             deduce_one_matches_either(board, &tiles)
         }
 
+        ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn) => {
+            deduce_two_of_three_in_column(board, &tiles)
+        }
+
         ClueType::Vertical(VerticalClueType::NotInSameColumn) => {
             deduce_clue_with_candidate_finder(board, &clue)
         }
@@ -510,6 +687,7 @@ pub fn deduce_clue(board: &GameBoard, clue: &Clue) -> Vec<Deduction> {
 pub enum EvaluationStepResult {
     Nothing,
     HiddenSetsFound,
+    ForcedPlacementsFound,
     DeductionsFound(Clue),
 }
 
@@ -522,13 +700,20 @@ pub fn perform_evaluation_step(board: &mut GameBoard, clues: &Vec<Clue>) -> Eval
 
     // apply clues
     for clue in clues.iter() {
-        let deductions = deduce_clue(board, clue);
+        let deductions = deduce_clue_in_context(board, clue, clues);
         if deductions.len() > 0 {
             board.apply_deductions(&deductions);
             return EvaluationStepResult::DeductionsFound(clue.clone());
         }
     }
 
+    // place cells with a single variant left
+    let deductions = deduce_column_exhaustion(board);
+    if deductions.len() > 0 {
+        board.apply_deductions(&deductions);
+        return EvaluationStepResult::ForcedPlacementsFound;
+    }
+
     // apply hidden sets
     let deductions = deduce_hidden_sets(board);
     if deductions.len() > 0 {
@@ -553,9 +738,10 @@ mod tests {
     use super::*;
     use crate::game::tests::create_test_solution;
     use crate::{
-        model::{Clue, GameBoard, Tile},
+        model::{Clue, ClueSet, GameBoard, Tile},
         tests::UsingLogger,
     };
+    use std::sync::Arc;
 
     #[test]
     fn test_deduce_three_adjacent_empty_board() {
@@ -586,6 +772,36 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("0d not col 2 (Converging)")));
     }
 
+    #[test]
+    fn test_deductions_are_returned_in_stable_order() {
+        let input = "\
+1|abcd|abcd|abcd|abcd|
+-----------------
+2|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+        let clue = Clue::three_adjacent(Tile::new(0, 'a'), Tile::new(0, 'b'), Tile::new(0, 'c'));
+
+        let deductions = deduce_clue(&board, &clue);
+
+        assert_eq!(
+            deductions,
+            vec![
+                Deduction::parse("0b not col 0 (Constraint)"),
+                Deduction::parse("0d not col 1 (Converging)"),
+                Deduction::parse("0d not col 2 (Converging)"),
+                Deduction::parse("0b not col 3 (Constraint)"),
+            ]
+        );
+        // the deductions are gathered in a HashSet, whose order changes from one set to the next;
+        // freshly built boards must still come out the same
+        for _ in 0..10 {
+            let board = GameBoard::parse(input, create_test_solution(2, 4));
+            assert_eq!(deduce_clue(&board, &clue), deductions);
+        }
+    }
+
     #[test_context(UsingLogger)]
     #[test]
     fn test_deduce_three_adjacent_partially_solved_board(_: &mut UsingLogger) {
@@ -640,6 +856,69 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("1a is col 2 (LastRemaining)")));
     }
 
+    #[test]
+    fn test_deduce_three_adjacent_ordered_empty_board() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue =
+            Clue::three_adjacent_ordered(Tile::new(0, 'a'), Tile::new(0, 'b'), Tile::new(0, 'c'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        // the edges as with three_adjacent, plus the columns only the reverse order would use
+        assert_eq!(deductions.len(), 8);
+        assert!(deductions.contains(&Deduction::parse("0a not col 2 (Converging)")));
+        assert!(deductions.contains(&Deduction::parse("0a not col 3 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("0b not col 0 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("0b not col 3 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("0c not col 0 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("0c not col 1 (Converging)")));
+        assert!(deductions.contains(&Deduction::parse("0d not col 1 (Converging)")));
+        assert!(deductions.contains(&Deduction::parse("0d not col 2 (Converging)")));
+
+        // either order leaves the outer tiles free to use any column
+        let unordered =
+            Clue::three_adjacent(Tile::new(0, 'a'), Tile::new(0, 'b'), Tile::new(0, 'c'));
+        let deductions = deduce_clue(&board, &unordered);
+        assert_eq!(deductions.len(), 4);
+        assert!(!deductions
+            .iter()
+            .any(|d| d.tile_assertion.tile == Tile::new(0, 'a')));
+    }
+
+    #[test]
+    fn test_deduce_three_adjacent_ordered_eliminates_reverse_placement() {
+        let input = "\
+0|abcd|<B> |abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue =
+            Clue::three_adjacent_ordered(Tile::new(0, 'a'), Tile::new(0, 'b'), Tile::new(1, 'a'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("0a is col 0 (LastRemaining)")));
+        assert!(deductions.contains(&Deduction::parse("1a is col 2 (LastRemaining)")));
+
+        // in either order 1a could still be on the left of 0b, next to 0a on the right
+        let unordered =
+            Clue::three_adjacent(Tile::new(0, 'a'), Tile::new(0, 'b'), Tile::new(1, 'a'));
+        let deductions = deduce_clue(&board, &unordered);
+        println!("Deductions: {:?}", deductions);
+        assert!(!deductions.contains(&Deduction::parse("1a is col 2 (LastRemaining)")));
+    }
+
     #[test]
     fn test_deduce_two_adjacent_partially_solved_board() {
         let input = "\
@@ -748,6 +1027,31 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("1a not col 2 (Constraint)")));
     }
 
+    #[test]
+    fn test_deduce_two_apart_not_middle_either_way_round() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1|a cd|abcd|a cd|abcd|
+-----------------
+2|abc |abcd|abc |abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // 1b and 2d take cols 1 and 3 one way round or the other, so 0a is kept out of col 2
+        // either way
+        let clue =
+            Clue::two_apart_not_middle(Tile::new(1, 'b'), Tile::new(0, 'a'), Tile::new(2, 'd'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(
+            deductions,
+            vec![Deduction::parse("0a not col 2 (Constraint)")]
+        );
+    }
+
     #[test]
     fn test_deduce_left_of_empty_board() {
         let input = "\
@@ -805,6 +1109,174 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("1a not col 1 (Constraint)")));
     }
 
+    #[test]
+    fn test_deduce_adjacent_left_of_empty_board() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue = Clue::adjacent_left_of(Tile::new(0, 'a'), Tile::new(1, 'b'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("0a not col 3 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("1b not col 0 (Constraint)")));
+
+        // plain adjacency allows either order, so nothing can be eliminated
+        let unordered = Clue::adjacent(Tile::new(0, 'a'), Tile::new(1, 'b'));
+        assert!(deduce_clue(&board, &unordered).is_empty());
+    }
+
+    #[test]
+    fn test_deduce_adjacent_left_of_eliminates_reverse_placement() {
+        let input = "\
+0|abcd|<A> |abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue = Clue::adjacent_left_of(Tile::new(0, 'a'), Tile::new(1, 'a'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 1);
+        assert!(deductions.contains(&Deduction::parse("1a is col 2 (LastRemaining)")));
+
+        // plain adjacency still allows 1a on either side of 0a
+        let unordered = Clue::adjacent(Tile::new(0, 'a'), Tile::new(1, 'a'));
+        let deductions = deduce_clue(&board, &unordered);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("1a not col 1 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("1a not col 3 (Constraint)")));
+    }
+
+    #[test]
+    fn test_deduce_adjacent_left_of_partially_solved() {
+        let input = "\
+0| bcd|abcd|abcd| bcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue = Clue::adjacent_left_of(Tile::new(0, 'a'), Tile::new(1, 'a'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("1a not col 0 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("1a not col 1 (Constraint)")));
+    }
+
+    #[test]
+    fn test_deduce_between_empty_board() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue = Clue::between(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(0, 'c'));
+
+        // the anchors may be either way round, so only the middle tile is constrained
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("1b not col 0 (Constraint)")));
+        assert!(deductions.contains(&Deduction::parse("1b not col 3 (Constraint)")));
+    }
+
+    #[test]
+    fn test_deduce_between_anchored() {
+        let input = "\
+0|abcd|<A> |abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue = Clue::between(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(0, 'c'));
+
+        // nothing fits strictly between columns 0 and 1, so the other anchor is to the right
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("1b is col 2 (LastRemaining)")));
+        assert!(deductions.contains(&Deduction::parse("0c is col 3 (LastRemaining)")));
+    }
+
+    #[test]
+    fn test_deduce_in_column_places_tile() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let clue = Clue::in_column(Tile::new(1, 'c'), 2);
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 1);
+        assert!(deductions.contains(&Deduction::parse("1c is col 2 (LastRemaining)")));
+    }
+
+    #[test]
+    fn test_deduce_rules_out_placements_that_strand_a_variant() {
+        // 0a has nowhere to go but column 0, so 0b can't take it, even though 1c could sit next
+        // to it there
+        let input = "\
+0|ab  | bcd| bcd| bcd|
+----------------------
+1|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let deductions = deduce_clue(
+            &board,
+            &Clue::adjacent(Tile::new(0, 'b'), Tile::new(1, 'c')),
+        );
+        assert_eq!(
+            deductions,
+            vec![Deduction::parse("0b not col 0 (Constraint)")]
+        );
+    }
+
+    #[test]
+    fn test_deduce_two_in_column_non_adjacent_rows() {
+        let input = "\
+0|<A> |abcd|abcd|abcd|
+-----------------
+1|abcd|abcd|abcd|abcd|
+-----------------
+2|abcd|abcd|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        let clue = Clue::two_in_column_non_adjacent_rows(Tile::new(0, 'a'), Tile::new(2, 'a'));
+
+        let deductions = deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 1);
+        assert!(deductions.contains(&Deduction::parse("2a is col 0 (LastRemaining)")));
+    }
+
     #[test]
     fn test_deduce_not_adjacent_empty_board() {
         let input = "\
@@ -1086,6 +1558,216 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("0a not col 2")));
     }
 
+    #[test]
+    fn test_deduce_one_matches_either_branch_without_shared_column() {
+        let input = "\
+0|a   | bcd| bcd| bcd|
+----------------------
+1| bcd|abcd|abcd|abcd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        let clue =
+            Clue::one_matches_either(Tile::new(0, 'a'), Tile::new(1, 'a'), Tile::new(2, 'c'));
+
+        // 0a and 1a share no possible column, so 2c must be with 0a
+        let deductions = deduce_clue(&board, &clue);
+        assert!(deductions.contains(&Deduction::parse("2c is col 0 (LastRemaining)")));
+    }
+
+    #[test]
+    fn test_deduce_one_matches_either_with_not_in_same_column_clue() {
+        let input = "\
+0|abcd|<A> |abcd|abcd|
+----------------------
+1|abcd|abcd|abcd|abcd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        let clue =
+            Clue::one_matches_either(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(2, 'c'));
+        let companion = Clue::two_not_in_same_column(Tile::new(0, 'a'), Tile::new(1, 'b'));
+
+        // in isolation, either branch could still hold
+        assert!(deduce_clue(&board, &clue).is_empty());
+
+        let clues = vec![clue.clone(), companion];
+        let deductions = deduce_clue_in_context(&board, &clue, &clues);
+        assert!(deductions.contains(&Deduction::parse("2c is col 1 (LastRemaining)")));
+
+        let mut board = board;
+        assert_eq!(
+            perform_evaluation_step(&mut board, &clues),
+            EvaluationStepResult::DeductionsFound(clue)
+        );
+        assert!(board.is_selected_in_column(&Tile::new(2, 'c'), 1));
+    }
+
+    fn two_of_three_clue() -> Clue {
+        Clue::two_of_three_in_column(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(2, 'c'))
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_empty_board() {
+        let input = "\
+0|abcd|abcd|abcd|abcd|
+----------------------
+1|abcd|abcd|abcd|abcd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions.len(), 0);
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_two_selected_apart() {
+        let input = "\
+0|<A> |abcd|abcd|abcd|
+----------------------
+1|abcd|<B> |abcd|abcd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // 0a and 1b don't share, so 2c must join one of them
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.contains(&Deduction::parse("2c not col 2")));
+        assert!(deductions.contains(&Deduction::parse("2c not col 3")));
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_two_selected_together() {
+        let input = "\
+0|abcd|<A> |abcd|abcd|
+----------------------
+1|abcd|<B> |abcd|abcd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // the clue is already satisfied; 2c can go anywhere
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions.len(), 0);
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_last_remaining() {
+        let input = "\
+0|<A> |abcd|abcd|abcd|
+----------------------
+1|abcd|<B> |abcd|abcd|
+----------------------
+2|abcd|ab d|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // 2c can't join 1b, so it has to be with 0a
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions, vec![Deduction::parse("2c is col 0")]);
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_one_pair_forced() {
+        let input = "\
+0|abcd|abcd|abcd| bcd|
+----------------------
+1|a cd|a cd|a cd|<B> |
+----------------------
+2|ab d|abcd|abcd|ab d|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // 1b can't share a column with either of the others, so 0a and 2c must pair up, in a
+        // column both can still take
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions, vec![Deduction::parse("0a not col 0")]);
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_one_option_eliminated() {
+        let input = "\
+0|abcd|<A> |abcd|abcd|
+----------------------
+1|abcd|a cd|abcd|a cd|
+----------------------
+2|abcd|ab d|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // neither can join 0a, so 1b and 2c share a column; 1b's options limit 2c
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions, vec![Deduction::parse("2c not col 3")]);
+    }
+
+    #[test]
+    fn test_deduce_two_of_three_in_column_open_while_any_pair_could_form() {
+        let input = "\
+0|abcd|<A> |abcd|abcd|
+----------------------
+1|abcd|abcd|abcd|a cd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        // 1b could still join 0a, which would leave 2c free, so nothing follows
+        let deductions = deduce_clue(&board, &two_of_three_clue());
+        assert_eq!(deductions, vec![]);
+    }
+
+    #[test]
+    fn test_candidate_finder_over_board_with_two_of_three_in_column() {
+        let input = "\
+0|<A> |abcd|abcd|abcd|
+----------------------
+1|abcd|<B> |abcd|abcd|
+----------------------
+2|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let mut board = GameBoard::parse(input, create_test_solution(3, 4));
+        let clues = vec![
+            two_of_three_clue(),
+            Clue::adjacent(Tile::new(0, 'a'), Tile::new(2, 'b')),
+        ];
+        board.set_clues(Arc::new(ClueSet::new(clues.clone())));
+
+        // the finder has no candidates to offer for this clue, and doesn't give up on the board
+        for addressed_clue in board.clue_set.all_clues() {
+            let clue = &addressed_clue.clue;
+            for column in 0..board.solution.n_variants {
+                let solutions = create_clue_constraint(clue).potential_solutions(&board, column);
+                if clue.clue_type == ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn) {
+                    assert!(solutions.is_empty());
+                }
+            }
+        }
+        assert_eq!(
+            perform_evaluation_step(&mut board, &clues),
+            EvaluationStepResult::DeductionsFound(clues[0].clone())
+        );
+        assert!(!board.is_candidate_available(2, 2, 'c'));
+    }
+
     #[test_context(UsingLogger)]
     #[test]
     fn test_deduce_hidden_pairs(_: &mut UsingLogger) {
@@ -1107,6 +1789,129 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("0b not col 2 (HiddenSet)")));
     }
 
+    #[test]
+    fn test_deduce_naked_triples() {
+        // columns 0, 2 and 3 can only hold a, b and c between them, which the pair search misses
+        let input = "\
+0|ab    |a  d  | bc   |a c   |a c  f|abc ef|
+----------------------------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(1, 6));
+        assert!(find_hidden_pairs_in_row(0, &board).is_empty());
+        assert!(find_naked_pairs_in_row(0, &board).is_empty());
+
+        let eliminated = |col: usize, variant: char| {
+            Deduction::new(
+                col,
+                TileAssertion {
+                    tile: Tile::new(0, variant),
+                    assertion: false,
+                },
+            )
+        };
+        assert_eq!(
+            deduce_hidden_sets(&board),
+            vec![
+                eliminated(1, 'a'),
+                eliminated(4, 'a'),
+                eliminated(4, 'c'),
+                eliminated(5, 'a'),
+                eliminated(5, 'b'),
+                eliminated(5, 'c'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deduce_hidden_sets_past_an_applied_pair() {
+        // a and b already hold columns 6 and 7 to themselves, so that pair rules nothing more out;
+        // g and h, confined to columns 4 and 5, still do
+        let input = "\
+0|  cd    |  c  f  |   de   |  c e   |  c e  h|  cde gh|ab      |ab      |
+------------------------------------------------------------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(1, 8));
+
+        let eliminated = |col: usize, variant: char| {
+            Deduction::new(
+                col,
+                TileAssertion {
+                    tile: Tile::new(0, variant),
+                    assertion: false,
+                },
+            )
+        };
+        assert_eq!(
+            deduce_hidden_sets(&board),
+            vec![
+                eliminated(4, 'c'),
+                eliminated(4, 'e'),
+                eliminated(5, 'c'),
+                eliminated(5, 'd'),
+                eliminated(5, 'e'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deduce_hidden_sets_ignores_pairs_across_rows() {
+        // 0a and 1b are both confined to columns 0 and 1, but the rows are independent: 0c could
+        // still share a column with 1b, and so on
+        let input = "\
+0|abcd|abcd| bcd| bcd|
+----------------------
+1|abcd|abcd|a cd|a cd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        assert_eq!(deduce_hidden_sets(&board), vec![]);
+    }
+
+    #[test]
+    fn test_deduce_column_exhaustion() {
+        let input = "\
+0|<a> |bcd |bcd |bcd |
+----------------------
+1|a   |abcd|abcd|abcd|
+----------------------
+2|abcd|abcd|ab  |abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+
+        let deductions = deduce_column_exhaustion(&board);
+        assert_eq!(
+            deductions,
+            vec![Deduction::parse("1a is col 0 (LastRemaining)")]
+        );
+
+        let input = "\
+0|ab  |ab  |abcd|abcd|
+----------------------
+1|abcd|abcd|abcd|abcd|
+----------------------
+";
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+        assert!(deduce_column_exhaustion(&board).is_empty());
+    }
+
+    #[test]
+    fn test_evaluation_step_places_forced_tiles() {
+        let input = "\
+0|<a> |bcd |bcd |bcd |
+----------------------
+1|a   |bcd |bcd |bcd |
+----------------------
+";
+        let mut board = GameBoard::parse(input, create_test_solution(2, 4));
+
+        let result = perform_evaluation_step(&mut board, &vec![]);
+
+        assert_eq!(result, EvaluationStepResult::ForcedPlacementsFound);
+        assert_eq!(board.get_selection(1, 0), Some(Tile::new(1, 'a')));
+    }
+
     #[test]
     fn test_left_of_handler_empty_board() {
         let input = "\
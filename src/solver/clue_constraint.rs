@@ -70,6 +70,22 @@ impl UnaryConstraint for EdgeConstraint {
     }
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct FixedColumnConstraint {
+    pub tile: Tile,
+    pub column: usize,
+}
+
+impl UnaryConstraint for FixedColumnConstraint {
+    fn var(&self) -> Tile {
+        self.tile
+    }
+
+    fn valid(&self, value: usize) -> bool {
+        value == self.column
+    }
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct InSameColumnConstraint {
     pub tile_a: Tile,
@@ -151,6 +167,28 @@ impl TernaryConstraint for TwoApartNotMiddleConstraint {
     }
 }
 
+/// The middle tile sits strictly between the other two, whichever side each of them is on
+#[derive(Debug, Clone, Hash)]
+pub struct BetweenConstraint {
+    pub tile_a: Tile,
+    pub tile_middle: Tile,
+    pub tile_c: Tile,
+}
+
+impl TernaryConstraint for BetweenConstraint {
+    fn vars(&self) -> Vec<Tile> {
+        vec![self.tile_a, self.tile_middle, self.tile_c]
+    }
+
+    fn valid(&self, values: &Vec<usize>) -> bool {
+        let a = values[0];
+        let middle = values[1];
+        let c = values[2];
+
+        (a < middle && middle < c) || (c < middle && middle < a)
+    }
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct LessThanConstraint {
     pub tile_a: Tile,
@@ -192,6 +230,28 @@ impl TernaryConstraint for OneMatchesEitherConstraint {
     }
 }
 
+/// At least two of the three tiles share a column; which two is left open
+#[derive(Debug, Clone, Hash)]
+pub struct TwoOfThreeInColumnConstraint {
+    pub tile_a: Tile,
+    pub tile_b: Tile,
+    pub tile_c: Tile,
+}
+
+impl TernaryConstraint for TwoOfThreeInColumnConstraint {
+    fn vars(&self) -> Vec<Tile> {
+        vec![self.tile_a, self.tile_b, self.tile_c]
+    }
+
+    fn valid(&self, values: &Vec<usize>) -> bool {
+        let a = values[0];
+        let b = values[1];
+        let c = values[2];
+
+        a == b || a == c || b == c
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct ConstraintSet {
     pub unary_constraints: Vec<Box<dyn UnaryConstraint>>,
@@ -340,6 +400,332 @@ impl ClueConstraint for LeftOfHandler {
     }
 }
 
+#[derive(Clone, Debug)]
+struct BetweenHandler {
+    anchor_tile: Tile,
+    middle_tile: Tile,
+    other_anchor_tile: Tile,
+}
+
+impl BetweenHandler {
+    fn new(clue: &Clue) -> Self {
+        assert_eq!(
+            clue.assertions.len(),
+            3,
+            "Clue assertions must have exactly 3 elements"
+        );
+        Self {
+            anchor_tile: clue.assertions[0].tile,
+            middle_tile: clue.assertions[1].tile,
+            other_anchor_tile: clue.assertions[2].tile,
+        }
+    }
+}
+
+impl ClueConstraint for BetweenHandler {
+    /// Solutions with the middle tile in `column`, and the anchors on either side of it
+    fn potential_solutions(
+        &self,
+        board: &GameBoard,
+        column: usize,
+    ) -> Vec<Vec<(usize, TileAssertion)>> {
+        let max_column = board.solution.n_variants - 1;
+        let mut solutions = Vec::new();
+
+        // the middle tile needs a column on each side
+        if column == 0 || column >= max_column {
+            return solutions;
+        }
+        if !board.is_candidate_available(self.middle_tile.row, column, self.middle_tile.variant) {
+            return solutions;
+        }
+
+        for left_col in 0..column {
+            for right_col in (column + 1)..=max_column {
+                for (left_tile, right_tile) in [
+                    (self.anchor_tile, self.other_anchor_tile),
+                    (self.other_anchor_tile, self.anchor_tile),
+                ] {
+                    solutions.push(vec![
+                        (
+                            left_col,
+                            TileAssertion {
+                                tile: left_tile,
+                                assertion: true,
+                            },
+                        ),
+                        (
+                            column,
+                            TileAssertion {
+                                tile: self.middle_tile,
+                                assertion: true,
+                            },
+                        ),
+                        (
+                            right_col,
+                            TileAssertion {
+                                tile: right_tile,
+                                assertion: true,
+                            },
+                        ),
+                    ]);
+                }
+            }
+        }
+
+        solutions.retain(|solution| is_partial_solution_valid(board, solution));
+        solutions
+    }
+
+    fn constraints(&self, difficulty: Difficulty) -> ConstraintSet {
+        let mut constraints = ConstraintSet::default();
+        constraints.unary_constraints.push(Box::new(EdgeConstraint {
+            tile: self.middle_tile,
+            difficulty,
+            allow_left: false,
+            allow_right: false,
+        }));
+        constraints
+            .ternary_constraints
+            .push(Box::new(BetweenConstraint {
+                tile_a: self.anchor_tile,
+                tile_middle: self.middle_tile,
+                tile_c: self.other_anchor_tile,
+            }));
+        constraints
+    }
+}
+
+#[derive(Clone, Debug)]
+struct InColumnHandler {
+    tile: Tile,
+    column: usize,
+}
+
+impl InColumnHandler {
+    fn new(clue: &Clue) -> Self {
+        assert_eq!(
+            clue.assertions.len(),
+            1,
+            "Clue assertions must have exactly 1 element"
+        );
+        Self {
+            tile: clue.assertions[0].tile,
+            column: clue.column.expect("In column clue must have a column"),
+        }
+    }
+}
+
+impl ClueConstraint for InColumnHandler {
+    fn potential_solutions(
+        &self,
+        board: &GameBoard,
+        column: usize,
+    ) -> Vec<Vec<(usize, TileAssertion)>> {
+        // the clue names its column outright; every other column has no solution
+        if column != self.column {
+            return Vec::new();
+        }
+        let mut solutions = vec![vec![(
+            column,
+            TileAssertion {
+                tile: self.tile,
+                assertion: true,
+            },
+        )]];
+        solutions.retain(|solution| is_partial_solution_valid(board, solution));
+        solutions
+    }
+
+    fn constraints(&self, _difficulty: Difficulty) -> ConstraintSet {
+        let mut constraints = ConstraintSet::default();
+        constraints
+            .unary_constraints
+            .push(Box::new(FixedColumnConstraint {
+                tile: self.tile,
+                column: self.column,
+            }));
+        constraints
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AdjacentLeftOfHandler {
+    left_tile: Tile,
+    right_tile: Tile,
+}
+
+impl AdjacentLeftOfHandler {
+    fn new(clue: &Clue) -> Self {
+        assert_eq!(
+            clue.assertions.len(),
+            2,
+            "Clue assertions must have exactly 2 elements"
+        );
+        Self {
+            left_tile: clue.assertions[0].tile,
+            right_tile: clue.assertions[1].tile,
+        }
+    }
+}
+
+impl ClueConstraint for AdjacentLeftOfHandler {
+    fn potential_solutions(
+        &self,
+        board: &GameBoard,
+        column: usize,
+    ) -> Vec<Vec<(usize, TileAssertion)>> {
+        let max_column = board.solution.n_variants - 1;
+        let mut solutions = Vec::new();
+
+        // Skip if we're at the last column - can't have a right tile
+        if column >= max_column {
+            return solutions;
+        }
+
+        // Unlike AdjacentHandler, only the forward order is a solution
+        solutions.push(vec![
+            (
+                column,
+                TileAssertion {
+                    tile: self.left_tile,
+                    assertion: true,
+                },
+            ),
+            (
+                column + 1,
+                TileAssertion {
+                    tile: self.right_tile,
+                    assertion: true,
+                },
+            ),
+        ]);
+
+        solutions.retain(|solution| is_partial_solution_valid(board, solution));
+        solutions
+    }
+
+    fn constraints(&self, difficulty: Difficulty) -> ConstraintSet {
+        let mut constraints = ConstraintSet::default();
+        constraints.unary_constraints.push(Box::new(EdgeConstraint {
+            tile: self.left_tile,
+            difficulty,
+            allow_left: true,
+            allow_right: false,
+        }));
+        constraints.unary_constraints.push(Box::new(EdgeConstraint {
+            tile: self.right_tile,
+            difficulty,
+            allow_left: false,
+            allow_right: true,
+        }));
+        constraints
+            .binary_constraints
+            .push(Box::new(LessThanConstraint {
+                tile_a: self.left_tile,
+                tile_b: self.right_tile,
+            }));
+        constraints
+            .binary_constraints
+            .push(Box::new(AdjacentConstraint {
+                tile_a: self.left_tile,
+                tile_b: self.right_tile,
+                distance: 1,
+            }));
+        constraints
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ThreeAdjacentOrderedHandler {
+    tiles: [Tile; 3],
+}
+
+impl ThreeAdjacentOrderedHandler {
+    fn new(clue: &Clue) -> Self {
+        assert_eq!(
+            clue.assertions.len(),
+            3,
+            "Clue assertions must have exactly 3 elements"
+        );
+        Self {
+            tiles: [
+                clue.assertions[0].tile,
+                clue.assertions[1].tile,
+                clue.assertions[2].tile,
+            ],
+        }
+    }
+}
+
+impl ClueConstraint for ThreeAdjacentOrderedHandler {
+    fn potential_solutions(
+        &self,
+        board: &GameBoard,
+        column: usize,
+    ) -> Vec<Vec<(usize, TileAssertion)>> {
+        let max_column = board.solution.n_variants - 1;
+        if column + 2 > max_column {
+            return Vec::new();
+        }
+
+        // Unlike AdjacentHandler, only the forward order is a solution
+        let solution = self
+            .tiles
+            .iter()
+            .enumerate()
+            .map(|(i, tile)| {
+                (
+                    column + i,
+                    TileAssertion {
+                        tile: *tile,
+                        assertion: true,
+                    },
+                )
+            })
+            .collect();
+        let mut solutions = vec![solution];
+        solutions.retain(|solution| is_partial_solution_valid(board, solution));
+        solutions
+    }
+
+    fn constraints(&self, difficulty: Difficulty) -> ConstraintSet {
+        let mut constraints = ConstraintSet::default();
+        let [left_tile, middle_tile, right_tile] = self.tiles;
+        constraints.unary_constraints.push(Box::new(EdgeConstraint {
+            tile: left_tile,
+            difficulty,
+            allow_left: true,
+            allow_right: false,
+        }));
+        constraints.unary_constraints.push(Box::new(EdgeConstraint {
+            tile: middle_tile,
+            difficulty,
+            allow_left: false,
+            allow_right: false,
+        }));
+        constraints.unary_constraints.push(Box::new(EdgeConstraint {
+            tile: right_tile,
+            difficulty,
+            allow_left: false,
+            allow_right: true,
+        }));
+        for (tile_a, tile_b) in [(left_tile, middle_tile), (middle_tile, right_tile)] {
+            constraints
+                .binary_constraints
+                .push(Box::new(LessThanConstraint { tile_a, tile_b }));
+            constraints
+                .binary_constraints
+                .push(Box::new(AdjacentConstraint {
+                    tile_a,
+                    tile_b,
+                    distance: 1,
+                }));
+        }
+        constraints
+    }
+}
+
 impl ClueConstraint for NotAdjacentHandler {
     fn potential_solutions(
         &self,
@@ -571,6 +957,64 @@ impl ClueConstraint for AllInColumnHandler {
     }
 }
 
+/// Two tiles in the same column, drawn from rows that aren't next to each other
+#[derive(Clone, Debug)]
+struct NonAdjacentRowsInColumnHandler {
+    tile_a: Tile,
+    tile_b: Tile,
+}
+
+impl NonAdjacentRowsInColumnHandler {
+    fn new(clue: &Clue) -> Self {
+        Self {
+            tile_a: clue.assertions[0].tile,
+            tile_b: clue.assertions[1].tile,
+        }
+    }
+}
+
+impl ClueConstraint for NonAdjacentRowsInColumnHandler {
+    fn potential_solutions(
+        &self,
+        board: &GameBoard,
+        column: usize,
+    ) -> Vec<Vec<(usize, TileAssertion)>> {
+        let solution = vec![
+            (
+                column,
+                TileAssertion {
+                    tile: self.tile_a,
+                    assertion: true,
+                },
+            ),
+            (
+                column,
+                TileAssertion {
+                    tile: self.tile_b,
+                    assertion: true,
+                },
+            ),
+        ];
+
+        if is_partial_solution_valid(board, &solution) {
+            vec![solution]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn constraints(&self, _difficulty: Difficulty) -> ConstraintSet {
+        let mut constraints = ConstraintSet::default();
+        constraints
+            .binary_constraints
+            .push(Box::new(InSameColumnConstraint {
+                tile_a: self.tile_a,
+                tile_b: self.tile_b,
+            }));
+        constraints
+    }
+}
+
 pub fn create_clue_constraint(clue: &Clue) -> Box<dyn ClueConstraint> {
     match &clue.clue_type {
         ClueType::Horizontal(h_type) => match h_type {
@@ -578,10 +1022,20 @@ pub fn create_clue_constraint(clue: &Clue) -> Box<dyn ClueConstraint> {
             HorizontalClueType::ThreeAdjacent => Box::new(AdjacentHandler::new(clue)),
             HorizontalClueType::TwoApartNotMiddle => Box::new(AdjacentHandler::new(clue)),
             HorizontalClueType::NotAdjacent => Box::new(NotAdjacentHandler::new(clue)),
+            HorizontalClueType::Between => Box::new(BetweenHandler::new(clue)),
+            HorizontalClueType::InColumn => Box::new(InColumnHandler::new(clue)),
             HorizontalClueType::LeftOf => Box::new(LeftOfHandler::new(clue)),
+            HorizontalClueType::AdjacentLeftOf => Box::new(AdjacentLeftOfHandler::new(clue)),
+            HorizontalClueType::ThreeAdjacentOrdered => {
+                Box::new(ThreeAdjacentOrderedHandler::new(clue))
+            }
         },
         ClueType::Vertical(v_type) => match v_type {
             VerticalClueType::OneMatchesEither => Box::new(OneMatchesEitherHandler::new(clue)),
+            VerticalClueType::TwoOfThreeInColumn => Box::new(TwoOfThreeInColumnHandler::new(clue)),
+            VerticalClueType::TwoInColumnNonAdjacentRows => {
+                Box::new(NonAdjacentRowsInColumnHandler::new(clue))
+            }
             _ => Box::new(AllInColumnHandler::new(clue)),
         },
     }
@@ -663,3 +1117,40 @@ impl ClueConstraint for OneMatchesEitherHandler {
         constraints
     }
 }
+
+#[derive(Clone, Debug)]
+struct TwoOfThreeInColumnHandler {
+    assertions: Vec<TileAssertion>,
+}
+
+impl TwoOfThreeInColumnHandler {
+    fn new(clue: &Clue) -> Self {
+        Self {
+            assertions: clue.assertions.clone(),
+        }
+    }
+}
+
+impl ClueConstraint for TwoOfThreeInColumnHandler {
+    fn potential_solutions(
+        &self,
+        _board: &GameBoard,
+        _column: usize,
+    ) -> Vec<Vec<(usize, TileAssertion)>> {
+        // a placement in one column says nothing about the tile left out, so this doesn't fit the
+        // candidate finder; deduce_clue sends it to its own deducer instead
+        vec![]
+    }
+
+    fn constraints(&self, _difficulty: Difficulty) -> ConstraintSet {
+        let mut constraints = ConstraintSet::default();
+        constraints
+            .ternary_constraints
+            .push(Box::new(TwoOfThreeInColumnConstraint {
+                tile_a: self.assertions[0].tile,
+                tile_b: self.assertions[1].tile,
+                tile_c: self.assertions[2].tile,
+            }));
+        constraints
+    }
+}
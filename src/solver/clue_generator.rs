@@ -1,17 +1,26 @@
 use super::{
     clue_generator_state::{ClueEvaluation, ClueGeneratorState},
-    puzzle_variants::{random_puzzle_variant, PuzzleVariant},
+    puzzle_variants::{
+        in_column_clue_weight, override_clue_weights, random_puzzle_variant, PuzzleVariant,
+    },
 };
 
 use log::{info, trace, warn};
-use std::{collections::BTreeSet, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
 use crate::{
-    model::{Clue, ClueSet, Difficulty, GameBoard, Tile},
+    model::{Clue, ClueSet, ClueType, Difficulty, GameBoard, Tile},
     solver::candidate_solver::{perform_evaluation_step, EvaluationStepResult},
 };
 
-use super::deduce_clue;
+use super::{deduce_clue, has_unique_solution};
 
 fn evaluate_clue(
     board: &GameBoard,
@@ -46,7 +55,61 @@ pub fn apply_selections(board: &GameBoard, tiles: &BTreeSet<Tile>) -> GameBoard
     board
 }
 
+/// Knobs for clue generation; `for_difficulty` gives the normal configuration
+#[derive(Debug, Clone)]
+pub struct ClueGeneratorOptions {
+    /// How many candidate clues to evaluate before picking the best one
+    pub look_ahead_count: usize,
+    /// Abandon generation once this passes
+    pub deadline: Option<Instant>,
+    /// Abandon generation as soon as this is set, e.g. because the window closed
+    pub cancelled: Option<Arc<AtomicBool>>,
+}
+
+impl ClueGeneratorOptions {
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        Self {
+            look_ahead_count: difficulty.look_ahead_count(),
+            deadline: None,
+            cancelled: None,
+        }
+    }
+
+    /// The cheapest configuration: take the first useful clue rather than the best of several.
+    /// Puzzles are still fully solvable, just less carefully tuned.
+    pub fn simplified() -> Self {
+        Self {
+            look_ahead_count: 1,
+            deadline: None,
+            cancelled: None,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .as_ref()
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    }
+}
+
 pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
+    let options = ClueGeneratorOptions::for_difficulty(init_board.solution.difficulty);
+    generate_clues_with_options(init_board, &options)
+        .expect("generation without a deadline always finishes")
+}
+
+/// Generate clues; returns None if `options.deadline` passes or `options.cancelled` is set first.
+/// The solution's `clue_weights` replace the puzzle variant's choice of clue types; otherwise its
+/// `clue_type_weights` adjust it.
+pub fn generate_clues_with_options(
+    init_board: &GameBoard,
+    options: &ClueGeneratorOptions,
+) -> Option<ClueGeneratorResult> {
     trace!(
         target: "clue_generator",
         "Generating clues... for board: {:?}; solution is {:?}",
@@ -56,7 +119,24 @@ pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
     let mut state = ClueGeneratorState::new(init_board.clone());
 
     let puzzle_variant = random_puzzle_variant(init_board.solution.difficulty, &mut state.rng);
-    let clue_weights = puzzle_variant.get_clue_weights();
+    let mut variant_clue_weights = puzzle_variant.get_clue_weights();
+    variant_clue_weights.extend(in_column_clue_weight(init_board.solution.difficulty));
+    let mut clue_weights = init_board.solution.clue_weights.clone().unwrap_or_else(|| {
+        override_clue_weights(
+            variant_clue_weights.clone(),
+            &init_board.solution.clue_type_weights,
+        )
+    });
+    // what to fall back on if the chosen clue types can't finish the board; types the player
+    // turned off stay off
+    let disabled_clue_types: HashMap<ClueType, u32> = init_board
+        .solution
+        .clue_type_weights
+        .iter()
+        .filter(|(_, weight)| **weight == 0)
+        .map(|(clue_type, weight)| (*clue_type, *weight))
+        .collect();
+    let fallback_clue_weights = override_clue_weights(variant_clue_weights, &disabled_clue_types);
     info!(
         target: "clue_generator",
         "Generating clues for seed {:?}, puzzle variant {:?}",
@@ -82,12 +162,20 @@ pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
         );
         let mut possible_clues = Vec::new();
         let mut clue_generation_loops = 0;
-        let clue_candidate_count = state.board.solution.difficulty.look_ahead_count();
+        let clue_candidate_count = options.look_ahead_count;
         state.reset_stats();
         while possible_clues.len() < clue_candidate_count
             && clue_generation_loops < clue_candidate_count * 1000
         /* TODO - need to make the clue generation guided to try to choose at least one unsolved tile. */
         {
+            if options.is_expired() {
+                info!(target: "clue_generator", "Clue generation ran out of time");
+                return None;
+            }
+            if options.is_cancelled() {
+                info!(target: "clue_generator", "Clue generation cancelled");
+                return None;
+            }
             clue_generation_loops += 1;
             if let Some(clue) = state.generate_random_clue_type(&clue_weights, None) {
                 if state.would_exceed_usage_limits(&clue) {
@@ -98,6 +186,14 @@ pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
                     );
                     continue;
                 }
+                if state.is_degenerate(&clue) {
+                    trace!(
+                        target: "clue_generator",
+                        "Skipping degenerate clue: {:?}",
+                        clue
+                    );
+                    continue;
+                }
                 let non_singleton_intersecting_clues = state
                     .clues
                     .iter()
@@ -171,6 +267,14 @@ pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
                 "Error! After clue {:?}, board entered an invalid state",
                 evaluated_clue
             );
+        } else if clue_weights != fallback_clue_weights {
+            // the chosen clue types can't finish this board; the variant's always can
+            info!(
+                target: "clue_generator",
+                "No useful clue among {:?}; falling back to the puzzle variant's clue weights",
+                clue_weights
+            );
+            clue_weights = fallback_clue_weights.clone();
         } else {
             warn!(target: "clue_generator", "Stats: {:?}", state.stats);
             panic!(
@@ -180,8 +284,16 @@ pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
         }
     }
 
+    if options.is_expired() {
+        info!(target: "clue_generator", "Clue generation ran out of time before pruning");
+        return None;
+    }
     ClueGeneratorState::merge_adjacent_clues(&mut state.clues);
-    state.optimized_prune(&init_board);
+    if !state.optimized_prune(&init_board, || {
+        options.is_cancelled() || options.is_expired()
+    }) {
+        return None;
+    }
     trace!(
         target: "clue_generator",
         "Solved board: {:?}",
@@ -195,20 +307,28 @@ pub fn generate_clues(init_board: &GameBoard) -> ClueGeneratorResult {
 
     let clue_set = Arc::new(ClueSet::new(state.clues.clone()));
     board_with_revealed_tiles.set_clues(clue_set);
+    debug_assert!(
+        has_unique_solution(&board_with_revealed_tiles, &state.clues),
+        "Clues for seed {:?} admit more than one solution: {:?}",
+        init_board.solution.seed,
+        state.clues
+    );
 
-    ClueGeneratorResult {
+    Some(ClueGeneratorResult {
         clues: state.clues,
         revealed_tiles: state.revealed_tiles.into_iter().collect(),
         board: board_with_revealed_tiles,
-    }
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        model::{Difficulty, GameBoard, Solution},
+        model::{ClueType, Difficulty, GameBoard, Solution, VerticalClueType},
+        solver::clue_weights_matching,
         tests::UsingLogger,
     };
+    use std::collections::HashMap;
     use test_context::test_context;
 
     use super::*;
@@ -290,4 +410,81 @@ mod tests {
             assert_eq!(tile1, tile2);
         }
     }
+
+    #[test]
+    fn test_generate_clues_gives_up_after_deadline() {
+        let solution = Arc::new(Solution::new(Difficulty::Hard, Some(42)));
+        let board = GameBoard::new(solution);
+        let options = ClueGeneratorOptions {
+            deadline: Some(Instant::now()),
+            ..ClueGeneratorOptions::for_difficulty(Difficulty::Hard)
+        };
+
+        assert!(generate_clues_with_options(&board, &options).is_none());
+    }
+
+    #[test]
+    fn test_generate_clues_stops_when_cancelled() {
+        let solution = Arc::new(Solution::new(Difficulty::Hard, Some(42)));
+        let board = GameBoard::new(solution);
+        let options = ClueGeneratorOptions {
+            cancelled: Some(Arc::new(AtomicBool::new(true))),
+            ..ClueGeneratorOptions::for_difficulty(Difficulty::Hard)
+        };
+
+        assert!(generate_clues_with_options(&board, &options).is_none());
+    }
+
+    #[test]
+    fn test_simplified_generation_is_solvable() {
+        let solution = Arc::new(Solution::new(Difficulty::Hard, Some(42)));
+        let board = GameBoard::new(solution);
+
+        let result = generate_clues_with_options(&board, &ClueGeneratorOptions::simplified())
+            .expect("no deadline");
+
+        let clues = result.clues.clone();
+        let mut board = result.board.clone();
+        while perform_evaluation_step(&mut board, &clues) != EvaluationStepResult::Nothing {
+            board.auto_solve_all();
+        }
+        assert!(board.is_complete());
+    }
+
+    #[test]
+    fn test_generation_with_matching_clue_weights_is_solvable() {
+        let template = generate_clues(&GameBoard::new(Arc::new(Solution::new(
+            Difficulty::Easy,
+            Some(42),
+        ))));
+        let board = GameBoard::new(Arc::new(Solution {
+            clue_weights: Some(clue_weights_matching(template.clues.iter())),
+            ..Solution::new(Difficulty::Easy, Some(43))
+        }));
+
+        let result = generate_clues(&board);
+
+        let clues = result.clues.clone();
+        let mut board = result.board.clone();
+        while perform_evaluation_step(&mut board, &clues) != EvaluationStepResult::Nothing {
+            board.auto_solve_all();
+        }
+        assert!(board.is_complete());
+    }
+
+    #[test]
+    fn test_generation_leaves_out_disabled_clue_types() {
+        let one_matches_either = ClueType::Vertical(VerticalClueType::OneMatchesEither);
+        for seed in 0..3 {
+            let board = GameBoard::new(Arc::new(Solution {
+                clue_type_weights: HashMap::from([(one_matches_either, 0)]),
+                ..Solution::new(Difficulty::Moderate, Some(seed))
+            }));
+            let result = generate_clues(&board);
+            assert!(result
+                .clues
+                .iter()
+                .all(|clue| clue.clue_type != one_matches_either));
+        }
+    }
 }
@@ -29,6 +29,7 @@ pub struct ClueGeneratorStats {
     pub n_rejected_max_vert: usize,
     pub n_rejected_max_horiz: usize,
     pub n_rejected_non_singleton_intersecting_clues: usize,
+    pub n_rejected_degenerate: usize,
 }
 
 #[derive(Debug)]
@@ -61,7 +62,13 @@ pub struct ClueGeneratorState {
 
 impl ClueGeneratorState {
     pub(crate) fn new(board: GameBoard) -> Self {
-        let board = board.clone();
+        let rng = Box::new(StdRng::seed_from_u64(board.solution.seed));
+        Self::new_with_rng(board, rng)
+    }
+
+    /// Like `new`, but draws random choices from `rng` rather than from an rng seeded with the
+    /// solution's seed, so tests can force particular branches
+    pub(crate) fn new_with_rng(board: GameBoard, rng: Box<dyn RngCore>) -> Self {
         let selection_count_by_row = vec![0; board.solution.n_rows];
         let selection_count_by_column = vec![0; board.solution.n_variants];
         let unsolved_columns: BTreeSet<usize> = (0..board.solution.n_variants).collect();
@@ -90,8 +97,6 @@ impl ClueGeneratorState {
             }
         }
 
-        let rng = Box::new(StdRng::seed_from_u64(board.solution.seed));
-
         Self {
             selection_count_by_row,
             selection_count_by_column,
@@ -211,6 +216,43 @@ impl ClueGeneratorState {
         }
     }
 
+    /// A two-apart-not-middle clue is degenerate when its not-middle tile is already ruled out of
+    /// every column that could still be the middle; it then says no more than its two ends do.
+    pub(crate) fn is_degenerate(&mut self, clue: &Clue) -> bool {
+        if !matches!(
+            clue.clue_type,
+            ClueType::Horizontal(HorizontalClueType::TwoApartNotMiddle)
+        ) {
+            return false;
+        }
+        let (left, not_middle, right) = (
+            clue.assertions[0].tile,
+            clue.assertions[1].tile,
+            clue.assertions[2].tile,
+        );
+
+        let board = &self.board;
+        let n_variants = board.solution.n_variants;
+        let constrains_middle = board.get_possible_cols_for_tile(left).any(|left_col| {
+            [left_col.checked_sub(2), Some(left_col + 2)]
+                .into_iter()
+                .flatten()
+                .filter(|&right_col| right_col < n_variants)
+                .filter(|&right_col| {
+                    board.is_candidate_available(right.row, right_col, right.variant)
+                })
+                .any(|right_col| {
+                    let middle_col = (left_col + right_col) / 2;
+                    board.is_candidate_available(not_middle.row, middle_col, not_middle.variant)
+                })
+        });
+
+        if !constrains_middle {
+            self.stats.n_rejected_degenerate += 1;
+        }
+        !constrains_middle
+    }
+
     fn record_clue_usage(&mut self, clue: &Clue) {
         if clue.is_horizontal() {
             for TileAssertion { tile, assertion } in clue.assertions.iter() {
@@ -321,7 +363,9 @@ impl ClueGeneratorState {
         self.tiles_without_evidence.remove(&(column, tile));
     }
 
-    pub fn optimized_prune(&mut self, board: &GameBoard) {
+    /// Drop clues the puzzle can be solved without. Returns false, leaving the clues untouched,
+    /// if `should_stop` says so before pruning finishes.
+    pub fn optimized_prune(&mut self, board: &GameBoard, should_stop: impl Fn() -> bool) -> bool {
         let mut required_clues: BTreeSet<Clue> = BTreeSet::new();
         let mut clues = self.clues.clone().into_iter().rev().collect::<Vec<_>>();
         info!(
@@ -331,6 +375,10 @@ impl ClueGeneratorState {
         );
 
         while !required_clues.contains(clues.last().unwrap()) {
+            if should_stop() {
+                info!(target: "clue_generator", "Optimized prune stopped");
+                return false;
+            }
             let mut used_clues = BTreeSet::new();
             let mut board = board.clone();
             while !board.is_complete() {
@@ -342,7 +390,8 @@ impl ClueGeneratorState {
                     EvaluationStepResult::DeductionsFound(clue) => {
                         used_clues.insert(clue);
                     }
-                    EvaluationStepResult::HiddenSetsFound => {
+                    EvaluationStepResult::HiddenSetsFound
+                    | EvaluationStepResult::ForcedPlacementsFound => {
                         // nothing
                     }
                 }
@@ -381,6 +430,7 @@ impl ClueGeneratorState {
             );
         }
         self.clues.retain(|clue| required_clues.contains(clue));
+        true
     }
 
     fn get_random_tile_not_from_columns(
@@ -517,6 +567,14 @@ impl ClueGeneratorState {
                         let (tiles, _) = self.get_random_horiz_tiles(2, &seed);
                         Some(Clue::three_adjacent(seed, tiles[1], tiles[2]))
                     }
+                    HorizontalClueType::ThreeAdjacentOrdered => {
+                        let (mut tiles, columns) = self.get_random_horiz_tiles(2, &seed);
+                        // the tiles run away from the seed, which may be leftwards
+                        if columns[0] > columns[2] {
+                            tiles.reverse();
+                        }
+                        Some(Clue::three_adjacent_ordered(tiles[0], tiles[1], tiles[2]))
+                    }
                     HorizontalClueType::TwoApartNotMiddle => {
                         let (tiles, columns) = self.get_random_horiz_tiles(2, &seed);
 
@@ -541,6 +599,52 @@ impl ClueGeneratorState {
                         Some(Clue::not_adjacent(seed, tile))
                     }
 
+                    HorizontalClueType::AdjacentLeftOf => {
+                        let (tiles, columns) = self.get_random_horiz_tiles(1, &seed);
+                        if columns[0] < columns[1] {
+                            Some(Clue::adjacent_left_of(seed, tiles[1]))
+                        } else {
+                            Some(Clue::adjacent_left_of(tiles[1], seed))
+                        }
+                    }
+
+                    HorizontalClueType::InColumn => {
+                        let (_, seed_col) = self.board.solution.find_tile(seed);
+                        Some(Clue::in_column(seed, seed_col))
+                    }
+
+                    HorizontalClueType::Between => {
+                        let n_rows = self.board.solution.n_rows;
+                        let (_, seed_col) = self.board.solution.find_tile(seed);
+                        // three distinct columns, one of them the seed's; whichever tile lands in
+                        // the middle column is the middle of the clue
+                        let mut columns = (0..self.board.solution.n_variants)
+                            .filter(|&c| c != seed_col)
+                            .choose_multiple(&mut self.rng, 2);
+                        if columns.len() < 2 {
+                            return None;
+                        }
+                        columns.push(seed_col);
+                        columns.sort();
+
+                        let tiles = columns
+                            .into_iter()
+                            .map(|col| {
+                                if col == seed_col {
+                                    seed
+                                } else {
+                                    let row = self.rng.random_range(0..n_rows);
+                                    self.board.solution.get(row, col)
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        if self.rng.random_bool(0.5) {
+                            Some(Clue::between(tiles[0], tiles[1], tiles[2]))
+                        } else {
+                            Some(Clue::between(tiles[2], tiles[1], tiles[0]))
+                        }
+                    }
+
                     HorizontalClueType::LeftOf => {
                         let (_, seed_col) = self.board.solution.find_tile(seed);
                         let possible_cols = (0..self.board.solution.n_variants)
@@ -573,6 +677,15 @@ impl ClueGeneratorState {
                             _ => None,
                         }
                     }
+                    VerticalClueType::TwoInColumnNonAdjacentRows => {
+                        let (seed_row, seed_col) = self.board.solution.find_tile(seed);
+                        let possible_rows = (0..self.board.solution.n_rows)
+                            .filter(|&r| r.abs_diff(seed_row) > 1)
+                            .collect::<Vec<_>>();
+                        let row = *possible_rows.choose(&mut self.rng)?;
+                        let tile = self.board.solution.get(row, seed_col);
+                        Some(Clue::two_in_column_non_adjacent_rows(seed, tile))
+                    }
                     VerticalClueType::NotInSameColumn => {
                         let (_, seed_col) = self.board.solution.find_tile(seed);
                         let not_tile = self
@@ -599,6 +712,15 @@ impl ClueGeneratorState {
                             });
                         Some(Clue::one_matches_either(seed, not_tile, tiles[0]))
                     }
+                    VerticalClueType::TwoOfThreeInColumn => {
+                        let (_, seed_col) = self.board.solution.find_tile(seed);
+                        let tiles = self.get_random_vertical_tiles(&seed, 1);
+                        let odd_tile = self
+                            .get_random_tile_not_from_columns(vec![seed_col as i32], |t| {
+                                t.row != seed.row && t.row != tiles[0].row
+                            });
+                        Some(Clue::two_of_three_in_column(seed, tiles[0], odd_tile))
+                    }
                 }
             }
         }
@@ -677,6 +799,53 @@ impl ClueGeneratorState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::tests::create_test_solution;
+
+    /// Always yields the same bits: all zeros picks the first of any choice, all ones the last
+    struct ConstantRng(u64);
+
+    impl RngCore for ConstantRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(self.0 as u8);
+        }
+    }
+
+    fn state_with_rng(value: u64) -> ClueGeneratorState {
+        let board = GameBoard::new(create_test_solution(3, 4));
+        ClueGeneratorState::new_with_rng(board, Box::new(ConstantRng(value)))
+    }
+
+    #[test]
+    fn test_horiz_tiles_follow_injected_direction() {
+        let seed = Tile::new(1, 'b');
+
+        // from column 1 both directions fit; the first choice is rightward
+        let (tiles, columns) = state_with_rng(0).get_random_horiz_tiles(1, &seed);
+        assert_eq!(tiles[0], seed);
+        assert_eq!(columns, vec![1, 2]);
+
+        let (_, columns) = state_with_rng(u64::MAX).get_random_horiz_tiles(1, &seed);
+        assert_eq!(columns, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_horiz_tiles_at_edge_have_one_direction() {
+        // no room to the left of column 0, whatever the rng picks
+        let (_, columns) = state_with_rng(u64::MAX).get_random_horiz_tiles(2, &Tile::new(0, 'a'));
+        assert_eq!(columns, vec![0, 1, 2]);
+
+        // nor to the right of the last column
+        let (_, columns) = state_with_rng(0).get_random_horiz_tiles(2, &Tile::new(0, 'd'));
+        assert_eq!(columns, vec![3, 2, 1]);
+    }
 
     #[test]
     fn test_merge_adjacent_clues() {
@@ -705,4 +874,38 @@ mod tests {
         ClueGeneratorState::merge_adjacent_clues(&mut clues);
         assert_eq!(clues.len(), 2);
     }
+
+    #[test]
+    fn test_two_apart_not_middle_degenerate() {
+        let input = "\
+1|abcd|abcd|abcd|abcd|
+-----------------
+2|abcd|acd|acd|abcd|
+-----------------";
+        let board = GameBoard::parse(input, create_test_solution(2, 4));
+        let mut state = ClueGeneratorState::new(board);
+
+        // 1b is already ruled out of columns 1 and 2, the only possible middles
+        let degenerate =
+            Clue::two_apart_not_middle(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(0, 'c'));
+        assert!(state.is_degenerate(&degenerate));
+
+        let useful =
+            Clue::two_apart_not_middle(Tile::new(0, 'a'), Tile::new(1, 'c'), Tile::new(0, 'c'));
+        assert!(!state.is_degenerate(&useful));
+
+        // other clue types are never degenerate
+        assert!(!state.is_degenerate(&Clue::adjacent(Tile::new(0, 'a'), Tile::new(1, 'b'))));
+    }
+
+    #[test]
+    fn test_optimized_prune_stops_when_asked() {
+        let mut state = state_with_rng(0);
+        let clues = vec![Clue::parse("|+0a,+1a|"), Clue::parse("|+0b,+1b|")];
+        state.clues = clues.clone();
+        let board = state.board.clone();
+
+        assert!(!state.optimized_prune(&board, || true));
+        assert!(state.clues == clues);
+    }
 }
@@ -12,7 +12,7 @@ use crate::{
 
 use super::{
     clue_constraint::{BinaryConstraint, TernaryConstraint, UnaryConstraint},
-    solver_helpers::get_domains_and_constraints,
+    solver_helpers::{get_domains_and_constraints, sort_deductions},
 };
 
 // / A simple Tile type.
@@ -322,6 +322,7 @@ impl ConstraintSolver {
                 }
             }
         }
+        sort_deductions(&mut deductions);
         deductions
     }
 }
@@ -330,7 +331,15 @@ impl ConstraintSolver {
 mod tests {
     use test_context::test_context;
 
-    use crate::{game::tests::create_test_solution, tests::UsingLogger};
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+    use std::sync::Arc;
+
+    use crate::{
+        game::tests::create_test_solution,
+        model::{ClueType, Difficulty, Solution, VerticalClueType},
+        solver::{deduce_clue, generate_clues},
+        tests::UsingLogger,
+    };
 
     use super::*;
 
@@ -532,4 +541,251 @@ mod tests {
         assert!(deductions.contains(&Deduction::parse("1b not col 0 (Constraint)")));
         assert!(deductions.contains(&Deduction::parse("2a is col 0 (LastRemaining)")));
     }
+
+    #[test]
+    fn test_two_of_three_in_column_one_pair_forced() {
+        let input = "
+0|abcd|abcd|abcd| bcd|
+-----------------
+1|a cd|a cd|a cd|<B> |
+-----------------
+2|ab d|abcd|abcd|ab d|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+        let clue =
+            Clue::two_of_three_in_column(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(2, 'c'));
+
+        // 1b pairs with neither, which leaves 0a with 2c
+        let deductions = ConstraintSolver::deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 1);
+        assert!(deductions.contains(&Deduction::parse("0a not col 0 (Constraint)")));
+    }
+
+    #[test]
+    fn test_two_of_three_in_column_one_option_eliminated() {
+        let input = "
+0|<A> |abcd|abcd|abcd|
+-----------------
+1|abcd|<B> |abcd|abcd|
+-----------------
+2|abcd|ab d|abcd|abcd|
+-----------------";
+
+        let board = GameBoard::parse(input, create_test_solution(3, 4));
+        let clue =
+            Clue::two_of_three_in_column(Tile::new(0, 'a'), Tile::new(1, 'b'), Tile::new(2, 'c'));
+
+        let deductions = ConstraintSolver::deduce_clue(&board, &clue);
+        println!("Deductions: {:?}", deductions);
+        assert_eq!(deductions.len(), 1);
+        assert!(deductions.contains(&Deduction::parse("2c is col 0 (LastRemaining)")));
+    }
+
+    /// Every column the constraint solver keeps for a tile has to be backed by a placement of the
+    /// other two that satisfies the clue, and every such column has to be kept
+    #[test]
+    fn test_two_of_three_in_column_matches_enumeration() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for seed in 0..5 {
+            let solution = Arc::new(Solution::new(Difficulty::Easy, Some(seed)));
+            let board = GameBoard::new(Arc::clone(&solution));
+            for _ in 0..40 {
+                let partial_board = random_partial_board(&board, &mut rng);
+
+                // a pair from one column, and a third tile from another row
+                let mut rows = (0..solution.n_rows).collect::<Vec<_>>();
+                rows.shuffle(&mut rng);
+                let col = rng.random_range(0..solution.n_variants);
+                let odd_col = rng.random_range(0..solution.n_variants);
+                let clue = Clue::two_of_three_in_column(
+                    solution.get(rows[0], col),
+                    solution.get(rows[1], col),
+                    solution.get(rows[2], odd_col),
+                );
+                let tiles = clue.assertions.iter().map(|a| a.tile).collect::<Vec<_>>();
+                let cols = |board: &GameBoard, tile: Tile| {
+                    board.get_possible_cols_for_tile(tile).collect::<Vec<_>>()
+                };
+
+                let expected = (0..3)
+                    .map(|idx| {
+                        let others = [tiles[(idx + 1) % 3], tiles[(idx + 2) % 3]];
+                        cols(&partial_board, tiles[idx])
+                            .into_iter()
+                            .filter(|&a| {
+                                cols(&partial_board, others[0]).iter().any(|&b| {
+                                    cols(&partial_board, others[1])
+                                        .iter()
+                                        .any(|&c| a == b || a == c || b == c)
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut deduced_board = partial_board.clone();
+                deduced_board
+                    .apply_deductions(&ConstraintSolver::deduce_clue(&partial_board, &clue));
+                let deduced = tiles
+                    .iter()
+                    .map(|tile| cols(&deduced_board, *tile))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(deduced, expected, "{:?}\n{:?}", clue, partial_board);
+                assert_eq!(
+                    deduce_clue(&partial_board, &clue).is_empty(),
+                    deduced
+                        == tiles
+                            .iter()
+                            .map(|tile| cols(&partial_board, *tile))
+                            .collect::<Vec<_>>(),
+                    "{:?}\n{:?}",
+                    clue,
+                    partial_board
+                );
+            }
+        }
+    }
+
+    /// A board partway through solving: some solution tiles placed and some wrong candidates
+    /// eliminated, so it's always consistent with the solution
+    fn random_partial_board(board: &GameBoard, rng: &mut StdRng) -> GameBoard {
+        let mut board = board.clone();
+        let solution = Arc::clone(&board.solution);
+        for tile in solution.all_tiles() {
+            if rng.random_bool(0.15) {
+                board.select_tile_from_solution(tile);
+            }
+        }
+        for row in 0..solution.n_rows {
+            for col in 0..solution.n_variants {
+                for variant in solution.variants.iter() {
+                    if *variant != solution.get(row, col).variant && rng.random_bool(0.3) {
+                        board.remove_candidate(col, Tile::new(row, *variant));
+                    }
+                }
+            }
+        }
+        board
+    }
+
+    /// Where each of the clue's tiles can still go once `deduce` has been applied to exhaustion,
+    /// auto-solving after each round. Running to a fixpoint makes placements and the equivalent
+    /// eliminations (a tile placed in a column, or eliminated from every other one) come out the
+    /// same, as do deductions one solver makes a round later than the other.
+    fn possible_cols_when_exhausted(
+        board: &GameBoard,
+        clue: &Clue,
+        deduce: impl Fn(&GameBoard, &Clue) -> Vec<Deduction>,
+    ) -> Vec<(Tile, Vec<usize>)> {
+        let mut board = board.clone();
+        for _ in 0..board.solution.n_variants * 4 {
+            let deductions = deduce(&board, clue);
+            if deductions.is_empty() {
+                break;
+            }
+            board.apply_deductions(&deductions);
+            board.auto_solve_all();
+        }
+        clue.assertions
+            .iter()
+            .map(|assertion| {
+                (
+                    assertion.tile,
+                    board.get_possible_cols_for_tile(assertion.tile).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// The constraint solver (used first for hints) and the candidate finder (used by the solve
+    /// loop) should agree on what a clue says about its own tiles, whatever the deduction kinds.
+    /// They aren't identical: the candidate finder also throws out placements that would leave
+    /// the rest of the board impossible, and when every placement of the clue lands on the same
+    /// cell it eliminates tiles the clue doesn't mention (`Converging`), which the constraint
+    /// solver can't see since its domains only cover the clue's tiles. So both must keep the
+    /// solution's column, and the candidate finder must never leave a column the constraint
+    /// solver ruled out.
+    #[test]
+    fn test_agrees_with_candidate_finder() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for seed in 0..3 {
+            let solution = Arc::new(Solution::new(Difficulty::Easy, Some(seed)));
+            let board = generate_clues(&GameBoard::new(solution)).board;
+            let clues = board
+                .clue_set
+                .all_clues()
+                .map(|addressed_clue| addressed_clue.clue.clone())
+                .collect::<Vec<_>>();
+
+            for _ in 0..20 {
+                let partial_board = random_partial_board(&board, &mut rng);
+                for clue in clues.iter() {
+                    let is_clue_tile = |tile: &Tile| {
+                        clue.assertions
+                            .iter()
+                            .any(|assertion| assertion.tile == *tile)
+                    };
+                    for deduction in deduce_clue(&partial_board, clue) {
+                        if !is_clue_tile(&deduction.tile_assertion.tile) {
+                            assert_eq!(
+                                deduction.deduction_kind,
+                                Some(DeductionKind::Converging),
+                                "{:?}",
+                                clue
+                            );
+                        }
+                    }
+
+                    let constraint_cols = possible_cols_when_exhausted(
+                        &partial_board,
+                        clue,
+                        ConstraintSolver::deduce_clue,
+                    );
+                    let candidate_cols =
+                        possible_cols_when_exhausted(&partial_board, clue, |board, clue| {
+                            deduce_clue(board, clue)
+                                .into_iter()
+                                .filter(|deduction| is_clue_tile(&deduction.tile_assertion.tile))
+                                .collect()
+                        });
+                    // one-matches-either has its own deducer, which only reasons about a
+                    // branch once the first tile's column is known; the constraint solver also
+                    // works through the exclusive "not both" case, so there it's the one that
+                    // may narrow further
+                    let (narrower, wider) = if clue.clue_type
+                        == ClueType::Vertical(VerticalClueType::OneMatchesEither)
+                    {
+                        (&constraint_cols, &candidate_cols)
+                    } else {
+                        (&candidate_cols, &constraint_cols)
+                    };
+                    for ((tile, narrower_cols), (_, wider_cols)) in
+                        narrower.iter().zip(wider.iter())
+                    {
+                        let (_, solution_col) = board.solution.find_tile(*tile);
+                        assert!(
+                            narrower_cols.contains(&solution_col)
+                                && wider_cols.contains(&solution_col),
+                            "{:?}: {:?} lost its column",
+                            clue,
+                            tile
+                        );
+                        assert!(
+                            narrower_cols.iter().all(|col| wider_cols.contains(col)),
+                            "{:?}: {:?} narrowed to {:?} by the candidate finder, {:?} by the \
+                             constraint solver\n{:?}",
+                            clue,
+                            tile,
+                            candidate_cols,
+                            constraint_cols,
+                            partial_board
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
@@ -96,76 +96,97 @@ fn find_isolated_bit_sets(bit_sets: &Vec<u8>, n_bits: usize) -> Vec<(Vec<usize>,
         .collect()
 }
 
-pub fn find_naked_pairs_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResult> {
-    /*
-
-    variant: a, column_set: 11111101
-    variant: b, column_set: 00000010
-    variant: c, column_set: 11111101
-    variant: d, column_set: 01010100
-    variant: e, column_set: 01010100
-    variant: f, column_set: 11111101
-    variant: g, column_set: 10101000
-    variant: h, column_set: 11111000
-
-         */
-
-    // create bitmask for column positions for each variant
-
-    fn variants_to_bit_set(variants: &[char]) -> u8 {
-        variants.iter().fold(0, |acc, variant| {
-            acc | (1 << (Tile::variant_to_usize(*variant)))
-        })
+/// Every combination of exactly `set_size` bit sets (each with at least two bits) whose union
+/// has `set_size` bits. Exhaustive, unlike `find_isolated_bit_sets`, so no set of that size is
+/// missed.
+fn find_bit_sets_of_size(bit_sets: &[u8], n_bits: usize, set_size: usize) -> Vec<(Vec<usize>, u8)> {
+    let candidates: Vec<usize> = (0..n_bits)
+        .filter(|idx| (2..=set_size).contains(&(bit_sets[*idx].count_ones() as usize)))
+        .collect();
+
+    fn extend(
+        bit_sets: &[u8],
+        candidates: &[usize],
+        set_size: usize,
+        members: &mut Vec<usize>,
+        union: u8,
+        results: &mut Vec<(Vec<usize>, u8)>,
+    ) {
+        if members.len() == set_size {
+            if union.count_ones() as usize == set_size {
+                results.push((members.clone(), union));
+            }
+            return;
+        }
+        for (i, idx) in candidates.iter().enumerate() {
+            let next_union = union | bit_sets[*idx];
+            if next_union.count_ones() as usize > set_size {
+                continue;
+            }
+            members.push(*idx);
+            extend(
+                bit_sets,
+                &candidates[i + 1..],
+                set_size,
+                members,
+                next_union,
+                results,
+            );
+            members.pop();
+        }
     }
 
-    fn bit_set_to_variants(bit_set: u8) -> Vec<char> {
-        (0..=7)
-            .filter(|i| bit_set & (1 << i) != 0)
-            .map(|i| Tile::usize_to_variant(i))
-            .collect()
-    }
+    let mut results = Vec::new();
+    extend(
+        bit_sets,
+        &candidates,
+        set_size,
+        &mut Vec::new(),
+        0,
+        &mut results,
+    );
+    results
+}
 
-    let mut column_variant_bit_sets: Vec<u8> = vec![0; board.solution.n_variants];
-    for col in 0..board.solution.n_variants {
-        let variants = board
-            .solution
-            .variants
-            .iter()
-            .filter(|v| board.is_candidate_available(row, col, **v))
-            .cloned()
-            .collect::<Vec<_>>();
-        column_variant_bit_sets[col] = variants_to_bit_set(&variants);
-    }
+fn variants_to_bit_set(variants: &[char]) -> u8 {
+    variants.iter().fold(0, |acc, variant| {
+        acc | (1 << (Tile::variant_to_usize(*variant)))
+    })
+}
 
-    let max_set_size = board.solution.n_variants as u32 / 2;
+fn bit_set_to_variants(bit_set: u8) -> Vec<char> {
+    (0..=7)
+        .filter(|i| bit_set & (1 << i) != 0)
+        .map(|i| Tile::usize_to_variant(i))
+        .collect()
+}
 
-    let mut possible_columns = Vec::new();
-    for col in 0..board.solution.n_variants {
-        if column_variant_bit_sets[col].count_ones() <= max_set_size {
-            possible_columns.push(col);
-        }
-    }
+fn columns_to_bit_set(columns: &[usize]) -> u8 {
+    columns.iter().fold(0, |acc, col| acc | (1 << col))
+}
 
-    let result = find_isolated_bit_sets(&column_variant_bit_sets, board.solution.n_variants);
-    result
-        .into_iter()
-        .map(|(columns, bit_set)| SubsetResult {
-            columns,
-            variants: bit_set_to_variants(bit_set),
+fn bit_set_to_columns(bit_set: u8, n_cols: usize) -> Vec<usize> {
+    (0..n_cols).filter(|i| bit_set & (1 << i) != 0).collect()
+}
+
+/// For each column of the row, the variants still available there
+fn column_variant_bit_sets(row: usize, board: &GameBoard) -> Vec<u8> {
+    (0..board.solution.n_variants)
+        .map(|col| {
+            let variants = board
+                .solution
+                .variants
+                .iter()
+                .filter(|v| board.is_candidate_available(row, col, **v))
+                .cloned()
+                .collect::<Vec<_>>();
+            variants_to_bit_set(&variants)
         })
         .collect()
 }
 
-pub fn find_hidden_pairs_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResult> {
-    fn columns_to_bit_set(columns: &[usize]) -> u8 {
-        columns.iter().fold(0, |acc, col| acc | (1 << col))
-    }
-
-    fn bit_set_to_columns(bit_set: u8, n_cols: usize) -> Vec<usize> {
-        (0..n_cols).filter(|i| bit_set & (1 << i) != 0).collect()
-    }
-
-    // create bitmask for column positions for each variant
+/// For each variant of the row, the columns it's still available in
+fn variant_column_bit_sets(row: usize, board: &GameBoard) -> Vec<u8> {
     let mut variant_column_sets: Vec<u8> = vec![0; board.solution.n_variants];
     for variant in board.solution.variants.iter() {
         let variant_index = Tile::variant_to_usize(*variant);
@@ -174,23 +195,80 @@ pub fn find_hidden_pairs_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResu
             .filter(|col| board.is_candidate_available(row, *col, *variant))
             .collect::<Vec<_>>();
 
-        let bit_set = columns_to_bit_set(&columns);
-        variant_column_sets[variant_index] = bit_set;
+        variant_column_sets[variant_index] = columns_to_bit_set(&columns);
     }
+    variant_column_sets
+}
 
-    let result = find_isolated_bit_sets(&variant_column_sets, board.solution.n_variants);
-    result
-        .into_iter()
+fn naked_subset_results(sets: Vec<(Vec<usize>, u8)>) -> Vec<SubsetResult> {
+    sets.into_iter()
+        .map(|(columns, bit_set)| SubsetResult {
+            columns,
+            variants: bit_set_to_variants(bit_set),
+        })
+        .collect()
+}
+
+fn hidden_subset_results(sets: Vec<(Vec<usize>, u8)>, n_cols: usize) -> Vec<SubsetResult> {
+    sets.into_iter()
         .map(|(variants, bit_set)| SubsetResult {
             variants: variants
                 .iter()
                 .map(|v| Tile::usize_to_variant(*v))
                 .collect(),
-            columns: bit_set_to_columns(bit_set, board.solution.n_variants),
+            columns: bit_set_to_columns(bit_set, n_cols),
         })
         .collect()
 }
 
+pub fn find_naked_pairs_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResult> {
+    /*
+
+    variant: a, column_set: 11111101
+    variant: b, column_set: 00000010
+    variant: c, column_set: 11111101
+    variant: d, column_set: 01010100
+    variant: e, column_set: 01010100
+    variant: f, column_set: 11111101
+    variant: g, column_set: 10101000
+    variant: h, column_set: 11111000
+
+         */
+
+    let column_variant_bit_sets = column_variant_bit_sets(row, board);
+    naked_subset_results(find_isolated_bit_sets(
+        &column_variant_bit_sets,
+        board.solution.n_variants,
+    ))
+}
+
+pub fn find_hidden_pairs_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResult> {
+    let variant_column_sets = variant_column_bit_sets(row, board);
+    hidden_subset_results(
+        find_isolated_bit_sets(&variant_column_sets, board.solution.n_variants),
+        board.solution.n_variants,
+    )
+}
+
+/// Three columns of the row that between them can only hold three variants
+pub fn find_naked_triples_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResult> {
+    let column_variant_bit_sets = column_variant_bit_sets(row, board);
+    naked_subset_results(find_bit_sets_of_size(
+        &column_variant_bit_sets,
+        board.solution.n_variants,
+        3,
+    ))
+}
+
+/// Three variants of the row that between them can only go in three columns
+pub fn find_hidden_triples_in_row(row: usize, board: &GameBoard) -> Vec<SubsetResult> {
+    let variant_column_sets = variant_column_bit_sets(row, board);
+    hidden_subset_results(
+        find_bit_sets_of_size(&variant_column_sets, board.solution.n_variants, 3),
+        board.solution.n_variants,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use test_context::test_context;
@@ -220,6 +298,37 @@ mod tests {
         assert_eq!(hidden_pairs[0].columns, vec![0, 2]);
     }
 
+    #[test]
+    fn test_find_triples_missed_by_pair_search() {
+        // columns 0, 2 and 3 only hold a, b and c; column 1 leads the pair search astray
+        let input = "\
+0|ab    |a  d  | bc   |a c   |a c  f|abc ef|
+";
+        let board = GameBoard::parse(input, create_test_solution(1, 6));
+        assert!(find_naked_pairs_in_row(0, &board).is_empty());
+        assert_eq!(
+            find_naked_triples_in_row(0, &board),
+            vec![SubsetResult {
+                columns: vec![0, 2, 3],
+                variants: vec!['a', 'b', 'c'],
+            }]
+        );
+
+        // a, c and d can only go in columns 0-2, though b competes for two of them
+        let input = "\
+0|ab d  |abc   |  cd  | b  ef|    ef|    ef|
+";
+        let board = GameBoard::parse(input, create_test_solution(1, 6));
+        assert!(find_hidden_pairs_in_row(0, &board).is_empty());
+        assert_eq!(
+            find_hidden_triples_in_row(0, &board),
+            vec![SubsetResult {
+                variants: vec!['a', 'c', 'd'],
+                columns: vec![0, 1, 2],
+            }]
+        );
+    }
+
     #[test_context(UsingLogger)]
     #[test]
     fn test_deduce_hidden_pairs_large_board(_: &mut UsingLogger) {
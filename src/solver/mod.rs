@@ -5,10 +5,14 @@ pub mod clue_generator;
 pub mod clue_generator_state;
 pub mod constraint_solver;
 pub mod hidden_pair_finder;
+pub mod puzzle_metrics;
 mod puzzle_variants;
+pub mod uniqueness_checker;
 pub use candidate_solver::deduce_clue;
 pub use clue_generator::generate_clues;
 mod solver_helpers;
 
 pub use constraint_solver::ConstraintSolver;
+pub use puzzle_variants::{clue_weights_matching, override_clue_weights, WeightedClueType};
 pub use solver_helpers::simplify_deductions;
+pub use uniqueness_checker::has_unique_solution;
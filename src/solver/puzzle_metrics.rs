@@ -0,0 +1,500 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::trace;
+
+use crate::model::{
+    Clue, ClueSet, DeductionKind, DeductionLogEntry, Difficulty, GameBoard, GameStateSnapshot,
+    Solution,
+};
+
+use super::candidate_solver::{
+    deduce_clue_in_context, deduce_column_exhaustion, deduce_hidden_sets, perform_evaluation_step,
+    EvaluationStepResult,
+};
+
+/// Difficulty metrics measured by solving a puzzle headlessly with its clue set
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleMetrics {
+    pub n_clues: usize,
+    pub n_solve_steps: usize,
+    pub deduction_kind_counts: HashMap<DeductionKind, usize>,
+    /// deductions that didn't carry a kind (plain clue eliminations)
+    pub n_unclassified_deductions: usize,
+    pub solved: bool,
+}
+
+impl PuzzleMetrics {
+    pub fn deduction_kind_count(&self, deduction_kind: &DeductionKind) -> usize {
+        self.deduction_kind_counts
+            .get(deduction_kind)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// Solve the board's clue set from its current state, the same way the solve button does: one
+/// clue (or hidden set) per step, auto-solving in between.
+pub fn measure_puzzle(board: &GameBoard) -> PuzzleMetrics {
+    let clues = board_clues(board);
+    let mut board = board.clone();
+    let mut metrics = PuzzleMetrics {
+        n_clues: clues.len(),
+        ..Default::default()
+    };
+
+    solve_headless(&mut board, &clues, &mut metrics);
+    metrics.solved = board.is_complete();
+    metrics
+}
+
+/// Whether a headless solve from the board's current state reaches the solution. Mistakes on the
+/// board (a wrong selection or an eliminated solution tile) leave it stuck or finished wrongly.
+/// The board itself is left untouched.
+pub fn is_solvable_from(board: &GameBoard) -> bool {
+    let clues = board_clues(board);
+    let mut board = board.clone();
+    solve_headless(&mut board, &clues, &mut PuzzleMetrics::default());
+    board.is_complete() && !board.is_incorrect()
+}
+
+/// The ordered steps a headless solve takes from the board's current state, each with the clue it
+/// used (None for hidden sets and forced placements) and the deductions it made. Stops early if the solve gets stuck.
+pub fn solution_walkthrough(board: &GameBoard) -> Vec<DeductionLogEntry> {
+    let clues = board_clues(board);
+    let mut board = board.clone();
+    let mut walkthrough = Vec::new();
+
+    while !board.is_complete() {
+        let board_before = board.clone();
+        let entry = match perform_evaluation_step(&mut board, &clues) {
+            EvaluationStepResult::Nothing => break,
+            EvaluationStepResult::HiddenSetsFound => DeductionLogEntry {
+                clue: None,
+                deductions: deduce_hidden_sets(&board_before),
+            },
+            EvaluationStepResult::ForcedPlacementsFound => DeductionLogEntry {
+                clue: None,
+                deductions: deduce_column_exhaustion(&board_before),
+            },
+            EvaluationStepResult::DeductionsFound(clue) => DeductionLogEntry {
+                clue: board_before
+                    .clue_set
+                    .all_clues()
+                    .find(|addressed_clue| addressed_clue.clue == clue)
+                    .map(|addressed_clue| addressed_clue.address()),
+                deductions: deduce_clue_in_context(&board_before, &clue, &clues),
+            },
+        };
+        walkthrough.push(entry);
+        board.auto_solve_all();
+    }
+
+    walkthrough
+}
+
+/// Outcome of solving a hand-written clue list headlessly from an empty board
+#[derive(Debug, Clone, Default)]
+pub struct ClueCheck {
+    pub metrics: PuzzleMetrics,
+    /// tiles the solve didn't place before it got stuck
+    pub n_unsolved_tiles: usize,
+    /// some clue doesn't hold for the solution, so the solve went wrong
+    pub contradicts_solution: bool,
+}
+
+/// Check whether `clues` alone solve `solution` from an empty board. The candidate solver only
+/// makes forced deductions, so reaching the solution means it's the unique one.
+pub fn check_clues(solution: &Arc<Solution>, clues: &[Clue]) -> ClueCheck {
+    // The clues stay as written rather than going into a ClueSet, which merges vertical clues
+    // and can't merge contradictory ones
+    let mut board = GameBoard::new(Arc::clone(solution));
+    let mut metrics = PuzzleMetrics {
+        n_clues: clues.len(),
+        ..Default::default()
+    };
+
+    solve_headless(&mut board, clues, &mut metrics);
+    let contradicts_solution = board.is_incorrect();
+    metrics.solved = board.is_complete() && !contradicts_solution;
+    let n_unsolved_tiles = solution
+        .all_tiles()
+        .into_iter()
+        .filter(|tile| !board.has_tile_selected_anywhere(tile))
+        .count();
+    ClueCheck {
+        metrics,
+        n_unsolved_tiles,
+        contradicts_solution,
+    }
+}
+
+/// Star rating from 1 (easiest) to 5 for a puzzle, independent of its difficulty tier. The clue
+/// set is solved headlessly from an empty board; whenever the clues get stuck the next unsolved
+/// tile is revealed, as the generator does. Puzzles rate higher when they lean on converging and
+/// hidden set deductions, revisit clues often, or have few clues for the size of the grid.
+pub fn rate_puzzle(clue_set: &Arc<ClueSet>, solution: &Arc<Solution>) -> u8 {
+    let mut board = GameBoard::new(Arc::clone(solution));
+    board.set_clues(Arc::clone(clue_set));
+    let clues = board_clues(&board);
+    let mut metrics = PuzzleMetrics {
+        n_clues: clues.len(),
+        ..Default::default()
+    };
+    let mut n_reveals = 0;
+
+    loop {
+        solve_headless(&mut board, &clues, &mut metrics);
+        let unsolved_tile = solution
+            .all_tiles()
+            .into_iter()
+            .find(|tile| !board.has_tile_selected_anywhere(tile));
+        let Some(tile) = unsolved_tile else {
+            break;
+        };
+        board.select_tile_from_solution(tile);
+        board.auto_solve_all();
+        n_reveals += 1;
+    }
+
+    let n_deductions =
+        metrics.deduction_kind_counts.values().sum::<usize>() + metrics.n_unclassified_deductions;
+    let n_advanced_deductions = metrics.deduction_kind_count(&DeductionKind::Converging)
+        + 2 * metrics.deduction_kind_count(&DeductionKind::HiddenSet);
+    let n_clues = metrics.n_clues.max(1) as f64;
+    let n_tiles = (solution.n_rows * solution.n_variants) as f64;
+
+    // each score is in 0..=1
+    let technique_score = (n_advanced_deductions as f64 / n_deductions.max(1) as f64).min(1.0);
+    let revisit_score = (metrics.n_solve_steps as f64 / n_clues - 1.0).clamp(0.0, 1.0);
+    let sparsity_score = (n_tiles / n_clues / 3.0).min(1.0);
+    let reveal_score = (n_reveals as f64 / solution.n_rows as f64).min(1.0);
+
+    let score = (2.0 * technique_score + revisit_score + sparsity_score - 0.5 * reveal_score) / 4.0;
+    trace!(
+        target: "solver",
+        "Puzzle rating: technique {:.2}, revisit {:.2}, sparsity {:.2}, reveals {}; score {:.2}",
+        technique_score,
+        revisit_score,
+        sparsity_score,
+        n_reveals,
+        score
+    );
+    1 + (score.clamp(0.0, 1.0) * 4.0).round() as u8
+}
+
+/// How hard a puzzle is to solve, by the techniques its solve steps need
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PuzzleRating {
+    /// steps that only needed plain constraint eliminations or forced placements
+    pub n_simple_steps: usize,
+    /// clue steps where every way of satisfying the clue converged on a deduction
+    pub n_converging_steps: usize,
+    pub n_hidden_set_steps: usize,
+    /// whether the solve reached the end; a stuck solve rates only the steps it took
+    pub solved: bool,
+    /// the steps weighted by how hard they are to spot
+    pub score: usize,
+}
+
+const SIMPLE_STEP_WEIGHT: usize = 1;
+const CONVERGING_STEP_WEIGHT: usize = 3;
+const HIDDEN_SET_STEP_WEIGHT: usize = 5;
+
+/// Rate the puzzle by solving it from `board` one `perform_evaluation_step` at a time, as the
+/// walkthrough does, and weighing each step by the technique it needed
+pub fn rate_difficulty(board: &GameBoard, clues: &Vec<Clue>) -> PuzzleRating {
+    let mut board = board.clone();
+    let mut rating = PuzzleRating::default();
+
+    while !board.is_complete() {
+        let board_before = board.clone();
+        match perform_evaluation_step(&mut board, clues) {
+            EvaluationStepResult::Nothing => break,
+            EvaluationStepResult::HiddenSetsFound => rating.n_hidden_set_steps += 1,
+            EvaluationStepResult::ForcedPlacementsFound => rating.n_simple_steps += 1,
+            EvaluationStepResult::DeductionsFound(clue) => {
+                let converging = deduce_clue_in_context(&board_before, &clue, clues)
+                    .iter()
+                    .any(|deduction| deduction.deduction_kind == Some(DeductionKind::Converging));
+                if converging {
+                    rating.n_converging_steps += 1;
+                } else {
+                    rating.n_simple_steps += 1;
+                }
+            }
+        }
+        board.auto_solve_all();
+    }
+
+    rating.solved = board.is_complete();
+    rating.score = rating.n_simple_steps * SIMPLE_STEP_WEIGHT
+        + rating.n_converging_steps * CONVERGING_STEP_WEIGHT
+        + rating.n_hidden_set_steps * HIDDEN_SET_STEP_WEIGHT;
+    rating
+}
+
+fn board_clues(board: &GameBoard) -> Vec<Clue> {
+    board
+        .clue_set
+        .all_clues()
+        .map(|clue| clue.clue.clone())
+        .collect()
+}
+
+/// Apply clue (or hidden set) deductions one step at a time until the board is complete or stuck
+fn solve_headless(board: &mut GameBoard, clues: &[Clue], metrics: &mut PuzzleMetrics) {
+    while !board.is_complete() {
+        let deductions = clues
+            .iter()
+            .map(|clue| deduce_clue_in_context(board, clue, clues))
+            .find(|deductions| !deductions.is_empty())
+            .unwrap_or_else(|| deduce_hidden_sets(board));
+
+        if deductions.is_empty() {
+            trace!(target: "solver", "Puzzle metrics: stuck on board {:?}", board);
+            break;
+        }
+
+        metrics.n_solve_steps += 1;
+        for deduction in deductions.iter() {
+            match &deduction.deduction_kind {
+                Some(deduction_kind) => {
+                    *metrics
+                        .deduction_kind_counts
+                        .entry(deduction_kind.clone())
+                        .or_default() += 1;
+                }
+                None => metrics.n_unclassified_deductions += 1,
+            }
+        }
+        let before = board.content_hash();
+        board.apply_deductions(&deductions);
+        board.auto_solve_all();
+        // A board that contradicts a clue can yield deductions that never take effect
+        if board.content_hash() == before {
+            trace!(target: "solver", "Puzzle metrics: no progress on board {:?}", board);
+            break;
+        }
+    }
+}
+
+/// Generate puzzles until one's headless solve uses `deduction_kind` at least once. Seeds count up
+/// from `first_seed` when given, otherwise each attempt is random. Gives up after `max_attempts`.
+pub fn find_puzzle_requiring(
+    difficulty: Difficulty,
+    deduction_kind: &DeductionKind,
+    first_seed: Option<u64>,
+    max_attempts: usize,
+) -> Option<(GameStateSnapshot, PuzzleMetrics)> {
+    (0..max_attempts).find_map(|attempt| {
+        let seed = first_seed.map(|seed| seed.wrapping_add(attempt as u64));
+        let snapshot = GameStateSnapshot::generate_new(difficulty, seed);
+        let metrics = measure_puzzle(&snapshot.board);
+        trace!(
+            target: "solver",
+            "Technique search attempt {}: seed {} used {:?} {} times",
+            attempt,
+            snapshot.board.solution.seed,
+            deduction_kind,
+            metrics.deduction_kind_count(deduction_kind)
+        );
+        (metrics.deduction_kind_count(deduction_kind) > 0).then_some((snapshot, metrics))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::create_test_solution;
+    use crate::model::Solution;
+    use crate::solver::generate_clues;
+
+    #[test]
+    fn test_measure_generated_puzzle() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        let board = generate_clues(&GameBoard::new(solution)).board;
+
+        let metrics = measure_puzzle(&board);
+
+        assert!(metrics.solved);
+        assert_eq!(metrics.n_clues, board.clue_set.all_clues().count());
+        assert!(metrics.n_solve_steps > 0);
+        let n_deductions = metrics.deduction_kind_counts.values().sum::<usize>()
+            + metrics.n_unclassified_deductions;
+        assert!(n_deductions >= metrics.n_solve_steps);
+    }
+
+    #[test]
+    fn test_measure_solved_puzzle_takes_no_steps() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        let mut board = generate_clues(&GameBoard::new(solution.clone())).board;
+        for tile in solution.all_tiles() {
+            board.select_tile_from_solution(tile);
+        }
+
+        let metrics = measure_puzzle(&board);
+
+        assert!(metrics.solved);
+        assert_eq!(metrics.n_solve_steps, 0);
+        assert!(metrics.deduction_kind_counts.is_empty());
+    }
+
+    #[test]
+    fn test_find_puzzle_requiring_technique() {
+        let (snapshot, metrics) =
+            find_puzzle_requiring(Difficulty::Easy, &DeductionKind::Constraint, Some(42), 20)
+                .expect("constraint deductions should turn up quickly");
+
+        assert!(metrics.deduction_kind_count(&DeductionKind::Constraint) > 0);
+        assert_eq!(metrics, measure_puzzle(&snapshot.board));
+    }
+
+    #[test]
+    fn test_find_puzzle_requiring_gives_up() {
+        assert!(
+            find_puzzle_requiring(Difficulty::Easy, &DeductionKind::HiddenSet, Some(42), 0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_rate_puzzle() {
+        let solution = Arc::new(Solution::new(Difficulty::Moderate, Some(42)));
+        let mut board = generate_clues(&GameBoard::new(solution.clone())).board;
+
+        let rating = rate_puzzle(&board.clue_set, &solution);
+
+        assert!((1..=5).contains(&rating));
+        // progress on the board doesn't change the rating
+        board.select_tile_from_solution(solution.get(0, 0));
+        assert_eq!(rate_puzzle(&board.clue_set, &board.solution), rating);
+    }
+
+    #[test]
+    fn test_rate_difficulty() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        let board = generate_clues(&GameBoard::new(solution)).board;
+        let clues = board_clues(&board);
+
+        let rating = rate_difficulty(&board, &clues);
+
+        assert!(rating.solved);
+        let n_steps = rating.n_simple_steps + rating.n_converging_steps + rating.n_hidden_set_steps;
+        assert_eq!(n_steps, solution_walkthrough(&board).len());
+        assert!(rating.score >= n_steps);
+
+        // a finished board takes no steps
+        let mut solved = board.clone();
+        for tile in solved.solution.all_tiles() {
+            solved.select_tile_from_solution(tile);
+        }
+        assert_eq!(
+            rate_difficulty(&solved, &clues),
+            PuzzleRating {
+                solved: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_solution_walkthrough_solves_puzzle() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        let board = generate_clues(&GameBoard::new(solution)).board;
+
+        let walkthrough = solution_walkthrough(&board);
+
+        assert_eq!(walkthrough.len(), measure_puzzle(&board).n_solve_steps);
+        let mut replayed = board.clone();
+        for entry in walkthrough.iter() {
+            assert!(!entry.deductions.is_empty());
+            if let Some(address) = entry.clue {
+                assert!(board.clue_set.get_clue(address).is_some());
+            }
+            replayed.apply_deductions(&entry.deductions);
+            replayed.auto_solve_all();
+        }
+        assert!(replayed.is_complete());
+    }
+
+    #[test]
+    fn test_is_solvable_from() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        let mut board = generate_clues(&GameBoard::new(solution.clone())).board;
+        assert!(is_solvable_from(&board));
+
+        board.select_tile_from_solution(solution.get(0, 0));
+        assert!(is_solvable_from(&board));
+
+        // place the wrong variant in a cell
+        let wrong_tile = solution.get(1, 1);
+        board.select_tile_at_position(0, wrong_tile);
+        let before = board.clone();
+        assert!(!is_solvable_from(&board));
+        assert!(board.same_content(&before));
+    }
+
+    #[test]
+    fn test_check_clues() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        // order the first row left to right, then tie every other tile to its column
+        let mut clues: Vec<Clue> = (1..solution.n_variants)
+            .map(|col| Clue::left_of(solution.get(0, col - 1), solution.get(0, col)))
+            .collect();
+        for row in 1..solution.n_rows {
+            for col in 0..solution.n_variants {
+                clues.push(Clue::two_in_column(
+                    solution.get(0, col),
+                    solution.get(row, col),
+                ));
+            }
+        }
+
+        let check = check_clues(&solution, &clues);
+        assert!(check.metrics.solved);
+        assert_eq!(check.n_unsolved_tiles, 0);
+        assert!(!check.contradicts_solution);
+
+        let check = check_clues(&solution, &clues[..solution.n_variants - 1]);
+        assert!(!check.metrics.solved);
+        assert_eq!(
+            check.n_unsolved_tiles,
+            (solution.n_rows - 1) * solution.n_variants
+        );
+
+        // tie a tile to the wrong column, ahead of the clues that place it correctly
+        clues.insert(
+            0,
+            Clue::two_in_column(solution.get(0, 0), solution.get(solution.n_rows - 1, 1)),
+        );
+        let check = check_clues(&solution, &clues);
+        assert!(!check.metrics.solved);
+        assert!(check.contradicts_solution);
+    }
+
+    #[test]
+    fn test_check_clues_reads_clues_in_context() {
+        let solution = create_test_solution(3, 4);
+        // the one-matches-either clue only settles which branch holds with the not-in-same-column
+        // clue beside it, as it does for the player
+        let clues: Vec<Clue> = [
+            "|+0a,?1b,?2a|",
+            "<1c...0d>",
+            "<0b...1d>",
+            "|+1c,+2c|",
+            "<1c...2d>",
+            "|+1a,-2b|",
+            "|+0a,-1b|",
+            "<0a...1b>",
+            "<0b...1c>",
+        ]
+        .iter()
+        .map(|clue| Clue::parse(clue))
+        .collect();
+
+        let check = check_clues(&solution, &clues);
+        assert!(check.metrics.solved);
+        assert_eq!(check.n_unsolved_tiles, 0);
+    }
+}
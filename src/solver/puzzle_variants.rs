@@ -7,7 +7,11 @@ use crate::{
 };
 use log::{info, trace};
 use rand::{seq::IndexedRandom, Rng, RngCore};
-use std::{fmt::Debug, ops::RangeInclusive};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    ops::RangeInclusive,
+};
 
 use super::{
     candidate_solver::deduce_hidden_sets_in_row, clue_generator_state::ClueGeneratorState,
@@ -15,7 +19,7 @@ use super::{
 
 const MAX_BOOST: usize = 100;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct WeightedClueType {
     pub weight: usize,
     pub clue_type: ClueType,
@@ -189,10 +193,26 @@ impl PuzzleVariant for StandardPuzzleVariant {
                 weight: 1,
                 clue_type: ClueType::Horizontal(HorizontalClueType::LeftOf),
             },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Horizontal(HorizontalClueType::AdjacentLeftOf),
+            },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered),
+            },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Horizontal(HorizontalClueType::Between),
+            },
             WeightedClueType {
                 weight: 6,
                 clue_type: ClueType::Vertical(VerticalClueType::TwoInColumn),
             },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows),
+            },
             WeightedClueType {
                 weight: 2,
                 clue_type: ClueType::Vertical(VerticalClueType::NotInSameColumn),
@@ -201,6 +221,10 @@ impl PuzzleVariant for StandardPuzzleVariant {
                 weight: 2,
                 clue_type: ClueType::Vertical(VerticalClueType::OneMatchesEither),
             },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn),
+            },
             WeightedClueType {
                 weight: 6,
                 clue_type: ClueType::Horizontal(HorizontalClueType::ThreeAdjacent),
@@ -245,6 +269,10 @@ impl PuzzleVariant for NarrowingPuzzleVariant {
                 weight: 3,
                 clue_type: ClueType::Vertical(VerticalClueType::TwoInColumn),
             },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows),
+            },
             WeightedClueType {
                 weight: 4,
                 clue_type: ClueType::Vertical(VerticalClueType::NotInSameColumn),
@@ -314,6 +342,10 @@ impl PuzzleVariant for StripingPuzzleVariant {
                 weight: 1,
                 clue_type: ClueType::Vertical(VerticalClueType::TwoInColumn),
             },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows),
+            },
             WeightedClueType {
                 weight: 1,
                 clue_type: ClueType::Vertical(VerticalClueType::NotInSameColumn),
@@ -424,6 +456,78 @@ impl PuzzleVariant for StripingPuzzleVariant {
     }
 }
 
+/// Clues naming a tile's column outright give a friendly foothold to new players, so they're only
+/// offered on the easiest difficulties. The generated clue takes its column from the seed tile.
+pub fn in_column_clue_weight(difficulty: Difficulty) -> Option<WeightedClueType> {
+    match difficulty {
+        Difficulty::Tutorial | Difficulty::Easy => Some(WeightedClueType {
+            weight: 1,
+            clue_type: ClueType::Horizontal(HorizontalClueType::InColumn),
+        }),
+        _ => None,
+    }
+}
+
+/// Clue weights in proportion to how often each clue type appears in `clues`, so generating with
+/// them gives a puzzle with a similar mix of clues. Empty if there are no clues.
+pub fn clue_weights_matching<'a>(clues: impl Iterator<Item = &'a Clue>) -> Vec<WeightedClueType> {
+    let mut counts: BTreeMap<ClueType, usize> = BTreeMap::new();
+    for clue in clues {
+        *counts.entry(clue.clue_type).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(clue_type, weight)| WeightedClueType { weight, clue_type })
+        .collect()
+}
+
+/// Apply the player's clue type weights on top of `clue_weights`. A weight of 0 leaves the type
+/// out, and types missing from `clue_weights` are added. If nothing would be left to pick from,
+/// `clue_weights` is kept as is.
+pub fn override_clue_weights(
+    clue_weights: Vec<WeightedClueType>,
+    overrides: &HashMap<ClueType, u32>,
+) -> Vec<WeightedClueType> {
+    if overrides.is_empty() {
+        return clue_weights;
+    }
+    let mut overridden: Vec<WeightedClueType> = clue_weights
+        .iter()
+        .map(|weighted| WeightedClueType {
+            weight: overrides
+                .get(&weighted.clue_type)
+                .map_or(weighted.weight, |weight| *weight as usize),
+            clue_type: weighted.clue_type,
+        })
+        .collect();
+    // sorted, so the same settings always draw clues in the same order for a seed
+    let mut added: Vec<(ClueType, u32)> = overrides
+        .iter()
+        .filter(|(clue_type, _)| {
+            !clue_weights
+                .iter()
+                .any(|weighted| weighted.clue_type == **clue_type)
+        })
+        .map(|(clue_type, weight)| (*clue_type, *weight))
+        .collect();
+    added.sort();
+    overridden.extend(
+        added
+            .into_iter()
+            .map(|(clue_type, weight)| WeightedClueType {
+                weight: weight as usize,
+                clue_type,
+            }),
+    );
+    overridden.retain(|weighted| weighted.weight > 0);
+
+    if overridden.is_empty() {
+        clue_weights
+    } else {
+        overridden
+    }
+}
+
 pub fn random_puzzle_variant(
     difficulty: Difficulty,
     rng: &mut Box<dyn RngCore>,
@@ -552,4 +656,86 @@ mod tests {
             "Reduced score should be 10 (100 / 2 / 5)"
         );
     }
+
+    #[test]
+    fn test_clue_weights_matching_counts_clue_types() {
+        let clues = vec![
+            Clue::parse("|+0a,+1b|"),
+            Clue::parse("|+0c,+1d|"),
+            Clue::parse("<0a...1b>"),
+        ];
+
+        let weights = clue_weights_matching(clues.iter());
+        let weight_of = |clue_type: ClueType| {
+            weights
+                .iter()
+                .find(|weighted| weighted.clue_type == clue_type)
+                .map(|weighted| weighted.weight)
+        };
+
+        assert_eq!(weights.len(), 2);
+        assert_eq!(
+            weight_of(ClueType::Vertical(VerticalClueType::TwoInColumn)),
+            Some(2)
+        );
+        assert_eq!(
+            weight_of(ClueType::Horizontal(HorizontalClueType::LeftOf)),
+            Some(1)
+        );
+        assert!(clue_weights_matching(std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_override_clue_weights() {
+        let one_matches_either = ClueType::Vertical(VerticalClueType::OneMatchesEither);
+        let left_of = ClueType::Horizontal(HorizontalClueType::LeftOf);
+        let between = ClueType::Horizontal(HorizontalClueType::Between);
+        let weights = vec![
+            WeightedClueType {
+                weight: 2,
+                clue_type: one_matches_either,
+            },
+            WeightedClueType {
+                weight: 1,
+                clue_type: left_of,
+            },
+            WeightedClueType {
+                weight: 1,
+                clue_type: ClueType::Horizontal(HorizontalClueType::InColumn),
+            },
+        ];
+
+        assert_eq!(
+            override_clue_weights(weights.clone(), &HashMap::new()),
+            weights
+        );
+
+        let overrides = HashMap::from([
+            (one_matches_either, 0),
+            (left_of, 5),
+            (between, 2),
+            (ClueType::Horizontal(HorizontalClueType::InColumn), 3),
+        ]);
+        let overridden = override_clue_weights(weights.clone(), &overrides);
+        let weight_of = |clue_type: ClueType| {
+            overridden
+                .iter()
+                .find(|weighted| weighted.clue_type == clue_type)
+                .map(|weighted| weighted.weight)
+        };
+        assert_eq!(weight_of(one_matches_either), None);
+        assert_eq!(weight_of(left_of), Some(5));
+        assert_eq!(weight_of(between), Some(2));
+        assert_eq!(
+            weight_of(ClueType::Horizontal(HorizontalClueType::InColumn)),
+            Some(3)
+        );
+
+        // turning everything off keeps the defaults rather than leaving nothing to pick
+        let all_off = weights
+            .iter()
+            .map(|weighted| (weighted.clue_type, 0))
+            .collect();
+        assert_eq!(override_clue_weights(weights.clone(), &all_off), weights);
+    }
 }
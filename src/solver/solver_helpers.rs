@@ -7,6 +7,19 @@ use super::{
     clue_constraint::{create_clue_constraint, ConstraintSet, NotInSameColumnConstraint},
 };
 
+// Orders deductions by (row, column, variant, positivity) so that solver output, and therefore
+// which deduction a hint shows first, doesn't depend on hash iteration order
+pub fn sort_deductions(deductions: &mut [Deduction]) {
+    deductions.sort_by_key(|deduction| {
+        (
+            deduction.tile_assertion.tile.row,
+            deduction.column,
+            deduction.tile_assertion.tile.variant,
+            deduction.tile_assertion.assertion,
+        )
+    });
+}
+
 // When a set of negative deductions eliminate all but one remaining candidate, convert it to a positive deduction
 pub fn simplify_deductions(
     board: &GameBoard,
@@ -58,7 +71,8 @@ pub fn simplify_deductions(
         .map(|assertion| assertion.tile)
         .collect();
 
-    // prefer tiles in the clue first;
+    sort_deductions(&mut new_deductions);
+    // prefer tiles in the clue first; the sort is stable so ties keep the order above
     new_deductions.sort_by_key(|deduction| {
         if clue_tiles.contains(&deduction.tile_assertion.tile) {
             if deduction.is_positive() {
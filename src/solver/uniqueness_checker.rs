@@ -0,0 +1,252 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::model::{Clue, GameBoard, Tile};
+
+use super::clue_constraint::{create_clue_constraint, ConstraintSet};
+
+/// Two solutions are enough to show a puzzle is ambiguous
+const SOLUTION_LIMIT: usize = 2;
+
+/// Whether `clues` pin `board` down to exactly one solution. Unlike `perform_evaluation_step`,
+/// this doesn't depend on which deduction techniques the solver knows: it backtracks over the
+/// columns each tile could take, so a clue chain beyond the solver's reach still counts.
+pub fn has_unique_solution(board: &GameBoard, clues: &[Clue]) -> bool {
+    count_solutions(board, clues, SOLUTION_LIMIT) == 1
+}
+
+/// Count the ways to finish `board` that satisfy every clue, stopping once `limit` are found
+pub fn count_solutions(board: &GameBoard, clues: &[Clue], limit: usize) -> usize {
+    let domains: BTreeMap<Tile, BTreeSet<usize>> = board
+        .solution
+        .all_tiles()
+        .into_iter()
+        .map(|tile| (tile, board.get_possible_cols_for_tile(tile).collect()))
+        .collect();
+
+    let mut constraints = ConstraintSet::default();
+    for clue in clues {
+        let clue_constraints = create_clue_constraint(clue).constraints(board.solution.difficulty);
+        constraints
+            .unary_constraints
+            .extend(clue_constraints.unary_constraints);
+        constraints
+            .binary_constraints
+            .extend(clue_constraints.binary_constraints);
+        constraints
+            .ternary_constraints
+            .extend(clue_constraints.ternary_constraints);
+    }
+
+    let mut counter = SolutionCounter {
+        constraints,
+        n_variants: board.solution.n_variants,
+        limit,
+        n_solutions: 0,
+    };
+    counter.search(domains);
+    counter.n_solutions
+}
+
+struct SolutionCounter {
+    constraints: ConstraintSet,
+    n_variants: usize,
+    limit: usize,
+    n_solutions: usize,
+}
+
+impl SolutionCounter {
+    /// Narrow the domains, then branch on the tile with the fewest columns left
+    fn search(&mut self, mut domains: BTreeMap<Tile, BTreeSet<usize>>) {
+        if !self.propagate(&mut domains) {
+            return;
+        }
+
+        let branch_tile = domains
+            .iter()
+            .filter(|(_, cols)| cols.len() > 1)
+            .min_by_key(|(_, cols)| cols.len())
+            .map(|(tile, _)| *tile);
+        let Some(tile) = branch_tile else {
+            // every tile is placed, and propagation checked each constraint against the placement
+            self.n_solutions += 1;
+            return;
+        };
+
+        for col in domains[&tile].clone() {
+            if self.n_solutions >= self.limit {
+                return;
+            }
+            let mut branch = domains.clone();
+            branch.insert(tile, BTreeSet::from([col]));
+            self.search(branch);
+        }
+    }
+
+    /// Remove columns until nothing changes. Returns false if the domains admit no solution.
+    fn propagate(&self, domains: &mut BTreeMap<Tile, BTreeSet<usize>>) -> bool {
+        loop {
+            let mut changed = false;
+            for constraint in self.constraints.unary_constraints.iter() {
+                let cols = domains.get_mut(&constraint.var()).unwrap();
+                let n_cols = cols.len();
+                cols.retain(|col| constraint.valid(*col));
+                changed |= cols.len() != n_cols;
+            }
+            for constraint in self.constraints.binary_constraints.iter() {
+                let (x, y) = constraint.vars();
+                changed |= revise(domains, &[x, y], |values| {
+                    constraint.valid(values[0], values[1])
+                });
+            }
+            for constraint in self.constraints.ternary_constraints.iter() {
+                changed |= revise(domains, &constraint.vars(), |values| {
+                    constraint.valid(&values.to_vec())
+                });
+            }
+            match self.enforce_rows_are_permutations(domains) {
+                None => return false,
+                Some(rows_changed) => changed |= rows_changed,
+            }
+
+            if domains.values().any(|cols| cols.is_empty()) {
+                return false;
+            }
+            if !changed {
+                return true;
+            }
+        }
+    }
+
+    /// Each row holds every variant exactly once: a placed tile's column is closed to the rest of
+    /// its row, and a column only one tile can still take must hold that tile. Returns whether
+    /// anything changed, or None if some column can't be filled.
+    fn enforce_rows_are_permutations(
+        &self,
+        domains: &mut BTreeMap<Tile, BTreeSet<usize>>,
+    ) -> Option<bool> {
+        let mut changed = false;
+        let tiles: Vec<Tile> = domains.keys().copied().collect();
+
+        for tile in tiles.iter() {
+            if domains[tile].len() != 1 {
+                continue;
+            }
+            let col = *domains[tile].first().unwrap();
+            for other in tiles.iter() {
+                if other.row == tile.row && other != tile {
+                    changed |= domains.get_mut(other).unwrap().remove(&col);
+                }
+            }
+        }
+
+        let rows: BTreeSet<usize> = tiles.iter().map(|tile| tile.row).collect();
+        for row in rows {
+            for col in 0..self.n_variants {
+                let holders: Vec<&Tile> = tiles
+                    .iter()
+                    .filter(|tile| tile.row == row && domains[*tile].contains(&col))
+                    .collect();
+                match holders.as_slice() {
+                    [] => return None,
+                    [tile] if domains[*tile].len() > 1 => {
+                        domains.insert(**tile, BTreeSet::from([col]));
+                        changed = true;
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Some(changed)
+    }
+}
+
+/// Drop each column of `vars` that no combination of the other vars' columns supports. Returns
+/// whether anything was dropped.
+fn revise(
+    domains: &mut BTreeMap<Tile, BTreeSet<usize>>,
+    vars: &[Tile],
+    valid: impl Fn(&[usize]) -> bool,
+) -> bool {
+    let var_domains: Vec<Vec<usize>> = vars
+        .iter()
+        .map(|var| domains[var].iter().copied().collect())
+        .collect();
+    let mut supported = vec![BTreeSet::new(); vars.len()];
+    let mut values = vec![0; vars.len()];
+    collect_supported(&var_domains, &mut values, 0, &valid, &mut supported);
+
+    let mut changed = false;
+    for (var, supported) in vars.iter().zip(supported) {
+        let cols = domains.get_mut(var).unwrap();
+        if cols.len() != supported.len() {
+            *cols = supported;
+            changed = true;
+        }
+    }
+    changed
+}
+
+fn collect_supported(
+    var_domains: &[Vec<usize>],
+    values: &mut Vec<usize>,
+    depth: usize,
+    valid: &impl Fn(&[usize]) -> bool,
+    supported: &mut [BTreeSet<usize>],
+) {
+    if depth == values.len() {
+        if valid(values) {
+            for (supported, value) in supported.iter_mut().zip(values.iter()) {
+                supported.insert(*value);
+            }
+        }
+        return;
+    }
+    for col in var_domains[depth].iter() {
+        values[depth] = *col;
+        collect_supported(var_domains, values, depth + 1, valid, supported);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        model::{Difficulty, Solution},
+        solver::generate_clues,
+    };
+
+    #[test]
+    fn test_generated_puzzle_has_unique_solution() {
+        let solution = Arc::new(Solution::new(Difficulty::Easy, Some(42)));
+        let result = generate_clues(&GameBoard::new(solution.clone()));
+        assert!(has_unique_solution(&result.board, &result.clues));
+
+        // without clues, only the revealed tiles constrain the board
+        assert_eq!(count_solutions(&result.board, &[], 2), 2);
+
+        // a solved board needs no clues
+        let mut solved = GameBoard::new(solution.clone());
+        for tile in solution.all_tiles() {
+            solved.select_tile_from_solution(tile);
+        }
+        assert!(has_unique_solution(&solved, &[]));
+    }
+
+    #[test]
+    fn test_count_solutions_of_small_board() {
+        let solution = Arc::new(Solution::new(Difficulty::Tutorial, Some(1)));
+        let board = GameBoard::new(solution.clone());
+        let n_rows = solution.n_rows;
+        let n_variants = solution.n_variants;
+        let permutations: usize = (1..=n_variants).product();
+
+        // each row is an independent permutation
+        let limit = 10_000;
+        assert_eq!(
+            count_solutions(&board, &[], limit),
+            permutations.pow(n_rows as u32).min(limit)
+        );
+    }
+}
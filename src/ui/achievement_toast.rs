@@ -0,0 +1,72 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+use glib::timeout_add_local_once;
+use gtk4::{prelude::*, Align, Label, Revealer, RevealerTransitionType};
+
+use crate::{destroyable::Destroyable, game::achievements::Achievement};
+use fluent_i18n::t;
+
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// Banner sliding up over the bottom of the window to announce newly earned achievements
+pub struct AchievementToast {
+    pub revealer: Revealer,
+    label: Label,
+    /// Bumped on every toast, so an older toast's timeout doesn't hide a newer one
+    generation: Rc<Cell<u32>>,
+}
+
+impl Destroyable for AchievementToast {
+    fn destroy(&mut self) {}
+}
+
+impl AchievementToast {
+    pub fn new() -> Rc<RefCell<Self>> {
+        let label = Label::builder().wrap(true).build();
+        let revealer = Revealer::builder()
+            .child(&label)
+            .transition_type(RevealerTransitionType::SlideUp)
+            .halign(Align::Center)
+            .valign(Align::End)
+            .margin_bottom(20)
+            .css_classes(["achievement-toast"])
+            .can_target(false)
+            .build();
+
+        Rc::new(RefCell::new(Self {
+            revealer,
+            label,
+            generation: Rc::new(Cell::new(0)),
+        }))
+    }
+
+    pub fn show(&self, achievements: &[Achievement]) {
+        if achievements.is_empty() {
+            return;
+        }
+        let titles = achievements
+            .iter()
+            .map(|achievement| t!(achievement.title_key()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.label
+            .set_text(&t!("achievement-earned", { "achievements" => titles }));
+        self.revealer.set_reveal_child(true);
+
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        timeout_add_local_once(TOAST_DURATION, {
+            let revealer = self.revealer.clone();
+            let current_generation = self.generation.clone();
+            move || {
+                if current_generation.get() == generation {
+                    revealer.set_reveal_child(false);
+                }
+            }
+        });
+    }
+}
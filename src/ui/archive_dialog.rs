@@ -0,0 +1,143 @@
+use glib::Propagation;
+use gtk4::{
+    gdk, prelude::*, Align, ApplicationWindow, Box, Button, EventControllerKey, Grid, Label,
+    Orientation, ScrolledWindow,
+};
+
+use crate::events::EventEmitter;
+use crate::game::daily::{self, DAILY_DIFFICULTY};
+use crate::game::stats_manager::StatsManager;
+use crate::model::GameEngineCommand;
+use fluent_i18n::t;
+
+/// How many past days of daily puzzles the archive lists, including today
+const ARCHIVE_DAYS: usize = 30;
+
+pub struct ArchiveDialog;
+
+impl ArchiveDialog {
+    fn create_archive_grid(
+        modal: &gtk4::Window,
+        stats_manager: &StatsManager,
+        game_engine_command_emitter: &EventEmitter<GameEngineCommand>,
+    ) -> Grid {
+        let archive_grid = Grid::new();
+        archive_grid.set_row_spacing(5);
+        archive_grid.set_column_spacing(10);
+
+        let headers = [&t!("stats-date"), &t!("archive-status")];
+        for (i, header) in headers.iter().enumerate() {
+            let label = Label::new(Some(header));
+            label.set_markup(&format!("<b>{}</b>", header));
+            label.set_halign(Align::Start);
+            archive_grid.attach(&label, i as i32, 0, 1, 1);
+        }
+
+        for (i, date) in daily::archive_dates(daily::today(), ARCHIVE_DAYS)
+            .into_iter()
+            .enumerate()
+        {
+            let row_index = (i + 1) as i32;
+            let seed = daily::seed_for_date(date);
+
+            let date_label = Label::new(Some(&date.format("%Y-%m-%d").to_string()));
+            date_label.set_halign(Align::Start);
+            archive_grid.attach(&date_label, 0, row_index, 1, 1);
+
+            let status = if stats_manager.is_daily_completed(date) {
+                t!("archive-completed")
+            } else {
+                t!("archive-not-completed")
+            };
+            let status_label = Label::new(Some(&status));
+            status_label.set_halign(Align::Start);
+            archive_grid.attach(&status_label, 1, row_index, 1, 1);
+
+            let play_button = Button::builder().label(&t!("archive-play")).build();
+            play_button.connect_clicked({
+                let modal = modal.clone();
+                let game_engine_command_emitter = game_engine_command_emitter.clone();
+                move |_| {
+                    game_engine_command_emitter.emit(GameEngineCommand::NewGame(
+                        Some(DAILY_DIFFICULTY),
+                        Some(seed),
+                    ));
+                    modal.close();
+                }
+            });
+            archive_grid.attach(&play_button, 2, row_index, 1, 1);
+        }
+
+        archive_grid
+    }
+
+    pub fn show(
+        window: &ApplicationWindow,
+        stats_manager: &StatsManager,
+        game_engine_command_emitter: &EventEmitter<GameEngineCommand>,
+    ) {
+        let vbox = Box::builder()
+            .orientation(Orientation::Vertical)
+            .spacing(10)
+            .margin_start(20)
+            .margin_end(20)
+            .margin_top(20)
+            .margin_bottom(20)
+            .build();
+        let modal = gtk4::Window::builder()
+            .title(&t!("archive-title"))
+            .modal(true)
+            .default_width(350)
+            .default_height(500)
+            .child(&vbox)
+            .transient_for(window)
+            .build();
+
+        let description = Label::new(Some(&t!("archive-description", {
+            "difficulty" => DAILY_DIFFICULTY.to_string()
+        })));
+        description.set_wrap(true);
+        description.set_halign(Align::Start);
+        vbox.append(&description);
+
+        let archive_grid =
+            Self::create_archive_grid(&modal, stats_manager, game_engine_command_emitter);
+        let scrolled_window = ScrolledWindow::builder()
+            .child(&archive_grid)
+            .vexpand(true)
+            .hscrollbar_policy(gtk4::PolicyType::Never)
+            .vscrollbar_policy(gtk4::PolicyType::Automatic)
+            .build();
+        vbox.append(&scrolled_window);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .halign(Align::End)
+            .build();
+        let close_button = gtk4::Button::builder().label(&t!("close")).build();
+        button_box.append(&close_button);
+
+        vbox.append(&button_box);
+
+        close_button.connect_clicked({
+            let modal = modal.clone();
+            move |_| {
+                modal.close();
+            }
+        });
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let modal = modal.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    modal.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        modal.add_controller(key_controller);
+
+        modal.present();
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    time::Duration,
+};
+
+use glib::{timeout_add_local, SourceId};
+
+use crate::{
+    destroyable::Destroyable, events::EventHandler, game::game_engine::GameEngine,
+    game::settings::Settings, model::GameEngineEvent,
+};
+
+/// Periodically saves the game in progress, so a crash doesn't lose more than one interval of
+/// play. The engine skips the write when nothing changed or the game is paused.
+pub struct AutosaveTimer {
+    game_engine: Weak<RefCell<GameEngine>>,
+    interval_secs: u64,
+    timer: Option<SourceId>,
+}
+
+impl Destroyable for AutosaveTimer {
+    fn destroy(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer.remove();
+        }
+    }
+}
+
+impl EventHandler<GameEngineEvent> for AutosaveTimer {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::SettingsChanged(settings) = event {
+            self.update_interval(settings.autosave_interval_secs);
+        }
+    }
+}
+
+impl AutosaveTimer {
+    pub fn new(game_engine: &Rc<RefCell<GameEngine>>, settings: &Settings) -> Rc<RefCell<Self>> {
+        let autosave_timer = Rc::new(RefCell::new(Self {
+            game_engine: Rc::downgrade(game_engine),
+            interval_secs: 0,
+            timer: None,
+        }));
+        autosave_timer
+            .borrow_mut()
+            .update_interval(settings.autosave_interval_secs);
+        autosave_timer
+    }
+
+    fn update_interval(&mut self, interval_secs: u64) {
+        if self.interval_secs == interval_secs {
+            return;
+        }
+        self.interval_secs = interval_secs;
+        if let Some(timer) = self.timer.take() {
+            timer.remove();
+        }
+        if interval_secs == 0 {
+            return;
+        }
+
+        let game_engine = self.game_engine.clone();
+        let timer = timeout_add_local(Duration::from_secs(interval_secs), move || {
+            let Some(game_engine) = game_engine.upgrade() else {
+                return glib::ControlFlow::Break;
+            };
+            // the engine may be mid-command; catch it on the next tick
+            if let Ok(mut game_engine) = game_engine.try_borrow_mut() {
+                if game_engine.autosave() {
+                    log::trace!(target: "autosave", "Game autosaved");
+                }
+            }
+            glib::ControlFlow::Continue
+        });
+        self.timer = Some(timer);
+    }
+}
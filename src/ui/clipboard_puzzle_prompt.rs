@@ -0,0 +1,124 @@
+use std::rc::Rc;
+
+use glib::Propagation;
+use gtk4::gdk;
+use gtk4::EventControllerKey;
+use gtk4::{prelude::*, ApplicationWindow, Label};
+use log::trace;
+
+use crate::{
+    events::EventEmitter,
+    model::{GameEngineCommand, PuzzleCode},
+};
+use fluent_i18n::t;
+
+/// Offers to load a puzzle when the app starts with a puzzle code on the clipboard
+pub struct ClipboardPuzzlePrompt;
+
+impl ClipboardPuzzlePrompt {
+    /// Read the clipboard and prompt if it holds a puzzle code other than `current_code`.
+    /// Anything that isn't a puzzle code is silently ignored.
+    pub fn check(
+        window: &Rc<ApplicationWindow>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+        current_code: Option<PuzzleCode>,
+    ) {
+        let clipboard = WidgetExt::clipboard(window.as_ref());
+        clipboard.read_text_async(None::<&gio::Cancellable>, {
+            let window = window.clone();
+            move |result| {
+                let code = match result {
+                    Ok(text) => text.and_then(|text| PuzzleCode::parse(text.as_str())),
+                    Err(err) => {
+                        trace!(target: "clipboard_puzzle_prompt", "Couldn't read clipboard: {}", err);
+                        None
+                    }
+                };
+                match code {
+                    Some(code) if Some(code) != current_code => {
+                        Self::show_prompt(&window, code, game_engine_command_emitter);
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    fn show_prompt(
+        window: &Rc<ApplicationWindow>,
+        code: PuzzleCode,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    ) {
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(10)
+            .margin_top(10)
+            .margin_start(20)
+            .margin_end(20)
+            .build();
+
+        let dialog = gtk4::Window::builder()
+            .title(&t!("clipboard-puzzle-title"))
+            .transient_for(window.as_ref())
+            .modal(true)
+            .child(&content_area)
+            .default_width(300)
+            .build();
+
+        let label = Label::new(Some(&t!("clipboard-puzzle-message", {
+            "difficulty" => code.difficulty.to_string(),
+            "code" => code.to_string()
+        })));
+        label.set_wrap(true);
+        content_area.append(&label);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .spacing(10)
+            .halign(gtk4::Align::End)
+            .build();
+        let ignore_button = gtk4::Button::builder()
+            .label(&t!("clipboard-puzzle-ignore"))
+            .build();
+        let load_button = gtk4::Button::builder()
+            .label(&t!("clipboard-puzzle-load"))
+            .build();
+        button_box.append(&ignore_button);
+        button_box.append(&load_button);
+        content_area.append(&button_box);
+
+        ignore_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        load_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                game_engine_command_emitter.emit(GameEngineCommand::NewGame(
+                    Some(code.difficulty),
+                    Some(code.seed),
+                ));
+                dialog.close();
+            }
+        });
+
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let dialog = dialog.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    dialog.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        dialog.add_controller(key_controller);
+        dialog.set_default_widget(Some(&load_button));
+        dialog.present();
+    }
+}
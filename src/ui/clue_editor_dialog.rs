@@ -0,0 +1,308 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use gtk4::{prelude::*, Align, ApplicationWindow, Entry, Label, ScrolledWindow};
+
+use crate::{
+    destroyable::Destroyable,
+    events::EventHandler,
+    model::{Clue, ClueType, GameEngineEvent, HorizontalClueType, Solution, Tile},
+    solver::puzzle_metrics::check_clues,
+};
+
+use super::template::TemplateParser;
+
+const MAX_CLUE_TILES: usize = 3;
+
+/// Debug tool for authoring puzzles by hand: build clues for the current solution, check whether
+/// they solve it, and copy them out in the format `Clue::parse` reads
+pub struct ClueEditorDialog {
+    window: Rc<ApplicationWindow>,
+    solution: Option<Arc<Solution>>,
+    clues: Rc<RefCell<Vec<Clue>>>,
+    dialog: Option<gtk4::Window>,
+}
+
+impl ClueEditorDialog {
+    pub fn new(window: &Rc<ApplicationWindow>) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            window: window.clone(),
+            solution: None,
+            clues: Rc::new(RefCell::new(Vec::new())),
+            dialog: None,
+        }))
+    }
+
+    fn clue_list_text(clues: &[Clue]) -> String {
+        clues
+            .iter()
+            .map(|clue| clue.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn check_text(solution: &Arc<Solution>, clues: &[Clue]) -> String {
+        let check = check_clues(solution, clues);
+        if check.contradicts_solution {
+            "These clues contradict the solution".to_string()
+        } else if check.metrics.solved {
+            format!(
+                "Uniquely solvable: {} clues, {} steps",
+                check.metrics.n_clues, check.metrics.n_solve_steps
+            )
+        } else {
+            format!(
+                "Stuck after {} steps with {} of {} tiles unplaced",
+                check.metrics.n_solve_steps,
+                check.n_unsolved_tiles,
+                solution.n_rows * solution.n_variants
+            )
+        }
+    }
+
+    /// Parse the tile entries for a clue of `clue_type`, rejecting tiles outside the solution
+    fn tiles_from_entries(
+        solution: &Solution,
+        clue_type: ClueType,
+        tile_entries: &[Entry],
+    ) -> Option<Vec<Tile>> {
+        tile_entries[..clue_type.n_tiles()]
+            .iter()
+            .map(|entry| {
+                Tile::try_parse(&entry.text()).filter(|tile| {
+                    tile.row < solution.n_rows
+                        && Tile::variant_to_usize(tile.variant) < solution.n_variants
+                })
+            })
+            .collect()
+    }
+
+    /// Parse the column entry, numbered from 1 as the clues describe it, for an in-column clue
+    fn column_from_entry(solution: &Solution, column_entry: &Entry) -> Option<usize> {
+        column_entry
+            .text()
+            .parse::<usize>()
+            .ok()
+            .filter(|column| (1..=solution.n_variants).contains(column))
+            .map(|column| column - 1)
+    }
+
+    /// Rebuild the list of working clues, each with a button to remove it
+    fn refresh_clue_list(list_box: &gtk4::Box, clues: &Rc<RefCell<Vec<Clue>>>) {
+        while let Some(child) = list_box.first_child() {
+            list_box.remove(&child);
+        }
+        for (index, clue) in clues.borrow().iter().enumerate() {
+            let row = gtk4::Box::builder()
+                .orientation(gtk4::Orientation::Horizontal)
+                .spacing(10)
+                .build();
+            let label = Label::builder()
+                .label(format!(
+                    "{}    {}",
+                    clue,
+                    TemplateParser::to_plain_text(&clue.description())
+                ))
+                .selectable(true)
+                .hexpand(true)
+                .halign(Align::Start)
+                .build();
+            let remove_button = gtk4::Button::builder().label("Remove").build();
+            remove_button.connect_clicked({
+                let list_box = list_box.clone();
+                let clues = clues.clone();
+                move |_| {
+                    clues.borrow_mut().remove(index);
+                    Self::refresh_clue_list(&list_box, &clues);
+                }
+            });
+            row.append(&label);
+            row.append(&remove_button);
+            list_box.append(&row);
+        }
+    }
+
+    pub fn show(&mut self) {
+        self.hide_dialog();
+        let Some(solution) = self.solution.clone() else {
+            return;
+        };
+
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(20)
+            .margin_top(20)
+            .margin_start(30)
+            .margin_end(30)
+            .build();
+
+        let dialog = gtk4::Window::builder()
+            .transient_for(self.window.as_ref())
+            .title("Clue Editor")
+            .child(&content_area)
+            .default_width(600)
+            .default_height(500)
+            .build();
+
+        let clue_types = ClueType::all();
+        let clue_type_titles = clue_types
+            .iter()
+            .map(|clue_type| clue_type.get_title())
+            .collect::<Vec<String>>();
+        let clue_type_selector = gtk4::DropDown::from_strings(
+            clue_type_titles
+                .iter()
+                .map(|title| title.as_str())
+                .collect::<Vec<&str>>()
+                .as_slice(),
+        );
+
+        let builder_row = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .spacing(10)
+            .build();
+        builder_row.append(&clue_type_selector);
+        let tile_entries: Vec<Entry> = (0..MAX_CLUE_TILES)
+            .map(|_| {
+                Entry::builder()
+                    .placeholder_text("0a")
+                    .max_length(2)
+                    .width_chars(3)
+                    .build()
+            })
+            .collect();
+        for entry in tile_entries.iter() {
+            builder_row.append(entry);
+        }
+        // an in-column clue needs a column as well as its tile
+        let column_entry = Entry::builder()
+            .placeholder_text("col")
+            .max_length(1)
+            .width_chars(3)
+            .build();
+        builder_row.append(&column_entry);
+        let add_button = gtk4::Button::builder().label("Add").build();
+        builder_row.append(&add_button);
+        content_area.append(&builder_row);
+
+        let update_tile_entries = {
+            let tile_entries = tile_entries.clone();
+            let column_entry = column_entry.clone();
+            let clue_types = clue_types.clone();
+            move |selector: &gtk4::DropDown| {
+                let clue_type = clue_types[selector.selected() as usize];
+                for (index, entry) in tile_entries.iter().enumerate() {
+                    entry.set_sensitive(index < clue_type.n_tiles());
+                }
+                column_entry
+                    .set_sensitive(clue_type == ClueType::Horizontal(HorizontalClueType::InColumn));
+            }
+        };
+        update_tile_entries(&clue_type_selector);
+        clue_type_selector.connect_selected_notify(update_tile_entries);
+
+        let list_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(5)
+            .build();
+        let scrolled_window = ScrolledWindow::builder()
+            .child(&list_box)
+            .vexpand(true)
+            .hscrollbar_policy(gtk4::PolicyType::Never)
+            .vscrollbar_policy(gtk4::PolicyType::Automatic)
+            .build();
+        content_area.append(&scrolled_window);
+        Self::refresh_clue_list(&list_box, &self.clues);
+
+        let status_label = Label::builder().wrap(true).halign(Align::Start).build();
+        content_area.append(&status_label);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .spacing(10)
+            .halign(Align::End)
+            .build();
+        let check_button = gtk4::Button::builder().label("Check").build();
+        let copy_button = gtk4::Button::builder().label("Copy Clues").build();
+        let close_button = gtk4::Button::builder().label("Close").build();
+        button_box.append(&check_button);
+        button_box.append(&copy_button);
+        button_box.append(&close_button);
+        content_area.append(&button_box);
+
+        add_button.connect_clicked({
+            let solution = solution.clone();
+            let clues = self.clues.clone();
+            let list_box = list_box.clone();
+            let status_label = status_label.clone();
+            move |_| {
+                let clue_type = clue_types[clue_type_selector.selected() as usize];
+                let column = Self::column_from_entry(&solution, &column_entry);
+                let clue = Self::tiles_from_entries(&solution, clue_type, &tile_entries)
+                    .and_then(|tiles| Clue::build(clue_type, &tiles, column));
+                match clue {
+                    Some(clue) => {
+                        status_label.set_text(&format!("Added {}", clue));
+                        clues.borrow_mut().push(clue);
+                        Self::refresh_clue_list(&list_box, &clues);
+                    }
+                    None => status_label
+                        .set_text(&format!("Invalid tiles for {}", clue_type.get_title())),
+                }
+            }
+        });
+        check_button.connect_clicked({
+            let clues = self.clues.clone();
+            let status_label = status_label.clone();
+            move |_| {
+                status_label.set_text(&Self::check_text(&solution, &clues.borrow()));
+            }
+        });
+        copy_button.connect_clicked({
+            let clues = self.clues.clone();
+            move |button| {
+                button
+                    .clipboard()
+                    .set_text(&Self::clue_list_text(&clues.borrow()));
+            }
+        });
+        close_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        dialog.present();
+        self.dialog = Some(dialog);
+    }
+
+    fn hide_dialog(&mut self) {
+        if let Some(dialog) = self.dialog.take() {
+            dialog.close();
+        }
+    }
+}
+
+impl Destroyable for ClueEditorDialog {
+    fn destroy(&mut self) {
+        self.hide_dialog();
+    }
+}
+
+impl EventHandler<GameEngineEvent> for ClueEditorDialog {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::GameBoardUpdated { board, .. } = event {
+            // the working clues only make sense for the solution they were written against
+            let solution_changed = match &self.solution {
+                Some(solution) => !Arc::ptr_eq(solution, &board.solution),
+                None => true,
+            };
+            if solution_changed {
+                self.solution = Some(board.solution.clone());
+                self.clues.borrow_mut().clear();
+                self.hide_dialog();
+            }
+        }
+    }
+}
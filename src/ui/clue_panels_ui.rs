@@ -1,14 +1,19 @@
 use gtk4::{
-    prelude::{GridExt, WidgetExt},
-    ApplicationWindow, Grid,
+    prelude::{BoxExt, GridExt, WidgetExt},
+    Align, ApplicationWindow, Grid, Label, Orientation,
+};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+    time::Duration,
 };
-use std::{cell::RefCell, collections::HashSet, rc::Rc, time::Duration};
 
 use crate::{
     destroyable::Destroyable,
     events::{EventHandler, Unsubscriber},
     game::settings::Settings,
-    model::{ClueAddress, ClueSelection},
+    model::{ClueAddress, ClueSelection, ClueSort},
 };
 use crate::{
     events::EventEmitter,
@@ -20,11 +25,21 @@ use crate::{
 };
 use crate::{model::Difficulty, ui::ImageSet};
 use crate::{model::LayoutConfiguration, ui::clue_ui::ClueUI};
+use fluent_i18n::t;
+
+/// Below this clue tile size the layout is too cramped to spare room for the panel headers
+const MIN_HEADER_CLUE_TILE_SIZE: i32 = 32;
 
 pub struct CluePanelsUI {
     window: Rc<ApplicationWindow>,
-    pub horizontal_grid: Grid,
-    pub vertical_grid: Grid,
+    /// Header and grid of the horizontal clues
+    pub horizontal_panel: gtk4::Box,
+    /// Header and grid of the vertical clues
+    pub vertical_panel: gtk4::Box,
+    horizontal_header: Label,
+    vertical_header: Label,
+    horizontal_grid: Grid,
+    vertical_grid: Grid,
     horizontal_clue_uis: Vec<Rc<RefCell<ClueUI>>>,
     vertical_clue_uis: Vec<Rc<RefCell<ClueUI>>>,
     input_event_emitter: EventEmitter<InputEvent>,
@@ -34,13 +49,29 @@ pub struct CluePanelsUI {
     current_layout: LayoutConfiguration,
     tooltips_enabled: bool,
     current_spotlight_enabled: bool,
+    locked_clues: HashSet<ClueAddress>,
+    completed_clues: HashSet<ClueAddress>,
+    /// Whether each clue yields a deduction or can be completed, as last reported by the engine
+    clue_actionability: HashMap<ClueAddress, bool>,
+    dim_inactive_clues: bool,
+    collapse_completed_clues: bool,
+    compact_negative_tiles: bool,
+    clues_per_column: usize,
+    show_panel_headers: bool,
+    clue_sort: ClueSort,
+    /// The clue set being shown; the clue UIs are indexed by the clues' addresses
+    clue_set: ClueSet,
+    /// Indexes of the horizontal clues in the order they're laid out
+    horizontal_display_order: Vec<usize>,
+    /// Indexes of the vertical clues in the order they're laid out
+    vertical_display_order: Vec<usize>,
 }
 
 impl Destroyable for CluePanelsUI {
     fn destroy(&mut self) {
         // Unparent all widgets
-        self.horizontal_grid.unparent();
-        self.vertical_grid.unparent();
+        self.horizontal_panel.unparent();
+        self.vertical_panel.unparent();
         if let Some(subscription_id) = self.game_engine_event_subscription_id.take() {
             subscription_id.unsubscribe();
         }
@@ -77,6 +108,18 @@ impl EventHandler<GameEngineEvent> for CluePanelsUI {
             GameEngineEvent::SettingsChanged(settings) => {
                 self.update_tooltip_visibility(settings.clue_tooltips_enabled);
                 self.update_spotlight_enabled(settings.clue_spotlight_enabled);
+                self.update_collapse_completed_clues(settings.collapse_completed_clues);
+                self.update_compact_negative_tiles(settings.compact_negative_tiles);
+                self.update_dim_inactive_clues(settings.dim_inactive_clues);
+                self.update_show_panel_headers(settings.show_clue_panel_headers);
+                self.update_clue_sort(settings.clue_sort);
+            }
+            GameEngineEvent::ClueActionabilityChanged(clue_actionability) => {
+                self.clue_actionability = clue_actionability.clone();
+                self.sync_inactive_clues();
+            }
+            GameEngineEvent::CluesHiddenChanged(clues_hidden) => {
+                self.set_clues_hidden(*clues_hidden);
             }
             _ => {}
         }
@@ -110,7 +153,6 @@ impl CluePanelsUI {
         let horizontal_clues_grid = Grid::builder()
             .row_spacing(layout.clues.horizontal_clue_panel.row_spacing)
             .column_spacing(layout.clues.horizontal_clue_panel.column_spacing)
-            .margin_start(layout.clues.horizontal_clue_panel.left_margin)
             .hexpand(true)
             .vexpand(true)
             .name("horizontal-clues-panel")
@@ -120,15 +162,35 @@ impl CluePanelsUI {
         // Create vertical clues area (bottom)
         let vertical_clues_grid = Grid::builder()
             .column_spacing(layout.clues.vertical_clue_panel.column_spacing)
-            .margin_top(layout.clues.vertical_clue_panel.margin_top)
             .hexpand(true)
             .vexpand(true)
             .name("vertical-clues-panel")
             .css_classes(["vertical-clues"])
             .build();
 
+        // The margins go on the panels so the headers line up with the clues
+        let horizontal_header = CluePanelsUI::panel_header(&t!("clue-panel-horizontal"));
+        let horizontal_panel = gtk4::Box::builder()
+            .orientation(Orientation::Vertical)
+            .margin_start(layout.clues.horizontal_clue_panel.left_margin)
+            .build();
+        horizontal_panel.append(&horizontal_header);
+        horizontal_panel.append(&horizontal_clues_grid);
+
+        let vertical_header = CluePanelsUI::panel_header(&t!("clue-panel-vertical"));
+        let vertical_panel = gtk4::Box::builder()
+            .orientation(Orientation::Vertical)
+            .margin_top(layout.clues.vertical_clue_panel.margin_top)
+            .build();
+        vertical_panel.append(&vertical_header);
+        vertical_panel.append(&vertical_clues_grid);
+
         let clue_set_ui = Rc::new(RefCell::new(Self {
             window,
+            horizontal_panel,
+            vertical_panel,
+            horizontal_header,
+            vertical_header,
             horizontal_grid: horizontal_clues_grid,
             vertical_grid: vertical_clues_grid,
             horizontal_clue_uis: Vec::with_capacity(MAX_HORIZ_CLUES),
@@ -140,11 +202,48 @@ impl CluePanelsUI {
             current_layout: layout,
             tooltips_enabled: settings.clue_tooltips_enabled,
             current_spotlight_enabled: settings.clue_spotlight_enabled,
+            locked_clues: HashSet::new(),
+            completed_clues: HashSet::new(),
+            clue_actionability: HashMap::new(),
+            dim_inactive_clues: settings.dim_inactive_clues,
+            collapse_completed_clues: settings.collapse_completed_clues,
+            compact_negative_tiles: settings.compact_negative_tiles,
+            clues_per_column: CluePanelsUI::calc_clues_per_column(settings.difficulty),
+            show_panel_headers: settings.show_clue_panel_headers,
+            clue_sort: settings.clue_sort,
+            clue_set: ClueSet::default(),
+            horizontal_display_order: Vec::new(),
+            vertical_display_order: Vec::new(),
         }));
+        clue_set_ui.borrow().sync_panel_headers();
 
         clue_set_ui
     }
 
+    fn panel_header(text: &str) -> Label {
+        Label::builder()
+            .label(text)
+            .halign(Align::Start)
+            .css_classes(["clue-panel-header"])
+            .build()
+    }
+
+    fn update_show_panel_headers(&mut self, show_panel_headers: bool) {
+        if self.show_panel_headers != show_panel_headers {
+            self.show_panel_headers = show_panel_headers;
+            self.sync_panel_headers();
+        }
+    }
+
+    /// Show the panel headers when enabled, unless the clues have been scaled down too far to
+    /// spare the room
+    fn sync_panel_headers(&self) {
+        let visible = self.show_panel_headers
+            && self.current_layout.clues.clue_tile_size.width >= MIN_HEADER_CLUE_TILE_SIZE;
+        self.horizontal_header.set_visible(visible);
+        self.vertical_header.set_visible(visible);
+    }
+
     fn update_spotlight_enabled(&mut self, enabled: bool) {
         self.current_spotlight_enabled = enabled;
         self.sync_spotlight_enabled();
@@ -165,8 +264,8 @@ impl CluePanelsUI {
     }
 
     fn allocate_clue_uis(&mut self, difficulty: Difficulty, clue_set: &ClueSet) {
-        let n_rows = difficulty.n_rows();
-        let clues_per_column = n_rows * 2;
+        let clues_per_column = CluePanelsUI::calc_clues_per_column(difficulty);
+        self.clues_per_column = clues_per_column;
 
         // horizontal clues
         for (idx, addressed_clue) in clue_set.horizontal_clues().iter().enumerate() {
@@ -189,6 +288,9 @@ impl CluePanelsUI {
                 1,
                 1,
             );
+            clue_set
+                .borrow_mut()
+                .set_compact_negative_tiles(self.compact_negative_tiles);
             self.horizontal_clue_uis.push(clue_set);
         }
 
@@ -205,6 +307,9 @@ impl CluePanelsUI {
             );
             self.vertical_grid
                 .attach(&clue_set.borrow().frame, col as i32, 0, 1, 1);
+            clue_set
+                .borrow_mut()
+                .set_compact_negative_tiles(self.compact_negative_tiles);
             self.vertical_clue_uis.push(clue_set);
         }
     }
@@ -266,14 +371,80 @@ impl CluePanelsUI {
     }
 
     fn set_clues(&mut self, clue_set: &ClueSet, difficulty: Difficulty) {
+        self.clue_set = clue_set.clone();
         self.clear_clue_uis();
         self.allocate_clue_uis(difficulty, clue_set);
-        self.populate_clue_uis(clue_set);
+        self.sync_clue_order();
+        self.sync_clue_locks();
+        self.sync_inactive_clues();
     }
 
-    fn populate_clue_uis(&mut self, clue_set: &ClueSet) {
-        let mut previous_clue: Option<&ClueWithAddress> = None;
+    /// Lock the given clues so only their shape is shown; all other clues are revealed
+    pub fn set_locked_clues(&mut self, locked_clues: HashSet<ClueAddress>) {
+        self.locked_clues = locked_clues;
+        self.sync_clue_locks();
+    }
+
+    fn sync_clue_locks(&self) {
         for (idx, clue_ui) in self.horizontal_clue_uis.iter().enumerate() {
+            let clue_address = ClueAddress {
+                orientation: ClueOrientation::Horizontal,
+                index: idx,
+            };
+            clue_ui
+                .borrow_mut()
+                .set_locked(self.locked_clues.contains(&clue_address));
+        }
+
+        for (idx, clue_ui) in self.vertical_clue_uis.iter().enumerate() {
+            let clue_address = ClueAddress {
+                orientation: ClueOrientation::Vertical,
+                index: idx,
+            };
+            clue_ui
+                .borrow_mut()
+                .set_locked(self.locked_clues.contains(&clue_address));
+        }
+    }
+
+    fn update_clue_sort(&mut self, clue_sort: ClueSort) {
+        if self.clue_sort != clue_sort {
+            self.clue_sort = clue_sort;
+            self.sync_clue_order();
+        }
+    }
+
+    /// Lay the clues out in the chosen sort order. Only the grid positions of the clue UIs
+    /// change; each stays at its clue's address, so selection, completion and locks carry over.
+    fn sync_clue_order(&mut self) {
+        self.horizontal_display_order = self
+            .clue_sort
+            .display_order(self.clue_set.horizontal_clues());
+        self.vertical_display_order = self.clue_sort.display_order(self.clue_set.vertical_clues());
+
+        for (col, &idx) in self.vertical_display_order.iter().enumerate() {
+            let Some(clue_ui) = self.vertical_clue_uis.get(idx) else {
+                continue;
+            };
+            let frame = clue_ui.borrow().frame.clone();
+            let (current_col, _, _, _) = self.vertical_grid.query_child(&frame);
+            if current_col != col as i32 {
+                self.vertical_grid.remove(&frame);
+                self.vertical_grid.attach(&frame, col as i32, 0, 1, 1);
+            }
+        }
+
+        self.populate_clue_uis();
+        self.sync_collapsed_clues();
+    }
+
+    fn populate_clue_uis(&self) {
+        let clue_set = &self.clue_set;
+        let mut previous_clue: Option<&ClueWithAddress> = None;
+        for &idx in self.horizontal_display_order.iter() {
+            let Some(clue_ui) = self.horizontal_clue_uis.get(idx) else {
+                continue;
+            };
             let clue = clue_set.horizontal_clues().get(idx);
             let is_new_group = match (clue, previous_clue) {
                 (Some(clue), Some(previous_clue)) => clue.group != previous_clue.group,
@@ -285,7 +456,10 @@ impl CluePanelsUI {
 
             previous_clue = clue;
         }
-        for (idx, clue_ui) in self.vertical_clue_uis.iter().enumerate() {
+        for &idx in self.vertical_display_order.iter() {
+            let Some(clue_ui) = self.vertical_clue_uis.get(idx) else {
+                continue;
+            };
             let clue = clue_set.vertical_clues().get(idx);
             let is_new_group = match (clue, previous_clue) {
                 (Some(clue), Some(previous_clue)) => clue.group != previous_clue.group,
@@ -305,7 +479,8 @@ impl CluePanelsUI {
             .set_size_request(horiz_dim.width, horiz_dim.height);
     }
 
-    fn set_clue_completion(&self, completed_clues: &HashSet<ClueAddress>) {
+    fn set_clue_completion(&mut self, completed_clues: &HashSet<ClueAddress>) {
+        self.completed_clues = completed_clues.clone();
         for (idx, clue_ui) in self.horizontal_clue_uis.iter().enumerate() {
             let clue_address = ClueAddress {
                 orientation: ClueOrientation::Horizontal,
@@ -325,6 +500,88 @@ impl CluePanelsUI {
                 .borrow_mut()
                 .set_completed(completed_clues.contains(&clue_address));
         }
+        self.sync_collapsed_clues();
+        self.sync_inactive_clues();
+    }
+
+    fn update_dim_inactive_clues(&mut self, dim_inactive_clues: bool) {
+        if self.dim_inactive_clues != dim_inactive_clues {
+            self.dim_inactive_clues = dim_inactive_clues;
+            self.sync_inactive_clues();
+        }
+    }
+
+    /// Dim clues with nothing to offer. Completed clues keep their own styling, and clues the
+    /// engine hasn't reported on yet are left alone.
+    fn sync_inactive_clues(&self) {
+        let orientations = [
+            (ClueOrientation::Horizontal, &self.horizontal_clue_uis),
+            (ClueOrientation::Vertical, &self.vertical_clue_uis),
+        ];
+        for (orientation, clue_uis) in orientations {
+            for (idx, clue_ui) in clue_uis.iter().enumerate() {
+                let clue_address = ClueAddress {
+                    orientation,
+                    index: idx,
+                };
+                let inactive = self.dim_inactive_clues
+                    && !self.completed_clues.contains(&clue_address)
+                    && self.clue_actionability.get(&clue_address) == Some(&false);
+                clue_ui.borrow().set_inactive(inactive);
+            }
+        }
+    }
+
+    fn update_compact_negative_tiles(&mut self, compact_negative_tiles: bool) {
+        self.compact_negative_tiles = compact_negative_tiles;
+        for clue_ui in self
+            .horizontal_clue_uis
+            .iter()
+            .chain(self.vertical_clue_uis.iter())
+        {
+            clue_ui
+                .borrow_mut()
+                .set_compact_negative_tiles(compact_negative_tiles);
+        }
+    }
+
+    fn update_collapse_completed_clues(&mut self, collapse_completed_clues: bool) {
+        if self.collapse_completed_clues != collapse_completed_clues {
+            self.collapse_completed_clues = collapse_completed_clues;
+            self.sync_collapsed_clues();
+        }
+    }
+
+    /// Re-pack the horizontal clue grid, skipping completed clues when they're collapsed, so the
+    /// remaining clues fill the columns the layout manager sized the panel for
+    fn sync_collapsed_clues(&self) {
+        let mut slot = 0;
+        for &idx in self.horizontal_display_order.iter() {
+            let Some(clue_ui) = self.horizontal_clue_uis.get(idx) else {
+                continue;
+            };
+            let clue_address = ClueAddress {
+                orientation: ClueOrientation::Horizontal,
+                index: idx,
+            };
+            let frame = clue_ui.borrow().frame.clone();
+            let collapsed =
+                self.collapse_completed_clues && self.completed_clues.contains(&clue_address);
+            frame.set_visible(!collapsed);
+            if collapsed {
+                continue;
+            }
+
+            let grid_col = (slot / self.clues_per_column) as i32;
+            let grid_row = (slot % self.clues_per_column) as i32;
+            let (current_col, current_row, _, _) = self.horizontal_grid.query_child(&frame);
+            if (current_col, current_row) != (grid_col, grid_row) {
+                self.horizontal_grid.remove(&frame);
+                self.horizontal_grid
+                    .attach(&frame, grid_col, grid_row, 1, 1);
+            }
+            slot += 1;
+        }
     }
 
     fn update_tooltip_visibility(&mut self, enabled: bool) {
@@ -345,7 +602,7 @@ impl CluePanelsUI {
             .set_row_spacing(layout.clues.horizontal_clue_panel.row_spacing as u32);
         self.horizontal_grid
             .set_column_spacing(layout.clues.horizontal_clue_panel.column_spacing as u32);
-        self.horizontal_grid
+        self.horizontal_panel
             .set_margin_start(layout.clues.horizontal_clue_panel.left_margin);
         let horiz_dim = &self
             .current_layout
@@ -359,7 +616,7 @@ impl CluePanelsUI {
         self.vertical_grid.set_row_spacing(0);
         self.vertical_grid
             .set_column_spacing(layout.clues.vertical_clue_panel.column_spacing as u32);
-        self.vertical_grid
+        self.vertical_panel
             .set_margin_top(layout.clues.vertical_clue_panel.margin_top);
         self.vertical_grid.set_size_request(
             -1,
@@ -376,6 +633,7 @@ impl CluePanelsUI {
         for clue_ui in self.vertical_clue_uis.iter_mut() {
             clue_ui.borrow_mut().update_layout(layout);
         }
+        self.sync_panel_headers();
     }
 
     pub fn calc_clues_per_column(difficulty: Difficulty) -> usize {
@@ -383,6 +641,16 @@ impl CluePanelsUI {
         n_rows * 2
     }
 
+    /// Hide the panels without collapsing them, so the layout doesn't shift and selection and
+    /// completion state are intact when they're shown again
+    fn set_clues_hidden(&self, clues_hidden: bool) {
+        let opacity = if clues_hidden { 0.0 } else { 1.0 };
+        for grid in [&self.horizontal_grid, &self.vertical_grid] {
+            grid.set_opacity(opacity);
+            grid.set_can_target(!clues_hidden);
+        }
+    }
+
     fn set_clue_selected(&self, clue_selection: &Option<ClueSelection>) {
         // dispatch to all clues
         for clue_ui in &self.horizontal_clue_uis {
@@ -6,35 +6,46 @@ use crate::destroyable::Destroyable;
 use crate::model::{Clue, ClueType, CluesSizing, HorizontalClueType, Tile, VerticalClueType};
 use gtk4::glib::{timeout_add_local_once, SourceId};
 use gtk4::prelude::*;
-use gtk4::{Frame, Image, Overlay, Widget};
+use gtk4::{Frame, Image, Label, Overlay, Widget};
 
 use super::ImageSet;
 
+const COMPACT_NEGATIVE_CSS_CLASS: &str = "negative-assertion-compact";
+
 enum Decoration {
     Negative,
     NotAdjacent,
     Maybe,
+    Ordered,
+    Between,
 }
 
 enum ClueTileContents {
     TileAssertion(Tile, Option<Decoration>),
     LeftOf, /* show the LeftOf icon */
+    Column(usize),
     None,
 }
 
 pub struct ClueTileUI {
     pub frame: Frame,
     pub overlay: Overlay,
-    image: Image,       // Main tile image
-    x_image: Image,     // Red X for negative assertions
-    maybe_image: Image, // Question mark for maybe assertions
-    left_of: Image,     // LeftOf clues
+    image: Image,        // Main tile image
+    x_image: Image,      // Red X for negative assertions
+    maybe_image: Image,  // Question mark for maybe assertions
+    left_of: Image,      // LeftOf clues
+    column_label: Label, // column number of InColumn clues
+    // "¬" marker for compact negative assertions
+    negative_badge: Label,
     highlight_frame: Arc<Frame>,
     decoration_frame: Arc<Frame>, // For red border on negative assertions or yellow for maybe
     resources: Rc<ImageSet>,
     highlight_timeout: Rc<RefCell<Option<SourceId>>>, // Track active highlight timeout
     clue: Option<Clue>,
     idx: usize, // 0..2, index of the clue cell, not the clueset
+    compact_negatives: bool,
+    tile_size: i32,
+    compact_tile_size: i32,
 }
 
 impl ClueTileUI {
@@ -69,6 +80,21 @@ impl ClueTileUI {
         left_of.set_halign(gtk4::Align::Center);
         left_of.set_valign(gtk4::Align::Center);
 
+        let column_label = Label::builder()
+            .visible(false)
+            .css_classes(["clue-column-number"])
+            .halign(gtk4::Align::Center)
+            .valign(gtk4::Align::Center)
+            .build();
+
+        let negative_badge = Label::builder()
+            .label("¬")
+            .visible(false)
+            .css_classes(["negative-assertion-badge"])
+            .halign(gtk4::Align::End)
+            .valign(gtk4::Align::Start)
+            .build();
+
         let highlight_frame = Frame::new(None);
         highlight_frame.set_visible(false);
 
@@ -81,6 +107,8 @@ impl ClueTileUI {
         overlay.add_overlay(&x_image);
         overlay.add_overlay(&maybe_image);
         overlay.add_overlay(&left_of);
+        overlay.add_overlay(&column_label);
+        overlay.add_overlay(&negative_badge);
         overlay.add_overlay(highlight_frame.upcast_ref::<Widget>());
         overlay.add_overlay(decoration_frame.upcast_ref::<Widget>());
 
@@ -93,18 +121,29 @@ impl ClueTileUI {
             x_image,
             maybe_image,
             left_of,
+            column_label,
+            negative_badge,
             highlight_frame: Arc::new(highlight_frame),
             decoration_frame: Arc::new(decoration_frame),
             resources,
             highlight_timeout: Rc::new(RefCell::new(None)),
             clue,
             idx,
+            compact_negatives: false,
+            tile_size: 0,
+            compact_tile_size: 0,
         }
     }
 
-    pub fn update_layout(&self, layout: &CluesSizing) {
-        // Update main image size
-        self.image.set_pixel_size(layout.clue_tile_size.width);
+    pub fn update_layout(&mut self, layout: &CluesSizing) {
+        self.tile_size = layout.clue_tile_size.width;
+        self.compact_tile_size = layout.clue_annotation_size.width;
+
+        // Update main image size; a compact negative tile keeps the full allocation so the clue
+        // still fits its dimensions
+        self.image
+            .set_size_request(layout.clue_tile_size.width, layout.clue_tile_size.height);
+        self.image.set_pixel_size(self.image_pixel_size());
         self.left_of.set_pixel_size(layout.clue_tile_size.width);
 
         // Update decoration sizes and force a queue_resize
@@ -116,17 +155,45 @@ impl ClueTileUI {
 
     pub fn set_clue(&mut self, clue: Option<&Clue>) {
         self.clue = clue.cloned();
-        // reset decorations
+        self.reset_decorations();
+        self.sync_images();
+    }
+
+    pub fn set_compact_negatives(&mut self, compact_negatives: bool) {
+        if self.compact_negatives != compact_negatives {
+            self.compact_negatives = compact_negatives;
+            self.reset_decorations();
+            self.sync_images();
+        }
+    }
+
+    fn reset_decorations(&self) {
         self.highlight_frame.set_visible(false);
         self.maybe_image.set_visible(false);
         self.x_image.set_visible(false);
         self.left_of.set_visible(false);
+        self.column_label.set_visible(false);
+        self.negative_badge.set_visible(false);
         self.decoration_frame.set_visible(false);
+        if self.image.has_css_class(COMPACT_NEGATIVE_CSS_CLASS) {
+            self.image.remove_css_class(COMPACT_NEGATIVE_CSS_CLASS);
+            self.image.set_pixel_size(self.tile_size);
+        }
+    }
 
-        self.sync_images();
+    fn image_pixel_size(&self) -> i32 {
+        if self.image.has_css_class(COMPACT_NEGATIVE_CSS_CLASS) {
+            self.compact_tile_size
+        } else {
+            self.tile_size
+        }
     }
 
     fn set_negative(&self) {
+        if self.compact_negatives {
+            self.set_compact_negative();
+            return;
+        }
         let x_pixbuf = self.resources.get_negative_assertion();
         self.x_image.set_paintable(Some(x_pixbuf.as_ref()));
         self.x_image.set_visible(true);
@@ -137,6 +204,15 @@ impl ClueTileUI {
         self.decoration_frame.set_visible(true);
     }
 
+    fn set_compact_negative(&self) {
+        self.image.add_css_class(COMPACT_NEGATIVE_CSS_CLASS);
+        self.image.set_pixel_size(self.compact_tile_size);
+        self.negative_badge.set_visible(true);
+        self.x_image.set_visible(false);
+        self.maybe_image.set_visible(false);
+        self.decoration_frame.set_visible(false);
+    }
+
     fn set_maybe(&self) {
         let paintable = if self.idx == 1 {
             self.resources.get_maybe_assertion_top()
@@ -161,6 +237,17 @@ impl ClueTileUI {
         self.decoration_frame.set_visible(true);
     }
 
+    fn set_ordered(&self) {
+        self.decoration_frame
+            .set_css_classes(&["ordered-adjacent-frame"]);
+        self.decoration_frame.set_visible(true);
+    }
+
+    fn set_between(&self) {
+        self.decoration_frame.set_css_classes(&["between-frame"]);
+        self.decoration_frame.set_visible(true);
+    }
+
     pub(crate) fn highlight_for(&self, from_secs: std::time::Duration) {
         // Cancel any existing timeout
         if let Some(source_id) = self.highlight_timeout.take() {
@@ -202,15 +289,16 @@ impl ClueTileUI {
 
             match my_tile_info {
                 ClueTileContents::TileAssertion(tile, decoration) => {
-                    if let Some(paintable) = self.resources.get_solution_icon(&tile) {
-                        self.image.set_paintable(Some(paintable.as_ref()));
-                        self.image.set_visible(true);
-                    }
+                    let paintable = self.resources.get_solution_icon(&tile);
+                    self.image.set_paintable(Some(paintable.as_ref()));
+                    self.image.set_visible(true);
                     if let Some(decoration) = decoration {
                         match decoration {
                             Decoration::Negative => self.set_negative(),
                             Decoration::Maybe => self.set_maybe(),
                             Decoration::NotAdjacent => self.set_not_adjacent(),
+                            Decoration::Ordered => self.set_ordered(),
+                            Decoration::Between => self.set_between(),
                         }
                     }
                 }
@@ -220,6 +308,12 @@ impl ClueTileUI {
                     self.left_of.set_visible(true);
                     self.image.clear();
                 }
+                ClueTileContents::Column(column) => {
+                    // counted from 1, as in the clue description
+                    self.column_label.set_text(&format!("@{}", column + 1));
+                    self.column_label.set_visible(true);
+                    self.image.clear();
+                }
                 ClueTileContents::None => {
                     self.image.clear();
                 }
@@ -235,6 +329,35 @@ impl ClueTileUI {
                 2 => ClueTileContents::TileAssertion(clue.assertions[1].tile, None),
                 _ => ClueTileContents::None,
             },
+            ClueType::Horizontal(HorizontalClueType::AdjacentLeftOf) => match idx {
+                0 | 1 => ClueTileContents::TileAssertion(
+                    clue.assertions[idx].tile,
+                    Some(Decoration::Ordered),
+                ),
+                _ => ClueTileContents::None,
+            },
+            ClueType::Horizontal(HorizontalClueType::ThreeAdjacentOrdered) => match idx {
+                0..=2 => ClueTileContents::TileAssertion(
+                    clue.assertions[idx].tile,
+                    Some(Decoration::Ordered),
+                ),
+                _ => ClueTileContents::None,
+            },
+            ClueType::Horizontal(HorizontalClueType::InColumn) => match idx {
+                0 => ClueTileContents::TileAssertion(clue.assertions[0].tile, None),
+                1 => clue
+                    .column
+                    .map_or(ClueTileContents::None, ClueTileContents::Column),
+                _ => ClueTileContents::None,
+            },
+            ClueType::Horizontal(HorizontalClueType::Between) => match idx {
+                0 | 2 => ClueTileContents::TileAssertion(clue.assertions[idx].tile, None),
+                1 => ClueTileContents::TileAssertion(
+                    clue.assertions[1].tile,
+                    Some(Decoration::Between),
+                ),
+                _ => ClueTileContents::None,
+            },
             ClueType::Horizontal(HorizontalClueType::NotAdjacent) => match idx {
                 0 => ClueTileContents::TileAssertion(
                     clue.assertions[0].tile,
@@ -246,6 +369,20 @@ impl ClueTileUI {
                 ),
                 _ => ClueTileContents::None,
             },
+            ClueType::Vertical(VerticalClueType::TwoInColumnNonAdjacentRows) => match idx {
+                0 | 1 => ClueTileContents::TileAssertion(
+                    clue.assertions[idx].tile,
+                    Some(Decoration::NotAdjacent),
+                ),
+                _ => ClueTileContents::None,
+            },
+            ClueType::Vertical(VerticalClueType::TwoOfThreeInColumn) => match idx {
+                0 | 1 | 2 => ClueTileContents::TileAssertion(
+                    clue.assertions[idx].tile,
+                    Some(Decoration::Maybe),
+                ),
+                _ => ClueTileContents::None,
+            },
             ClueType::Vertical(VerticalClueType::OneMatchesEither) => match idx {
                 0 => ClueTileContents::TileAssertion(clue.assertions[0].tile, None),
                 1 => ClueTileContents::TileAssertion(
@@ -292,6 +429,7 @@ impl Drop for ClueTileUI {
         self.overlay.remove_overlay(&self.x_image);
         self.overlay.remove_overlay(&self.maybe_image);
         self.overlay.remove_overlay(&self.left_of);
+        self.overlay.remove_overlay(&self.negative_badge);
         self.overlay.remove_overlay(self.highlight_frame.as_ref());
         self.overlay.remove_overlay(self.decoration_frame.as_ref());
 
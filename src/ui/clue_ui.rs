@@ -39,6 +39,8 @@ pub struct ClueUI {
     gesture_right: Option<gtk4::GestureClick>,
     gesture_left: Option<gtk4::GestureClick>,
     clue_spotlight_enabled: bool,
+    tooltips_enabled: bool,
+    locked: bool,
 }
 
 fn tooltip_rect(layout: &CluesSizing) -> Rectangle {
@@ -105,6 +107,8 @@ impl ClueUI {
             gesture_right: None,
             gesture_left: None,
             clue_spotlight_enabled,
+            tooltips_enabled,
+            locked: false,
         };
         let clue_ui_ref = Rc::new(RefCell::new(clue_ui));
         ClueUI::wire_handlers(clue_ui_ref.clone());
@@ -135,7 +139,34 @@ impl ClueUI {
     }
 
     pub fn set_tooltips_enabled(&mut self, enabled: bool) {
-        self.frame.set_has_tooltip(enabled);
+        self.tooltips_enabled = enabled;
+        self.sync_tooltip_visibility();
+    }
+
+    fn sync_tooltip_visibility(&self) {
+        // a locked clue shouldn't give away its meaning via the tooltip
+        self.frame
+            .set_has_tooltip(self.tooltips_enabled && !self.locked);
+    }
+
+    /// Locked clues show only their shape, greyed out; the tiles are revealed once unlocked
+    pub fn set_locked(&mut self, locked: bool) {
+        if self.locked == locked {
+            return;
+        }
+        self.locked = locked;
+        if locked {
+            self.frame.add_css_class("locked");
+        } else {
+            self.frame.remove_css_class("locked");
+        }
+        for clue_tile in &self.clue_tiles {
+            // keep the overlay allocated so the clue keeps its shape
+            clue_tile
+                .overlay
+                .set_opacity(if locked { 0.0 } else { 1.0 });
+        }
+        self.sync_tooltip_visibility();
     }
 
     fn wire_handlers(clue_ui: Rc<RefCell<Self>>) {
@@ -173,7 +204,7 @@ impl ClueUI {
         clue_ui.gesture_left = Some(gesture_left);
     }
 
-    fn apply_layout(&self) {
+    fn apply_layout(&mut self) {
         match self.clue.address().orientation {
             ClueOrientation::Horizontal => {
                 self.frame.set_size_request(
@@ -201,7 +232,7 @@ impl ClueUI {
         self.grid.set_margin_end(self.layout.clue_padding);
 
         // Update individual tile sizes
-        for cell in &self.clue_tiles {
+        for cell in &mut self.clue_tiles {
             cell.update_layout(&self.layout);
         }
     }
@@ -257,6 +288,15 @@ impl ClueUI {
         }
     }
 
+    /// Dim a clue that has nothing to offer on the current board; lighter than completed
+    pub fn set_inactive(&self, inactive: bool) {
+        if inactive {
+            self.frame.add_css_class("inactive");
+        } else {
+            self.frame.remove_css_class("inactive");
+        }
+    }
+
     fn create_tooltip_widget(&self) -> Box {
         let rect = tooltip_rect(&self.layout);
         let tooltip_box = Box::builder()
@@ -343,6 +383,12 @@ impl ClueUI {
         self.clue_spotlight_enabled = enabled;
     }
 
+    pub(crate) fn set_compact_negative_tiles(&mut self, compact: bool) {
+        for clue_tile in &mut self.clue_tiles {
+            clue_tile.set_compact_negatives(compact);
+        }
+    }
+
     pub(crate) fn set_image_set(&mut self, image_set: Rc<ImageSet>) {
         self.resources = image_set;
         self.sync_images();
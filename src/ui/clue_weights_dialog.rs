@@ -0,0 +1,179 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use glib::Propagation;
+use gtk4::{
+    gdk, prelude::*, Align, ApplicationWindow, Button, DropDown, EventControllerKey, Grid, Label,
+    Orientation,
+};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    game::settings::Settings,
+    model::{ClueType, GameEngineCommand, GameEngineEvent, SettingsChange},
+};
+use fluent_i18n::t;
+
+/// Heaviest weight the dialog offers
+const MAX_WEIGHT: u32 = 10;
+
+/// Lets the player change how often each clue type is picked for new puzzles, or leave a type
+/// out altogether
+pub struct ClueWeightsDialog {
+    window: Rc<ApplicationWindow>,
+    game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    clue_type_weights: HashMap<ClueType, u32>,
+}
+
+impl Destroyable for ClueWeightsDialog {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for ClueWeightsDialog {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::SettingsChanged(settings) = event {
+            self.clue_type_weights = settings.clue_type_weights.clone();
+        }
+    }
+}
+
+impl ClueWeightsDialog {
+    pub fn new(
+        window: &Rc<ApplicationWindow>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+        initial_settings: &Settings,
+    ) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            window: window.clone(),
+            game_engine_command_emitter,
+            clue_type_weights: initial_settings.clue_type_weights.clone(),
+        }))
+    }
+
+    /// The dropdown offers the default first, then weights 0 through `MAX_WEIGHT`
+    fn selector_index(weight: Option<u32>) -> u32 {
+        weight.map_or(0, |weight| weight.min(MAX_WEIGHT) + 1)
+    }
+
+    fn selector_weight(index: u32) -> Option<u32> {
+        index.checked_sub(1)
+    }
+
+    pub fn show(&self) {
+        let vbox = gtk4::Box::builder()
+            .orientation(Orientation::Vertical)
+            .spacing(10)
+            .margin_start(20)
+            .margin_end(20)
+            .margin_top(20)
+            .margin_bottom(20)
+            .build();
+        let modal = gtk4::Window::builder()
+            .title(&t!("clue-weights-title"))
+            .modal(true)
+            .resizable(false)
+            .child(&vbox)
+            .transient_for(self.window.as_ref())
+            .build();
+
+        let help_label = Label::builder()
+            .label(&t!("clue-weights-help"))
+            .wrap(true)
+            .max_width_chars(50)
+            .halign(Align::Start)
+            .css_classes(["dim-label"])
+            .build();
+        vbox.append(&help_label);
+
+        let choices: Vec<String> = [t!("clue-weights-default"), t!("clue-weights-off")]
+            .into_iter()
+            .chain((1..=MAX_WEIGHT).map(|weight| weight.to_string()))
+            .collect();
+        let choices: Vec<&str> = choices.iter().map(|choice| choice.as_str()).collect();
+
+        let grid = Grid::builder().row_spacing(6).column_spacing(20).build();
+        let selectors: Vec<(ClueType, DropDown)> = ClueType::all()
+            .into_iter()
+            .enumerate()
+            .map(|(row, clue_type)| {
+                let title = Label::builder()
+                    .label(&clue_type.get_title())
+                    .halign(Align::Start)
+                    .build();
+                let selector = DropDown::from_strings(&choices);
+                selector.set_selected(Self::selector_index(
+                    self.clue_type_weights.get(&clue_type).copied(),
+                ));
+                grid.attach(&title, 0, row as i32, 1, 1);
+                grid.attach(&selector, 1, row as i32, 1, 1);
+                (clue_type, selector)
+            })
+            .collect();
+        vbox.append(&grid);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .spacing(10)
+            .halign(Align::End)
+            .build();
+        let reset_button = Button::builder().label(&t!("clue-weights-reset")).build();
+        let cancel_button = Button::builder().label(&t!("cancel")).build();
+        let apply_button = Button::builder()
+            .label(&t!("clue-weights-apply"))
+            .css_classes(["suggested-action"])
+            .build();
+        button_box.append(&reset_button);
+        button_box.append(&cancel_button);
+        button_box.append(&apply_button);
+        vbox.append(&button_box);
+
+        reset_button.connect_clicked({
+            let selectors = selectors.clone();
+            move |_| {
+                for (_, selector) in selectors.iter() {
+                    selector.set_selected(Self::selector_index(None));
+                }
+            }
+        });
+        cancel_button.connect_clicked({
+            let modal = modal.clone();
+            move |_| {
+                modal.close();
+            }
+        });
+        apply_button.connect_clicked({
+            let modal = modal.clone();
+            let game_engine_command_emitter = self.game_engine_command_emitter.clone();
+            move |_| {
+                let clue_type_weights = selectors
+                    .iter()
+                    .filter_map(|(clue_type, selector)| {
+                        Self::selector_weight(selector.selected())
+                            .map(|weight| (*clue_type, weight))
+                    })
+                    .collect();
+                game_engine_command_emitter.emit(GameEngineCommand::ChangeSettings(
+                    SettingsChange {
+                        clue_type_weights: Some(clue_type_weights),
+                        ..Default::default()
+                    },
+                ));
+                modal.close();
+            }
+        });
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let modal = modal.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    modal.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        modal.add_controller(key_controller);
+
+        modal.present();
+    }
+}
@@ -0,0 +1,81 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gtk4::{prelude::*, Button};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    game::{daily, stats_manager::StatsManager},
+    model::{GameEngineCommand, GameEngineEvent, PuzzleCompletionState},
+};
+use fluent_i18n::t;
+
+/// Header button starting today's daily puzzle, with a checkmark once it's been solved
+pub struct DailyPuzzleUI {
+    pub button: Button,
+    stats_manager: Rc<RefCell<StatsManager>>,
+}
+
+impl Destroyable for DailyPuzzleUI {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for DailyPuzzleUI {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        match event {
+            // the completion is recorded by another handler of this event, so go by the stats
+            GameEngineEvent::PuzzleCompleted(PuzzleCompletionState::Correct(stats)) => {
+                let is_todays_daily = stats
+                    .seed
+                    .and_then(|seed| daily::daily_date(stats.difficulty, seed))
+                    == Some(daily::today());
+                if is_todays_daily {
+                    self.show_completed(true);
+                }
+            }
+            // a new puzzle may come after midnight, when the daily has moved on
+            GameEngineEvent::ClueSetUpdated(..) => self.refresh(),
+            _ => {}
+        }
+    }
+}
+
+impl DailyPuzzleUI {
+    pub fn new(
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+        stats_manager: &Rc<RefCell<StatsManager>>,
+    ) -> Rc<RefCell<Self>> {
+        let button = Button::new();
+        button.connect_clicked(move |_| {
+            game_engine_command_emitter.emit(GameEngineCommand::NewDailyPuzzle);
+        });
+
+        let daily_puzzle_ui = Self {
+            button,
+            stats_manager: stats_manager.clone(),
+        };
+        daily_puzzle_ui.refresh();
+        Rc::new(RefCell::new(daily_puzzle_ui))
+    }
+
+    fn refresh(&self) {
+        let completed = self
+            .stats_manager
+            .borrow()
+            .is_daily_completed(daily::today());
+        self.show_completed(completed);
+    }
+
+    fn show_completed(&self, completed: bool) {
+        if completed {
+            self.button
+                .set_label(&format!("✓ {}", t!("daily-puzzle-button")));
+            self.button
+                .set_tooltip_text(Some(&t!("daily-puzzle-completed-tooltip")));
+        } else {
+            self.button.set_label(&t!("daily-puzzle-button"));
+            self.button
+                .set_tooltip_text(Some(&t!("daily-puzzle-tooltip")));
+        }
+    }
+}
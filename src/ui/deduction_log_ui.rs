@@ -0,0 +1,160 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use gtk4::{
+    prelude::*, Align, ApplicationWindow, IconTheme, Orientation, ScrolledWindow, TextBuffer,
+    TextView, WrapMode,
+};
+
+use crate::{
+    destroyable::Destroyable,
+    events::EventHandler,
+    game::settings::Settings,
+    model::{ClueSet, Deduction, DeductionLogEntry, GameEngineEvent, LayoutManagerEvent},
+};
+use fluent_i18n::t;
+
+use super::{template::TemplateParser, ImageSet};
+
+/// Side panel listing which clue each hint drew its deductions from
+pub struct DeductionLogUI {
+    window: Rc<ApplicationWindow>,
+    resources: Rc<ImageSet>,
+    pub panel: gtk4::Box,
+    log_text: TextView,
+    buffer: TextBuffer,
+    clue_set: Option<Arc<ClueSet>>,
+    entries: Vec<DeductionLogEntry>,
+    enabled: bool,
+}
+
+impl Destroyable for DeductionLogUI {
+    fn destroy(&mut self) {
+        // Subscriptions are handled centrally via `subscribe_component`
+    }
+}
+
+impl EventHandler<GameEngineEvent> for DeductionLogUI {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        match event {
+            GameEngineEvent::ClueSetUpdated(clue_set, _, _) => {
+                self.clue_set = Some(clue_set.clone());
+                self.sync_log_text();
+            }
+            GameEngineEvent::DeductionLogUpdated(entries) => {
+                self.entries = entries.clone();
+                self.sync_log_text();
+            }
+            GameEngineEvent::SettingsChanged(settings) => {
+                self.enabled = settings.log_hint_deductions;
+                self.sync_log_text();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl EventHandler<LayoutManagerEvent> for DeductionLogUI {
+    fn handle_event(&mut self, event: &LayoutManagerEvent) {
+        if let LayoutManagerEvent::ImagesOptimized(image_set) = event {
+            self.resources = image_set.clone();
+            self.sync_log_text();
+        }
+    }
+}
+
+impl DeductionLogUI {
+    pub fn new(
+        window: &Rc<ApplicationWindow>,
+        resources: &Rc<ImageSet>,
+        settings: &Settings,
+    ) -> Rc<RefCell<Self>> {
+        let buffer = TextBuffer::builder().build();
+        let log_text = TextView::builder()
+            .buffer(&buffer)
+            .editable(false)
+            .cursor_visible(false)
+            .css_classes(["deduction-log-text"])
+            .wrap_mode(WrapMode::Word)
+            .build();
+
+        let scrolled_window = ScrolledWindow::builder()
+            .child(&log_text)
+            .vexpand(true)
+            .hscrollbar_policy(gtk4::PolicyType::Never)
+            .vscrollbar_policy(gtk4::PolicyType::Automatic)
+            .build();
+
+        let panel = gtk4::Box::builder()
+            .name("deduction-log-box")
+            .orientation(Orientation::Vertical)
+            .valign(Align::Fill)
+            .visible(settings.log_hint_deductions)
+            .build();
+        panel.append(&scrolled_window);
+
+        let deduction_log_ui = Rc::new(RefCell::new(Self {
+            window: window.clone(),
+            resources: resources.clone(),
+            panel,
+            log_text,
+            buffer,
+            clue_set: None,
+            entries: vec![],
+            enabled: settings.log_hint_deductions,
+        }));
+        deduction_log_ui.borrow().sync_log_text();
+
+        deduction_log_ui
+    }
+
+    fn deduction_template(deduction: &Deduction) -> String {
+        let action = if deduction.is_positive() {
+            t!("action-must-be")
+        } else {
+            t!("action-cannot-be")
+        };
+        t!("deduction-log-deduction", {
+            "tile" => deduction.tile_assertion.tile.to_string(),
+            "column" => (deduction.column + 1).to_string(),
+            "action" => action
+        })
+    }
+
+    fn entry_template(&self, entry: &DeductionLogEntry) -> String {
+        let clue_description = entry
+            .clue
+            .and_then(|address| {
+                self.clue_set
+                    .as_ref()
+                    .and_then(|clue_set| clue_set.get_clue(address))
+            })
+            .map(|clue| clue.clue.description())
+            .unwrap_or_else(|| t!("deduction-log-hidden-set"));
+
+        let mut lines = vec![clue_description];
+        lines.extend(entry.deductions.iter().map(Self::deduction_template));
+        lines.join("\n")
+    }
+
+    fn sync_log_text(&self) {
+        self.panel.set_visible(self.enabled);
+        if !self.enabled {
+            return;
+        }
+
+        let mut start = self.buffer.start_iter();
+        let mut end = self.buffer.end_iter();
+        self.buffer.delete(&mut start, &mut end);
+
+        let mut sections = vec![t!("deduction-log-title")];
+        if self.entries.is_empty() {
+            sections.push(t!("deduction-log-empty"));
+        }
+        sections.extend(self.entries.iter().map(|entry| self.entry_template(entry)));
+
+        let display = WidgetExt::display(self.window.as_ref());
+        let theme = IconTheme::for_display(&display);
+        let parser = TemplateParser::new(self.resources.clone(), Some(Rc::new(theme)));
+        parser.append_to_text_buffer(&self.log_text, &mut end, &sections.join("\n\n"));
+    }
+}
@@ -0,0 +1,269 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glib::Propagation;
+use gtk4::{
+    gdk, prelude::*, Align, ApplicationWindow, Button, Entry, EventControllerKey, Grid, Label,
+    Orientation, ScrolledWindow,
+};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    game::{favorites::Favorites, stats_manager::StatsManager},
+    model::{Difficulty, GameEngineCommand, GameEngineEvent},
+};
+use fluent_i18n::t;
+
+/// Lists the puzzles the player saved as favorites so they can be replayed
+pub struct FavoritesDialog {
+    window: Rc<ApplicationWindow>,
+    game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    stats_manager: Rc<RefCell<StatsManager>>,
+    favorites: Rc<RefCell<Favorites>>,
+    current_puzzle: Option<(Difficulty, u64)>,
+}
+
+impl Destroyable for FavoritesDialog {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for FavoritesDialog {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::GameBoardUpdated { board, .. } = event {
+            self.current_puzzle = board
+                .solution
+                .puzzle_code()
+                .map(|code| (code.difficulty, code.seed));
+        }
+    }
+}
+
+impl FavoritesDialog {
+    pub fn new(
+        window: &Rc<ApplicationWindow>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+        stats_manager: &Rc<RefCell<StatsManager>>,
+    ) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            window: window.clone(),
+            game_engine_command_emitter,
+            stats_manager: stats_manager.clone(),
+            favorites: Rc::new(RefCell::new(Favorites::new())),
+            current_puzzle: None,
+        }))
+    }
+
+    /// Add the puzzle being played to the favorites, without a label
+    pub fn add_current(&self) {
+        if let Some((difficulty, seed)) = self.current_puzzle {
+            if let Err(e) = self.favorites.borrow_mut().add(difficulty, seed, None) {
+                log::error!(target: "favorites", "Failed to save favorites: {}", e);
+            }
+        }
+    }
+
+    fn refresh_grid(
+        grid: &Grid,
+        modal: &gtk4::Window,
+        favorites: &Rc<RefCell<Favorites>>,
+        stats_manager: &Rc<RefCell<StatsManager>>,
+        game_engine_command_emitter: &EventEmitter<GameEngineCommand>,
+    ) {
+        while let Some(child) = grid.first_child() {
+            grid.remove(&child);
+        }
+
+        if favorites.borrow().entries().is_empty() {
+            let label = Label::new(Some(&t!("favorites-empty")));
+            label.set_halign(Align::Start);
+            grid.attach(&label, 0, 0, 1, 1);
+            return;
+        }
+
+        let headers = [
+            &t!("favorites-puzzle"),
+            &t!("stats-difficulty"),
+            &t!("archive-status"),
+        ];
+        for (i, header) in headers.iter().enumerate() {
+            let label = Label::new(Some(header));
+            label.set_markup(&format!("<b>{}</b>", header));
+            label.set_halign(Align::Start);
+            grid.attach(&label, i as i32, 0, 1, 1);
+        }
+
+        let entries = favorites.borrow().entries().to_vec();
+        for (i, favorite) in entries.into_iter().enumerate() {
+            let row_index = (i + 1) as i32;
+            let (difficulty, seed) = (favorite.difficulty, favorite.seed);
+
+            let name_label = Label::new(Some(&favorite.display_name()));
+            name_label.set_halign(Align::Start);
+            name_label.set_selectable(true);
+            name_label.set_tooltip_text(Some(&favorite.puzzle_code().to_string()));
+            grid.attach(&name_label, 0, row_index, 1, 1);
+
+            let difficulty_label = Label::new(Some(&favorite.difficulty.to_string()));
+            difficulty_label.set_halign(Align::Start);
+            grid.attach(&difficulty_label, 1, row_index, 1, 1);
+
+            let status = if stats_manager.borrow().is_seed_completed(difficulty, seed) {
+                t!("archive-completed")
+            } else {
+                t!("archive-not-completed")
+            };
+            let status_label = Label::new(Some(&status));
+            status_label.set_halign(Align::Start);
+            grid.attach(&status_label, 2, row_index, 1, 1);
+
+            let play_button = Button::builder().label(&t!("archive-play")).build();
+            play_button.connect_clicked({
+                let modal = modal.clone();
+                let game_engine_command_emitter = game_engine_command_emitter.clone();
+                move |_| {
+                    game_engine_command_emitter
+                        .emit(GameEngineCommand::NewGame(Some(difficulty), Some(seed)));
+                    modal.close();
+                }
+            });
+            grid.attach(&play_button, 3, row_index, 1, 1);
+
+            let remove_button = Button::builder().label(&t!("favorites-remove")).build();
+            remove_button.connect_clicked({
+                let grid = grid.clone();
+                let modal = modal.clone();
+                let favorites = favorites.clone();
+                let stats_manager = stats_manager.clone();
+                let game_engine_command_emitter = game_engine_command_emitter.clone();
+                move |_| {
+                    if let Err(e) = favorites.borrow_mut().remove(difficulty, seed) {
+                        log::error!(target: "favorites", "Failed to save favorites: {}", e);
+                    }
+                    Self::refresh_grid(
+                        &grid,
+                        &modal,
+                        &favorites,
+                        &stats_manager,
+                        &game_engine_command_emitter,
+                    );
+                }
+            });
+            grid.attach(&remove_button, 4, row_index, 1, 1);
+        }
+    }
+
+    pub fn show(&self) {
+        let vbox = gtk4::Box::builder()
+            .orientation(Orientation::Vertical)
+            .spacing(10)
+            .margin_start(20)
+            .margin_end(20)
+            .margin_top(20)
+            .margin_bottom(20)
+            .build();
+        let modal = gtk4::Window::builder()
+            .title(&t!("favorites-title"))
+            .modal(true)
+            .default_width(500)
+            .default_height(500)
+            .child(&vbox)
+            .transient_for(self.window.as_ref())
+            .build();
+
+        let grid = Grid::new();
+        grid.set_row_spacing(5);
+        grid.set_column_spacing(10);
+
+        let add_row = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .spacing(10)
+            .build();
+        let label_entry = Entry::builder()
+            .placeholder_text(&t!("favorites-label-placeholder"))
+            .hexpand(true)
+            .build();
+        let add_button = Button::builder()
+            .label(&t!("favorites-add-current"))
+            .sensitive(self.current_puzzle.is_some())
+            .build();
+        add_row.append(&label_entry);
+        add_row.append(&add_button);
+        vbox.append(&add_row);
+
+        let add_current = {
+            let grid = grid.clone();
+            let modal = modal.clone();
+            let label_entry = label_entry.clone();
+            let favorites = self.favorites.clone();
+            let stats_manager = self.stats_manager.clone();
+            let game_engine_command_emitter = self.game_engine_command_emitter.clone();
+            let current_puzzle = self.current_puzzle;
+            move || {
+                let Some((difficulty, seed)) = current_puzzle else {
+                    return;
+                };
+                let label = Some(label_entry.text().to_string());
+                if let Err(e) = favorites.borrow_mut().add(difficulty, seed, label) {
+                    log::error!(target: "favorites", "Failed to save favorites: {}", e);
+                }
+                label_entry.set_text("");
+                Self::refresh_grid(
+                    &grid,
+                    &modal,
+                    &favorites,
+                    &stats_manager,
+                    &game_engine_command_emitter,
+                );
+            }
+        };
+        add_button.connect_clicked({
+            let add_current = add_current.clone();
+            move |_| add_current()
+        });
+        label_entry.connect_activate(move |_| add_current());
+
+        Self::refresh_grid(
+            &grid,
+            &modal,
+            &self.favorites,
+            &self.stats_manager,
+            &self.game_engine_command_emitter,
+        );
+        let scrolled_window = ScrolledWindow::builder()
+            .child(&grid)
+            .vexpand(true)
+            .hscrollbar_policy(gtk4::PolicyType::Never)
+            .vscrollbar_policy(gtk4::PolicyType::Automatic)
+            .build();
+        vbox.append(&scrolled_window);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .halign(Align::End)
+            .build();
+        let close_button = Button::builder().label(&t!("close")).build();
+        button_box.append(&close_button);
+        vbox.append(&button_box);
+
+        close_button.connect_clicked({
+            let modal = modal.clone();
+            move |_| {
+                modal.close();
+            }
+        });
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let modal = modal.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    modal.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        modal.add_controller(key_controller);
+
+        modal.present();
+    }
+}
@@ -9,17 +9,24 @@ use std::{
 use glib::{timeout_add_local, SourceId};
 use gtk4::{prelude::*, Box, Label, Orientation};
 
+use fluent_i18n::t;
+
 use crate::{
     destroyable::Destroyable,
     events::EventHandler,
-    model::{GameEngineEvent, TimerState},
+    game::settings::Settings,
+    model::{format_hint_count, GameEngineEvent, PuzzleCompletionState, TimerState},
 };
 
 pub struct GameInfoUI {
     hints_used: u32,
+    /// Puzzles solved correctly since launch; not persisted
+    puzzles_completed: u32,
     timer_state: TimerState,
     pub timer_label: Label,
     pub hints_label: Label,
+    pub rating_label: Label,
+    pub completed_label: Label,
     timer: Option<SourceId>,
     pub game_box: Rc<Box>,
     pause_screen: Rc<Box>,
@@ -35,13 +42,21 @@ impl Destroyable for GameInfoUI {
 }
 
 impl GameInfoUI {
-    pub fn new(pause_screen: Rc<Box>) -> Rc<RefCell<Self>> {
-        // Create timer label with monospace font
+    pub fn new(pause_screen: Rc<Box>, settings: &Settings) -> Rc<RefCell<Self>> {
+        // Create timer label with monospace font; it keeps updating even while hidden
         let timer_label = Label::new(None);
         timer_label.set_css_classes(&["timer"]);
+        timer_label.set_visible(settings.show_timer_during_play);
         // Create hints label
         let hints_label = Label::new(Some("0"));
         hints_label.set_css_classes(&["hints"]);
+        // Create puzzle rating label; filled in once the puzzle is rated
+        let rating_label = Label::new(None);
+        rating_label.set_css_classes(&["puzzle-rating"]);
+        // Create session completion counter; hidden until the first puzzle is solved
+        let completed_label = Label::new(None);
+        completed_label.set_css_classes(&["puzzles-completed"]);
+        completed_label.set_visible(false);
 
         // Set up timer update
         let timer_state = TimerState::default();
@@ -61,9 +76,12 @@ impl GameInfoUI {
         );
         let game_info = Rc::new(RefCell::new(Self {
             hints_used: 0,
+            puzzles_completed: 0,
             timer_state,
             timer_label,
             hints_label,
+            rating_label,
+            completed_label,
             timer: None,
             game_box,
             pause_screen,
@@ -79,7 +97,32 @@ impl GameInfoUI {
 
     pub fn update_hints_used(&mut self, hints_used: u32) {
         self.hints_used = hints_used;
-        self.hints_label.set_text(&format!("{}", hints_used));
+        self.hints_label.set_text(&format_hint_count(hints_used));
+    }
+
+    pub fn update_puzzle_rating(&mut self, rating: Option<u8>) {
+        let Some(rating) = rating else {
+            self.rating_label.set_text("");
+            self.rating_label.set_tooltip_text(None);
+            return;
+        };
+        let rating = rating.min(5) as usize;
+        self.rating_label
+            .set_text(&format!("{}{}", "★".repeat(rating), "☆".repeat(5 - rating)));
+        self.rating_label.set_tooltip_text(Some(
+            &t!("puzzle-rating-tooltip", { "stars" => rating.to_string() }),
+        ));
+    }
+
+    fn increment_puzzles_completed(&mut self) {
+        self.puzzles_completed += 1;
+        self.completed_label
+            .set_text(&format!("✓ {}", self.puzzles_completed));
+        self.completed_label.set_tooltip_text(Some(&t!(
+            "puzzles-completed-tooltip",
+            { "count" => self.puzzles_completed.to_string() }
+        )));
+        self.completed_label.set_visible(true);
     }
 
     pub fn update_timer_state(&mut self, new_timer_state: &TimerState) {
@@ -131,11 +174,26 @@ impl GameInfoUI {
         }
     }
 
+    fn format_minutes_seconds(duration: Duration) -> String {
+        format!(
+            "{:02}:{:02}",
+            duration.as_secs() / 60,
+            duration.as_secs() % 60
+        )
+    }
+
     fn update_timer_label(timer_label: &Label, timer_state: &TimerState) {
-        let elapsed = timer_state.elapsed();
-        let minutes = elapsed.as_secs() / 60;
-        let seconds = elapsed.as_secs() % 60;
-        timer_label.set_text(&format!("{:02}:{:02}", minutes, seconds));
+        let elapsed = Self::format_minutes_seconds(timer_state.elapsed());
+        if timer_state.penalty_duration.is_zero() {
+            timer_label.set_text(&elapsed);
+            timer_label.set_tooltip_text(None);
+        } else {
+            let penalty = Self::format_minutes_seconds(timer_state.penalty_duration);
+            timer_label.set_text(&format!("{} (+{})", elapsed, penalty));
+            timer_label.set_tooltip_text(Some(&t!("timer-penalty-tooltip", {
+                "penalty" => penalty
+            })));
+        }
     }
 }
 
@@ -158,6 +216,16 @@ impl EventHandler<GameEngineEvent> for GameInfoUI {
             GameEngineEvent::HintUsageChanged(hints_used) => {
                 self.update_hints_used(*hints_used);
             }
+            GameEngineEvent::PuzzleRated(rating) => {
+                self.update_puzzle_rating(*rating);
+            }
+            GameEngineEvent::PuzzleCompleted(PuzzleCompletionState::Correct(_)) => {
+                self.increment_puzzles_completed();
+            }
+            GameEngineEvent::SettingsChanged(settings) => {
+                self.timer_label
+                    .set_visible(settings.show_timer_during_play);
+            }
             _ => {}
         }
     }
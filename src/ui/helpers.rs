@@ -31,10 +31,11 @@ pub fn register_left_click_handler<
         move |gesture, i, x, y| {
             if let Some(event_emitter) = event_emitter.upgrade() {
                 let clickable = handler(gesture, i, x, y);
-                press_start_for_press.set(Some(Instant::now()));
 
-                // Emit LeftClick immediately on press
+                // Emit LeftClick immediately on press. A press the handler dealt with itself
+                // isn't timed, so its release doesn't run the handler a second time.
                 if let Some(clickable) = clickable {
+                    press_start_for_press.set(Some(Instant::now()));
                     event_emitter.emit(InputEvent::LeftClick(clickable));
 
                     // Set up timer for long press
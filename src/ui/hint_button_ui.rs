@@ -62,12 +62,19 @@ impl HintButtonUI {
 
         hint_button.connect_clicked(move |button| {
             let board_is_incorrect = game_state.borrow().current_board.is_incorrect();
+            let can_restore_candidate = game_state
+                .borrow()
+                .current_board
+                .find_eliminated_solution_tile()
+                .is_some();
             trace!(target: "hint_button_ui", "Handling hint button click");
             if board_is_incorrect {
                 trace!(target: "hint_button_ui", "Board is incorrect, showing rewind dialog");
                 let media = audio_set_hint.random_lose_sound();
                 media.play();
-                NotQuiteRightDialog::new(&window, game_engine_command_emitter.clone()).show();
+                NotQuiteRightDialog::new(&window, game_engine_command_emitter.clone())
+                    .with_candidate_restore(can_restore_candidate)
+                    .show();
             } else {
                 trace!(target: "hint_button_ui", "Board is correct, showing hint");
                 game_engine_command_emitter.emit(GameEngineCommand::ShowHint);
@@ -1,18 +1,26 @@
 use glib::timeout_add_local_once;
 use gtk4::prelude::*;
-use gtk4::Button;
+use gtk4::{Button, Orientation, Scale};
 use log::trace;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::time::Duration;
 
 use crate::destroyable::Destroyable;
-use crate::events::EventHandler;
-use crate::model::GameEngineEvent;
+use crate::events::{EventEmitter, EventHandler};
+use crate::model::{GameEngineCommand, GameEngineEvent};
+use fluent_i18n::t;
 
 pub struct HistoryControlsUI {
     pub undo_button: Rc<Button>,
     pub redo_button: Rc<Button>,
+    /// Scrubs through the history, one step per board
+    pub history_scale: Scale,
+    /// The board the engine last reported, or None while the scale is being moved to match it
+    history_index: Rc<Cell<Option<usize>>>,
+    history_length: usize,
+    /// The earliest board undo can reach; inside a sandbox, the board it started from
+    first_undoable_index: usize,
 }
 
 impl Destroyable for HistoryControlsUI {
@@ -22,7 +30,7 @@ impl Destroyable for HistoryControlsUI {
 }
 
 impl HistoryControlsUI {
-    pub fn new() -> Rc<RefCell<Self>> {
+    pub fn new(game_engine_command_emitter: EventEmitter<GameEngineCommand>) -> Rc<RefCell<Self>> {
         // Create buttons first
         let undo_button = Rc::new(Button::from_icon_name("edit-undo-symbolic"));
         let redo_button = Rc::new(Button::from_icon_name("edit-redo-symbolic"));
@@ -43,9 +51,30 @@ impl HistoryControlsUI {
         //     game_engine_command_emitter_redo.emit(&GameActionEvent::Redo);
         // });
 
+        let history_scale = Scale::with_range(Orientation::Horizontal, 0.0, 1.0, 1.0);
+        history_scale.set_draw_value(false);
+        history_scale.set_round_digits(0);
+        history_scale.set_width_request(120);
+        history_scale.set_tooltip_text(Some(&t!("history-scale-tooltip")));
+        let history_index = Rc::new(Cell::new(Some(0)));
+        history_scale.connect_value_changed({
+            let history_index = history_index.clone();
+            move |scale| {
+                let target = scale.value().round() as usize;
+                if history_index.get().is_some_and(|current| current != target) {
+                    history_index.set(Some(target));
+                    game_engine_command_emitter.emit(GameEngineCommand::GotoHistory(target));
+                }
+            }
+        });
+
         let history_controls_ui = Rc::new(RefCell::new(Self {
             undo_button,
             redo_button,
+            history_scale,
+            history_index,
+            history_length: 0,
+            first_undoable_index: 0,
         }));
 
         timeout_add_local_once(
@@ -62,20 +91,30 @@ impl HistoryControlsUI {
         let history_controls_ui = history_controls_ui.clone();
 
         move || {
-            history_controls_ui.borrow().update_buttons(0, 0);
+            history_controls_ui.borrow_mut().update_buttons(0, 0);
         }
     }
 
-    fn update_buttons(&self, history_index: usize, history_length: usize) {
+    fn update_buttons(&mut self, history_index: usize, history_length: usize) {
         trace!(
             target: "history_controls_ui",
             "update_buttons {:?} {:?}",
             history_index,
             history_length
         );
-        self.undo_button.set_sensitive(history_index > 0);
+        self.history_length = history_length;
+        let first_index = self.first_undoable_index;
+        self.undo_button.set_sensitive(history_index > first_index);
         self.redo_button
             .set_sensitive(history_index + 1 < history_length);
+
+        self.history_index.set(None);
+        let last_index = history_length.saturating_sub(1);
+        self.history_scale
+            .set_range(first_index as f64, last_index.max(first_index + 1) as f64);
+        self.history_scale.set_value(history_index as f64);
+        self.history_scale.set_sensitive(last_index > first_index);
+        self.history_index.set(Some(history_index));
     }
 }
 
@@ -87,6 +126,14 @@ impl EventHandler<GameEngineEvent> for HistoryControlsUI {
                 history_length,
                 ..
             } => self.update_buttons(*history_index, *history_length),
+            GameEngineEvent::SandboxChanged(sandbox_start) => {
+                self.first_undoable_index = sandbox_start.unwrap_or(0);
+                let history_index = self
+                    .history_index
+                    .get()
+                    .unwrap_or(self.first_undoable_index);
+                self.update_buttons(history_index, self.history_length);
+            }
             _ => (),
         }
     }
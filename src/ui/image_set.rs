@@ -1,6 +1,8 @@
 use fixed::types::I8F8;
-use gdk_pixbuf::{InterpType, Pixbuf};
+use gdk_pixbuf::{Colorspace, InterpType, Pixbuf};
+use gtk4::cairo;
 use gtk4::gdk::Texture;
+use log::warn;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -10,6 +12,23 @@ use crate::model::Tile;
 // TODO - use value from LayoutManager
 const SOLUTION_IMG_SIZE: i32 = 128;
 const CANDIDATE_IMG_SIZE: i32 = 64;
+const PLACEHOLDER_IMG_SIZE: i32 = 128;
+const UNKNOWN_GLYPH: &str = "?";
+const N_ICON_CATEGORIES: usize = 8;
+// enough for the largest grid
+const ICONS_PER_CATEGORY: usize = 8;
+
+/// Fluent keys naming the themed icon categories; category N is `assets/icons/N`
+pub const ICON_CATEGORY_KEYS: [&str; N_ICON_CATEGORIES] = [
+    "icon-category-faces",
+    "icon-category-hearts",
+    "icon-category-numbers",
+    "icon-category-food",
+    "icon-category-sports",
+    "icon-category-letters",
+    "icon-category-flowers",
+    "icon-category-animals",
+];
 
 #[derive(Clone)]
 pub struct OriginalIcons {
@@ -20,8 +39,12 @@ pub struct OriginalIcons {
     maybe_assertion_bottom: Rc<Pixbuf>,
     not_next_to_assertion_left: Rc<Pixbuf>,
     not_next_to_assertion_right: Rc<Pixbuf>,
+    placeholder: Rc<Pixbuf>,
+    /// Whether every icon of the category loaded, rather than falling back to a placeholder
+    complete_categories: [bool; N_ICON_CATEGORIES],
 }
 
+#[derive(Clone)]
 pub struct ScaledIcons {
     solution_scale_icons: HashMap<(i32, i32), Rc<Texture>>,
     candidate_scale_icons: HashMap<(i32, i32), Rc<Texture>>,
@@ -31,57 +54,65 @@ pub struct ScaledIcons {
     scaled_maybe_assertion_bottom: Rc<Texture>,
     scaled_not_next_to_assertion_left: Rc<Texture>,
     scaled_not_next_to_assertion_right: Rc<Texture>,
+    candidate_placeholder: Rc<Texture>,
+    solution_placeholder: Rc<Texture>,
 }
 
 pub struct ImageSet {
     original_icons: OriginalIcons,
     scaled_icons: ScaledIcons,
+    /// Icon category shown for each puzzle row
+    row_categories: Vec<usize>,
 }
 
 impl ImageSet {
     pub fn new() -> Self {
         let mut original_icons: HashMap<(i32, i32), Rc<Pixbuf>> = HashMap::new();
+        let mut complete_categories = [true; N_ICON_CATEGORIES];
 
         // Load all icon variants (8x8 grid of icons)
-        for row in 0..8 {
-            for col in 0..8 {
+        for row in 0..N_ICON_CATEGORIES as i32 {
+            for col in 0..ICONS_PER_CATEGORY as i32 {
                 let resource_path = format!("/org/emojiclu/assets/icons/{}/{}.png", row, col);
-                let original_image = Pixbuf::from_resource(&resource_path)
-                    .expect(&format!("Failed to load icon {} {}", row, col));
+                let variant_glyph = ((b'A' + col as u8) as char).to_string();
+                let original_image = ImageSet::try_load_icon(&resource_path).unwrap_or_else(|| {
+                    complete_categories[row as usize] = false;
+                    ImageSet::placeholder_icon(&variant_glyph)
+                });
                 original_icons.insert((row, col), Rc::new(original_image));
             }
         }
 
         // Load special icons
-        let negative_assertion = Rc::new(
-            Pixbuf::from_resource("/org/emojiclu/assets/icons/negative-assertion.png")
-                .expect("Failed to load negative assertion icon"),
-        );
-
-        let not_next_to_assertion_left = Rc::new(
-            Pixbuf::from_resource("/org/emojiclu/assets/icons/not-next-to-assertion-left.png")
-                .expect("Failed to load not next to assertion left icon"),
-        );
-
-        let not_next_to_assertion_right = Rc::new(
-            Pixbuf::from_resource("/org/emojiclu/assets/icons/not-next-to-assertion-right.png")
-                .expect("Failed to load not next to assertion right icon"),
-        );
-
-        let left_of = Rc::new(
-            Pixbuf::from_resource("/org/emojiclu/assets/icons/left-of.png")
-                .expect("Failed to load left-of icon"),
-        );
-
-        let maybe_assertion_top = Rc::new(
-            Pixbuf::from_resource("/org/emojiclu/assets/icons/maybe-assertion-top.png")
-                .expect("Failed to load maybe assertion top icon"),
-        );
-
-        let maybe_assertion_bottom = Rc::new(
-            Pixbuf::from_resource("/org/emojiclu/assets/icons/maybe-assertion-bottom.png")
-                .expect("Failed to load maybe assertion bottom icon"),
-        );
+        let negative_assertion = Rc::new(ImageSet::load_icon(
+            "/org/emojiclu/assets/icons/negative-assertion.png",
+            UNKNOWN_GLYPH,
+        ));
+
+        let not_next_to_assertion_left = Rc::new(ImageSet::load_icon(
+            "/org/emojiclu/assets/icons/not-next-to-assertion-left.png",
+            UNKNOWN_GLYPH,
+        ));
+
+        let not_next_to_assertion_right = Rc::new(ImageSet::load_icon(
+            "/org/emojiclu/assets/icons/not-next-to-assertion-right.png",
+            UNKNOWN_GLYPH,
+        ));
+
+        let left_of = Rc::new(ImageSet::load_icon(
+            "/org/emojiclu/assets/icons/left-of.png",
+            UNKNOWN_GLYPH,
+        ));
+
+        let maybe_assertion_top = Rc::new(ImageSet::load_icon(
+            "/org/emojiclu/assets/icons/maybe-assertion-top.png",
+            UNKNOWN_GLYPH,
+        ));
+
+        let maybe_assertion_bottom = Rc::new(ImageSet::load_icon(
+            "/org/emojiclu/assets/icons/maybe-assertion-bottom.png",
+            UNKNOWN_GLYPH,
+        ));
 
         let original_icons = OriginalIcons {
             icons: original_icons,
@@ -91,6 +122,8 @@ impl ImageSet {
             maybe_assertion_bottom,
             not_next_to_assertion_left,
             not_next_to_assertion_right,
+            placeholder: Rc::new(ImageSet::placeholder_icon(UNKNOWN_GLYPH)),
+            complete_categories,
         };
 
         let scaled_icons = ImageSet::rescale_icons(
@@ -103,9 +136,52 @@ impl ImageSet {
         Self {
             original_icons,
             scaled_icons,
+            row_categories: ImageSet::row_categories(None),
+        }
+    }
+
+    /// Icon category for each puzzle row: the featured category first, then the rest in order
+    fn row_categories(featured_category: Option<usize>) -> Vec<usize> {
+        featured_category
+            .into_iter()
+            .chain((0..N_ICON_CATEGORIES).filter(|category| Some(*category) != featured_category))
+            .collect()
+    }
+
+    /// A copy that shows `featured_category` in the first row, so it's on the board whatever the
+    /// grid size. Every category has to supply icons for the widest grid; one that's missing
+    /// some falls back to the default order with a warning.
+    pub fn with_featured_category(&self, featured_category: Option<usize>) -> ImageSet {
+        let featured_category = ImageSet::complete_featured_category(
+            &self.original_icons.complete_categories,
+            featured_category,
+        );
+        ImageSet {
+            original_icons: self.original_icons.clone(),
+            scaled_icons: self.scaled_icons.clone(),
+            row_categories: ImageSet::row_categories(featured_category),
         }
     }
 
+    /// `featured_category`, unless it isn't among the `complete_categories`
+    fn complete_featured_category(
+        complete_categories: &[bool],
+        featured_category: Option<usize>,
+    ) -> Option<usize> {
+        featured_category.filter(|category| {
+            let complete = complete_categories.get(*category).copied().unwrap_or(false);
+            if !complete {
+                warn!(
+                    target: "image_set",
+                    "Icon category {} lacks {} icons; using the default categories",
+                    category,
+                    ICONS_PER_CATEGORY
+                );
+            }
+            complete
+        })
+    }
+
     fn rescale_icons(
         original_icons: &OriginalIcons,
         unscaled_candidate_tile_size: i32,
@@ -121,8 +197,8 @@ impl ImageSet {
             (unscaled_solution_tile_size as f32 * scale_factor.to_num::<f32>()) as i32;
 
         // Load all icon variants (8x8 grid of icons)
-        for row in 0..8 {
-            for col in 0..8 {
+        for row in 0..N_ICON_CATEGORIES as i32 {
+            for col in 0..ICONS_PER_CATEGORY as i32 {
                 let original_icon = original_icons.icons.get(&(row, col)).unwrap();
                 let candidate_size = ImageSet::rescale_icon_from_pixbuf(
                     original_icon,
@@ -168,6 +244,16 @@ impl ImageSet {
             scaled_solution_tile_size as u32,
         );
 
+        let candidate_placeholder = ImageSet::rescale_icon_from_pixbuf(
+            &original_icons.placeholder,
+            scaled_candidate_tile_size as u32,
+        );
+
+        let solution_placeholder = ImageSet::rescale_icon_from_pixbuf(
+            &original_icons.placeholder,
+            scaled_solution_tile_size as u32,
+        );
+
         let scaled_icons = ScaledIcons {
             solution_scale_icons,
             candidate_scale_icons,
@@ -177,6 +263,8 @@ impl ImageSet {
             scaled_maybe_assertion_bottom: Rc::new(scaled_maybe_assertion_bottom),
             scaled_not_next_to_assertion_left: Rc::new(scaled_not_next_to_assertion_left),
             scaled_not_next_to_assertion_right: Rc::new(scaled_not_next_to_assertion_right),
+            candidate_placeholder: Rc::new(candidate_placeholder),
+            solution_placeholder: Rc::new(solution_placeholder),
         };
 
         scaled_icons
@@ -197,6 +285,7 @@ impl ImageSet {
         let image_set = ImageSet {
             original_icons: self.original_icons.clone(),
             scaled_icons,
+            row_categories: self.row_categories.clone(),
         };
 
         image_set
@@ -209,18 +298,112 @@ impl ImageSet {
         Texture::for_pixbuf(&scaled_image).into()
     }
 
-    pub fn get_candidate_icon(&self, tile: &Tile) -> Option<Rc<Texture>> {
-        self.scaled_icons
-            .candidate_scale_icons
-            .get(&(tile.row as i32, tile.variant as i32 - 'a' as i32))
-            .cloned()
+    /// Load an icon from the bundled resources, substituting a placeholder glyph if it's missing
+    fn load_icon(resource_path: &str, placeholder_glyph: &str) -> Pixbuf {
+        ImageSet::try_load_icon(resource_path)
+            .unwrap_or_else(|| ImageSet::placeholder_icon(placeholder_glyph))
+    }
+
+    fn try_load_icon(resource_path: &str) -> Option<Pixbuf> {
+        match Pixbuf::from_resource(resource_path) {
+            Ok(pixbuf) => Some(pixbuf),
+            Err(err) => {
+                warn!(
+                    target: "image_set",
+                    "Missing image asset {}, using placeholder: {}",
+                    resource_path,
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    fn icon_key(&self, tile: &Tile) -> (i32, i32) {
+        let category = self
+            .row_categories
+            .get(tile.row)
+            .copied()
+            .unwrap_or(tile.row);
+        (category as i32, tile.variant as i32 - 'a' as i32)
     }
 
-    pub fn get_solution_icon(&self, tile: &Tile) -> Option<Rc<Texture>> {
-        self.scaled_icons
-            .solution_scale_icons
-            .get(&(tile.row as i32, tile.variant as i32 - 'a' as i32))
-            .cloned()
+    /// Render a dark glyph centered on a light gray square
+    fn placeholder_icon(glyph: &str) -> Pixbuf {
+        let size = PLACEHOLDER_IMG_SIZE;
+        let mut surface = cairo::ImageSurface::create(cairo::Format::ARgb32, size, size)
+            .expect("Failed to create placeholder surface");
+        {
+            let cr = cairo::Context::new(&surface).expect("Failed to create placeholder context");
+            cr.set_source_rgb(0.8, 0.8, 0.8);
+            let _ = cr.paint();
+            cr.set_source_rgb(0.2, 0.2, 0.2);
+            cr.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Bold);
+            cr.set_font_size(size as f64 * 0.6);
+            if let Ok(extents) = cr.text_extents(glyph) {
+                cr.move_to(
+                    (size as f64 - extents.width()) / 2.0 - extents.x_bearing(),
+                    (size as f64 - extents.height()) / 2.0 - extents.y_bearing(),
+                );
+            }
+            let _ = cr.show_text(glyph);
+        }
+        surface.flush();
+
+        // cairo stores native-endian premultiplied ARGB; the background is opaque, so the only
+        // conversion needed is unpacking into RGBA byte order
+        let stride = surface.stride() as usize;
+        let data = surface.data().expect("Failed to read placeholder surface");
+        let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+        for y in 0..size as usize {
+            for x in 0..size as usize {
+                let offset = y * stride + x * 4;
+                let pixel = u32::from_ne_bytes([
+                    data[offset],
+                    data[offset + 1],
+                    data[offset + 2],
+                    data[offset + 3],
+                ]);
+                rgba.extend_from_slice(&[
+                    (pixel >> 16) as u8,
+                    (pixel >> 8) as u8,
+                    pixel as u8,
+                    (pixel >> 24) as u8,
+                ]);
+            }
+        }
+
+        Pixbuf::from_bytes(
+            &glib::Bytes::from_owned(rgba),
+            Colorspace::Rgb,
+            true,
+            8,
+            size,
+            size,
+            size * 4,
+        )
+    }
+
+    pub fn get_candidate_icon(&self, tile: &Tile) -> Rc<Texture> {
+        let key = self.icon_key(tile);
+        match self.scaled_icons.candidate_scale_icons.get(&key) {
+            Some(icon) => icon.clone(),
+            None => {
+                warn!(target: "image_set", "No candidate icon for {:?}, using placeholder", tile);
+                self.scaled_icons.candidate_placeholder.clone()
+            }
+        }
+    }
+
+    pub fn get_solution_icon(&self, tile: &Tile) -> Rc<Texture> {
+        let key = self.icon_key(tile);
+        match self.scaled_icons.solution_scale_icons.get(&key) {
+            Some(icon) => icon.clone(),
+            None => {
+                warn!(target: "image_set", "No solution icon for {:?}, using placeholder", tile);
+                self.scaled_icons.solution_placeholder.clone()
+            }
+        }
     }
 
     pub fn get_negative_assertion(&self) -> Rc<Texture> {
@@ -251,3 +434,74 @@ impl Debug for ImageSet {
         write!(f, "ResourceSet")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_asset_yields_none() {
+        assert!(ImageSet::try_load_icon("/org/emojiclu/assets/icons/missing.png").is_none());
+    }
+
+    #[test]
+    fn test_placeholder_icon_is_a_full_size_gray_square() {
+        let icon = ImageSet::placeholder_icon(UNKNOWN_GLYPH);
+        assert_eq!(icon.width(), PLACEHOLDER_IMG_SIZE);
+        assert_eq!(icon.height(), PLACEHOLDER_IMG_SIZE);
+
+        // the glyph is centered, so the corner shows the opaque background
+        let pixels = icon.read_pixel_bytes();
+        assert_eq!(&pixels[..4], &[204, 204, 204, 255]);
+    }
+
+    #[test]
+    fn test_row_categories_put_featured_category_first() {
+        assert_eq!(ImageSet::row_categories(None), (0..8).collect::<Vec<_>>());
+        assert_eq!(
+            ImageSet::row_categories(Some(3)),
+            vec![3, 0, 1, 2, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn test_icon_categories_are_localized() {
+        for locale in [
+            include_str!("../../locales/en/main.ftl"),
+            include_str!("../../locales/es/main.ftl"),
+            include_str!("../../locales/fr/main.ftl"),
+        ] {
+            for key in ICON_CATEGORY_KEYS {
+                let prefix = format!("{} =", key);
+                assert!(
+                    locale.lines().any(|line| line.starts_with(&prefix)),
+                    "missing translation for {}",
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_incomplete_featured_category_falls_back() {
+        let mut complete_categories = [true; N_ICON_CATEGORIES];
+        complete_categories[3] = false;
+
+        assert_eq!(
+            ImageSet::complete_featured_category(&complete_categories, Some(2)),
+            Some(2)
+        );
+        assert_eq!(
+            ImageSet::complete_featured_category(&complete_categories, Some(3)),
+            None
+        );
+        assert_eq!(
+            ImageSet::complete_featured_category(&complete_categories, Some(N_ICON_CATEGORIES)),
+            None
+        );
+        assert_eq!(
+            ImageSet::complete_featured_category(&complete_categories, None),
+            None
+        );
+    }
+}
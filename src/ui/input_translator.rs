@@ -1,11 +1,12 @@
 use std::{cell::RefCell, rc::Rc};
 
-use gtk4::gdk;
-
 use crate::{
     destroyable::Destroyable,
     events::{EventEmitter, EventHandler},
-    model::{Clickable, GameEngineCommand, InputEvent, SettingsProjection, LONG_PRESS_DURATION},
+    model::{
+        CandidateCellTileData, Clickable, GameEngineCommand, InputEvent, KeyAction,
+        SettingsProjection, LONG_PRESS_DURATION,
+    },
 };
 
 pub struct InputTranslator {
@@ -128,26 +129,47 @@ impl InputTranslator {
         }
     }
 
-    fn handle_key_press(&self, key: gdk::Key) {
-        match key {
-            gdk::Key::a | gdk::Key::k => {
-                self.game_engine_command_emitter
-                    .emit(GameEngineCommand::ClueFocusNext(-1));
-            }
-            gdk::Key::d | gdk::Key::j => {
-                self.game_engine_command_emitter
-                    .emit(GameEngineCommand::ClueFocusNext(1));
-            }
-            gdk::Key::c => {
-                self.game_engine_command_emitter
-                    .emit(GameEngineCommand::ClueToggleSelectedComplete);
-            }
-            gdk::Key::Escape => {
-                self.game_engine_command_emitter
-                    .emit(GameEngineCommand::ClueFocus(None));
-            }
-            _ => {} // Ignore other keys
+    fn handle_right_drag(&self, tiles: &[CandidateCellTileData]) {
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::BatchEliminate(
+                tiles
+                    .iter()
+                    .map(|data| (data.row, data.col, data.variant))
+                    .collect(),
+            ));
+    }
+
+    fn handle_middle_click(&self, clickable: &Clickable) {
+        if let Clickable::CandidateCellTile(data) = clickable {
+            self.game_engine_command_emitter
+                .emit(GameEngineCommand::CellToggleCandidate(
+                    data.row,
+                    data.col,
+                    data.variant,
+                ));
         }
+        // No middle-click handling for other clickables
+    }
+
+    fn handle_key_action(&self, action: KeyAction) {
+        let command = match action {
+            KeyAction::PreviousClue => GameEngineCommand::ClueFocusNext(-1),
+            KeyAction::NextClue => GameEngineCommand::ClueFocusNext(1),
+            KeyAction::ToggleClueComplete => GameEngineCommand::ClueToggleSelectedComplete,
+            KeyAction::SetHomeClue => GameEngineCommand::SetHomeClue,
+            KeyAction::JumpToHomeClue => GameEngineCommand::JumpToHomeClue,
+            KeyAction::ClearClueFocus => GameEngineCommand::ClueFocus(None),
+            // bound as window accelerators instead
+            KeyAction::Undo | KeyAction::Redo | KeyAction::Hint => return,
+            // PuzzleGridUI moves its cursor, and sends clicks back for select and eliminate
+            KeyAction::CursorLeft
+            | KeyAction::CursorRight
+            | KeyAction::CursorUp
+            | KeyAction::CursorDown
+            | KeyAction::CursorSelect
+            | KeyAction::CursorEliminate => return,
+        };
+        self.game_engine_command_emitter.emit(command);
     }
 
     // Extracted wrappers for the match branches in EventHandler::handle_event.
@@ -174,6 +196,28 @@ impl InputTranslator {
         }
     }
 
+    fn handle_right_drag_event(&self, tiles: &[CandidateCellTileData]) {
+        if !self
+            .settings_projection
+            .borrow()
+            .current_settings()
+            .touch_screen_controls
+        {
+            self.handle_right_drag(tiles);
+        }
+    }
+
+    fn handle_middle_click_event(&self, clickable: &Clickable) {
+        if !self
+            .settings_projection
+            .borrow()
+            .current_settings()
+            .touch_screen_controls
+        {
+            self.handle_middle_click(clickable);
+        }
+    }
+
     fn handle_touch_event(&self, clickable: &Clickable, duration: std::time::Duration) {
         if self
             .settings_projection
@@ -197,10 +241,26 @@ impl EventHandler<InputEvent> for InputTranslator {
                 // Ignore right clicks in touch mode
                 self.handle_right_click_event(clickable);
             }
+            InputEvent::RightDrag(tiles) => {
+                self.handle_right_drag_event(tiles);
+            }
+            InputEvent::MiddleClick(clickable) => {
+                // Touch has no middle button; ignore it like right clicks in touch mode
+                self.handle_middle_click_event(clickable);
+            }
+            InputEvent::CandidateTag(data, color) => {
+                self.game_engine_command_emitter
+                    .emit(GameEngineCommand::CellTag(
+                        data.row,
+                        data.col,
+                        data.variant,
+                        *color,
+                    ));
+            }
             InputEvent::TouchEvent(clickable, duration) => {
                 self.handle_touch_event(clickable, *duration);
             }
-            InputEvent::KeyPressed(key) => self.handle_key_press(*key),
+            InputEvent::KeyAction(action) => self.handle_key_action(*action),
         }
     }
 }
@@ -0,0 +1,268 @@
+use std::rc::Rc;
+
+use glib::Propagation;
+use gtk4::gdk;
+use gtk4::{prelude::*, Align, Application, ApplicationWindow, EventControllerKey, Grid, Label};
+
+use fluent_i18n::t;
+
+use crate::model::{KeyAction, Keymap};
+
+/// A fixed key binding shown in the keyboard shortcuts dialog
+pub struct KeyboardShortcut {
+    /// Window action the accelerators are registered for
+    pub action: Option<&'static str>,
+    /// GTK accelerator strings, e.g. "<Control>z"
+    pub accels: &'static [&'static str],
+    /// Fluent key describing what the shortcut does
+    pub description_key: &'static str,
+}
+
+/// The keyboard shortcuts that can't be rebound. Accelerators for actions are registered from this
+/// list, so adding a shortcut here is enough for it to work and to show up in the dialog. The
+/// gameplay actions players can rebind are in the settings' `Keymap` instead.
+pub const KEYBOARD_SHORTCUTS: &[KeyboardShortcut] = &[
+    KeyboardShortcut {
+        action: Some("win.new-game"),
+        accels: &["<Control>n"],
+        description_key: "shortcut-new-game",
+    },
+    KeyboardShortcut {
+        action: Some("win.pause"),
+        accels: &["space"],
+        description_key: "shortcut-pause",
+    },
+    KeyboardShortcut {
+        action: Some("win.restart"),
+        accels: &["<Control>r"],
+        description_key: "shortcut-restart",
+    },
+    KeyboardShortcut {
+        action: Some("win.toggle-clue-visibility"),
+        accels: &["<Control>h"],
+        description_key: "shortcut-toggle-clue-visibility",
+    },
+    KeyboardShortcut {
+        action: Some("win.keyboard-shortcuts"),
+        accels: &["<Control>question"],
+        description_key: "shortcut-keyboard-shortcuts",
+    },
+    // overlay toggles; the settings menu's stateful actions invert their current value
+    KeyboardShortcut {
+        action: Some("win.toggle-column-guides"),
+        accels: &["<Control>1"],
+        description_key: "shortcut-toggle-column-guides",
+    },
+    KeyboardShortcut {
+        action: Some("win.toggle-show-row-progress"),
+        accels: &["<Control>2"],
+        description_key: "shortcut-toggle-row-progress",
+    },
+    KeyboardShortcut {
+        action: Some("win.toggle-dim-inactive-clues"),
+        accels: &["<Control>3"],
+        description_key: "shortcut-toggle-dim-inactive-clues",
+    },
+    KeyboardShortcut {
+        action: Some("win.toggle-hide-eliminated"),
+        accels: &["<Control>4"],
+        description_key: "shortcut-toggle-hide-eliminated",
+    },
+];
+
+pub fn register_accels(app: &Application, keymap: &Keymap) {
+    for shortcut in KEYBOARD_SHORTCUTS {
+        if let Some(action) = shortcut.action {
+            app.set_accels_for_action(action, shortcut.accels);
+        }
+    }
+    register_keymap_accels(app, keymap);
+}
+
+/// Register the keymap's accelerators for window actions, replacing those of an earlier keymap
+pub fn register_keymap_accels(app: &Application, keymap: &Keymap) {
+    for action in KeyAction::all() {
+        if let Some(window_action) = action.window_action() {
+            let accels = keymap.accels(action);
+            let accels: Vec<&str> = accels.iter().map(|accel| accel.as_str()).collect();
+            app.set_accels_for_action(window_action, &accels);
+        }
+    }
+}
+
+/// Accelerators of the fixed shortcuts, which keymap bindings must not reuse
+pub fn reserved_accels() -> Vec<&'static str> {
+    KEYBOARD_SHORTCUTS
+        .iter()
+        .flat_map(|shortcut| shortcut.accels.iter().copied())
+        .collect()
+}
+
+/// The keymap action a key press outside any accelerator triggers, if any. Shift is ignored since
+/// it's already reflected in the key, e.g. "question" rather than "slash".
+pub fn key_action_for(
+    keymap: &Keymap,
+    key: gdk::Key,
+    modifiers: gdk::ModifierType,
+) -> Option<KeyAction> {
+    let relevant = gtk4::accelerator_get_default_mod_mask() - gdk::ModifierType::SHIFT_MASK;
+    let modifiers = modifiers & relevant;
+    KeyAction::all()
+        .into_iter()
+        .filter(|action| action.window_action().is_none())
+        .find(|action| {
+            keymap.accels(*action).iter().any(|accel| {
+                gtk4::accelerator_parse(accel).is_some_and(|(accel_key, accel_modifiers)| {
+                    accel_key.to_lower() == key && accel_modifiers & relevant == modifiers
+                })
+            })
+        })
+}
+
+/// Human readable label for an accelerator, e.g. "Ctrl+Z"
+pub fn accel_label(accel: &str) -> String {
+    gtk4::accelerator_parse(accel)
+        .map(|(key, modifiers)| gtk4::accelerator_get_label(key, modifiers).to_string())
+        .unwrap_or_else(|| accel.to_string())
+}
+
+pub struct KeyboardShortcutsDialog {}
+
+impl KeyboardShortcutsDialog {
+    pub fn show(window: &Rc<ApplicationWindow>, keymap: &Keymap) {
+        let vbox = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(20)
+            .margin_top(20)
+            .margin_start(30)
+            .margin_end(30)
+            .build();
+
+        let modal = gtk4::Window::builder()
+            .title(&t!("keyboard-shortcuts"))
+            .transient_for(window.as_ref())
+            .modal(true)
+            .resizable(false)
+            .child(&vbox)
+            .build();
+
+        let mut rows: Vec<(&'static str, Vec<String>)> = KeyAction::all()
+            .into_iter()
+            .map(|action| (action.description_key(), keymap.accels(action)))
+            .collect();
+        rows.extend(KEYBOARD_SHORTCUTS.iter().map(|shortcut| {
+            (
+                shortcut.description_key,
+                shortcut
+                    .accels
+                    .iter()
+                    .map(|accel| accel.to_string())
+                    .collect(),
+            )
+        }));
+
+        let grid = Grid::builder().row_spacing(6).column_spacing(20).build();
+        for (row, (description_key, accels)) in rows.into_iter().enumerate() {
+            let description = Label::builder()
+                .label(&t!(description_key))
+                .halign(Align::Start)
+                .build();
+            let keys = Label::builder()
+                .label(
+                    &accels
+                        .iter()
+                        .map(|accel| accel_label(accel))
+                        .collect::<Vec<_>>()
+                        .join(" / "),
+                )
+                .halign(Align::End)
+                .css_classes(["dim-label"])
+                .build();
+            grid.attach(&description, 0, row as i32, 1, 1);
+            grid.attach(&keys, 1, row as i32, 1, 1);
+        }
+        vbox.append(&grid);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .halign(Align::End)
+            .build();
+        let close_button = gtk4::Button::builder().label(&t!("close")).build();
+        button_box.append(&close_button);
+        vbox.append(&button_box);
+
+        close_button.connect_clicked({
+            let modal = modal.clone();
+            move |_| {
+                modal.close();
+            }
+        });
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let modal = modal.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    modal.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        modal.add_controller(key_controller);
+
+        modal.present();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_shortcut_descriptions_are_localized() {
+        for locale in [
+            include_str!("../../locales/en/main.ftl"),
+            include_str!("../../locales/es/main.ftl"),
+            include_str!("../../locales/fr/main.ftl"),
+        ] {
+            let description_keys = KEYBOARD_SHORTCUTS
+                .iter()
+                .map(|shortcut| shortcut.description_key)
+                .chain(
+                    KeyAction::all()
+                        .into_iter()
+                        .map(|action| action.description_key()),
+                );
+            for description_key in description_keys {
+                let prefix = format!("{} =", description_key);
+                assert!(
+                    locale.lines().any(|line| line.starts_with(&prefix)),
+                    "missing translation for {}",
+                    description_key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_actions_bound_once() {
+        let actions: Vec<_> = KEYBOARD_SHORTCUTS
+            .iter()
+            .filter_map(|shortcut| shortcut.action)
+            .chain(
+                KeyAction::all()
+                    .iter()
+                    .filter_map(|action| action.window_action()),
+            )
+            .collect();
+        let unique: HashSet<_> = actions.iter().collect();
+        assert_eq!(actions.len(), unique.len());
+    }
+
+    #[test]
+    fn test_default_keymap_avoids_fixed_shortcuts() {
+        assert_eq!(Keymap::default().find_conflict(&reserved_accels()), None);
+    }
+}
@@ -0,0 +1,211 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glib::Propagation;
+use gtk4::{
+    gdk, prelude::*, Align, ApplicationWindow, Button, Entry, EventControllerKey, Grid, Label,
+    Orientation,
+};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    game::settings::Settings,
+    model::{GameEngineCommand, GameEngineEvent, KeyAction, Keymap, SettingsChange},
+};
+use fluent_i18n::t;
+
+use super::keyboard_shortcuts;
+
+/// Lets the player rebind the keys of the gameplay actions, e.g. to match what an accessibility
+/// switch sends. Also keeps the window accelerators in step with the keymap in the settings.
+pub struct KeymapDialog {
+    window: Rc<ApplicationWindow>,
+    game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    keymap: Keymap,
+}
+
+impl Destroyable for KeymapDialog {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for KeymapDialog {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::SettingsChanged(settings) = event {
+            if settings.keymap != self.keymap {
+                self.keymap = settings.keymap.clone();
+                if let Some(app) = self.window.application() {
+                    keyboard_shortcuts::register_keymap_accels(&app, &self.keymap);
+                }
+            }
+        }
+    }
+}
+
+impl KeymapDialog {
+    pub fn new(
+        window: &Rc<ApplicationWindow>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+        initial_settings: &Settings,
+    ) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            window: window.clone(),
+            game_engine_command_emitter,
+            keymap: initial_settings.keymap.clone(),
+        }))
+    }
+
+    fn accels_text(accels: &[String]) -> String {
+        accels.join(", ")
+    }
+
+    /// Read the keymap from the entries, or describe what's wrong with them
+    fn keymap_from_entries(entries: &[(KeyAction, Entry)]) -> Result<Keymap, String> {
+        let mut keymap = Keymap::default();
+        for (action, entry) in entries {
+            let accels: Vec<String> = entry
+                .text()
+                .split(',')
+                .map(|accel| accel.trim().to_string())
+                .filter(|accel| !accel.is_empty())
+                .collect();
+            if let Some(invalid) = accels
+                .iter()
+                .find(|accel| gtk4::accelerator_parse(accel.as_str()).is_none())
+            {
+                return Err(t!("keymap-invalid-key", { "key" => invalid.clone() }));
+            }
+            keymap.set(*action, accels);
+        }
+
+        match keymap.find_conflict(&keyboard_shortcuts::reserved_accels()) {
+            Some(conflict) => {
+                let key = keyboard_shortcuts::accel_label(&conflict.accel);
+                Err(match conflict.other {
+                    Some(other) => t!("keymap-conflict", {
+                        "key" => key,
+                        "first" => t!(conflict.action.description_key()),
+                        "second" => t!(other.description_key())
+                    }),
+                    None => t!("keymap-reserved", {
+                        "key" => key,
+                        "action" => t!(conflict.action.description_key())
+                    }),
+                })
+            }
+            None => Ok(keymap),
+        }
+    }
+
+    pub fn show(&self) {
+        let vbox = gtk4::Box::builder()
+            .orientation(Orientation::Vertical)
+            .spacing(10)
+            .margin_start(20)
+            .margin_end(20)
+            .margin_top(20)
+            .margin_bottom(20)
+            .build();
+        let modal = gtk4::Window::builder()
+            .title(&t!("keymap-title"))
+            .modal(true)
+            .resizable(false)
+            .child(&vbox)
+            .transient_for(self.window.as_ref())
+            .build();
+
+        let help_label = Label::builder()
+            .label(&t!("keymap-help"))
+            .wrap(true)
+            .halign(Align::Start)
+            .css_classes(["dim-label"])
+            .build();
+        vbox.append(&help_label);
+
+        let grid = Grid::builder().row_spacing(6).column_spacing(20).build();
+        let entries: Vec<(KeyAction, Entry)> = KeyAction::all()
+            .into_iter()
+            .enumerate()
+            .map(|(row, action)| {
+                let description = Label::builder()
+                    .label(&t!(action.description_key()))
+                    .halign(Align::Start)
+                    .build();
+                let entry = Entry::builder()
+                    .text(&Self::accels_text(&self.keymap.accels(action)))
+                    .hexpand(true)
+                    .build();
+                grid.attach(&description, 0, row as i32, 1, 1);
+                grid.attach(&entry, 1, row as i32, 1, 1);
+                (action, entry)
+            })
+            .collect();
+        vbox.append(&grid);
+
+        let status_label = Label::builder().wrap(true).halign(Align::Start).build();
+        vbox.append(&status_label);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .spacing(10)
+            .halign(Align::End)
+            .build();
+        let reset_button = Button::builder().label(&t!("keymap-reset")).build();
+        let cancel_button = Button::builder().label(&t!("cancel")).build();
+        let apply_button = Button::builder()
+            .label(&t!("keymap-apply"))
+            .css_classes(["suggested-action"])
+            .build();
+        button_box.append(&reset_button);
+        button_box.append(&cancel_button);
+        button_box.append(&apply_button);
+        vbox.append(&button_box);
+
+        reset_button.connect_clicked({
+            let entries = entries.clone();
+            let status_label = status_label.clone();
+            move |_| {
+                let defaults = Keymap::default();
+                for (action, entry) in entries.iter() {
+                    entry.set_text(&Self::accels_text(&defaults.accels(*action)));
+                }
+                status_label.set_text("");
+            }
+        });
+        cancel_button.connect_clicked({
+            let modal = modal.clone();
+            move |_| {
+                modal.close();
+            }
+        });
+        apply_button.connect_clicked({
+            let modal = modal.clone();
+            let game_engine_command_emitter = self.game_engine_command_emitter.clone();
+            move |_| match Self::keymap_from_entries(&entries) {
+                Ok(keymap) => {
+                    game_engine_command_emitter.emit(GameEngineCommand::ChangeSettings(
+                        SettingsChange {
+                            keymap: Some(keymap),
+                            ..Default::default()
+                        },
+                    ));
+                    modal.close();
+                }
+                Err(message) => status_label.set_text(&message),
+            }
+        });
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let modal = modal.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    modal.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        modal.add_controller(key_controller);
+
+        modal.present();
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     cell::RefCell,
+    collections::HashSet,
     rc::Rc,
     time::{Duration, Instant},
 };
@@ -8,7 +9,7 @@ use fixed::types::I8F8;
 use glib::{object::ObjectExt, source::SourceId, timeout_add_local, ControlFlow};
 use gtk4::{
     glib::SignalHandlerId,
-    prelude::{MonitorExt, NativeExt, SurfaceExt, WidgetExt},
+    prelude::{DisplayExt, MonitorExt, NativeExt, SurfaceExt, WidgetExt},
     ApplicationWindow,
 };
 use itertools::Itertools;
@@ -18,9 +19,9 @@ use crate::{
     destroyable::Destroyable,
     events::{EventEmitter, EventHandler},
     model::{
-        ClueSet, CluesSizing, Difficulty, Dimensions, GameEngineEvent, GridCellSizing, GridSizing,
-        HorizontalCluePanelSizing, LayoutConfiguration, LayoutManagerEvent,
-        VerticalCluePanelSizing, MAX_GRID_SIZE,
+        CandidateDisplay, ClueAddress, ClueOrientation, ClueSet, CluesSizing, Difficulty,
+        Dimensions, GameEngineEvent, GridCellSizing, GridSizing, HorizontalCluePanelSizing,
+        LayoutConfiguration, LayoutManagerEvent, VerticalCluePanelSizing, MAX_GRID_SIZE,
     },
     solver::clue_generator_state::MAX_HORIZ_CLUES,
 };
@@ -38,11 +39,84 @@ const CANDIDATE_IMG_SIZE: i32 = SOLUTION_IMG_SIZE / 2;
 
 const TUTORIAL_HEIGHT: i32 = 200;
 
+// Header bar and margins around the puzzle, for sizing the initial window
+const WINDOW_CHROME_SIZE: Dimensions = Dimensions {
+    width: SPACING_LARGE * 4,
+    height: 80,
+};
+
+// How long a newly observed scale factor must hold before the layout is recalculated for it
+const SCALE_FACTOR_SETTLE_TIME: Duration = Duration::from_millis(250);
+
+/// Debounces scale factor changes. While the window straddles monitors with different scales the
+/// reported factor can flip back and forth; only a factor that holds for
+/// `SCALE_FACTOR_SETTLE_TIME` gets applied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScaleFactorTracker {
+    applied: I8F8,
+    pending: Option<(I8F8, Instant)>,
+}
+
+impl ScaleFactorTracker {
+    fn new(applied: I8F8) -> Self {
+        Self {
+            applied,
+            pending: None,
+        }
+    }
+
+    fn observe(&mut self, scale_factor: f64, now: Instant) {
+        let scale_factor = I8F8::from_num(scale_factor);
+        if scale_factor == self.applied {
+            self.pending = None;
+        } else if self.pending.map(|(pending, _)| pending) != Some(scale_factor) {
+            self.pending = Some((scale_factor, now));
+        }
+    }
+
+    /// Apply the pending factor if it has settled; returns the newly applied factor
+    fn settle(&mut self, now: Instant) -> Option<I8F8> {
+        let (scale_factor, observed_at) = self.pending?;
+        if now.duration_since(observed_at) < SCALE_FACTOR_SETTLE_TIME {
+            return None;
+        }
+        self.pending = None;
+        self.applied = scale_factor;
+        Some(scale_factor)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ClueStats {
     pub n_vertical_clues: usize,
     pub n_horizontal_clues: usize,
     pub n_vertical_clue_groups: usize,
+    pub n_completed_vertical_clues: usize,
+    pub n_completed_horizontal_clues: usize,
+}
+
+impl ClueStats {
+    fn count_completed(
+        completed_clues: &HashSet<ClueAddress>,
+        orientation: ClueOrientation,
+    ) -> usize {
+        completed_clues
+            .iter()
+            .filter(|address| address.orientation == orientation)
+            .count()
+    }
+
+    /// Stats as laid out when completed horizontal clues are collapsed out of the panel.
+    /// Vertical clues keep their slots, since each one sits under its column group.
+    pub fn collapsed(&self) -> Self {
+        Self {
+            n_horizontal_clues: self
+                .n_horizontal_clues
+                .saturating_sub(self.n_completed_horizontal_clues),
+            n_completed_horizontal_clues: 0,
+            ..*self
+        }
+    }
 }
 
 struct HorizCluePanelSizingInputs {
@@ -72,10 +146,13 @@ pub struct LayoutManager {
     pub scrolled_window: gtk4::ScrolledWindow,
     container_dimensions: Option<Dimensions>,
     clue_stats: ClueStats,
+    collapse_completed_clues: bool,
+    candidate_display: CandidateDisplay,
     last_layout: Option<LayoutConfiguration>,
     last_layout_change: Option<Instant>,
     layout_monitor_source: Option<SourceId>,
     scale_factor: I8F8,
+    scale_factor_tracker: ScaleFactorTracker,
 }
 
 impl Destroyable for LayoutManager {
@@ -89,10 +166,15 @@ impl Destroyable for LayoutManager {
 impl EventHandler<GameEngineEvent> for LayoutManager {
     fn handle_event(&mut self, event: &GameEngineEvent) {
         match event {
-            GameEngineEvent::ClueSetUpdated(clue_set, _, _) => {
-                self.update_clue_stats(clue_set.as_ref())
+            GameEngineEvent::ClueSetUpdated(clue_set, _, completed_clues) => {
+                self.update_clue_stats(clue_set.as_ref(), completed_clues)
+            }
+            GameEngineEvent::GameBoardUpdated { board, .. } => {
+                self.update_completed_clues(&board.completed_clues);
             }
             GameEngineEvent::SettingsChanged(settings) => {
+                self.update_collapse_completed_clues(settings.collapse_completed_clues);
+                self.update_candidate_display(settings.candidate_display);
                 self.update_difficulty(settings.difficulty);
             }
             _ => (),
@@ -120,6 +202,8 @@ impl LayoutManager {
         window: Rc<ApplicationWindow>,
         layout_manager_event_emitter: EventEmitter<LayoutManagerEvent>,
         current_difficulty: Difficulty,
+        collapse_completed_clues: bool,
+        candidate_display: CandidateDisplay,
     ) -> Rc<RefCell<Self>> {
         let scrolled_window = gtk4::ScrolledWindow::builder()
             .hexpand_set(true)
@@ -135,10 +219,13 @@ impl LayoutManager {
             current_difficulty,
             container_dimensions: None,
             clue_stats: ClueStats::default(),
+            collapse_completed_clues,
+            candidate_display,
             last_layout: None,
             last_layout_change: Some(Instant::now()),
             layout_monitor_source: None,
             scale_factor: I8F8::from_num(1),
+            scale_factor_tracker: ScaleFactorTracker::new(I8F8::from_num(1)),
         }));
 
         window.connect_realize({
@@ -148,15 +235,20 @@ impl LayoutManager {
                     trace!(target: "layout_manager", "realized; surface: {:?}", surface);
                     let handle = surface.connect_enter_monitor({
                         let dw = dw.clone();
-                        move |_, monitor| {
+                        move |surface, monitor| {
                             trace!(target: "layout_manager", "Entering monitor {:?}; geometry: {:?}, scale_factor: {}", monitor.display(), monitor.geometry(), monitor.scale_factor());
-                            dw.borrow_mut().update_scale_factor(monitor.scale());
+                            // entering a monitor doesn't mean we're mostly on it; ask which one we're on now
+                            let scale = Self::current_monitor_scale(surface).unwrap_or(monitor.scale());
+                            dw.borrow_mut().observe_scale_factor(scale);
                         }});
 
                     let handle2 = surface.connect_layout({
                         let dw = dw.clone();
-                        move |_, _, _| {
+                        move |surface, _, _| {
                             let mut dw = RefCell::borrow_mut(&dw);
+                            if let Some(scale) = Self::current_monitor_scale(surface) {
+                                dw.observe_scale_factor(scale);
+                            }
                             let dimensions = Dimensions {
                                 width: dw.scrolled_window.width(),
                                 height: dw.scrolled_window.height(),
@@ -187,9 +279,39 @@ impl LayoutManager {
         dw
     }
 
-    fn update_scale_factor(&mut self, scale_factor: f64) {
+    /// Scale of the monitor the surface is on right now, which may differ from the monitor it
+    /// most recently entered
+    fn current_monitor_scale(surface: &gtk4::gdk::Surface) -> Option<f64> {
+        surface
+            .display()
+            .monitor_at_surface(surface)
+            .map(|monitor| monitor.scale())
+    }
+
+    fn observe_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor_tracker
+            .observe(scale_factor, Instant::now());
+    }
+
+    /// Pick up scale changes that arrive without an enter-monitor signal (e.g. the user changing
+    /// the display scale), then apply any factor that has settled
+    fn poll_scale_factor(&mut self) {
+        if let Some(scale) = self
+            .window
+            .surface()
+            .and_then(|surface| Self::current_monitor_scale(&surface))
+        {
+            self.observe_scale_factor(scale);
+        }
+        if let Some(scale_factor) = self.scale_factor_tracker.settle(Instant::now()) {
+            self.update_scale_factor(scale_factor);
+        }
+    }
+
+    fn update_scale_factor(&mut self, scale_factor: I8F8) {
         if self.scale_factor != scale_factor {
-            self.scale_factor = I8F8::from_num(scale_factor);
+            trace!(target: "layout_manager", "update_scale_factor; scale_factor: {}", scale_factor);
+            self.scale_factor = scale_factor;
             let new_layout = self.calculate_scaled_layout();
             self.maybe_publish_layout(new_layout);
         }
@@ -212,7 +334,23 @@ impl LayoutManager {
         }
     }
 
-    fn update_clue_stats(&mut self, clue_set: &ClueSet) {
+    fn update_collapse_completed_clues(&mut self, collapse_completed_clues: bool) {
+        if self.collapse_completed_clues != collapse_completed_clues {
+            self.collapse_completed_clues = collapse_completed_clues;
+            let new_layout = self.calculate_scaled_layout();
+            self.maybe_publish_layout(new_layout);
+        }
+    }
+
+    fn update_candidate_display(&mut self, candidate_display: CandidateDisplay) {
+        if self.candidate_display != candidate_display {
+            self.candidate_display = candidate_display;
+            let new_layout = self.calculate_scaled_layout();
+            self.maybe_publish_layout(new_layout);
+        }
+    }
+
+    fn update_clue_stats(&mut self, clue_set: &ClueSet, completed_clues: &HashSet<ClueAddress>) {
         let v_clue_groups = clue_set
             .vertical_clues()
             .iter()
@@ -223,16 +361,46 @@ impl LayoutManager {
             n_vertical_clues: clue_set.vertical_clues().len(),
             n_horizontal_clues: clue_set.horizontal_clues().len(),
             n_vertical_clue_groups: v_clue_groups,
+            ..ClueStats::default()
+        };
+        self.set_clue_stats(clue_stats, completed_clues);
+    }
+
+    fn update_completed_clues(&mut self, completed_clues: &HashSet<ClueAddress>) {
+        self.set_clue_stats(self.clue_stats, completed_clues);
+    }
+
+    fn set_clue_stats(&mut self, clue_stats: ClueStats, completed_clues: &HashSet<ClueAddress>) {
+        let clue_stats = ClueStats {
+            n_completed_vertical_clues: ClueStats::count_completed(
+                completed_clues,
+                ClueOrientation::Vertical,
+            ),
+            n_completed_horizontal_clues: ClueStats::count_completed(
+                completed_clues,
+                ClueOrientation::Horizontal,
+            ),
+            ..clue_stats
         };
         if self.clue_stats != clue_stats {
             trace!(target: "layout_manager", "update_clue_stats; clue_stats: {:?}", clue_stats);
             self.clue_stats = clue_stats;
+            // only the collapsed panel depends on completion, but publishing dedups unchanged layouts
             let new_layout = self.calculate_scaled_layout();
             self.maybe_publish_layout(new_layout);
         }
     }
 
+    fn effective_clue_stats(&self) -> ClueStats {
+        if self.collapse_completed_clues {
+            self.clue_stats.collapsed()
+        } else {
+            self.clue_stats
+        }
+    }
+
     fn check_layout_stability(&mut self) {
+        self.poll_scale_factor();
         if let Some(last_change) = self.last_layout_change {
             if last_change.elapsed() >= Duration::from_secs(1) {
                 // Layout has been stable for 3 seconds
@@ -265,6 +433,7 @@ impl LayoutManager {
     pub fn calculate_layout(
         difficulty: Difficulty,
         clue_stats: Option<ClueStats>,
+        candidate_display: CandidateDisplay,
     ) -> LayoutConfiguration {
         let n_variants = difficulty.n_cols();
         let n_rows = difficulty.n_rows();
@@ -292,6 +461,7 @@ impl LayoutManager {
             candidate_image: candidate_image,
             n_variants: n_variants as i32,
             n_rows: n_rows as i32,
+            candidate_display,
             candidate_spacing: SPACING_SMALL,
             grid_column_spacing: SPACING_LARGE,
             grid_row_spacing: SPACING_LARGE,
@@ -351,16 +521,39 @@ impl LayoutManager {
 
     // TODO - get rid of inputs array
     fn calculate_scaled_layout(&self) -> LayoutConfiguration {
-        let base_layout =
-            LayoutManager::calculate_layout(self.current_difficulty, Some(self.clue_stats));
+        let base_layout = LayoutManager::calculate_layout(
+            self.current_difficulty,
+            Some(self.effective_clue_stats()),
+            self.candidate_display,
+        );
 
         if self.container_dimensions.is_none() {
             return base_layout;
         }
 
         let surface = self.container_dimensions.as_ref().unwrap();
-        let n_variants = self.current_difficulty.n_cols();
-        let n_rows = self.current_difficulty.n_rows();
+        let required = LayoutManager::required_dimensions(&base_layout, self.current_difficulty);
+
+        // Calculate scaling factors based on window dimensions
+        let available_width = surface.width;
+        let available_height = surface.height - base_layout.tutorial.height; // don't scale the tutorial height
+
+        // Calculate scale factors for both dimensions
+        let width_scale = available_width as f32 / required.width as f32;
+        let height_scale = available_height as f32 / required.height as f32;
+
+        // Use the smaller scale factor to maintain aspect ratio
+        let scale = width_scale.min(height_scale);
+        self.scale_layout(base_layout, scale)
+    }
+
+    /// Space the grid and clue panels of an unscaled `layout` need, excluding the tutorial
+    fn required_dimensions(
+        base_layout: &LayoutConfiguration,
+        difficulty: Difficulty,
+    ) -> Dimensions {
+        let n_variants = difficulty.n_cols();
+        let n_rows = difficulty.n_rows();
 
         // Calculate total required dimensions
         let total_grid_width = base_layout.grid.cell.dimensions.width * n_variants as i32
@@ -390,17 +583,29 @@ impl LayoutManager {
                 .width
             + SPACING_LARGE;
 
-        // Calculate scaling factors based on window dimensions
-        let available_width = surface.width;
-        let available_height = surface.height - base_layout.tutorial.height; // don't scale the tutorial height
-
-        // Calculate scale factors for both dimensions
-        let width_scale = available_width as f32 / total_required_width as f32;
-        let height_scale = available_height as f32 / total_required_height as f32;
+        Dimensions {
+            width: total_required_width,
+            height: total_required_height,
+        }
+    }
 
-        // Use the smaller scale factor to maintain aspect ratio
-        let scale = width_scale.min(height_scale);
-        self.scale_layout(base_layout, scale)
+    /// Window size at which a `difficulty` puzzle fits without scaling, assuming a typical clue
+    /// count (one full column of horizontal clues)
+    pub fn estimate_window_size(difficulty: Difficulty) -> Dimensions {
+        let clue_stats = ClueStats {
+            n_horizontal_clues: CluePanelsUI::calc_clues_per_column(difficulty),
+            ..ClueStats::default()
+        };
+        let layout = LayoutManager::calculate_layout(
+            difficulty,
+            Some(clue_stats),
+            CandidateDisplay::default(),
+        );
+        let required = LayoutManager::required_dimensions(&layout, difficulty);
+        Dimensions {
+            width: required.width + WINDOW_CHROME_SIZE.width,
+            height: required.height + layout.tutorial.height + WINDOW_CHROME_SIZE.height,
+        }
     }
 
     fn scale_layout(&self, layout: LayoutConfiguration, scale: f32) -> LayoutConfiguration {
@@ -442,6 +647,7 @@ impl LayoutManager {
             candidate_image: candidate_image,
             n_variants: layout.grid.n_variants,
             n_rows: layout.grid.n_rows,
+            candidate_display: self.candidate_display,
             candidate_spacing: (layout.grid.cell.candidate_spacing as f32 * scale) as i32,
             grid_column_spacing: (layout.grid.column_spacing as f32 * scale) as i32,
             grid_row_spacing: (layout.grid.row_spacing as f32 * scale) as i32,
@@ -460,6 +666,7 @@ impl LayoutManager {
                 candidate_image: candidate_image,
                 n_variants: layout.grid.n_variants,
                 n_rows: layout.grid.n_rows,
+                candidate_display: self.candidate_display,
                 candidate_spacing: (layout.grid.cell.candidate_spacing as f32 * scale) as i32,
                 grid_column_spacing: (layout.grid.column_spacing as f32 * scale) as i32,
                 grid_row_spacing: (layout.grid.row_spacing as f32 * scale) as i32,
@@ -536,25 +743,50 @@ impl LayoutManager {
         let n_variants = inputs.n_variants;
         let n_rows = inputs.n_rows;
 
-        let candidate_n_rows = (n_variants as i32 + 1) / 2;
-        let candidate_n_columns = n_variants as i32;
-
-        let base_cell_aspect_ratio_width = (n_variants as i32 + 1) / 2;
-        let base_cell_aspect_ratio_height = 2;
-
-        let cell_width = (inputs.solution_image.width * base_cell_aspect_ratio_width
-            / base_cell_aspect_ratio_height)
-            + inputs.candidate_spacing * (candidate_n_columns - 1);
-
-        let cell_height =
-            inputs.solution_image.height + inputs.candidate_spacing * (candidate_n_rows - 1);
+        let (candidate_n_rows, candidate_n_columns, cell_width, cell_height, solution_image) =
+            match inputs.candidate_display {
+                CandidateDisplay::Grid => {
+                    let base_cell_aspect_ratio_width = (n_variants as i32 + 1) / 2;
+                    let base_cell_aspect_ratio_height = 2;
+
+                    // candidates span two half-tile grid columns, so there's a gap per variant
+                    let cell_width = (inputs.solution_image.width * base_cell_aspect_ratio_width
+                        / base_cell_aspect_ratio_height)
+                        + inputs.candidate_spacing * (n_variants as i32 - 1);
+
+                    let cell_height = inputs.solution_image.height
+                        + inputs.candidate_spacing * ((n_variants as i32 + 1) / 2 - 1);
+
+                    (
+                        2,
+                        base_cell_aspect_ratio_width,
+                        cell_width,
+                        cell_height,
+                        inputs.solution_image,
+                    )
+                }
+                CandidateDisplay::List => {
+                    // one candidate per row; the solution shrinks to fit the narrow cell
+                    let cell_width = inputs.candidate_image.width;
+                    let cell_height = inputs.candidate_image.height * n_variants as i32
+                        + inputs.candidate_spacing * (n_variants as i32 - 1);
+
+                    (
+                        n_variants as i32,
+                        1,
+                        cell_width,
+                        cell_height,
+                        inputs.candidate_image,
+                    )
+                }
+            };
 
         let base_cell_sizing = GridCellSizing {
             dimensions: Dimensions {
                 width: cell_width,
                 height: cell_height,
             },
-            solution_image: inputs.solution_image,
+            solution_image,
             candidate_image: inputs.candidate_image,
             candidate_spacing: inputs.candidate_spacing,
             candidate_rows: candidate_n_rows,
@@ -590,6 +822,7 @@ struct GridSizingInputs {
     candidate_image: Dimensions,
     n_variants: i32,
     n_rows: i32,
+    candidate_display: CandidateDisplay,
     candidate_spacing: i32, // space between candidate tiles
     grid_column_spacing: i32,
     grid_row_spacing: i32,
@@ -600,6 +833,15 @@ struct GridSizingInputs {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_estimate_window_size_grows_with_difficulty() {
+        let easy = LayoutManager::estimate_window_size(Difficulty::Easy);
+        let veteran = LayoutManager::estimate_window_size(Difficulty::Veteran);
+
+        assert!(veteran.width > easy.width);
+        assert!(veteran.height > easy.height);
+    }
+
     #[test]
     fn test_calc_horiz_clue_columns() {
         // Test case 1: Empty case
@@ -626,4 +868,71 @@ mod tests {
         // Test case 8: Negative number of clues (should handle gracefully)
         assert_eq!(LayoutManager::calc_horiz_clue_columns(-1, 16), (1, 0));
     }
+
+    #[test]
+    fn test_collapsed_clue_stats_shrink_horizontal_panel() {
+        let clue_stats = ClueStats {
+            n_vertical_clues: 6,
+            n_horizontal_clues: 20,
+            n_vertical_clue_groups: 3,
+            n_completed_vertical_clues: 2,
+            n_completed_horizontal_clues: 12,
+        };
+        let collapsed = clue_stats.collapsed();
+        assert_eq!(collapsed.n_horizontal_clues, 8);
+        assert_eq!(collapsed.n_vertical_clues, 6);
+
+        let panel_width = |clue_stats| {
+            LayoutManager::calculate_layout(
+                Difficulty::Veteran,
+                Some(clue_stats),
+                CandidateDisplay::Grid,
+            )
+            .clues
+            .horizontal_clue_panel
+            .total_clues_dimensions
+            .width
+        };
+        assert!(panel_width(collapsed) < panel_width(clue_stats));
+    }
+
+    #[test]
+    fn test_list_candidate_display_uses_single_column() {
+        let layout_for = |candidate_display| {
+            LayoutManager::calculate_layout(Difficulty::Hard, None, candidate_display).grid
+        };
+        let grid = layout_for(CandidateDisplay::Grid);
+        let list = layout_for(CandidateDisplay::List);
+
+        assert_eq!(grid.cell.candidate_rows, 2);
+        assert_eq!(grid.cell.candidate_columns, 3);
+        assert_eq!(list.cell.candidate_rows, 6);
+        assert_eq!(list.cell.candidate_columns, 1);
+        assert!(list.cell.dimensions.width < grid.cell.dimensions.width);
+        assert!(list.cell.dimensions.height > grid.cell.dimensions.height);
+        assert!(list.cell.solution_image.width <= list.cell.dimensions.width);
+    }
+
+    #[test]
+    fn test_scale_factor_tracker_debounces() {
+        let start = Instant::now();
+        let mut tracker = ScaleFactorTracker::new(I8F8::from_num(1));
+
+        tracker.observe(2.0, start);
+        assert_eq!(tracker.settle(start + Duration::from_millis(100)), None);
+
+        // flipping back to the applied factor cancels the pending change
+        tracker.observe(1.0, start + Duration::from_millis(150));
+        assert_eq!(tracker.settle(start + Duration::from_secs(1)), None);
+
+        tracker.observe(2.0, start + Duration::from_secs(2));
+        // re-observing the same factor doesn't restart the settle timer
+        tracker.observe(2.0, start + Duration::from_millis(2200));
+        assert_eq!(
+            tracker.settle(start + Duration::from_millis(2300)),
+            Some(I8F8::from_num(2))
+        );
+        assert_eq!(tracker.applied, I8F8::from_num(2));
+        assert_eq!(tracker.settle(start + Duration::from_secs(5)), None);
+    }
 }
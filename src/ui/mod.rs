@@ -1,51 +1,85 @@
+mod achievement_toast;
+mod archive_dialog;
 mod audio_set;
+mod autosave_timer;
+mod clipboard_puzzle_prompt;
+mod clue_editor_dialog;
 mod clue_panels_ui;
 mod clue_tile_ui;
 mod clue_ui;
+mod clue_weights_dialog;
+mod daily_puzzle_ui;
+mod deduction_log_ui;
+mod favorites_dialog;
 mod game_info_ui;
 mod helpers;
 mod hint_button_ui;
 mod history_controls_ui;
 mod image_set;
 mod input_translator;
+mod keyboard_shortcuts;
+mod keymap_dialog;
 mod layout;
 mod layout_manager;
 mod not_quite_right_dialog;
+mod obvious_consequence_ui;
 mod pause_screen_ui;
 mod puzzle_cell_ui;
 mod puzzle_generation_dialog;
 mod puzzle_grid_ui;
 mod resource_manager;
+mod sandbox_banner_ui;
 mod seed_dialog;
 mod settings_menu_ui;
+mod solution_preview_ui;
+mod solvability_check_ui;
 mod stats_dialog;
 mod submit_ui;
 mod template;
 mod timer_button_ui;
 mod top_level_input_event_monitor;
 pub mod tutorial_ui;
+mod walkthrough_dialog;
+mod welcome_prompt;
 mod window;
 
+pub use achievement_toast::AchievementToast;
+pub use archive_dialog::ArchiveDialog;
+pub use autosave_timer::AutosaveTimer;
+pub use clipboard_puzzle_prompt::ClipboardPuzzlePrompt;
+pub use clue_editor_dialog::ClueEditorDialog;
 pub use clue_panels_ui::CluePanelsUI;
 pub use clue_tile_ui::ClueTileUI;
 pub use clue_ui::ClueUI;
+pub use clue_weights_dialog::ClueWeightsDialog;
+pub use daily_puzzle_ui::DailyPuzzleUI;
+pub use deduction_log_ui::DeductionLogUI;
+pub use favorites_dialog::FavoritesDialog;
 pub use game_info_ui::GameInfoUI;
 pub use helpers::*;
 pub use hint_button_ui::HintButtonUI;
 pub use history_controls_ui::HistoryControlsUI;
 pub use image_set::ImageSet;
 pub use input_translator::InputTranslator;
+pub use keyboard_shortcuts::KeyboardShortcutsDialog;
+pub use keymap_dialog::KeymapDialog;
 pub use layout_manager::LayoutManager;
 pub use not_quite_right_dialog::NotQuiteRightDialog;
+pub use obvious_consequence_ui::ObviousConsequenceUI;
 pub use pause_screen_ui::PauseScreenUI;
 pub use puzzle_cell_ui::PuzzleCellUI;
 pub use puzzle_generation_dialog::PuzzleGenerationDialog;
 pub use puzzle_grid_ui::PuzzleGridUI;
 pub use resource_manager::ResourceManager;
+pub use sandbox_banner_ui::SandboxBannerUI;
 pub use seed_dialog::SeedDialog;
 pub use settings_menu_ui::SettingsMenuUI;
+pub use solution_preview_ui::SolutionPreviewUI;
+pub use solvability_check_ui::SolvabilityCheckUI;
 pub use stats_dialog::StatsDialog;
 pub use submit_ui::SubmitUI;
 pub use timer_button_ui::TimerButtonUI;
 pub use top_level_input_event_monitor::TopLevelInputEventMonitor;
+pub use walkthrough_dialog::WalkthroughDialog;
+pub use welcome_prompt::WelcomePrompt;
 pub use window::build_ui;
@@ -7,12 +7,30 @@ use gtk4::{
     ApplicationWindow, EventControllerKey, Label,
 };
 
-use crate::{events::EventEmitter, model::GameEngineCommand};
+use crate::{
+    events::EventEmitter, game::game_engine::ERROR_CORRECTION_HINT_PENALTY,
+    model::GameEngineCommand,
+};
 use fluent_i18n::t;
 
+/// How the player closed the dialog
+#[derive(Clone, Copy)]
+enum Choice {
+    /// Cancel or Escape: take back the last move
+    Undo,
+    Rewind,
+    RestoreCandidate,
+    /// Leave the board as it is and go back to editing it
+    KeepEditing,
+    /// Keep editing, with the wrong tiles flashed
+    ShowMistakes,
+}
+
 pub struct NotQuiteRightDialog {
     window: Rc<ApplicationWindow>,
     game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    offer_candidate_restore: bool,
+    offer_keep_editing: bool,
 }
 
 impl NotQuiteRightDialog {
@@ -23,9 +41,24 @@ impl NotQuiteRightDialog {
         Self {
             window: window.clone(),
             game_engine_command_emitter,
+            offer_candidate_restore: false,
+            offer_keep_editing: false,
         }
     }
 
+    /// Also offer to restore a wrongly eliminated candidate instead of rewinding
+    pub fn with_candidate_restore(mut self, offer_candidate_restore: bool) -> Self {
+        self.offer_candidate_restore = offer_candidate_restore;
+        self
+    }
+
+    /// Also offer to go back to editing the board as it is, optionally shown where it's wrong.
+    /// Meant for a filled board that was submitted, where the mistake is likely small.
+    pub fn with_keep_editing(mut self, offer_keep_editing: bool) -> Self {
+        self.offer_keep_editing = offer_keep_editing;
+        self
+    }
+
     pub fn show(&self) {
         let content_area = gtk4::Box::builder()
             .orientation(gtk4::Orientation::Vertical)
@@ -41,9 +74,7 @@ impl NotQuiteRightDialog {
             .modal(true)
             .build();
 
-        content_area.append(&Label::new(Some(&t!(
-            "not-quite-right-message"
-        ))));
+        content_area.append(&Label::new(Some(&t!("not-quite-right-message"))));
 
         let buttons = gtk4::Box::builder()
             .orientation(gtk4::Orientation::Horizontal)
@@ -52,28 +83,46 @@ impl NotQuiteRightDialog {
             .build();
         content_area.append(&buttons);
 
-        let cancel_button = gtk4::Button::builder().label(&t!("cancel")).build();
-        buttons.append(&cancel_button);
-        let ok_button = gtk4::Button::builder().label(&t!("ok")).build();
-        buttons.append(&ok_button);
-
-        let ok_clicked = Rc::new(Cell::new(false));
-
-        cancel_button.connect_clicked({
-            let dialog = dialog.clone();
-            move |_| {
-                dialog.close();
-            }
-        });
+        let choice = Rc::new(Cell::new(Choice::Undo));
+        let add_button = |label: &str, tooltip: Option<String>, button_choice: Choice| {
+            let button = gtk4::Button::builder().label(label).build();
+            button.set_tooltip_text(tooltip.as_deref());
+            buttons.append(&button);
+            button.connect_clicked({
+                let dialog = dialog.clone();
+                let choice = choice.clone();
+                move |_| {
+                    choice.set(button_choice);
+                    dialog.close();
+                }
+            });
+        };
 
-        ok_button.connect_clicked({
-            let dialog = dialog.clone();
-            let ok_clicked = ok_clicked.clone();
-            move |_| {
-                ok_clicked.set(true);
-                dialog.close();
-            }
-        });
+        add_button(&t!("cancel"), None, Choice::Undo);
+        if self.offer_keep_editing {
+            add_button(
+                &t!("not-quite-right-keep-editing"),
+                None,
+                Choice::KeepEditing,
+            );
+            add_button(
+                &t!("not-quite-right-show-mistakes"),
+                Some(t!("not-quite-right-show-mistakes-tooltip", {
+                    "penalty" => ERROR_CORRECTION_HINT_PENALTY.to_string()
+                })),
+                Choice::ShowMistakes,
+            );
+        }
+        if self.offer_candidate_restore {
+            add_button(
+                &t!("not-quite-right-restore-candidate"),
+                Some(t!("not-quite-right-restore-candidate-tooltip", {
+                    "penalty" => ERROR_CORRECTION_HINT_PENALTY.to_string()
+                })),
+                Choice::RestoreCandidate,
+            );
+        }
+        add_button(&t!("ok"), None, Choice::Rewind);
 
         let key_controller = EventControllerKey::new();
         key_controller.connect_key_pressed({
@@ -91,10 +140,17 @@ impl NotQuiteRightDialog {
         dialog.connect_close_request({
             let game_engine_command_emitter = self.game_engine_command_emitter.clone();
             move |_| {
-                if ok_clicked.get() {
-                    game_engine_command_emitter.emit(GameEngineCommand::RewindLastGood);
-                } else {
-                    game_engine_command_emitter.emit(GameEngineCommand::Undo);
+                match choice.get() {
+                    Choice::Undo => game_engine_command_emitter.emit(GameEngineCommand::Undo),
+                    Choice::Rewind => {
+                        game_engine_command_emitter.emit(GameEngineCommand::RewindLastGood)
+                    }
+                    Choice::RestoreCandidate => game_engine_command_emitter
+                        .emit(GameEngineCommand::RestoreEliminatedSolutionTile),
+                    Choice::KeepEditing => {}
+                    Choice::ShowMistakes => {
+                        game_engine_command_emitter.emit(GameEngineCommand::ShowIncorrectCells)
+                    }
                 }
                 Propagation::Proceed
             }
@@ -0,0 +1,128 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gtk4::{prelude::*, Button, Image, Label, Orientation};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    model::{Deduction, GameEngineCommand, GameEngineEvent, LayoutManagerEvent},
+};
+use fluent_i18n::t;
+
+use super::ImageSet;
+
+const PROMPT_ICON_SIZE: i32 = 20;
+
+/// Header prompt offering to place a tile that the clue for the last placement forces. It goes
+/// away with the next board change, whether or not it was taken up.
+pub struct ObviousConsequenceUI {
+    pub button: Button,
+    image: Image,
+    label: Label,
+    resources: Rc<ImageSet>,
+    deduction: Rc<RefCell<Option<Deduction>>>,
+}
+
+impl Destroyable for ObviousConsequenceUI {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for ObviousConsequenceUI {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        match event {
+            GameEngineEvent::ObviousConsequenceAvailable(deduction, _) => {
+                self.offer(deduction);
+            }
+            GameEngineEvent::GameBoardUpdated { .. } => self.dismiss(),
+            GameEngineEvent::SettingsChanged(settings)
+                if !settings.suggest_obvious_consequences =>
+            {
+                self.dismiss();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl EventHandler<LayoutManagerEvent> for ObviousConsequenceUI {
+    fn handle_event(&mut self, event: &LayoutManagerEvent) {
+        if let LayoutManagerEvent::ImagesOptimized(image_set) = event {
+            self.resources = image_set.clone();
+            if let Some(deduction) = self.deduction.borrow().as_ref() {
+                self.image.set_paintable(Some(
+                    self.resources
+                        .get_solution_icon(&deduction.tile_assertion.tile)
+                        .as_ref(),
+                ));
+            }
+        }
+    }
+}
+
+impl ObviousConsequenceUI {
+    pub fn new(
+        resources: &Rc<ImageSet>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    ) -> Rc<RefCell<Self>> {
+        let image = Image::builder().pixel_size(PROMPT_ICON_SIZE).build();
+        let label = Label::new(None);
+        let content = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .spacing(5)
+            .build();
+        content.append(&image);
+        content.append(&label);
+        let button = Button::builder()
+            .child(&content)
+            .css_classes(["obvious-consequence"])
+            .visible(false)
+            .build();
+
+        let deduction: Rc<RefCell<Option<Deduction>>> = Rc::new(RefCell::new(None));
+        button.connect_clicked({
+            let deduction = deduction.clone();
+            move |_| {
+                // the board update this causes dismisses the prompt
+                let pending = deduction.borrow().clone();
+                if let Some(deduction) = pending {
+                    let tile = deduction.tile_assertion.tile;
+                    game_engine_command_emitter.emit(GameEngineCommand::CellSelect(
+                        tile.row,
+                        deduction.column,
+                        Some(tile.variant),
+                    ));
+                }
+            }
+        });
+
+        Rc::new(RefCell::new(Self {
+            button,
+            image,
+            label,
+            resources: resources.clone(),
+            deduction,
+        }))
+    }
+
+    fn offer(&self, deduction: &Deduction) {
+        let column = (deduction.column + 1).to_string();
+        self.image.set_paintable(Some(
+            self.resources
+                .get_solution_icon(&deduction.tile_assertion.tile)
+                .as_ref(),
+        ));
+        self.label
+            .set_text(&t!("obvious-consequence-label", { "column" => column.clone() }));
+        self.button.set_tooltip_text(Some(&t!(
+            "obvious-consequence-tooltip",
+            { "column" => column }
+        )));
+        self.button.set_visible(true);
+        *self.deduction.borrow_mut() = Some(deduction.clone());
+    }
+
+    fn dismiss(&self) {
+        self.button.set_visible(false);
+        *self.deduction.borrow_mut() = None;
+    }
+}
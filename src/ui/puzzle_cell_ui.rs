@@ -6,24 +6,39 @@ use std::rc::Rc;
 use crate::destroyable::Destroyable;
 use crate::events::EventEmitter;
 use crate::model::{
-    Candidate, CandidateCellTileData, CandidateState, Clickable, ClueWithAddress, GridSizing,
-    InputEvent, SolutionTileData, Tile,
+    Candidate, CandidateCellTileData, CandidateColor, CandidateState, Clickable, ClueWithAddress,
+    GridSizing, InputEvent, SolutionTileData, Tile,
 };
 use glib::timeout_add_local_once;
-use gtk4::{prelude::*, GestureClick, Widget};
+use gtk4::{gdk, prelude::*, GestureClick, GestureDrag, Widget};
 use gtk4::{Frame, Grid, Image, Overlay};
 use log::{trace, warn};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
 
 use super::register_left_click_handler;
 use super::ImageSet;
 
+const SHAKE_CSS_CLASS: &str = "invalid-shake";
+const CURSOR_CSS_CLASS: &str = "grid-cursor";
+// matches the invalid-shake animation in style.css
+const SHAKE_DURATION: std::time::Duration = std::time::Duration::from_millis(300);
+
+fn candidate_color_css_class(color: CandidateColor) -> &'static str {
+    match color {
+        CandidateColor::Red => "candidate-tag-red",
+        CandidateColor::Blue => "candidate-tag-blue",
+        CandidateColor::Green => "candidate-tag-green",
+        CandidateColor::Orange => "candidate-tag-orange",
+    }
+}
+
 pub struct PuzzleCellUI {
     pub frame: Frame,
-    pub candidates_grid: Grid,                 // 2x3 grid for candidates
-    pub solution_image: Image,                 // Large image for selected solution
-    pub solution_overlay: Rc<Overlay>,         // Overlay for solution image
-    pub candidate_images: Vec<Image>,          // Small images for candidates
-    pub _candidate_overlays: Vec<Rc<Overlay>>, // Overlays for highlighting; need to hold references for GTK
+    pub candidates_grid: Grid,                // 2x3 grid for candidates
+    pub solution_image: Image,                // Large image for selected solution
+    pub solution_overlay: Rc<Overlay>,        // Overlay for solution image
+    pub candidate_images: Vec<Image>,         // Small images for candidates
+    pub candidate_overlays: Vec<Rc<Overlay>>, // Overlays for highlighting; need to hold references for GTK
     pub candidate_highlight_frames: Vec<Rc<Frame>>, // Frames for showing highlights
     pub resources: Rc<ImageSet>,
     pub row: usize,
@@ -32,21 +47,52 @@ pub struct PuzzleCellUI {
     pub _variants: RangeInclusive<char>,
     pub n_variants: usize,
     current_layout: GridSizing,
+    center_partial_row: bool,
+    hide_eliminated: bool,
+    // temporarily show hidden eliminated candidates so they can be clicked back in
+    revealing_eliminated: bool,
+    // order the candidates are laid out in; hit-testing goes through it, so it's free to differ
+    // from the variant order
+    display_order: Vec<usize>,
+    // indices of the candidates currently attached to the grid, in display order
+    shown_variants: Vec<usize>,
     gesture_click: Option<GestureClick>,
-    gesture_right: Option<GestureClick>,
+    gesture_right: Option<GestureDrag>,
+    gesture_middle: Option<GestureClick>,
+    // candidates touched by the right-button drag in progress, in the order they were touched
+    dragged_variants: Vec<char>,
     available_tiles: HashSet<Tile>,
     selected_tile: Option<Tile>,
     clue_selection: Option<ClueWithAddress>,
+    // candidate the keyboard cursor is on, when it's in this cell
+    cursor: Option<char>,
+    // the player's color tag on each candidate, indexed by variant
+    candidate_colors: Vec<Option<CandidateColor>>,
 }
 
 impl PuzzleCellUI {
-    fn grid_dimensions(n_variants: usize, idx: usize) -> (usize, usize) {
-        let n_cols = (n_variants + 1) / 2;
+    fn grid_dimensions(n_cols: usize, idx: usize) -> (usize, usize) {
         let row = idx / n_cols;
         let col = idx % n_cols;
         (row, col)
     }
 
+    /// How many half-tile columns the candidates in `row` are shifted right. Each candidate spans
+    /// two half-tile grid columns so that a short last row can be offset by half a tile.
+    fn row_half_offset(
+        n_cols: usize,
+        n_shown: usize,
+        row: usize,
+        center_partial_row: bool,
+    ) -> usize {
+        let n_in_row = n_shown.saturating_sub(row * n_cols).min(n_cols);
+        if center_partial_row {
+            n_cols - n_in_row
+        } else {
+            0
+        }
+    }
+
     pub fn new(
         resources: Rc<ImageSet>,
         row: usize,
@@ -54,6 +100,8 @@ impl PuzzleCellUI {
         input_event_emitter: EventEmitter<InputEvent>,
         variants: RangeInclusive<char>,
         layout: GridSizing,
+        center_partial_row: bool,
+        hide_eliminated: bool,
     ) -> Rc<RefCell<Self>> {
         let frame = Frame::builder()
             .name(&format!("puzzle-cell-frame-{}", row))
@@ -65,6 +113,7 @@ impl PuzzleCellUI {
         candidates_grid.set_valign(gtk4::Align::Center);
         candidates_grid.set_hexpand(false);
         candidates_grid.set_vexpand(false);
+        candidates_grid.set_column_homogeneous(true);
 
         let solution_image = Image::new();
         solution_image.set_visible(false);
@@ -96,14 +145,9 @@ impl PuzzleCellUI {
         let candidate_overlays: Vec<Rc<Overlay>> =
             variants.clone().map(|_| Rc::new(Overlay::new())).collect();
 
-        // Set up grid of candidate overlays
         for (idx, overlay) in candidate_overlays.iter().enumerate() {
-            let (grid_row, grid_col) = PuzzleCellUI::grid_dimensions(n_variants, idx);
-
             overlay.set_child(Some(&candidate_images[idx]));
             overlay.add_overlay(candidate_highlight_frames[idx].upcast_ref::<Widget>());
-
-            candidates_grid.attach(overlay.as_ref(), grid_col as i32, grid_row as i32, 1, 1);
         }
 
         // Create root overlay that will contain either candidates_grid or solution_overlay
@@ -118,7 +162,7 @@ impl PuzzleCellUI {
             solution_image,
             solution_overlay,
             candidate_images,
-            _candidate_overlays: candidate_overlays,
+            candidate_overlays,
             candidate_highlight_frames,
             resources,
             row,
@@ -127,12 +171,22 @@ impl PuzzleCellUI {
             _variants: variants.clone(),
             n_variants,
             current_layout: layout,
+            center_partial_row,
+            hide_eliminated,
+            revealing_eliminated: false,
+            display_order: (0..n_variants).collect(),
+            shown_variants: (0..n_variants).collect(),
             gesture_click: None,
             gesture_right: None,
+            gesture_middle: None,
+            dragged_variants: Vec::new(),
             available_tiles: HashSet::new(),
             selected_tile: None,
             clue_selection: None,
+            cursor: None,
+            candidate_colors: vec![None; n_variants],
         };
+        cell_ui.attach_candidates();
         cell_ui.apply_layout();
 
         let cell_ui = Rc::new(RefCell::new(cell_ui));
@@ -213,9 +267,115 @@ impl PuzzleCellUI {
             .set_column_spacing(self.current_layout.cell.candidate_spacing as u32);
     }
 
+    /// Set up grid of candidate overlays; only `shown_variants` are attached, packed in order
+    fn attach_candidates(&self) {
+        let n_shown = self.shown_variants.len();
+        let n_cols = self.candidate_columns();
+        for (idx, overlay) in self.candidate_overlays.iter().enumerate() {
+            if overlay.parent().is_some() {
+                self.candidates_grid.remove(overlay.as_ref());
+            }
+            let Some(slot) = self.shown_variants.iter().position(|shown| *shown == idx) else {
+                continue;
+            };
+            let (grid_row, grid_col) = PuzzleCellUI::grid_dimensions(n_cols, slot);
+            let half_offset =
+                PuzzleCellUI::row_half_offset(n_cols, n_shown, grid_row, self.center_partial_row);
+
+            self.candidates_grid.attach(
+                overlay.as_ref(),
+                (grid_col * 2 + half_offset) as i32,
+                grid_row as i32,
+                2,
+                1,
+            );
+        }
+    }
+
+    pub fn set_center_partial_row(&mut self, center_partial_row: bool) {
+        if self.center_partial_row == center_partial_row {
+            return;
+        }
+        self.center_partial_row = center_partial_row;
+        self.attach_candidates();
+    }
+
+    fn is_variant_shown(&self, variant_idx: usize) -> bool {
+        if !self.hide_eliminated || self.revealing_eliminated {
+            return true;
+        }
+        let variant = (b'a' + variant_idx as u8) as char;
+        self.available_tiles.contains(&Tile::new(self.row, variant))
+    }
+
+    /// Reflow the candidates grid if the set of shown candidates or their order changed
+    fn sync_shown_variants(&mut self) {
+        let shown_variants: Vec<usize> = self
+            .display_order
+            .iter()
+            .copied()
+            .filter(|idx| self.is_variant_shown(*idx))
+            .collect();
+        if shown_variants != self.shown_variants {
+            self.shown_variants = shown_variants;
+            self.attach_candidates();
+        }
+    }
+
+    /// A shuffled candidate order for the cell at `row`, `col`, the same every time for a seed
+    pub fn shuffled_display_order(
+        n_variants: usize,
+        seed: u64,
+        row: usize,
+        col: usize,
+    ) -> Vec<usize> {
+        let cell_seed = seed ^ ((row as u64) << 56) ^ ((col as u64) << 48);
+        let mut rng = StdRng::seed_from_u64(cell_seed);
+        let mut display_order: Vec<usize> = (0..n_variants).collect();
+        display_order.shuffle(&mut rng);
+        display_order
+    }
+
+    /// Lay the candidates out in `display_order`, a permutation of the variant indices
+    pub fn set_display_order(&mut self, display_order: Vec<usize>) {
+        if self.display_order == display_order {
+            return;
+        }
+        self.display_order = display_order;
+        self.sync_shown_variants();
+    }
+
+    pub fn set_hide_eliminated(&mut self, hide_eliminated: bool) {
+        if self.hide_eliminated == hide_eliminated {
+            return;
+        }
+        self.hide_eliminated = hide_eliminated;
+        self.revealing_eliminated = false;
+        self.sync_shown_variants();
+    }
+
+    /// Show hidden eliminated candidates until the next board update, so one can be clicked to
+    /// restore it
+    fn reveal_eliminated(&mut self) {
+        if !self.hide_eliminated || self.revealing_eliminated {
+            return;
+        }
+        self.revealing_eliminated = true;
+        self.sync_shown_variants();
+    }
+
+    /// Candidates per row, as laid out for the current candidate display
+    fn candidate_columns(&self) -> usize {
+        self.current_layout.cell.candidate_columns.max(1) as usize
+    }
+
     pub fn update_layout(&mut self, layout: &GridSizing) {
+        let reflow = layout.cell.candidate_columns != self.current_layout.cell.candidate_columns;
         self.current_layout = layout.clone();
         self.apply_layout();
+        if reflow {
+            self.attach_candidates();
+        }
     }
 
     fn register_click_handler(cell_ui: Rc<RefCell<Self>>) {
@@ -233,10 +393,24 @@ impl PuzzleCellUI {
             &gesture_click,
             {
                 let cell_ui = Rc::downgrade(&cell_ui);
-                move |_, _, x, y| {
+                move |gesture: &GestureClick, _, x, y| {
                     if let Some(cell_ui) = cell_ui.upgrade() {
-                        let cell_ui = cell_ui.borrow();
+                        let mut cell_ui = cell_ui.borrow_mut();
+                        let shift_held = gesture
+                            .current_event_state()
+                            .contains(gdk::ModifierType::SHIFT_MASK);
                         if let Some(variant) = cell_ui.get_variant_at_position(x, y) {
+                            if shift_held {
+                                // shift-click moves the candidate on to the next color tag
+                                let color =
+                                    cell_ui.candidate_colors[Tile::variant_to_usize(variant)];
+                                cell_ui.input_event_emitter.emit(InputEvent::CandidateTag(
+                                    CandidateCellTileData { row, col, variant },
+                                    CandidateColor::cycle(color),
+                                ));
+                                gesture.set_state(gtk4::EventSequenceState::Claimed);
+                                return None;
+                            }
                             Some(Clickable::CandidateCellTile(CandidateCellTileData {
                                 row,
                                 col,
@@ -245,6 +419,8 @@ impl PuzzleCellUI {
                         } else if cell_ui.selected_tile.is_some() {
                             Some(Clickable::SolutionTile(SolutionTileData { row, col }))
                         } else {
+                            // empty space is the way back to hidden eliminated candidates
+                            cell_ui.reveal_eliminated();
                             None
                         }
                     } else {
@@ -254,29 +430,108 @@ impl PuzzleCellUI {
             },
         );
 
-        // Right click handler
-        let gesture_right = gtk4::GestureClick::new();
+        // Right button handler: a click eliminates one candidate, a drag eliminates every
+        // candidate it passes over
+        let gesture_right = gtk4::GestureDrag::new();
         gesture_right.set_button(3);
 
-        gesture_right.connect_pressed({
+        gesture_right.connect_drag_begin({
+            let cell_ui = Rc::downgrade(&cell_ui);
+            move |gesture, x, y| {
+                if let Some(cell_ui) = cell_ui.upgrade() {
+                    let mut cell_ui = cell_ui.borrow_mut();
+                    cell_ui.dragged_variants.clear();
+                    if let Some(variant) = cell_ui.get_variant_at_position(x, y) {
+                        cell_ui.dragged_variants.push(variant);
+                    }
+                    gesture.set_state(gtk4::EventSequenceState::Claimed);
+                } else {
+                    warn!(target: "puzzle_cell_ui", "Stale handler called!");
+                }
+            }
+        });
+
+        gesture_right.connect_drag_update({
+            let cell_ui = Rc::downgrade(&cell_ui);
+            move |gesture, offset_x, offset_y| {
+                let Some(cell_ui) = cell_ui.upgrade() else {
+                    return;
+                };
+                let Some((start_x, start_y)) = gesture.start_point() else {
+                    return;
+                };
+                let mut cell_ui = cell_ui.borrow_mut();
+                if let Some(variant) =
+                    cell_ui.get_variant_at_position(start_x + offset_x, start_y + offset_y)
+                {
+                    if !cell_ui.dragged_variants.contains(&variant) {
+                        cell_ui.dragged_variants.push(variant);
+                    }
+                }
+            }
+        });
+
+        gesture_right.connect_drag_end({
+            let cell_ui = Rc::downgrade(&cell_ui);
+            move |_, _, _| {
+                let Some(cell_ui) = cell_ui.upgrade() else {
+                    warn!(target: "puzzle_cell_ui", "Stale handler called!");
+                    return;
+                };
+                let mut cell_ui = cell_ui.borrow_mut();
+                let dragged_variants = std::mem::take(&mut cell_ui.dragged_variants);
+                match dragged_variants.as_slice() {
+                    [] => {
+                        if cell_ui.selected_tile.is_some() {
+                            cell_ui.input_event_emitter.emit(InputEvent::RightClick(
+                                Clickable::SolutionTile(SolutionTileData { row, col }),
+                            ));
+                        }
+                    }
+                    [variant] => {
+                        cell_ui.input_event_emitter.emit(InputEvent::RightClick(
+                            Clickable::CandidateCellTile(CandidateCellTileData {
+                                row,
+                                col,
+                                variant: *variant,
+                            }),
+                        ));
+                    }
+                    _ => {
+                        cell_ui.input_event_emitter.emit(InputEvent::RightDrag(
+                            dragged_variants
+                                .iter()
+                                .map(|variant| CandidateCellTileData {
+                                    row,
+                                    col,
+                                    variant: *variant,
+                                })
+                                .collect(),
+                        ));
+                    }
+                }
+            }
+        });
+
+        // Middle click handler: toggles a candidate between available and eliminated
+        let gesture_middle = gtk4::GestureClick::new();
+        gesture_middle.set_button(2);
+
+        gesture_middle.connect_pressed({
             let cell_ui = Rc::downgrade(&cell_ui);
             move |gesture, _, x, y| {
                 if let Some(cell_ui) = cell_ui.upgrade() {
                     let cell_ui = cell_ui.borrow();
                     if let Some(variant) = cell_ui.get_variant_at_position(x, y) {
-                        cell_ui.input_event_emitter.emit(InputEvent::RightClick(
+                        cell_ui.input_event_emitter.emit(InputEvent::MiddleClick(
                             Clickable::CandidateCellTile(CandidateCellTileData {
                                 row,
                                 col,
                                 variant,
                             }),
                         ));
-                    } else if cell_ui.selected_tile.is_some() {
-                        cell_ui.input_event_emitter.emit(InputEvent::RightClick(
-                            Clickable::SolutionTile(SolutionTileData { row, col }),
-                        ));
+                        gesture.set_state(gtk4::EventSequenceState::Claimed);
                     }
-                    gesture.set_state(gtk4::EventSequenceState::Claimed);
                 } else {
                     warn!(target: "puzzle_cell_ui", "Stale handler called!");
                 }
@@ -286,8 +541,10 @@ impl PuzzleCellUI {
         let frame: &Frame = &cell_ui_borrowed.frame;
         frame.add_controller(gesture_click.clone());
         frame.add_controller(gesture_right.clone());
+        frame.add_controller(gesture_middle.clone());
         cell_ui_borrowed.gesture_click = Some(gesture_click);
         cell_ui_borrowed.gesture_right = Some(gesture_right);
+        cell_ui_borrowed.gesture_middle = Some(gesture_middle);
     }
 
     pub fn highlight_candidate(&self, index: char, highlight_class: Option<&str>) {
@@ -312,10 +569,35 @@ impl PuzzleCellUI {
                 });
             }
         }
+        self.revealing_eliminated = false;
+        self.sync_shown_variants();
         self.sync_images();
         self.sync_clue_spotlight();
     }
 
+    /// Show the player's color tags as a border around each candidate, indexed like
+    /// `set_candidates`
+    pub fn set_candidate_colors(&mut self, candidate_colors: Vec<Option<CandidateColor>>) {
+        for (overlay, color) in self.candidate_overlays.iter().zip(candidate_colors.iter()) {
+            for other in CandidateColor::all() {
+                overlay.remove_css_class(candidate_color_css_class(other));
+            }
+            if let Some(color) = color {
+                overlay.add_css_class(candidate_color_css_class(*color));
+            }
+        }
+        self.candidate_colors = candidate_colors;
+    }
+
+    /// Set per-candidate tooltip text, indexed like `set_candidates`
+    pub fn set_candidate_tooltips(&self, tooltips: Vec<Option<String>>) {
+        for (variant_idx, tooltip) in tooltips.iter().enumerate() {
+            if let Some(image) = self.candidate_images.get(variant_idx) {
+                image.set_tooltip_text(tooltip.as_deref());
+            }
+        }
+    }
+
     pub fn set_solution(&mut self, tile: Option<&Tile>) {
         // First, remove current child to ensure clean state
         self.frame.set_child(Option::<&Widget>::None);
@@ -339,11 +621,53 @@ impl PuzzleCellUI {
         }
         self.sync_images();
         self.sync_clue_spotlight();
+        self.sync_cursor();
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.selected_tile.is_some()
+    }
+
+    /// Where the keyboard cursor can stop in this cell, in display order: each shown candidate,
+    /// or only the placed tile once the cell is solved
+    pub fn cursor_stops(&self) -> Vec<char> {
+        match &self.selected_tile {
+            Some(tile) => vec![tile.variant],
+            None => self
+                .shown_variants
+                .iter()
+                .map(|idx| (b'a' + *idx as u8) as char)
+                .collect(),
+        }
+    }
+
+    /// Show the keyboard cursor on `variant`'s candidate, or around the whole cell once it's
+    /// solved; None hides it
+    pub fn set_cursor(&mut self, cursor: Option<char>) {
+        self.cursor = cursor;
+        self.sync_cursor();
+    }
+
+    fn sync_cursor(&self) {
+        let on_solution = self.cursor.is_some() && self.selected_tile.is_some();
+        if on_solution {
+            self.frame.add_css_class(CURSOR_CSS_CLASS);
+        } else {
+            self.frame.remove_css_class(CURSOR_CSS_CLASS);
+        }
+        for (idx, overlay) in self.candidate_overlays.iter().enumerate() {
+            let variant = (b'a' + idx as u8) as char;
+            if !on_solution && self.cursor == Some(variant) {
+                overlay.add_css_class(CURSOR_CSS_CLASS);
+            } else {
+                overlay.remove_css_class(CURSOR_CSS_CLASS);
+            }
+        }
     }
 
     pub fn get_variant_at_position(&self, x: f64, y: f64) -> Option<char> {
-        let ncols = (self.n_variants + 1) / 2;
-        let nrows = 2;
+        let ncols = self.candidate_columns();
+        let nrows = self.current_layout.cell.candidate_rows.max(1) as usize;
 
         let grid_width = self.current_layout.total_dimensions.width as f64;
         let grid_height = self.current_layout.total_dimensions.height as f64;
@@ -373,13 +697,28 @@ impl PuzzleCellUI {
             return None;
         }
 
-        let col = (grid_x
-            / (candidate_image.width as f64 + self.current_layout.cell.candidate_spacing as f64))
-            .floor() as usize; // Add 2px for gap
         let row = (grid_y
             / (candidate_image.height as f64 + self.current_layout.cell.candidate_spacing as f64))
             .floor() as usize; // Add 2px for gap
 
+        // a centered partial row is shifted right by half a tile per missing candidate
+        let candidate_pitch =
+            candidate_image.width as f64 + self.current_layout.cell.candidate_spacing as f64;
+        let row_x = grid_x
+            - PuzzleCellUI::row_half_offset(
+                ncols,
+                self.shown_variants.len(),
+                row,
+                self.center_partial_row,
+            ) as f64
+                * candidate_pitch
+                / 2.0;
+        if row_x < 0.0 {
+            trace!(target: "puzzle_cell_ui", "Click left of centered row");
+            return None;
+        }
+        let col = (row_x / candidate_pitch).floor() as usize;
+
         trace!(target: "puzzle_cell_ui", "Calculated grid position: row={}, col={}", row, col);
 
         if row >= nrows || col >= ncols {
@@ -387,12 +726,12 @@ impl PuzzleCellUI {
             return None;
         }
 
-        // Convert grid position to variant (a-f)
-        let variant_index = row * ncols + col;
-        if variant_index >= self.n_variants {
-            trace!(target: "puzzle_cell_ui", "Variant index {} out of range", variant_index);
+        // Convert grid position to variant (a-f); slots only hold the shown candidates
+        let slot = row * ncols + col;
+        let Some(&variant_index) = self.shown_variants.get(slot) else {
+            trace!(target: "puzzle_cell_ui", "Slot {} is empty", slot);
             return None;
-        }
+        };
 
         let variant = (b'a' + variant_index as u8) as char;
         trace!(target: "puzzle_cell_ui", "Selected variant: {}", variant);
@@ -417,6 +756,26 @@ impl PuzzleCellUI {
         });
     }
 
+    /// Briefly shake the cell; clicks while it's still shaking don't restart the animation
+    pub fn shake(&self) {
+        if self.frame.has_css_class(SHAKE_CSS_CLASS) {
+            return;
+        }
+        self.frame.add_css_class(SHAKE_CSS_CLASS);
+        let frame = self.frame.clone();
+        timeout_add_local_once(SHAKE_DURATION, move || {
+            frame.remove_css_class(SHAKE_CSS_CLASS);
+        });
+    }
+
+    pub fn flash_incorrect_for(&self, duration: std::time::Duration) {
+        self.frame.add_css_class("incorrect-cell");
+        let frame = self.frame.clone();
+        timeout_add_local_once(duration, move || {
+            frame.remove_css_class("incorrect-cell");
+        });
+    }
+
     pub(crate) fn set_image_set(&mut self, image_set: Rc<ImageSet>) {
         self.resources = image_set;
         self.sync_images();
@@ -425,18 +784,16 @@ impl PuzzleCellUI {
     fn sync_images(&self) {
         for (idx, img) in self.candidate_images.iter().enumerate() {
             let variant = (b'a' + idx as u8) as char;
-            if let Some(icon) = self.resources.get_candidate_icon(&Tile {
+            let icon = self.resources.get_candidate_icon(&Tile {
                 row: self.row,
                 variant,
-            }) {
-                img.set_paintable(Some(icon.as_ref()));
-            }
+            });
+            img.set_paintable(Some(icon.as_ref()));
         }
 
         if let Some(tile) = &self.selected_tile {
-            if let Some(icon) = self.resources.get_solution_icon(tile) {
-                self.solution_image.set_paintable(Some(icon.as_ref()));
-            }
+            let icon = self.resources.get_solution_icon(tile);
+            self.solution_image.set_paintable(Some(icon.as_ref()));
         }
     }
 }
@@ -458,5 +815,67 @@ impl Destroyable for PuzzleCellUI {
         if let Some(gesture_right) = self.gesture_right.take() {
             self.frame.remove_controller(&gesture_right);
         }
+        if let Some(gesture_middle) = self.gesture_middle.take() {
+            self.frame.remove_controller(&gesture_middle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Visual check: with the setting on, a 5-variant cell should show `a b c` over `d e` with
+    // `d e` straddling the gaps between `a b` and `b c`; a 7-variant cell shows `a b c d` over
+    // `e f g` the same way. Clicking each shifted candidate must still select that candidate.
+    #[test]
+    fn test_row_half_offset_odd_variants() {
+        // 5 variants: 3 columns, last row has 2 candidates
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 5, 0, true), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 5, 1, true), 1);
+        // 7 variants: 4 columns, last row has 3 candidates
+        assert_eq!(PuzzleCellUI::row_half_offset(4, 7, 0, true), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(4, 7, 1, true), 1);
+    }
+
+    #[test]
+    fn test_row_half_offset_even_or_disabled() {
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 6, 1, true), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(4, 8, 1, true), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 5, 1, false), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(4, 7, 1, false), 0);
+    }
+
+    #[test]
+    fn test_row_half_offset_with_hidden_candidates() {
+        // 6 variants with 4 eliminated and hidden: `x x` centered in a 3-wide first row
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 2, 0, true), 1);
+        // 6 variants with 1 hidden: the second row has 2 of 3
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 5, 0, true), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 5, 1, true), 1);
+        assert_eq!(PuzzleCellUI::row_half_offset(3, 2, 0, false), 0);
+    }
+
+    #[test]
+    fn test_shuffled_display_order() {
+        let order = PuzzleCellUI::shuffled_display_order(6, 42, 1, 2);
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..6).collect::<Vec<_>>());
+
+        // the same for a seed and cell, but not the same for every cell
+        assert_eq!(order, PuzzleCellUI::shuffled_display_order(6, 42, 1, 2));
+        let orders = (0..6)
+            .flat_map(|row| (0..6).map(move |col| (row, col)))
+            .map(|(row, col)| PuzzleCellUI::shuffled_display_order(6, 42, row, col))
+            .collect::<HashSet<_>>();
+        assert!(orders.len() > 1);
+    }
+
+    #[test]
+    fn test_row_half_offset_list_display() {
+        // a single-column list never has a partial row to center
+        assert_eq!(PuzzleCellUI::row_half_offset(1, 5, 0, true), 0);
+        assert_eq!(PuzzleCellUI::row_half_offset(1, 5, 4, true), 0);
     }
 }
@@ -1,20 +1,24 @@
 use std::rc::Rc;
 
 use gtk4::{
-    prelude::{BoxExt, GtkWindowExt},
+    prelude::{BoxExt, ButtonExt, GtkWindowExt, WidgetExt},
     ApplicationWindow, Label, Spinner,
 };
 
 use crate::{
     destroyable::Destroyable,
     events::EventHandler,
-    model::{GameBoardChangeReason, GameEngineEvent},
+    model::{DeductionKind, Difficulty, GameBoardChangeReason, GameEngineEvent},
+    solver::puzzle_metrics::PuzzleMetrics,
 };
 use fluent_i18n::t;
 
 pub struct PuzzleGenerationDialog {
     window: Rc<ApplicationWindow>,
     dialog: Option<gtk4::Window>,
+    metrics_dialog: Option<gtk4::Window>,
+    /// summary of a technique search, shown above the metrics of the puzzle it found
+    search_summary: Option<String>,
 }
 
 impl PuzzleGenerationDialog {
@@ -22,6 +26,8 @@ impl PuzzleGenerationDialog {
         let dialog = Rc::new(std::cell::RefCell::new(Self {
             window: window.clone(),
             dialog: None,
+            metrics_dialog: None,
+            search_summary: None,
         }));
         dialog
     }
@@ -71,11 +77,181 @@ impl PuzzleGenerationDialog {
             dialog.close();
         }
     }
+
+    fn format_metrics(metrics: &PuzzleMetrics) -> String {
+        let mut lines = vec![
+            format!("Clues: {}", metrics.n_clues),
+            format!("Solve steps: {}", metrics.n_solve_steps),
+        ];
+        for deduction_kind in DeductionKind::all() {
+            lines.push(format!(
+                "{:?} deductions: {}",
+                deduction_kind,
+                metrics.deduction_kind_count(&deduction_kind)
+            ));
+        }
+        lines.push(format!(
+            "Other deductions: {}",
+            metrics.n_unclassified_deductions
+        ));
+        if !metrics.solved {
+            lines.push("Headless solve got stuck before completing the puzzle".to_string());
+        }
+        lines.join("\n")
+    }
+
+    fn handle_technique_search_finished(
+        &mut self,
+        deduction_kind: &DeductionKind,
+        max_attempts: usize,
+        seed: Option<u64>,
+    ) {
+        match seed {
+            Some(seed) => {
+                // the metrics for the found puzzle follow; show the seed alongside them
+                self.search_summary = Some(format!("Seed {} uses {:?}", seed, deduction_kind));
+            }
+            None => {
+                self.hide_dialog();
+                self.show_report_dialog(
+                    "Technique Search",
+                    format!(
+                        "No puzzle using {:?} found in {} attempts",
+                        deduction_kind, max_attempts
+                    ),
+                );
+            }
+        }
+    }
+
+    fn show_metrics_dialog(&mut self, metrics: &PuzzleMetrics) {
+        let metrics_text = match self.search_summary.take() {
+            Some(summary) => format!("{}\n\n{}", summary, Self::format_metrics(metrics)),
+            None => Self::format_metrics(metrics),
+        };
+        self.show_report_dialog("Puzzle Metrics", metrics_text);
+    }
+
+    fn show_report_dialog(&mut self, title: &str, report_text: String) {
+        self.hide_metrics_dialog();
+
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(20)
+            .margin_top(20)
+            .margin_start(30)
+            .margin_end(30)
+            .build();
+
+        let dialog = gtk4::Window::builder()
+            .transient_for(self.window.as_ref())
+            .title(title)
+            .child(&content_area)
+            .resizable(false)
+            .build();
+
+        let label = Label::builder()
+            .label(&report_text)
+            .selectable(true)
+            .halign(gtk4::Align::Start)
+            .build();
+        content_area.append(&label);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .spacing(10)
+            .halign(gtk4::Align::End)
+            .build();
+        let copy_button = gtk4::Button::builder().label("Copy").build();
+        let close_button = gtk4::Button::builder().label(&t!("close")).build();
+        button_box.append(&copy_button);
+        button_box.append(&close_button);
+        content_area.append(&button_box);
+
+        copy_button.connect_clicked(move |button| {
+            button.clipboard().set_text(&report_text);
+        });
+        close_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        dialog.present();
+        self.metrics_dialog = Some(dialog);
+    }
+
+    /// Let the player know the puzzle they got is a simplified one
+    fn show_fallback_notice(&self, difficulty: Difficulty) {
+        self.show_notice(
+            &t!("generation-fallback-title"),
+            &t!("generation-fallback-message", {
+                "difficulty" => difficulty.to_string()
+            }),
+        );
+    }
+
+    /// Let the player know the progress they were sent couldn't be put onto the puzzle
+    fn show_shared_game_rejected_notice(&self) {
+        self.show_notice(
+            &t!("shared-game-rejected-title"),
+            &t!("shared-game-rejected-message"),
+        );
+    }
+
+    fn show_notice(&self, title: &str, message: &str) {
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(20)
+            .margin_top(20)
+            .margin_start(30)
+            .margin_end(30)
+            .build();
+
+        let dialog = gtk4::Window::builder()
+            .transient_for(self.window.as_ref())
+            .title(title)
+            .child(&content_area)
+            .modal(true)
+            .resizable(false)
+            .build();
+
+        let label = Label::builder()
+            .label(message)
+            .wrap(true)
+            .max_width_chars(40)
+            .build();
+        content_area.append(&label);
+
+        let ok_button = gtk4::Button::builder()
+            .label(&t!("ok"))
+            .halign(gtk4::Align::End)
+            .build();
+        content_area.append(&ok_button);
+        ok_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        dialog.present();
+    }
+
+    fn hide_metrics_dialog(&mut self) {
+        if let Some(dialog) = self.metrics_dialog.take() {
+            dialog.close();
+        }
+    }
 }
 
 impl Destroyable for PuzzleGenerationDialog {
     fn destroy(&mut self) {
         self.hide_dialog();
+        self.hide_metrics_dialog();
     }
 }
 
@@ -83,7 +259,20 @@ impl EventHandler<GameEngineEvent> for PuzzleGenerationDialog {
     fn handle_event(&mut self, event: &GameEngineEvent) {
         // delegate to the existing handler method
         match event {
-            GameEngineEvent::PuzzleGenerationStarted => self.show_dialog(),
+            GameEngineEvent::PuzzleGenerationStarted => {
+                self.hide_metrics_dialog();
+                self.show_dialog();
+            }
+            GameEngineEvent::PuzzleMetricsMeasured(metrics) => self.show_metrics_dialog(metrics),
+            GameEngineEvent::PuzzleGenerationFellBack(difficulty) => {
+                self.show_fallback_notice(*difficulty)
+            }
+            GameEngineEvent::SharedGameRejected => self.show_shared_game_rejected_notice(),
+            GameEngineEvent::TechniqueSearchFinished {
+                deduction_kind,
+                max_attempts,
+                seed,
+            } => self.handle_technique_search_finished(deduction_kind, *max_attempts, *seed),
             GameEngineEvent::GameBoardUpdated { change_reason, .. } => {
                 if *change_reason == GameBoardChangeReason::NewGame {
                     self.hide_dialog();
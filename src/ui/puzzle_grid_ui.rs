@@ -1,6 +1,6 @@
 use gtk4::{
     prelude::{GridExt, WidgetExt},
-    Grid,
+    Align, Grid, Label, Orientation, Separator,
 };
 use log::trace;
 use std::{cell::RefCell, collections::HashSet, rc::Rc, time::Duration};
@@ -10,13 +10,87 @@ use crate::{
     events::{EventEmitter, EventHandler},
     game::settings::Settings,
     model::{
-        ClueAddress, ClueSelection, ClueWithAddress, Difficulty, GameBoard, GameEngineEvent,
-        InputEvent, LayoutConfiguration, LayoutManagerEvent, Solution,
+        CandidateCellTileData, Clickable, ClueAddress, ClueSelection, ClueWithAddress, Difficulty,
+        EliminationReason, GameBoard, GameEngineEvent, InputEvent, KeyAction, LayoutConfiguration,
+        LayoutManagerEvent, PuzzleCompletionState, Solution, SolutionTileData, Tile,
+        LONG_PRESS_DURATION,
     },
 };
 
+use fluent_i18n::t;
+
 use super::{puzzle_cell_ui::PuzzleCellUI, ImageSet};
 
+/// Where the keyboard cursor is: a candidate of a cell, or the cell itself once it's solved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusCell {
+    pub row: usize,
+    pub col: usize,
+    pub variant: char,
+}
+
+impl FocusCell {
+    /// Move `delta` stops along the row, through each cell's `stops` in turn, wrapping around at
+    /// the row's ends. `stops` holds the stops of every cell in the row, by column.
+    fn step_along_row(self, stops: &[Vec<char>], delta: isize) -> FocusCell {
+        let positions: Vec<(usize, char)> = stops
+            .iter()
+            .enumerate()
+            .flat_map(|(col, stops)| stops.iter().map(move |variant| (col, *variant)))
+            .collect();
+        if positions.is_empty() {
+            return self;
+        }
+        let index = match positions
+            .iter()
+            .position(|position| *position == (self.col, self.variant))
+        {
+            Some(index) => (index as isize + delta).rem_euclid(positions.len() as isize) as usize,
+            // the cursor's candidate is no longer a stop, e.g. it was hidden or its cell solved;
+            // land on the nearest stop in the direction of travel
+            None if delta > 0 => positions
+                .iter()
+                .position(|(col, _)| *col >= self.col)
+                .unwrap_or(0),
+            None => positions
+                .iter()
+                .rposition(|(col, _)| *col <= self.col)
+                .unwrap_or(positions.len() - 1),
+        };
+        let (col, variant) = positions[index];
+        FocusCell {
+            col,
+            variant,
+            ..self
+        }
+    }
+
+    /// Move `delta` rows in the same column, wrapping between the top and bottom rows. The
+    /// candidate is kept if it's one of the new cell's `stops`, otherwise the first stop is taken.
+    fn step_across_rows(
+        self,
+        n_rows: usize,
+        delta: isize,
+        stops: impl Fn(usize) -> Vec<char>,
+    ) -> FocusCell {
+        if n_rows == 0 {
+            return self;
+        }
+        let row = (self.row as isize + delta).rem_euclid(n_rows as isize) as usize;
+        let stops = stops(row);
+        let variant = if stops.contains(&self.variant) {
+            self.variant
+        } else {
+            stops.first().copied().unwrap_or(self.variant)
+        };
+        FocusCell {
+            row,
+            variant,
+            ..self
+        }
+    }
+}
+
 pub struct PuzzleGridUI {
     pub grid: Grid,
     cells: Vec<Vec<Rc<RefCell<PuzzleCellUI>>>>,
@@ -29,8 +103,20 @@ pub struct PuzzleGridUI {
     current_focused_clue: Option<ClueWithAddress>,
     completed_clues: HashSet<ClueAddress>,
     current_clue_hint: Option<ClueWithAddress>,
+    /// The last hinted clue, which stays spotlighted while focused when hint_keeps_clue_selected
+    /// is on, even after the hint itself is cleared
+    pinned_hint_clue: Option<ClueWithAddress>,
     current_difficulty: Difficulty,
     settings: Settings,
+    column_guides: Vec<Separator>,
+    column_numbers: Vec<Label>,
+    row_progress: Vec<Label>,
+    /// Unplaced variants per row on the last board, shown by the row progress labels
+    unplaced_per_row: Vec<usize>,
+    /// Seed the cells' candidate order was last laid out for, if it's still current
+    display_order_seed: Option<u64>,
+    /// Keyboard cursor; None until an arrow key is first pressed
+    focus_cell: Option<FocusCell>,
 }
 
 impl Destroyable for PuzzleGridUI {
@@ -58,8 +144,29 @@ impl EventHandler<GameEngineEvent> for PuzzleGridUI {
             }
             GameEngineEvent::ClueHintHighlighted(addressed_clue) => {
                 self.current_clue_hint = addressed_clue.clone();
+                if addressed_clue.is_some() && self.settings.hint_keeps_clue_selected {
+                    self.pinned_hint_clue = addressed_clue.clone();
+                }
                 self.sync_spotlight();
             }
+            GameEngineEvent::SettingsChanged(settings) => {
+                self.handle_settings_changed(settings);
+            }
+            GameEngineEvent::InvalidInteraction { row, col } => {
+                if let Some(cell) = self.cells.get(*row).and_then(|row| row.get(*col)) {
+                    cell.borrow().shake();
+                }
+            }
+            GameEngineEvent::PuzzleCompleted(PuzzleCompletionState::TryAgain {
+                incorrect_cells,
+            })
+            | GameEngineEvent::IncorrectCellsShown(incorrect_cells) => {
+                for (row, col) in incorrect_cells {
+                    self.cells[*row][*col]
+                        .borrow()
+                        .flash_incorrect_for(Duration::from_secs(4));
+                }
+            }
 
             _ => {}
         }
@@ -78,6 +185,22 @@ impl EventHandler<LayoutManagerEvent> for PuzzleGridUI {
     }
 }
 
+impl EventHandler<InputEvent> for PuzzleGridUI {
+    fn handle_event(&mut self, event: &InputEvent) {
+        if let InputEvent::KeyAction(action) = event {
+            match action {
+                KeyAction::CursorLeft => self.move_cursor_along_row(-1),
+                KeyAction::CursorRight => self.move_cursor_along_row(1),
+                KeyAction::CursorUp => self.move_cursor_across_rows(-1),
+                KeyAction::CursorDown => self.move_cursor_across_rows(1),
+                KeyAction::CursorSelect => self.click_cursor(true),
+                KeyAction::CursorEliminate => self.click_cursor(false),
+                _ => (),
+            }
+        }
+    }
+}
+
 impl PuzzleGridUI {
     pub fn new(
         input_event_emitter: EventEmitter<InputEvent>,
@@ -100,8 +223,15 @@ impl PuzzleGridUI {
             current_focused_clue: None,
             completed_clues: HashSet::new(),
             current_clue_hint: None,
+            pinned_hint_clue: None,
             current_difficulty: settings.difficulty,
             settings: settings.clone(),
+            column_guides: vec![],
+            column_numbers: vec![],
+            row_progress: vec![],
+            unplaced_per_row: vec![],
+            display_order_seed: None,
+            focus_cell: None,
         }));
 
         puzzle_grid_ui
@@ -130,6 +260,8 @@ impl PuzzleGridUI {
                 cell.borrow_mut().update_layout(&layout.grid);
             }
         }
+        self.sync_column_guides();
+        self.sync_row_progress();
     }
 
     fn set_current_clue(&mut self, clue: &Option<ClueWithAddress>) {
@@ -138,6 +270,9 @@ impl PuzzleGridUI {
             // clear the hint state we move on
             self.current_clue_hint = None;
         }
+        if self.current_focused_clue != self.pinned_hint_clue {
+            self.pinned_hint_clue = None;
+        }
         self.sync_spotlight();
     }
 
@@ -148,7 +283,9 @@ impl PuzzleGridUI {
             .map(|clue| self.completed_clues.contains(&clue.address()))
             .unwrap_or(false);
 
-        let selected_clue_is_hint = self.current_clue_hint == self.current_focused_clue;
+        let selected_clue_is_hint = self.current_clue_hint == self.current_focused_clue
+            || (self.pinned_hint_clue.is_some()
+                && self.pinned_hint_clue == self.current_focused_clue);
 
         let spotlight_clue = if (selected_clue_is_hint || self.current_spotlight_enabled)
             && !current_focused_clue_completed
@@ -193,6 +330,7 @@ impl PuzzleGridUI {
         });
 
         self.cells.clear();
+        self.focus_cell = None;
         let variants_range = Solution::variants_range(n_variants);
 
         for row in 0..n_rows {
@@ -205,6 +343,8 @@ impl PuzzleGridUI {
                     self.input_event_emitter.clone(),
                     variants_range.clone(),
                     self.current_layout.grid.clone(),
+                    self.settings.center_partial_candidate_row,
+                    self.settings.hide_eliminated_candidates,
                 );
                 self.grid
                     .attach(&cell_ui.borrow().frame, col as i32, row as i32, 1, 1);
@@ -212,6 +352,10 @@ impl PuzzleGridUI {
             }
             self.cells.push(row_cells);
         }
+        self.unplaced_per_row = vec![n_variants; n_rows];
+        self.display_order_seed = None;
+        self.sync_column_guides();
+        self.sync_row_progress();
 
         // let padding_size_from_css = 3;
         // let total_width = total_cell_width + total_col_spacing + padding_size_from_css;
@@ -221,15 +365,257 @@ impl PuzzleGridUI {
         // self.grid.set_vexpand(false);
     }
 
+    /// Column guides are attached after the cells so they draw over them, and are rebuilt whenever
+    /// the grid is resized or re-laid out. The numbers sit in place of the grid's top margin.
+    fn sync_column_guides(&mut self) {
+        for guide in self.column_guides.drain(..) {
+            self.grid.remove(&guide);
+        }
+        for number in self.column_numbers.drain(..) {
+            self.grid.remove(&number);
+        }
+
+        let layout = &self.current_layout.grid;
+        if !self.settings.show_column_guides {
+            self.grid.set_margin_top(layout.outer_margin);
+            return;
+        }
+        self.grid.set_margin_top(0);
+
+        for col in 0..self.n_variants.saturating_sub(1) {
+            // centered across two columns, the guide lands in the middle of the gap between them
+            let guide = Separator::builder()
+                .orientation(Orientation::Vertical)
+                .halign(Align::Center)
+                .can_target(false)
+                .css_classes(["column-guide"])
+                .build();
+            self.grid
+                .attach(&guide, col as i32, 0, 2, self.n_rows as i32);
+            self.column_guides.push(guide);
+        }
+
+        let font_px = (layout.cell.dimensions.width / 5).clamp(8, 16);
+        for col in 0..self.n_variants {
+            let number = Label::builder()
+                .css_classes(["column-number"])
+                .can_target(false)
+                .build();
+            number.set_markup(&format!("<span font=\"{}px\">{}</span>", font_px, col + 1));
+            self.grid.attach(&number, col as i32, -1, 1, 1);
+            self.column_numbers.push(number);
+        }
+    }
+
+    /// Row progress labels sit in an extra column to the right of the cells, in place of the
+    /// grid's end margin, and are rebuilt along with the column guides
+    fn sync_row_progress(&mut self) {
+        for label in self.row_progress.drain(..) {
+            self.grid.remove(&label);
+        }
+
+        let layout = &self.current_layout.grid;
+        if !self.settings.show_row_progress {
+            self.grid.set_margin_end(layout.outer_margin);
+            return;
+        }
+        self.grid.set_margin_end(0);
+
+        for row in 0..self.n_rows {
+            let label = Label::builder()
+                .css_classes(["row-progress"])
+                .valign(Align::Center)
+                .build();
+            self.grid
+                .attach(&label, self.n_variants as i32, row as i32, 1, 1);
+            self.row_progress.push(label);
+        }
+        self.sync_row_progress_counts();
+    }
+
+    fn sync_row_progress_counts(&self) {
+        let font_px = (self.current_layout.grid.cell.dimensions.width / 5).clamp(8, 16);
+        for (label, unplaced) in self.row_progress.iter().zip(&self.unplaced_per_row) {
+            let text = if *unplaced == 0 {
+                "✓".to_string()
+            } else {
+                unplaced.to_string()
+            };
+            label.set_markup(&format!("<span font=\"{}px\">{}</span>", font_px, text));
+            label.set_tooltip_text(Some(&t!("row-progress-tooltip", {
+                "count" => unplaced.to_string()
+            })));
+        }
+    }
+
+    fn handle_settings_changed(&mut self, settings: &Settings) {
+        if !settings.hint_keeps_clue_selected {
+            self.pinned_hint_clue = None;
+        }
+        let column_guides_changed = self.settings.show_column_guides != settings.show_column_guides;
+        let row_progress_changed = self.settings.show_row_progress != settings.show_row_progress;
+        let display_order_changed =
+            self.settings.shuffle_candidate_order != settings.shuffle_candidate_order;
+        self.settings = settings.clone();
+        if let (true, Some(seed)) = (display_order_changed, self.display_order_seed) {
+            self.sync_candidate_display_order(seed);
+        }
+        if column_guides_changed {
+            self.sync_column_guides();
+        }
+        if row_progress_changed {
+            self.sync_row_progress();
+        }
+        for row in &self.cells {
+            for cell in row {
+                let mut cell = cell.borrow_mut();
+                cell.set_center_partial_row(settings.center_partial_candidate_row);
+                cell.set_hide_eliminated(settings.hide_eliminated_candidates);
+                if !settings.track_elimination_reasons {
+                    cell.set_candidate_tooltips(vec![None; self.n_variants]);
+                }
+            }
+        }
+    }
+
+    fn cursor_stops(&self, row: usize, col: usize) -> Vec<char> {
+        self.cells[row][col].borrow().cursor_stops()
+    }
+
+    fn set_focus_cell(&mut self, focus_cell: Option<FocusCell>) {
+        if let Some(old) = self.focus_cell {
+            self.cells[old.row][old.col].borrow_mut().set_cursor(None);
+        }
+        self.focus_cell = focus_cell;
+        if let Some(new) = focus_cell {
+            self.cells[new.row][new.col]
+                .borrow_mut()
+                .set_cursor(Some(new.variant));
+        }
+    }
+
+    /// The cursor, or None after putting it on the top left cell if it wasn't showing yet, so
+    /// the first key press only reveals it
+    fn shown_focus_cell(&mut self) -> Option<FocusCell> {
+        if self.focus_cell.is_some() {
+            return self.focus_cell;
+        }
+        if self.cells.is_empty() {
+            return None;
+        }
+        if let Some(&variant) = self.cursor_stops(0, 0).first() {
+            self.set_focus_cell(Some(FocusCell {
+                row: 0,
+                col: 0,
+                variant,
+            }));
+        }
+        None
+    }
+
+    fn move_cursor_along_row(&mut self, delta: isize) {
+        let Some(focus_cell) = self.shown_focus_cell() else {
+            return;
+        };
+        let stops: Vec<Vec<char>> = (0..self.n_variants)
+            .map(|col| self.cursor_stops(focus_cell.row, col))
+            .collect();
+        self.set_focus_cell(Some(focus_cell.step_along_row(&stops, delta)));
+    }
+
+    fn move_cursor_across_rows(&mut self, delta: isize) {
+        let Some(focus_cell) = self.shown_focus_cell() else {
+            return;
+        };
+        let moved = focus_cell.step_across_rows(self.n_rows, delta, |row| {
+            self.cursor_stops(row, focus_cell.col)
+        });
+        self.set_focus_cell(Some(moved));
+    }
+
+    /// Click the cursor's candidate as the mouse would: select, or eliminate. A solved cell has
+    /// nothing left to select, and eliminating clears it like a right click on the tile.
+    fn click_cursor(&self, select: bool) {
+        let Some(focus_cell) = self.focus_cell else {
+            return;
+        };
+        let clickable = if self.cells[focus_cell.row][focus_cell.col]
+            .borrow()
+            .is_solved()
+        {
+            if select {
+                return;
+            }
+            Clickable::SolutionTile(SolutionTileData {
+                row: focus_cell.row,
+                col: focus_cell.col,
+            })
+        } else {
+            Clickable::CandidateCellTile(CandidateCellTileData {
+                row: focus_cell.row,
+                col: focus_cell.col,
+                variant: focus_cell.variant,
+            })
+        };
+        let event = if self.settings.touch_screen_controls {
+            // touch controls read a long press as select and a tap as eliminate
+            let duration = if select {
+                LONG_PRESS_DURATION
+            } else {
+                Duration::ZERO
+            };
+            InputEvent::TouchEvent(clickable, duration)
+        } else if select {
+            InputEvent::LeftClick(clickable)
+        } else {
+            InputEvent::RightClick(clickable)
+        };
+        self.input_event_emitter.emit(event);
+    }
+
     pub(crate) fn highlight_candidate(&self, row: usize, column: usize, variant: char) {
         self.cells[row][column]
             .borrow()
             .hint_highlight_candidate_for(Duration::from_secs(4), variant);
     }
 
+    fn elimination_reason_text(board: &GameBoard, reason: &EliminationReason) -> String {
+        match reason {
+            EliminationReason::Deduction { clue, .. } => clue
+                .and_then(|address| board.clue_set.get_clue(address))
+                .map(|clue| {
+                    t!("elimination-reason-clue", {
+                        "clue" => clue.clue.clue_type.get_title()
+                    })
+                })
+                .unwrap_or_else(|| t!("elimination-reason-hidden-set")),
+            EliminationReason::PlacedElsewhere(column) => {
+                t!("elimination-reason-placed", { "column" => (column + 1).to_string() })
+            }
+        }
+    }
+
+    /// Lay out every cell's candidates in variant order, or shuffled for `seed` when that's on
+    fn sync_candidate_display_order(&mut self, seed: u64) {
+        self.display_order_seed = Some(seed);
+        for (row, row_cells) in self.cells.iter().enumerate() {
+            for (col, cell) in row_cells.iter().enumerate() {
+                let display_order = if self.settings.shuffle_candidate_order {
+                    PuzzleCellUI::shuffled_display_order(self.n_variants, seed, row, col)
+                } else {
+                    (0..self.n_variants).collect()
+                };
+                cell.borrow_mut().set_display_order(display_order);
+            }
+        }
+    }
+
     fn handle_game_board_updated(&mut self, board: &GameBoard) {
         self.current_difficulty = board.solution.difficulty;
         self.set_grid_size(board.solution.n_rows, board.solution.n_variants);
+        if self.display_order_seed != Some(board.solution.seed) {
+            self.sync_candidate_display_order(board.solution.seed);
+        }
         for row in 0..board.solution.n_rows {
             for col in 0..board.solution.n_variants {
                 if let Some(cell) = self.cells.get(row).and_then(|row| row.get(col)) {
@@ -248,10 +634,37 @@ impl PuzzleGridUI {
                                 .map(|v| board.get_candidate(row, col, *v))
                                 .collect::<Vec<_>>(),
                         );
+                        cell.set_candidate_tooltips(
+                            board
+                                .solution
+                                .variants
+                                .iter()
+                                .map(|v| {
+                                    if !self.settings.track_elimination_reasons {
+                                        return None;
+                                    }
+                                    board
+                                        .get_elimination_reason(row, col, *v)
+                                        .map(|reason| Self::elimination_reason_text(board, &reason))
+                                })
+                                .collect::<Vec<_>>(),
+                        );
+                        cell.set_candidate_colors(
+                            board
+                                .solution
+                                .variants
+                                .iter()
+                                .map(|v| board.candidate_color(col, Tile::new(row, *v)))
+                                .collect(),
+                        );
                     }
                 }
             }
         }
+        self.unplaced_per_row = (0..board.solution.n_rows)
+            .map(|row| board.unplaced_in_row(row))
+            .collect();
+        self.sync_row_progress_counts();
         self.completed_clues = board.completed_clues().clone();
         self.sync_clue_spotlight_enabled();
     }
@@ -278,3 +691,51 @@ impl PuzzleGridUI {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn focus(col: usize, variant: char) -> FocusCell {
+        FocusCell {
+            row: 1,
+            col,
+            variant,
+        }
+    }
+
+    #[test]
+    fn test_step_along_row_wraps() {
+        // the middle cell is solved, so it's a single stop
+        let stops = vec![vec!['a', 'b'], vec!['c'], vec!['b', 'a']];
+
+        assert_eq!(focus(0, 'a').step_along_row(&stops, 1), focus(0, 'b'));
+        assert_eq!(focus(0, 'b').step_along_row(&stops, 1), focus(1, 'c'));
+        assert_eq!(focus(1, 'c').step_along_row(&stops, 1), focus(2, 'b'));
+        assert_eq!(focus(2, 'a').step_along_row(&stops, 1), focus(0, 'a'));
+        assert_eq!(focus(0, 'a').step_along_row(&stops, -1), focus(2, 'a'));
+
+        // a candidate that's no longer a stop moves to the nearest one
+        assert_eq!(focus(1, 'a').step_along_row(&stops, 1), focus(1, 'c'));
+        assert_eq!(focus(1, 'a').step_along_row(&stops, -1), focus(1, 'c'));
+    }
+
+    #[test]
+    fn test_step_across_rows_wraps() {
+        let stops = |row: usize| match row {
+            0 => vec!['c'],
+            _ => vec!['a', 'b', 'c'],
+        };
+
+        let cursor = FocusCell {
+            row: 2,
+            col: 3,
+            variant: 'b',
+        };
+        let down = cursor.step_across_rows(3, 1, stops);
+        // row 0 is solved with 'c', so the cursor takes its only stop
+        assert_eq!((down.row, down.col, down.variant), (0, 3, 'c'));
+        let up = cursor.step_across_rows(3, -1, stops);
+        assert_eq!((up.row, up.col, up.variant), (1, 3, 'b'));
+    }
+}
@@ -6,7 +6,7 @@ use log::trace;
 use crate::{
     destroyable::Destroyable,
     events::{EventEmitter, EventHandler},
-    model::LayoutManagerEvent,
+    model::{GameEngineEvent, LayoutManagerEvent},
 };
 
 use super::{audio_set::AudioSet, image_set::ImageSet};
@@ -14,6 +14,7 @@ use super::{audio_set::AudioSet, image_set::ImageSet};
 pub struct ResourceManager {
     image_set: Rc<ImageSet>,
     audio_set: Rc<AudioSet>,
+    featured_icon_category: Option<usize>,
     layout_manager_event_emitter: EventEmitter<LayoutManagerEvent>,
 }
 
@@ -38,15 +39,27 @@ impl EventHandler<LayoutManagerEvent> for ResourceManager {
     }
 }
 
+impl EventHandler<GameEngineEvent> for ResourceManager {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::SettingsChanged(settings) = event {
+            if settings.featured_icon_category != self.featured_icon_category {
+                self.set_featured_icon_category(settings.featured_icon_category);
+            }
+        }
+    }
+}
+
 impl ResourceManager {
     pub fn new(
         layout_manager_event_emitter: EventEmitter<LayoutManagerEvent>,
+        featured_icon_category: Option<usize>,
     ) -> Rc<RefCell<Self>> {
-        let image_set = Rc::new(ImageSet::new());
+        let image_set = Rc::new(ImageSet::new().with_featured_category(featured_icon_category));
         let audio_set = Rc::new(AudioSet::new());
         let manager = Rc::new(RefCell::new(Self {
             image_set: image_set.clone(),
             audio_set: audio_set.clone(),
+            featured_icon_category,
             layout_manager_event_emitter,
         }));
 
@@ -77,4 +90,15 @@ impl ResourceManager {
         self.layout_manager_event_emitter
             .emit(LayoutManagerEvent::ImagesOptimized(self.image_set.clone()));
     }
+
+    /// Swap which icon category leads the grid; the icons are already scaled, so this is cheap
+    fn set_featured_icon_category(&mut self, featured_icon_category: Option<usize>) {
+        self.featured_icon_category = featured_icon_category;
+        self.image_set = Rc::new(
+            self.image_set
+                .with_featured_category(featured_icon_category),
+        );
+        self.layout_manager_event_emitter
+            .emit(LayoutManagerEvent::ImagesOptimized(self.image_set.clone()));
+    }
 }
@@ -0,0 +1,71 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gtk4::{prelude::*, Align, Button, Label, Orientation};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    model::{GameEngineCommand, GameEngineEvent},
+};
+use fluent_i18n::t;
+
+/// Banner above the board while a sandbox is open, so the player knows their moves are
+/// tentative, with buttons to keep or throw them away
+pub struct SandboxBannerUI {
+    pub banner: gtk4::Box,
+}
+
+impl Destroyable for SandboxBannerUI {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for SandboxBannerUI {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::SandboxChanged(sandbox_start) = event {
+            self.banner.set_visible(sandbox_start.is_some());
+        }
+    }
+}
+
+impl SandboxBannerUI {
+    pub fn new(game_engine_command_emitter: EventEmitter<GameEngineCommand>) -> Rc<RefCell<Self>> {
+        let label = Label::builder()
+            .label(&t!("sandbox-banner"))
+            .wrap(true)
+            .hexpand(true)
+            .halign(Align::Start)
+            .build();
+        let commit_button = Button::builder()
+            .label(&t!("sandbox-commit"))
+            .tooltip_text(&t!("sandbox-commit-tooltip"))
+            .css_classes(["suggested-action"])
+            .build();
+        let discard_button = Button::builder()
+            .label(&t!("sandbox-discard"))
+            .tooltip_text(&t!("sandbox-discard-tooltip"))
+            .css_classes(["destructive-action"])
+            .build();
+
+        let banner = gtk4::Box::builder()
+            .orientation(Orientation::Horizontal)
+            .spacing(10)
+            .css_classes(["sandbox-banner"])
+            .visible(false)
+            .build();
+        banner.append(&label);
+        banner.append(&commit_button);
+        banner.append(&discard_button);
+
+        commit_button.connect_clicked({
+            let game_engine_command_emitter = game_engine_command_emitter.clone();
+            move |_| {
+                game_engine_command_emitter.emit(GameEngineCommand::CommitSandbox);
+            }
+        });
+        discard_button.connect_clicked(move |_| {
+            game_engine_command_emitter.emit(GameEngineCommand::DiscardSandbox);
+        });
+
+        Rc::new(RefCell::new(Self { banner }))
+    }
+}
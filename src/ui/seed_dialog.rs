@@ -10,7 +10,7 @@ use crate::model::GameBoard;
 use crate::{
     destroyable::Destroyable,
     events::{EventEmitter, EventHandler},
-    model::{Difficulty, GameEngineCommand, GameEngineEvent},
+    model::{Difficulty, GameEngineCommand, GameEngineEvent, GameStateSnapshot, PuzzleCode},
 };
 use fluent_i18n::t;
 
@@ -70,16 +70,38 @@ impl SeedDialog {
             .build();
         content_area.append(&entry);
 
+        // a shared puzzle brings the sender's progress along, so it takes precedence over the seed
+        let shared_game_entry = Entry::builder()
+            .placeholder_text(&t!("shared-game-placeholder"))
+            .build();
+        content_area.append(&shared_game_entry);
+
         let button_box = gtk4::Box::builder()
             .orientation(gtk4::Orientation::Horizontal)
             .build();
         let ok_button = gtk4::Button::builder().label(&t!("ok")).build();
         let cancel_button = gtk4::Button::builder().label(&t!("cancel")).build();
+        let copy_code_button = gtk4::Button::builder()
+            .label(&t!("copy-puzzle-code"))
+            .sensitive(self.current_seed.is_some())
+            .build();
+        button_box.append(&copy_code_button);
         button_box.append(&cancel_button);
         button_box.append(&ok_button);
         button_box.set_halign(gtk4::Align::End);
         content_area.append(&button_box);
 
+        copy_code_button.connect_clicked({
+            let puzzle_code = self
+                .current_seed
+                .map(|seed| PuzzleCode::new(self.current_difficulty, seed));
+            move |button| {
+                if let Some(puzzle_code) = puzzle_code {
+                    button.clipboard().set_text(&puzzle_code.to_string());
+                }
+            }
+        });
+
         cancel_button.connect_clicked({
             let dialog = dialog.clone();
             move |_| {
@@ -99,14 +121,16 @@ impl SeedDialog {
             }
         });
 
-        entry.connect_activate({
-            let dialog = dialog.clone();
-            let value_accepted = value_accepted.clone();
-            move |_| {
-                value_accepted.set(true);
-                dialog.close();
-            }
-        });
+        for accepting_entry in [&entry, &shared_game_entry] {
+            accepting_entry.connect_activate({
+                let dialog = dialog.clone();
+                let value_accepted = value_accepted.clone();
+                move |_| {
+                    value_accepted.set(true);
+                    dialog.close();
+                }
+            });
+        }
 
         let key_controller = EventControllerKey::new();
         key_controller.connect_key_pressed({
@@ -126,7 +150,12 @@ impl SeedDialog {
             let current_difficulty = self.current_difficulty;
             move |_| {
                 if value_accepted.take() {
-                    if let Ok(new_seed) = entry.text().as_str().parse::<u64>() {
+                    if let Some(shared_game) =
+                        GameStateSnapshot::from_share_string(shared_game_entry.text().as_str())
+                    {
+                        game_engine_command_emitter
+                            .emit(GameEngineCommand::LoadSharedGame(shared_game));
+                    } else if let Ok(new_seed) = entry.text().as_str().parse::<u64>() {
                         if Some(new_seed) != current_seed {
                             game_engine_command_emitter.emit(GameEngineCommand::NewGame(
                                 Some(current_difficulty),
@@ -143,7 +172,8 @@ impl SeedDialog {
     }
 
     fn handle_game_board_updated(&mut self, board: &GameBoard) {
-        self.current_seed = Some(board.solution.seed);
+        // a puzzle with custom clue weights has no seed that regenerates it
+        self.current_seed = board.solution.puzzle_code().map(|code| code.seed);
         self.current_difficulty = board.solution.difficulty;
     }
 }
@@ -11,17 +11,63 @@ use crate::{
     destroyable::Destroyable,
     events::{EventEmitter, Unsubscriber},
     game::settings::Settings,
-    model::{GameEngineCommand, GameEngineEvent, SettingsChange},
+    model::{
+        AutoSolveMode, CandidateDisplay, ClueSort, Difficulty, GameEngineCommand, GameEngineEvent,
+        SettingsChange,
+    },
 };
 use fluent_i18n::t;
 
+use super::image_set::ICON_CATEGORY_KEYS;
+
+// generation time limits offered in the menu, in seconds; 0 means no limit
+const GENERATION_TIME_LIMIT_CHOICES: [u64; 4] = [0, 10, 30, 60];
+// autosave intervals offered in the menu, in seconds; 0 means only saving on close
+const AUTOSAVE_INTERVAL_CHOICES: [u64; 4] = [0, 30, 60, 300];
+// delays between auto-solved cells offered in the menu, in milliseconds; 0 means instant
+const CASCADE_DELAY_CHOICES: [u64; 4] = [0, 250, 500, 1000];
+// state of the featured icon category action when no category is featured
+const NO_FEATURED_ICON_CATEGORY: &str = "none";
+
 pub struct SettingsMenuUI {
     window: Rc<ApplicationWindow>,
     settings_menu: Menu,
     action_toggle_tooltips: SimpleAction,
     action_toggle_spotlight: SimpleAction,
     action_toggle_touch_controls: SimpleAction,
-    action_toggle_auto_solve: SimpleAction,
+    action_auto_solve_mode: SimpleAction,
+    action_toggle_progressive_reveal: SimpleAction,
+    action_toggle_lock_solved_cells: SimpleAction,
+    action_toggle_clue_nav_wrap: SimpleAction,
+    action_toggle_center_partial_row: SimpleAction,
+    action_toggle_log_hint_deductions: SimpleAction,
+    action_toggle_resume_last_game: SimpleAction,
+    action_preferred_difficulty: SimpleAction,
+    action_toggle_track_elimination_reasons: SimpleAction,
+    action_toggle_hide_eliminated: SimpleAction,
+    action_generation_time_limit: SimpleAction,
+    action_eager_clue_propagation: SimpleAction,
+    action_collapse_completed_clues: SimpleAction,
+    action_autosave_interval: SimpleAction,
+    action_compact_negative_tiles: SimpleAction,
+    action_show_column_guides: SimpleAction,
+    action_retry_incorrect_submissions: SimpleAction,
+    action_confirm_last_candidate_elimination: SimpleAction,
+    action_hint_keeps_clue_selected: SimpleAction,
+    action_candidate_display: SimpleAction,
+    action_clue_sort: SimpleAction,
+    action_dim_inactive_clues: SimpleAction,
+    action_show_row_progress: SimpleAction,
+    action_cascade_delay: SimpleAction,
+    action_pause_on_focus_loss: SimpleAction,
+    action_featured_icon_category: SimpleAction,
+    action_show_timer_during_play: SimpleAction,
+    action_difficulty_dropdown_immediate: SimpleAction,
+    action_incorrect_submission_penalty: SimpleAction,
+    action_show_clue_panel_headers: SimpleAction,
+    action_shuffle_candidate_order: SimpleAction,
+    action_suggest_obvious_consequences: SimpleAction,
+    action_prefer_placement_hints: SimpleAction,
     game_engine_event_subscription: Option<Unsubscriber<GameEngineEvent>>,
     game_engine_command_emitter: EventEmitter<GameEngineCommand>,
 }
@@ -39,7 +85,69 @@ impl Destroyable for SettingsMenuUI {
         self.window
             .remove_action(&self.action_toggle_touch_controls.name());
         self.window
-            .remove_action(&self.action_toggle_auto_solve.name());
+            .remove_action(&self.action_auto_solve_mode.name());
+        self.window
+            .remove_action(&self.action_toggle_progressive_reveal.name());
+        self.window
+            .remove_action(&self.action_toggle_lock_solved_cells.name());
+        self.window
+            .remove_action(&self.action_toggle_clue_nav_wrap.name());
+        self.window
+            .remove_action(&self.action_toggle_center_partial_row.name());
+        self.window
+            .remove_action(&self.action_toggle_log_hint_deductions.name());
+        self.window
+            .remove_action(&self.action_toggle_resume_last_game.name());
+        self.window
+            .remove_action(&self.action_preferred_difficulty.name());
+        self.window
+            .remove_action(&self.action_toggle_track_elimination_reasons.name());
+        self.window
+            .remove_action(&self.action_toggle_hide_eliminated.name());
+        self.window
+            .remove_action(&self.action_generation_time_limit.name());
+        self.window
+            .remove_action(&self.action_eager_clue_propagation.name());
+        self.window
+            .remove_action(&self.action_collapse_completed_clues.name());
+        self.window
+            .remove_action(&self.action_autosave_interval.name());
+        self.window
+            .remove_action(&self.action_compact_negative_tiles.name());
+        self.window
+            .remove_action(&self.action_show_column_guides.name());
+        self.window
+            .remove_action(&self.action_retry_incorrect_submissions.name());
+        self.window
+            .remove_action(&self.action_confirm_last_candidate_elimination.name());
+        self.window
+            .remove_action(&self.action_hint_keeps_clue_selected.name());
+        self.window
+            .remove_action(&self.action_candidate_display.name());
+        self.window.remove_action(&self.action_clue_sort.name());
+        self.window
+            .remove_action(&self.action_dim_inactive_clues.name());
+        self.window
+            .remove_action(&self.action_show_row_progress.name());
+        self.window.remove_action(&self.action_cascade_delay.name());
+        self.window
+            .remove_action(&self.action_pause_on_focus_loss.name());
+        self.window
+            .remove_action(&self.action_featured_icon_category.name());
+        self.window
+            .remove_action(&self.action_show_timer_during_play.name());
+        self.window
+            .remove_action(&self.action_difficulty_dropdown_immediate.name());
+        self.window
+            .remove_action(&self.action_incorrect_submission_penalty.name());
+        self.window
+            .remove_action(&self.action_show_clue_panel_headers.name());
+        self.window
+            .remove_action(&self.action_shuffle_candidate_order.name());
+        self.window
+            .remove_action(&self.action_suggest_obvious_consequences.name());
+        self.window
+            .remove_action(&self.action_prefer_placement_hints.name());
     }
 }
 
@@ -58,9 +166,227 @@ impl SettingsMenuUI {
             Some(&t!("settings-touch-screen-controls")),
             Some("win.toggle-touch-controls"),
         );
+        let auto_solve_menu = Menu::new();
+        for auto_solve_mode in AutoSolveMode::all() {
+            let label = match auto_solve_mode {
+                AutoSolveMode::Off => t!("settings-auto-solve-off"),
+                AutoSolveMode::Row => t!("settings-auto-solve-row"),
+                AutoSolveMode::Board => t!("settings-auto-solve-board"),
+            };
+            auto_solve_menu.append(
+                Some(&label),
+                Some(&format!("win.auto-solve-mode::{}", auto_solve_mode.index())),
+            );
+        }
+        settings_menu.append_submenu(Some(&t!("settings-auto-solve")), &auto_solve_menu);
+        settings_menu.append(
+            Some(&t!("settings-progressive-clue-reveal")),
+            Some("win.toggle-progressive-reveal"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-lock-solved-cells")),
+            Some("win.toggle-lock-solved-cells"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-clue-nav-wraps-within-orientation")),
+            Some("win.toggle-clue-nav-wrap"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-center-partial-candidate-row")),
+            Some("win.toggle-center-partial-row"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-log-hint-deductions")),
+            Some("win.toggle-log-hint-deductions"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-resume-last-game")),
+            Some("win.toggle-resume-last-game"),
+        );
+
+        let preferred_difficulty_menu = Menu::new();
+        for difficulty in Difficulty::all() {
+            preferred_difficulty_menu.append(
+                Some(&difficulty.to_string()),
+                Some(&format!("win.preferred-difficulty::{}", difficulty.index())),
+            );
+        }
+        settings_menu.append_submenu(
+            Some(&t!("settings-preferred-difficulty")),
+            &preferred_difficulty_menu,
+        );
+        settings_menu.append(
+            Some(&t!("settings-track-elimination-reasons")),
+            Some("win.toggle-track-elimination-reasons"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-hide-eliminated-candidates")),
+            Some("win.toggle-hide-eliminated"),
+        );
+
+        let generation_time_limit_menu = Menu::new();
+        for secs in GENERATION_TIME_LIMIT_CHOICES {
+            let label = if secs == 0 {
+                t!("settings-generation-time-limit-none")
+            } else {
+                t!("settings-generation-time-limit-seconds", { "seconds" => secs.to_string() })
+            };
+            generation_time_limit_menu.append(
+                Some(&label),
+                Some(&format!("win.generation-time-limit::{}", secs)),
+            );
+        }
+        settings_menu.append_submenu(
+            Some(&t!("settings-generation-time-limit")),
+            &generation_time_limit_menu,
+        );
+        settings_menu.append(
+            Some(&t!("settings-eager-clue-propagation")),
+            Some("win.toggle-eager-clue-propagation"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-collapse-completed-clues")),
+            Some("win.toggle-collapse-completed-clues"),
+        );
+
+        let autosave_interval_menu = Menu::new();
+        for secs in AUTOSAVE_INTERVAL_CHOICES {
+            let label = if secs == 0 {
+                t!("settings-autosave-interval-off")
+            } else {
+                t!("settings-autosave-interval-seconds", { "seconds" => secs.to_string() })
+            };
+            autosave_interval_menu.append(
+                Some(&label),
+                Some(&format!("win.autosave-interval::{}", secs)),
+            );
+        }
+        settings_menu.append_submenu(
+            Some(&t!("settings-autosave-interval")),
+            &autosave_interval_menu,
+        );
+
+        let cascade_delay_menu = Menu::new();
+        for ms in CASCADE_DELAY_CHOICES {
+            let label = if ms == 0 {
+                t!("settings-cascade-delay-instant")
+            } else {
+                t!("settings-cascade-delay-ms", { "ms" => ms.to_string() })
+            };
+            cascade_delay_menu.append(Some(&label), Some(&format!("win.cascade-delay::{}", ms)));
+        }
+        settings_menu.append_submenu(Some(&t!("settings-cascade-delay")), &cascade_delay_menu);
+        settings_menu.append(
+            Some(&t!("settings-compact-negative-tiles")),
+            Some("win.toggle-compact-negative-tiles"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-show-column-guides")),
+            Some("win.toggle-column-guides"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-retry-incorrect-submissions")),
+            Some("win.toggle-retry-incorrect-submissions"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-incorrect-submission-penalty")),
+            Some("win.toggle-incorrect-submission-penalty"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-confirm-last-candidate-elimination")),
+            Some("win.toggle-confirm-last-candidate-elimination"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-hint-keeps-clue-selected")),
+            Some("win.toggle-hint-keeps-clue-selected"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-prefer-placement-hints")),
+            Some("win.toggle-prefer-placement-hints"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-dim-inactive-clues")),
+            Some("win.toggle-dim-inactive-clues"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-show-row-progress")),
+            Some("win.toggle-show-row-progress"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-pause-on-focus-loss")),
+            Some("win.toggle-pause-on-focus-loss"),
+        );
+
+        let candidate_display_menu = Menu::new();
+        for candidate_display in CandidateDisplay::all() {
+            let label = match candidate_display {
+                CandidateDisplay::Grid => t!("settings-candidate-display-grid"),
+                CandidateDisplay::List => t!("settings-candidate-display-list"),
+            };
+            candidate_display_menu.append(
+                Some(&label),
+                Some(&format!(
+                    "win.candidate-display::{}",
+                    candidate_display.index()
+                )),
+            );
+        }
+        settings_menu.append_submenu(
+            Some(&t!("settings-candidate-display")),
+            &candidate_display_menu,
+        );
+
+        let clue_sort_menu = Menu::new();
+        for clue_sort in ClueSort::all() {
+            let label = match clue_sort {
+                ClueSort::Type => t!("settings-clue-sort-type"),
+                ClueSort::Position => t!("settings-clue-sort-position"),
+                ClueSort::TileCount => t!("settings-clue-sort-tile-count"),
+            };
+            clue_sort_menu.append(
+                Some(&label),
+                Some(&format!("win.clue-sort::{}", clue_sort.index())),
+            );
+        }
+        settings_menu.append_submenu(Some(&t!("settings-clue-sort")), &clue_sort_menu);
+
+        let featured_icon_category_menu = Menu::new();
+        featured_icon_category_menu.append(
+            Some(&t!("settings-featured-icon-category-none")),
+            Some(&format!(
+                "win.featured-icon-category::{}",
+                NO_FEATURED_ICON_CATEGORY
+            )),
+        );
+        for (category, key) in ICON_CATEGORY_KEYS.into_iter().enumerate() {
+            featured_icon_category_menu.append(
+                Some(&t!(key)),
+                Some(&format!("win.featured-icon-category::{}", category)),
+            );
+        }
+        settings_menu.append_submenu(
+            Some(&t!("settings-featured-icon-category")),
+            &featured_icon_category_menu,
+        );
+        settings_menu.append(
+            Some(&t!("settings-show-timer-during-play")),
+            Some("win.toggle-show-timer-during-play"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-difficulty-dropdown-immediate")),
+            Some("win.toggle-difficulty-dropdown-immediate"),
+        );
         settings_menu.append(
-            Some(&t!("settings-auto-solve")),
-            Some("win.toggle-auto-solve"),
+            Some(&t!("settings-show-clue-panel-headers")),
+            Some("win.toggle-show-clue-panel-headers"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-shuffle-candidate-order")),
+            Some("win.toggle-shuffle-candidate-order"),
+        );
+        settings_menu.append(
+            Some(&t!("settings-suggest-obvious-consequences")),
+            Some("win.toggle-suggest-obvious-consequences"),
         );
 
         if Settings::is_debug_mode() {
@@ -70,7 +396,39 @@ impl SettingsMenuUI {
         let action_toggle_tooltips: SimpleAction;
         let action_toggle_spotlight: SimpleAction;
         let action_toggle_touch_controls: SimpleAction;
-        let action_toggle_auto_solve: SimpleAction;
+        let action_auto_solve_mode: SimpleAction;
+        let action_toggle_progressive_reveal: SimpleAction;
+        let action_toggle_lock_solved_cells: SimpleAction;
+        let action_toggle_clue_nav_wrap: SimpleAction;
+        let action_toggle_center_partial_row: SimpleAction;
+        let action_toggle_log_hint_deductions: SimpleAction;
+        let action_toggle_resume_last_game: SimpleAction;
+        let action_preferred_difficulty: SimpleAction;
+        let action_toggle_track_elimination_reasons: SimpleAction;
+        let action_toggle_hide_eliminated: SimpleAction;
+        let action_generation_time_limit: SimpleAction;
+        let action_eager_clue_propagation: SimpleAction;
+        let action_collapse_completed_clues: SimpleAction;
+        let action_autosave_interval: SimpleAction;
+        let action_compact_negative_tiles: SimpleAction;
+        let action_show_column_guides: SimpleAction;
+        let action_retry_incorrect_submissions: SimpleAction;
+        let action_confirm_last_candidate_elimination: SimpleAction;
+        let action_hint_keeps_clue_selected: SimpleAction;
+        let action_candidate_display: SimpleAction;
+        let action_clue_sort: SimpleAction;
+        let action_dim_inactive_clues: SimpleAction;
+        let action_show_row_progress: SimpleAction;
+        let action_cascade_delay: SimpleAction;
+        let action_pause_on_focus_loss: SimpleAction;
+        let action_featured_icon_category: SimpleAction;
+        let action_show_timer_during_play: SimpleAction;
+        let action_difficulty_dropdown_immediate: SimpleAction;
+        let action_incorrect_submission_penalty: SimpleAction;
+        let action_show_clue_panel_headers: SimpleAction;
+        let action_shuffle_candidate_order: SimpleAction;
+        let action_suggest_obvious_consequences: SimpleAction;
+        let action_prefer_placement_hints: SimpleAction;
 
         {
             action_toggle_tooltips = SimpleAction::new_stateful(
@@ -91,10 +449,215 @@ impl SettingsMenuUI {
                 &settings.touch_screen_controls.to_variant(),
             );
 
-            action_toggle_auto_solve = SimpleAction::new_stateful(
-                "toggle-auto-solve",
+            action_auto_solve_mode = SimpleAction::new_stateful(
+                "auto-solve-mode",
+                Some(glib::VariantTy::STRING),
+                &settings.auto_solve_mode.index().to_string().to_variant(),
+            );
+
+            action_toggle_progressive_reveal = SimpleAction::new_stateful(
+                "toggle-progressive-reveal",
+                None,
+                &settings.progressive_clue_reveal.to_variant(),
+            );
+
+            action_toggle_lock_solved_cells = SimpleAction::new_stateful(
+                "toggle-lock-solved-cells",
+                None,
+                &settings.lock_solved_cells.to_variant(),
+            );
+
+            action_toggle_clue_nav_wrap = SimpleAction::new_stateful(
+                "toggle-clue-nav-wrap",
+                None,
+                &settings.clue_nav_wraps_within_orientation.to_variant(),
+            );
+
+            action_toggle_center_partial_row = SimpleAction::new_stateful(
+                "toggle-center-partial-row",
+                None,
+                &settings.center_partial_candidate_row.to_variant(),
+            );
+
+            action_toggle_log_hint_deductions = SimpleAction::new_stateful(
+                "toggle-log-hint-deductions",
+                None,
+                &settings.log_hint_deductions.to_variant(),
+            );
+
+            action_toggle_resume_last_game = SimpleAction::new_stateful(
+                "toggle-resume-last-game",
+                None,
+                &settings.resume_last_game.to_variant(),
+            );
+
+            // radio items; the state is the difficulty index as a string
+            action_preferred_difficulty = SimpleAction::new_stateful(
+                "preferred-difficulty",
+                Some(glib::VariantTy::STRING),
+                &settings
+                    .preferred_difficulty
+                    .index()
+                    .to_string()
+                    .to_variant(),
+            );
+
+            action_toggle_track_elimination_reasons = SimpleAction::new_stateful(
+                "toggle-track-elimination-reasons",
+                None,
+                &settings.track_elimination_reasons.to_variant(),
+            );
+
+            action_toggle_hide_eliminated = SimpleAction::new_stateful(
+                "toggle-hide-eliminated",
+                None,
+                &settings.hide_eliminated_candidates.to_variant(),
+            );
+
+            // radio items; the state is the limit in seconds as a string
+            action_generation_time_limit = SimpleAction::new_stateful(
+                "generation-time-limit",
+                Some(glib::VariantTy::STRING),
+                &settings.generation_time_limit_secs.to_string().to_variant(),
+            );
+
+            action_eager_clue_propagation = SimpleAction::new_stateful(
+                "toggle-eager-clue-propagation",
+                None,
+                &settings.eager_clue_propagation.to_variant(),
+            );
+
+            action_collapse_completed_clues = SimpleAction::new_stateful(
+                "toggle-collapse-completed-clues",
+                None,
+                &settings.collapse_completed_clues.to_variant(),
+            );
+
+            // radio items; the state is the interval in seconds as a string
+            action_autosave_interval = SimpleAction::new_stateful(
+                "autosave-interval",
+                Some(glib::VariantTy::STRING),
+                &settings.autosave_interval_secs.to_string().to_variant(),
+            );
+
+            action_compact_negative_tiles = SimpleAction::new_stateful(
+                "toggle-compact-negative-tiles",
+                None,
+                &settings.compact_negative_tiles.to_variant(),
+            );
+
+            action_show_column_guides = SimpleAction::new_stateful(
+                "toggle-column-guides",
+                None,
+                &settings.show_column_guides.to_variant(),
+            );
+
+            action_retry_incorrect_submissions = SimpleAction::new_stateful(
+                "toggle-retry-incorrect-submissions",
+                None,
+                &settings.retry_incorrect_submissions.to_variant(),
+            );
+
+            action_confirm_last_candidate_elimination = SimpleAction::new_stateful(
+                "toggle-confirm-last-candidate-elimination",
+                None,
+                &settings.confirm_last_candidate_elimination.to_variant(),
+            );
+
+            action_hint_keeps_clue_selected = SimpleAction::new_stateful(
+                "toggle-hint-keeps-clue-selected",
+                None,
+                &settings.hint_keeps_clue_selected.to_variant(),
+            );
+
+            // radio items; the state is the display's index as a string
+            action_candidate_display = SimpleAction::new_stateful(
+                "candidate-display",
+                Some(glib::VariantTy::STRING),
+                &settings.candidate_display.index().to_string().to_variant(),
+            );
+            action_clue_sort = SimpleAction::new_stateful(
+                "clue-sort",
+                Some(glib::VariantTy::STRING),
+                &settings.clue_sort.index().to_string().to_variant(),
+            );
+
+            action_dim_inactive_clues = SimpleAction::new_stateful(
+                "toggle-dim-inactive-clues",
+                None,
+                &settings.dim_inactive_clues.to_variant(),
+            );
+
+            action_show_row_progress = SimpleAction::new_stateful(
+                "toggle-show-row-progress",
+                None,
+                &settings.show_row_progress.to_variant(),
+            );
+
+            // radio items; the state is the delay in milliseconds as a string
+            action_cascade_delay = SimpleAction::new_stateful(
+                "cascade-delay",
+                Some(glib::VariantTy::STRING),
+                &settings.cascade_delay_ms.to_string().to_variant(),
+            );
+
+            action_pause_on_focus_loss = SimpleAction::new_stateful(
+                "toggle-pause-on-focus-loss",
+                None,
+                &settings.pause_on_focus_loss.to_variant(),
+            );
+
+            // radio items; the state is the category's index as a string, or "none"
+            action_featured_icon_category = SimpleAction::new_stateful(
+                "featured-icon-category",
+                Some(glib::VariantTy::STRING),
+                &settings
+                    .featured_icon_category
+                    .map(|category| category.to_string())
+                    .unwrap_or_else(|| NO_FEATURED_ICON_CATEGORY.to_string())
+                    .to_variant(),
+            );
+
+            action_show_timer_during_play = SimpleAction::new_stateful(
+                "toggle-show-timer-during-play",
+                None,
+                &settings.show_timer_during_play.to_variant(),
+            );
+
+            action_difficulty_dropdown_immediate = SimpleAction::new_stateful(
+                "toggle-difficulty-dropdown-immediate",
+                None,
+                &settings.difficulty_dropdown_immediate.to_variant(),
+            );
+
+            action_incorrect_submission_penalty = SimpleAction::new_stateful(
+                "toggle-incorrect-submission-penalty",
+                None,
+                &settings.incorrect_submission_penalty.to_variant(),
+            );
+
+            action_show_clue_panel_headers = SimpleAction::new_stateful(
+                "toggle-show-clue-panel-headers",
+                None,
+                &settings.show_clue_panel_headers.to_variant(),
+            );
+
+            action_shuffle_candidate_order = SimpleAction::new_stateful(
+                "toggle-shuffle-candidate-order",
+                None,
+                &settings.shuffle_candidate_order.to_variant(),
+            );
+
+            action_suggest_obvious_consequences = SimpleAction::new_stateful(
+                "toggle-suggest-obvious-consequences",
                 None,
-                &settings.auto_solve_enabled.to_variant(),
+                &settings.suggest_obvious_consequences.to_variant(),
+            );
+
+            action_prefer_placement_hints = SimpleAction::new_stateful(
+                "toggle-prefer-placement-hints",
+                None,
+                &settings.prefer_placement_hints.to_variant(),
             );
         }
 
@@ -104,7 +667,39 @@ impl SettingsMenuUI {
             action_toggle_tooltips,
             action_toggle_spotlight,
             action_toggle_touch_controls,
-            action_toggle_auto_solve,
+            action_auto_solve_mode,
+            action_toggle_progressive_reveal,
+            action_toggle_lock_solved_cells,
+            action_toggle_clue_nav_wrap,
+            action_toggle_center_partial_row,
+            action_toggle_log_hint_deductions,
+            action_toggle_resume_last_game,
+            action_preferred_difficulty,
+            action_toggle_track_elimination_reasons,
+            action_toggle_hide_eliminated,
+            action_generation_time_limit,
+            action_eager_clue_propagation,
+            action_collapse_completed_clues,
+            action_autosave_interval,
+            action_compact_negative_tiles,
+            action_show_column_guides,
+            action_retry_incorrect_submissions,
+            action_confirm_last_candidate_elimination,
+            action_hint_keeps_clue_selected,
+            action_candidate_display,
+            action_clue_sort,
+            action_dim_inactive_clues,
+            action_show_row_progress,
+            action_cascade_delay,
+            action_pause_on_focus_loss,
+            action_featured_icon_category,
+            action_show_timer_during_play,
+            action_difficulty_dropdown_immediate,
+            action_incorrect_submission_penalty,
+            action_show_clue_panel_headers,
+            action_shuffle_candidate_order,
+            action_suggest_obvious_consequences,
+            action_prefer_placement_hints,
             game_engine_event_subscription: None,
             game_engine_command_emitter: game_engine_command_emitter.clone(),
         }));
@@ -173,9 +768,30 @@ impl SettingsMenuUI {
             });
         window.add_action(&settings_menu_ui_ref.action_toggle_touch_controls);
 
-        // Connect auto-solve action
+        // Connect auto-solve mode action
+        settings_menu_ui_ref
+            .action_auto_solve_mode
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, parameter| {
+                    let index = parameter
+                        .and_then(|parameter| parameter.get::<String>())
+                        .and_then(|index| index.parse::<usize>().ok());
+                    if let Some(index) = index {
+                        action.set_state(&index.to_string().to_variant());
+                        if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                            settings_menu_ui
+                                .borrow_mut()
+                                .set_auto_solve_mode(AutoSolveMode::from_index(index));
+                        }
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_auto_solve_mode);
+
+        // Connect progressive clue reveal action
         settings_menu_ui_ref
-            .action_toggle_auto_solve
+            .action_toggle_progressive_reveal
             .connect_activate({
                 let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
                 move |action, _| {
@@ -185,37 +801,837 @@ impl SettingsMenuUI {
                     if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
                         settings_menu_ui
                             .borrow_mut()
-                            .set_auto_solve_enabled(new_state);
+                            .set_progressive_clue_reveal(new_state);
                     }
                 }
             });
-        window.add_action(&settings_menu_ui_ref.action_toggle_auto_solve);
-    }
+        window.add_action(&settings_menu_ui_ref.action_toggle_progressive_reveal);
 
-    fn set_tooltips_enabled(&mut self, enabled: bool) {
-        let mut settings_change = SettingsChange::default();
-        settings_change.clue_spotlight_enabled = Some(enabled);
-        self.game_engine_command_emitter
-            .emit(GameEngineCommand::ChangeSettings(settings_change));
-    }
+        // Connect lock solved cells action
+        settings_menu_ui_ref
+            .action_toggle_lock_solved_cells
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_lock_solved_cells(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_lock_solved_cells);
 
-    fn set_clue_spotlight_enabled(&mut self, enabled: bool) {
-        let mut settings_change = SettingsChange::default();
-        settings_change.clue_spotlight_enabled = Some(enabled);
-        self.game_engine_command_emitter
-            .emit(GameEngineCommand::ChangeSettings(settings_change));
-    }
+        // Connect clue nav wrap action
+        settings_menu_ui_ref
+            .action_toggle_clue_nav_wrap
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_clue_nav_wraps_within_orientation(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_clue_nav_wrap);
 
-    fn set_touch_screen_controls(&mut self, enabled: bool) {
-        let mut settings_change = SettingsChange::default();
-        settings_change.touch_screen_controls = Some(enabled);
+        // Connect center partial row action
+        settings_menu_ui_ref
+            .action_toggle_center_partial_row
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_center_partial_candidate_row(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_center_partial_row);
+
+        // Connect log hint deductions action
+        settings_menu_ui_ref
+            .action_toggle_log_hint_deductions
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_log_hint_deductions(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_log_hint_deductions);
+
+        // Connect resume last game action
+        settings_menu_ui_ref
+            .action_toggle_resume_last_game
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_resume_last_game(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_resume_last_game);
+
+        // Connect preferred difficulty action
+        settings_menu_ui_ref
+            .action_preferred_difficulty
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, parameter| {
+                    let index = parameter
+                        .and_then(|parameter| parameter.get::<String>())
+                        .and_then(|index| index.parse::<usize>().ok());
+                    if let Some(index) = index {
+                        action.set_state(&index.to_string().to_variant());
+                        if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                            settings_menu_ui
+                                .borrow_mut()
+                                .set_preferred_difficulty(Difficulty::from_index(index));
+                        }
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_preferred_difficulty);
+
+        // Connect track elimination reasons action
+        settings_menu_ui_ref
+            .action_toggle_track_elimination_reasons
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_track_elimination_reasons(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_track_elimination_reasons);
+
+        // Connect hide eliminated action
+        settings_menu_ui_ref
+            .action_toggle_hide_eliminated
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_hide_eliminated_candidates(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_toggle_hide_eliminated);
+
+        // Connect generation time limit action
+        settings_menu_ui_ref
+            .action_generation_time_limit
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, parameter| {
+                    let secs = parameter
+                        .and_then(|parameter| parameter.get::<String>())
+                        .and_then(|secs| secs.parse::<u64>().ok());
+                    if let Some(secs) = secs {
+                        action.set_state(&secs.to_string().to_variant());
+                        if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                            settings_menu_ui
+                                .borrow_mut()
+                                .set_generation_time_limit_secs(secs);
+                        }
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_generation_time_limit);
+
+        // Connect eager clue propagation action
+        settings_menu_ui_ref
+            .action_eager_clue_propagation
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_eager_clue_propagation(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_eager_clue_propagation);
+
+        // Connect collapse completed clues action
+        settings_menu_ui_ref
+            .action_collapse_completed_clues
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_collapse_completed_clues(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_collapse_completed_clues);
+
+        // Connect autosave interval action
+        settings_menu_ui_ref
+            .action_autosave_interval
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, parameter| {
+                    let secs = parameter
+                        .and_then(|parameter| parameter.get::<String>())
+                        .and_then(|secs| secs.parse::<u64>().ok());
+                    if let Some(secs) = secs {
+                        action.set_state(&secs.to_string().to_variant());
+                        if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                            settings_menu_ui
+                                .borrow_mut()
+                                .set_autosave_interval_secs(secs);
+                        }
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_autosave_interval);
+
+        // Connect compact negative tiles action
+        settings_menu_ui_ref
+            .action_compact_negative_tiles
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_compact_negative_tiles(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_compact_negative_tiles);
+
+        // Connect column guides action
+        settings_menu_ui_ref
+            .action_show_column_guides
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_show_column_guides(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_show_column_guides);
+
+        // Connect retry incorrect submissions action
+        settings_menu_ui_ref
+            .action_retry_incorrect_submissions
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_retry_incorrect_submissions(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_retry_incorrect_submissions);
+
+        // Connect confirm last candidate elimination action
+        settings_menu_ui_ref
+            .action_confirm_last_candidate_elimination
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_confirm_last_candidate_elimination(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_confirm_last_candidate_elimination);
+
+        // Connect hint keeps clue selected action
+        settings_menu_ui_ref
+            .action_hint_keeps_clue_selected
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_hint_keeps_clue_selected(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_hint_keeps_clue_selected);
+
+        // Connect candidate display action
+        settings_menu_ui_ref
+            .action_candidate_display
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, parameter| {
+                    let index = parameter
+                        .and_then(|parameter| parameter.get::<String>())
+                        .and_then(|index| index.parse::<usize>().ok());
+                    if let Some(index) = index {
+                        action.set_state(&index.to_string().to_variant());
+                        if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                            settings_menu_ui
+                                .borrow_mut()
+                                .set_candidate_display(CandidateDisplay::from_index(index));
+                        }
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_candidate_display);
+
+        // Connect clue sort action
+        settings_menu_ui_ref.action_clue_sort.connect_activate({
+            let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+            move |action, parameter| {
+                let index = parameter
+                    .and_then(|parameter| parameter.get::<String>())
+                    .and_then(|index| index.parse::<usize>().ok());
+                if let Some(index) = index {
+                    action.set_state(&index.to_string().to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_clue_sort(ClueSort::from_index(index));
+                    }
+                }
+            }
+        });
+        window.add_action(&settings_menu_ui_ref.action_clue_sort);
+
+        // Connect dim inactive clues action
+        settings_menu_ui_ref
+            .action_dim_inactive_clues
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_dim_inactive_clues(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_dim_inactive_clues);
+
+        // Connect show row progress action
+        settings_menu_ui_ref
+            .action_show_row_progress
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_show_row_progress(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_show_row_progress);
+
+        // Connect cascade delay action
+        settings_menu_ui_ref.action_cascade_delay.connect_activate({
+            let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+            move |action, parameter| {
+                let ms = parameter
+                    .and_then(|parameter| parameter.get::<String>())
+                    .and_then(|ms| ms.parse::<u64>().ok());
+                if let Some(ms) = ms {
+                    action.set_state(&ms.to_string().to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui.borrow_mut().set_cascade_delay_ms(ms);
+                    }
+                }
+            }
+        });
+        window.add_action(&settings_menu_ui_ref.action_cascade_delay);
+
+        // Connect pause on focus loss action
+        settings_menu_ui_ref
+            .action_pause_on_focus_loss
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_pause_on_focus_loss(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_pause_on_focus_loss);
+
+        // Connect featured icon category action
+        settings_menu_ui_ref
+            .action_featured_icon_category
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, parameter| {
+                    let Some(choice) = parameter.and_then(|parameter| parameter.get::<String>())
+                    else {
+                        return;
+                    };
+                    let category = choice.parse::<usize>().ok();
+                    if category.is_none() && choice != NO_FEATURED_ICON_CATEGORY {
+                        return;
+                    }
+                    action.set_state(&choice.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_featured_icon_category(category);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_featured_icon_category);
+
+        // Connect show timer during play action
+        settings_menu_ui_ref
+            .action_show_timer_during_play
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_show_timer_during_play(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_show_timer_during_play);
+
+        // Connect difficulty dropdown immediate action
+        settings_menu_ui_ref
+            .action_difficulty_dropdown_immediate
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_difficulty_dropdown_immediate(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_difficulty_dropdown_immediate);
+
+        // Connect incorrect submission penalty action
+        settings_menu_ui_ref
+            .action_incorrect_submission_penalty
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_incorrect_submission_penalty(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_incorrect_submission_penalty);
+
+        // Connect show clue panel headers action
+        settings_menu_ui_ref
+            .action_show_clue_panel_headers
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_show_clue_panel_headers(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_show_clue_panel_headers);
+
+        // Connect shuffle candidate order action
+        settings_menu_ui_ref
+            .action_shuffle_candidate_order
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_shuffle_candidate_order(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_shuffle_candidate_order);
+
+        // Connect suggest obvious consequences action
+        settings_menu_ui_ref
+            .action_suggest_obvious_consequences
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_suggest_obvious_consequences(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_suggest_obvious_consequences);
+
+        // Connect prefer placement hints action
+        settings_menu_ui_ref
+            .action_prefer_placement_hints
+            .connect_activate({
+                let weak_settings_menu_ui = Weak::clone(&weak_settings_menu_ui);
+                move |action, _| {
+                    let current_state = action.state().unwrap().get::<bool>().unwrap();
+                    let new_state = !current_state;
+                    action.set_state(&new_state.to_variant());
+                    if let Some(settings_menu_ui) = weak_settings_menu_ui.upgrade() {
+                        settings_menu_ui
+                            .borrow_mut()
+                            .set_prefer_placement_hints(new_state);
+                    }
+                }
+            });
+        window.add_action(&settings_menu_ui_ref.action_prefer_placement_hints);
+    }
+
+    fn set_tooltips_enabled(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.clue_spotlight_enabled = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_clue_spotlight_enabled(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.clue_spotlight_enabled = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_touch_screen_controls(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.touch_screen_controls = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_auto_solve_mode(&mut self, auto_solve_mode: AutoSolveMode) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.auto_solve_mode = Some(auto_solve_mode);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_progressive_clue_reveal(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.progressive_clue_reveal = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_lock_solved_cells(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.lock_solved_cells = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_clue_nav_wraps_within_orientation(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.clue_nav_wraps_within_orientation = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_center_partial_candidate_row(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.center_partial_candidate_row = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_log_hint_deductions(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.log_hint_deductions = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_resume_last_game(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.resume_last_game = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_preferred_difficulty(&mut self, difficulty: Difficulty) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.preferred_difficulty = Some(difficulty);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_track_elimination_reasons(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.track_elimination_reasons = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_hide_eliminated_candidates(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.hide_eliminated_candidates = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_generation_time_limit_secs(&mut self, secs: u64) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.generation_time_limit_secs = Some(secs);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_eager_clue_propagation(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.eager_clue_propagation = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_collapse_completed_clues(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.collapse_completed_clues = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_autosave_interval_secs(&mut self, secs: u64) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.autosave_interval_secs = Some(secs);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_compact_negative_tiles(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.compact_negative_tiles = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_show_column_guides(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.show_column_guides = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_retry_incorrect_submissions(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.retry_incorrect_submissions = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_confirm_last_candidate_elimination(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.confirm_last_candidate_elimination = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_hint_keeps_clue_selected(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.hint_keeps_clue_selected = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_candidate_display(&mut self, candidate_display: CandidateDisplay) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.candidate_display = Some(candidate_display);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_clue_sort(&mut self, clue_sort: ClueSort) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.clue_sort = Some(clue_sort);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_dim_inactive_clues(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.dim_inactive_clues = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_show_row_progress(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.show_row_progress = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_cascade_delay_ms(&mut self, ms: u64) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.cascade_delay_ms = Some(ms);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_pause_on_focus_loss(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.pause_on_focus_loss = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_featured_icon_category(&mut self, category: Option<usize>) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.featured_icon_category = Some(category);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_show_timer_during_play(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.show_timer_during_play = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_difficulty_dropdown_immediate(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.difficulty_dropdown_immediate = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_incorrect_submission_penalty(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.incorrect_submission_penalty = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_show_clue_panel_headers(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.show_clue_panel_headers = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_shuffle_candidate_order(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.shuffle_candidate_order = Some(enabled);
+        self.game_engine_command_emitter
+            .emit(GameEngineCommand::ChangeSettings(settings_change));
+    }
+
+    fn set_suggest_obvious_consequences(&mut self, enabled: bool) {
+        let mut settings_change = SettingsChange::default();
+        settings_change.suggest_obvious_consequences = Some(enabled);
         self.game_engine_command_emitter
             .emit(GameEngineCommand::ChangeSettings(settings_change));
     }
 
-    fn set_auto_solve_enabled(&mut self, enabled: bool) {
+    fn set_prefer_placement_hints(&mut self, enabled: bool) {
         let mut settings_change = SettingsChange::default();
-        settings_change.auto_solve_enabled = Some(enabled);
+        settings_change.prefer_placement_hints = Some(enabled);
         self.game_engine_command_emitter
             .emit(GameEngineCommand::ChangeSettings(settings_change));
     }
@@ -0,0 +1,95 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use gtk4::{prelude::*, Grid, Image, MenuButton, Popover};
+
+use crate::{
+    destroyable::Destroyable,
+    events::EventHandler,
+    model::{GameEngineEvent, LayoutManagerEvent, Solution},
+};
+
+use super::ImageSet;
+
+const PREVIEW_TILE_SIZE: i32 = 16;
+
+/// Debug-only header button showing a thumbnail of the whole solution, for eyeballing the answer
+/// while testing generation, hints and the solver
+pub struct SolutionPreviewUI {
+    pub button: MenuButton,
+    grid: Grid,
+    resources: Rc<ImageSet>,
+    solution: Option<Arc<Solution>>,
+}
+
+impl Destroyable for SolutionPreviewUI {
+    fn destroy(&mut self) {}
+}
+
+impl EventHandler<GameEngineEvent> for SolutionPreviewUI {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        if let GameEngineEvent::GameBoardUpdated { board, .. } = event {
+            // the board updates on every move; only a new game or loaded state changes the solution
+            let solution_changed = match &self.solution {
+                Some(solution) => !Arc::ptr_eq(solution, &board.solution),
+                None => true,
+            };
+            if solution_changed {
+                self.solution = Some(board.solution.clone());
+                self.sync_grid();
+            }
+        }
+    }
+}
+
+impl EventHandler<LayoutManagerEvent> for SolutionPreviewUI {
+    fn handle_event(&mut self, event: &LayoutManagerEvent) {
+        if let LayoutManagerEvent::ImagesOptimized(image_set) = event {
+            self.resources = image_set.clone();
+            self.sync_grid();
+        }
+    }
+}
+
+impl SolutionPreviewUI {
+    pub fn new(resources: &Rc<ImageSet>) -> Rc<RefCell<Self>> {
+        let grid = Grid::builder()
+            .row_spacing(1)
+            .column_spacing(1)
+            .margin_top(5)
+            .margin_bottom(5)
+            .margin_start(5)
+            .margin_end(5)
+            .build();
+        let popover = Popover::builder().child(&grid).build();
+        let button = MenuButton::builder()
+            .icon_name("view-grid-symbolic")
+            .tooltip_text("Solution Preview")
+            .popover(&popover)
+            .build();
+
+        Rc::new(RefCell::new(Self {
+            button,
+            grid,
+            resources: resources.clone(),
+            solution: None,
+        }))
+    }
+
+    fn sync_grid(&self) {
+        while let Some(child) = self.grid.first_child() {
+            self.grid.remove(&child);
+        }
+        let Some(solution) = &self.solution else {
+            return;
+        };
+        for row in 0..solution.n_rows {
+            for col in 0..solution.n_variants {
+                let tile = solution.get(row, col);
+                let image = Image::builder().pixel_size(PREVIEW_TILE_SIZE).build();
+                image.set_paintable(Some(self.resources.get_solution_icon(&tile).as_ref()));
+                image.set_tooltip_text(Some(&tile.to_string()));
+                self.grid.attach(&image, col as i32, row as i32, 1, 1);
+            }
+        }
+    }
+}
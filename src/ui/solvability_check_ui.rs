@@ -0,0 +1,134 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gtk4::{prelude::*, Align, ApplicationWindow, Button, Label};
+
+use crate::{
+    destroyable::Destroyable,
+    events::{EventEmitter, EventHandler},
+    model::{GameEngineCommand, GameEngineEvent},
+};
+use fluent_i18n::t;
+
+/// Header button asking whether the puzzle can still be finished from the current board, and the
+/// dialog reporting the answer. Nothing about the solution is revealed either way. The same
+/// dialog offers to rewind as soon as a move leaves the board contradicting itself.
+pub struct SolvabilityCheckUI {
+    pub check_button: Button,
+    window: Rc<ApplicationWindow>,
+    game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    dialog: Option<gtk4::Window>,
+}
+
+impl SolvabilityCheckUI {
+    pub fn new(
+        window: &Rc<ApplicationWindow>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    ) -> Rc<RefCell<Self>> {
+        let check_button = Button::builder()
+            .icon_name("emblem-ok-symbolic")
+            .tooltip_text(&t!("check-solvable-tooltip"))
+            .build();
+        check_button.connect_clicked({
+            let game_engine_command_emitter = game_engine_command_emitter.clone();
+            move |_| {
+                game_engine_command_emitter.emit(GameEngineCommand::CheckSolvability);
+            }
+        });
+
+        Rc::new(RefCell::new(Self {
+            check_button,
+            window: window.clone(),
+            game_engine_command_emitter,
+            dialog: None,
+        }))
+    }
+
+    fn show_result(&mut self, solvable: bool) {
+        let message = if solvable {
+            t!("check-solvable-yes")
+        } else {
+            t!("check-solvable-no")
+        };
+        self.show_dialog(&t!("check-solvable-title"), &message, !solvable);
+    }
+
+    fn show_dialog(&mut self, title: &str, message: &str, offer_rewind: bool) {
+        self.hide_dialog();
+
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(10)
+            .margin_top(10)
+            .margin_start(20)
+            .margin_end(20)
+            .build();
+        let dialog = gtk4::Window::builder()
+            .transient_for(self.window.as_ref())
+            .title(title)
+            .child(&content_area)
+            .modal(true)
+            .build();
+
+        content_area.append(&Label::builder().label(message).wrap(true).build());
+
+        let buttons = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .halign(Align::End)
+            .spacing(10)
+            .build();
+        content_area.append(&buttons);
+
+        if offer_rewind {
+            let rewind_button = Button::builder()
+                .label(&t!("check-solvable-rewind"))
+                .build();
+            buttons.append(&rewind_button);
+            rewind_button.connect_clicked({
+                let dialog = dialog.clone();
+                let game_engine_command_emitter = self.game_engine_command_emitter.clone();
+                move |_| {
+                    game_engine_command_emitter.emit(GameEngineCommand::RewindLastGood);
+                    dialog.close();
+                }
+            });
+        }
+        let close_button = Button::builder().label(&t!("close")).build();
+        buttons.append(&close_button);
+        close_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        dialog.present();
+        self.dialog = Some(dialog);
+    }
+
+    fn hide_dialog(&mut self) {
+        if let Some(dialog) = self.dialog.take() {
+            dialog.close();
+        }
+    }
+}
+
+impl Destroyable for SolvabilityCheckUI {
+    fn destroy(&mut self) {
+        self.hide_dialog();
+    }
+}
+
+impl EventHandler<GameEngineEvent> for SolvabilityCheckUI {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        match event {
+            GameEngineEvent::SolvabilityChecked(solvable) => self.show_result(*solvable),
+            GameEngineEvent::BoardContradiction { .. } => self.show_dialog(
+                &t!("board-contradiction-title"),
+                &t!("board-contradiction-message"),
+                true,
+            ),
+            _ => (),
+        }
+    }
+}
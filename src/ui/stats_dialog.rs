@@ -4,10 +4,14 @@ use gtk4::{
     gdk, prelude::*, Align, ApplicationWindow, Box, EventControllerKey, Grid, Label, Orientation,
     Separator,
 };
+use std::cell::Cell;
+use std::rc::Rc;
 use std::time::Duration;
 
-use crate::game::stats_manager::StatsManager;
-use crate::model::{Difficulty, GameStats};
+use crate::game::achievements::Achievement;
+use crate::game::stats_manager::{StatsGrouping, StatsManager};
+use crate::model::{format_hint_count, Difficulty, GameStats};
+use crate::solver::puzzle_metrics::PuzzleRating;
 use fluent_i18n::t;
 
 pub struct StatsDialog;
@@ -27,7 +31,7 @@ impl StatsDialog {
     }
 
     fn create_high_scores_grid(
-        difficulty: Difficulty,
+        grouping: StatsGrouping,
         this_game_stats: Option<&GameStats>,
         stats_manager: &StatsManager,
     ) -> Grid {
@@ -51,11 +55,14 @@ impl StatsDialog {
             scores_grid.attach(&label, i as i32, 0, 1, 1);
         }
 
-        for (i, score) in stats_manager
-            .get_high_scores(difficulty, 20)
-            .into_iter()
-            .enumerate()
-        {
+        let high_scores = stats_manager.get_high_scores_for(grouping, 20);
+        if high_scores.is_empty() {
+            let empty_label = Label::new(Some(&t!("stats-no-games")));
+            empty_label.set_halign(Align::Start);
+            scores_grid.attach(&empty_label, 0, 1, headers.len() as i32, 1);
+        }
+
+        for (i, score) in high_scores.into_iter().enumerate() {
             let is_current_playthrough = this_game_stats
                 .map(|stats| stats.playthrough_id == score.playthrough_id)
                 .unwrap_or(false);
@@ -76,7 +83,7 @@ impl StatsDialog {
             }
             scores_grid.attach(&time, 1, row_index, 1, 1);
 
-            let hints = Label::new(Some(&format!("{}", score.hints_used)));
+            let hints = Label::new(Some(&format_hint_count(score.hints_used)));
             hints.set_halign(Align::End);
             if is_current_playthrough {
                 hints.add_css_class("highlight-score");
@@ -113,8 +120,8 @@ impl StatsDialog {
         scores_grid
     }
 
-    fn create_global_stats_grid(stats_manager: &StatsManager, difficulty: Difficulty) -> Grid {
-        let stats = stats_manager.get_global_stats(difficulty);
+    fn create_global_stats_grid(stats_manager: &StatsManager, grouping: StatsGrouping) -> Grid {
+        let stats = stats_manager.get_global_stats_for(grouping);
         let stats_grid = Grid::new();
         stats_grid.set_row_spacing(5);
         stats_grid.set_column_spacing(10);
@@ -155,17 +162,130 @@ impl StatsDialog {
         total_hints_value.set_halign(Align::End);
         stats_grid.attach(&total_hints_value, 1, 3, 1, 1);
 
+        let avg_hints = Label::new(Some(&t!("stats-average-hints-used")));
+        avg_hints.set_halign(Align::Start);
+        stats_grid.attach(&avg_hints, 0, 4, 1, 1);
+        let avg_hints_value = Label::new(Some(
+            &stats
+                .mean_hints_used()
+                .map(|mean| format!("{:.1}", mean))
+                .unwrap_or_else(|| "-".to_string()),
+        ));
+        avg_hints_value.set_halign(Align::End);
+        stats_grid.attach(&avg_hints_value, 1, 4, 1, 1);
+
+        let median_hints = Label::new(Some(&t!("stats-median-hints-used")));
+        median_hints.set_halign(Align::Start);
+        stats_grid.attach(&median_hints, 0, 5, 1, 1);
+        let median_hints_value = Label::new(Some(
+            &stats
+                .median_hints_used()
+                .map(format_hint_count)
+                .unwrap_or_else(|| "-".to_string()),
+        ));
+        median_hints_value.set_halign(Align::End);
+        stats_grid.attach(&median_hints_value, 1, 5, 1, 1);
+
+        let current_streak = Label::new(Some(&t!("stats-current-streak")));
+        current_streak.set_halign(Align::Start);
+        stats_grid.attach(&current_streak, 0, 6, 1, 1);
+        let current_streak_value = Label::new(Some(&stats.current_streak.to_string()));
+        current_streak_value.set_halign(Align::End);
+        stats_grid.attach(&current_streak_value, 1, 6, 1, 1);
+
+        let best_streak = Label::new(Some(&t!("stats-best-streak")));
+        best_streak.set_halign(Align::Start);
+        stats_grid.attach(&best_streak, 0, 7, 1, 1);
+        let best_streak_value = Label::new(Some(&stats.best_streak.to_string()));
+        best_streak_value.set_halign(Align::End);
+        stats_grid.attach(&best_streak_value, 1, 7, 1, 1);
+
         stats_grid
     }
 
+    /// Every achievement, with the date it was earned; the ones still to earn are dimmed
+    fn create_achievements_grid(stats_manager: &StatsManager) -> Grid {
+        let earned = stats_manager.earned_achievements();
+        let achievements_grid = Grid::new();
+        achievements_grid.set_row_spacing(5);
+        achievements_grid.set_column_spacing(10);
+        achievements_grid.set_margin_start(10);
+
+        for (row, achievement) in Achievement::all().into_iter().enumerate() {
+            let title = Label::new(Some(&t!(achievement.title_key())));
+            title.set_halign(Align::Start);
+            title.set_tooltip_text(Some(&t!(achievement.description_key())));
+            let date = match earned.get(&achievement) {
+                Some(timestamp) => Local
+                    .timestamp_opt(*timestamp, 0)
+                    .single()
+                    .map(|dt| dt.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| t!("stats-unknown").to_string()),
+                None => {
+                    title.add_css_class("dim-label");
+                    t!("achievement-not-earned").to_string()
+                }
+            };
+            let date_label = Label::new(Some(&date));
+            date_label.set_halign(Align::End);
+            achievements_grid.attach(&title, 0, row as i32, 1, 1);
+            achievements_grid.attach(&date_label, 1, row as i32, 1, 1);
+        }
+
+        achievements_grid
+    }
+
+    fn create_stats_view(
+        grouping: StatsGrouping,
+        group_name: &str,
+        this_game_stats: Option<&GameStats>,
+        stats_manager: &StatsManager,
+    ) -> Box {
+        let view = Box::builder()
+            .orientation(Orientation::Vertical)
+            .spacing(10)
+            .build();
+
+        // Add title for high scores
+        let best_times = format!("{} ({})", t!("best-times"), group_name);
+        let high_scores_label = Label::new(Some(&best_times));
+        high_scores_label.set_markup(&format!("<b>{}</b>", best_times));
+        high_scores_label.set_margin_bottom(10);
+        view.append(&high_scores_label);
+
+        // Add high scores grid
+        let scores_grid = Self::create_high_scores_grid(grouping, this_game_stats, stats_manager);
+        view.append(&scores_grid);
+
+        // Add separator
+        let separator = Separator::new(Orientation::Horizontal);
+        separator.set_margin_top(20);
+        separator.set_margin_bottom(20);
+        view.append(&separator);
+
+        // Add global stats
+        let global_stats_label = Label::new(Some(&t!("global-statistics")));
+        global_stats_label.set_markup(&format!("<b>{}</b>", t!("global-statistics")));
+        global_stats_label.set_margin_bottom(10);
+        view.append(&global_stats_label);
+
+        let stats_grid = Self::create_global_stats_grid(stats_manager, grouping);
+        view.append(&stats_grid);
+
+        view
+    }
+
+    /// Show the statistics. With `this_game_stats` (after a win) there's also a "more like this"
+    /// button; `on_close` is told whether the dialog was closed with it.
     pub fn show<F>(
         window: &ApplicationWindow,
         difficulty: Difficulty,
         stats_manager: &StatsManager,
         this_game_stats: Option<&GameStats>,
+        puzzle_rating: Option<&PuzzleRating>,
         on_close: F,
     ) where
-        F: Fn() + 'static,
+        F: Fn(bool) + 'static,
     {
         let vbox = Box::builder()
             .orientation(Orientation::Vertical)
@@ -183,35 +303,99 @@ impl StatsDialog {
             .transient_for(window)
             .build();
 
-        // Add title for high scores
-        let high_scores_label = Label::new(Some(&t!("best-times")));
-        high_scores_label.set_markup(&format!("<b>{}</b>", t!("best-times")));
-        high_scores_label.set_margin_bottom(10);
-        vbox.append(&high_scores_label);
+        // The final time is always shown after a win, even if the timer was hidden during play
+        if let Some(stats) = this_game_stats {
+            let your_time = t!("stats-your-time", {
+                "time" => Self::format_duration(stats.completion_time)
+            });
+            let your_time_label = Label::new(None);
+            your_time_label.set_markup(&format!("<b>{}</b>", your_time));
+            vbox.append(&your_time_label);
+        }
 
-        // Add high scores grid
-        let scores_grid = Self::create_high_scores_grid(difficulty, this_game_stats, stats_manager);
-        vbox.append(&scores_grid);
+        if let Some(rating) = puzzle_rating {
+            let rating_label = Label::new(Some(&t!("stats-puzzle-difficulty", {
+                "score" => rating.score.to_string()
+            })));
+            rating_label.set_tooltip_text(Some(&t!("stats-puzzle-difficulty-tooltip", {
+                "simple" => rating.n_simple_steps.to_string(),
+                "converging" => rating.n_converging_steps.to_string(),
+                "hidden_sets" => rating.n_hidden_set_steps.to_string()
+            })));
+            vbox.append(&rating_label);
+        }
+
+        // Toggle between grouping by difficulty and by grid size
+        let grouping_box = Box::builder()
+            .orientation(Orientation::Horizontal)
+            .spacing(5)
+            .halign(Align::End)
+            .build();
+        grouping_box.append(&Label::new(Some(&t!("stats-group-by"))));
+        let grouping_selector = gtk4::DropDown::from_strings(&[
+            t!("stats-group-difficulty").as_str(),
+            t!("stats-group-grid-size").as_str(),
+        ]);
+        grouping_box.append(&grouping_selector);
+        vbox.append(&grouping_box);
+
+        let grid_size = this_game_stats
+            .map(|stats| stats.grid_size)
+            .unwrap_or_else(|| difficulty.grid_size());
+
+        // Both views are built up front; the selector just flips which one is visible
+        let difficulty_view = Self::create_stats_view(
+            StatsGrouping::Difficulty(difficulty),
+            &difficulty.to_string(),
+            this_game_stats,
+            stats_manager,
+        );
+        let grid_size_view = Self::create_stats_view(
+            StatsGrouping::GridSize(grid_size),
+            &format!("{}x{}", grid_size, grid_size),
+            this_game_stats,
+            stats_manager,
+        );
+        grid_size_view.set_visible(false);
+        vbox.append(&difficulty_view);
+        vbox.append(&grid_size_view);
+
+        grouping_selector.connect_selected_notify(move |selector| {
+            let by_grid_size = selector.selected() == 1;
+            difficulty_view.set_visible(!by_grid_size);
+            grid_size_view.set_visible(by_grid_size);
+        });
 
-        // Add separator
         let separator = Separator::new(Orientation::Horizontal);
-        separator.set_margin_top(20);
-        separator.set_margin_bottom(20);
+        separator.set_margin_top(10);
+        separator.set_margin_bottom(10);
         vbox.append(&separator);
-
-        // Add global stats
-        let global_stats_label = Label::new(Some(&t!("global-statistics")));
-        global_stats_label.set_markup(&format!("<b>{}</b>", t!("global-statistics")));
-        global_stats_label.set_margin_bottom(10);
-        vbox.append(&global_stats_label);
-
-        let stats_grid = Self::create_global_stats_grid(stats_manager, difficulty);
-        vbox.append(&stats_grid);
+        let achievements_label = Label::new(None);
+        achievements_label.set_markup(&format!("<b>{}</b>", t!("achievements")));
+        vbox.append(&achievements_label);
+        vbox.append(&Self::create_achievements_grid(stats_manager));
 
         let button_box = gtk4::Box::builder()
             .orientation(Orientation::Horizontal)
+            .spacing(10)
             .halign(Align::End)
             .build();
+        let more_like_this = Rc::new(Cell::new(false));
+        if this_game_stats.is_some() {
+            let more_like_this_button = gtk4::Button::builder()
+                .label(&t!("stats-more-like-this"))
+                .tooltip_text(&t!("stats-more-like-this-tooltip"))
+                .build();
+            more_like_this_button.connect_clicked({
+                let modal = modal.clone();
+                let more_like_this = Rc::clone(&more_like_this);
+                move |_| {
+                    more_like_this.set(true);
+                    modal.close();
+                }
+            });
+            button_box.append(&more_like_this_button);
+        }
         let close_button = gtk4::Button::builder().label(&t!("close")).build();
         button_box.append(&close_button);
 
@@ -237,7 +421,7 @@ impl StatsDialog {
 
         modal.connect_close_request({
             move |_| {
-                on_close();
+                on_close(more_like_this.get());
                 Propagation::Proceed
             }
         });
@@ -11,9 +11,11 @@ use crate::events::EventHandler;
 use crate::game::stats_manager::StatsManager;
 use crate::model::GameEngineEvent;
 use crate::model::{GameEngineCommand, PuzzleCompletionState};
+use crate::solver::puzzle_metrics::PuzzleRating;
 use crate::ui::stats_dialog::StatsDialog;
 use fluent_i18n::t;
 
+use super::achievement_toast::AchievementToast;
 use super::audio_set::AudioSet;
 use super::NotQuiteRightDialog;
 
@@ -23,6 +25,9 @@ pub struct SubmitUI {
     window: Rc<ApplicationWindow>,
     game_engine_command_emitter: EventEmitter<GameEngineCommand>,
     submit_dialog: Rc<RefCell<CompletionDialog>>,
+    achievement_toast: Rc<RefCell<AchievementToast>>,
+    /// Requested once the board is filled in, for the stats dialog shown on a correct submission
+    puzzle_rating: Option<PuzzleRating>,
 }
 
 impl Destroyable for SubmitUI {
@@ -37,6 +42,7 @@ impl SubmitUI {
         stats_manager: &Rc<RefCell<StatsManager>>,
         audio_set: &Rc<AudioSet>,
         window: &Rc<ApplicationWindow>,
+        achievement_toast: &Rc<RefCell<AchievementToast>>,
     ) -> Rc<RefCell<Self>> {
         // Create submit button
         let submit_button = Rc::new(Button::with_label(&t!("submit")));
@@ -67,6 +73,8 @@ impl SubmitUI {
             window: Rc::clone(window),
             game_engine_command_emitter: game_engine_command_emitter,
             submit_dialog,
+            achievement_toast: Rc::clone(achievement_toast),
+            puzzle_rating: None,
         }));
         submit_ui
     }
@@ -82,8 +90,11 @@ impl SubmitUI {
 
                 let difficulty = stats.difficulty;
 
-                if let Err(e) = self.stats_manager.borrow_mut().record_game(&stats) {
-                    log::error!(target: "window", "Failed to record game stats: {}", e);
+                match self.stats_manager.borrow_mut().record_game(&stats) {
+                    Ok(earned) => self.achievement_toast.borrow().show(&earned),
+                    Err(e) => {
+                        log::error!(target: "window", "Failed to record game stats: {}", e)
+                    }
                 }
 
                 // Drop the mutable borrow before showing stats
@@ -94,9 +105,13 @@ impl SubmitUI {
                     difficulty,
                     &stats_manager,
                     Some(stats),
-                    move || {
-                        game_engine_command_emitter
-                            .emit(GameEngineCommand::NewGame(Some(difficulty), None));
+                    self.puzzle_rating.as_ref(),
+                    move |more_like_this| {
+                        game_engine_command_emitter.emit(if more_like_this {
+                            GameEngineCommand::NewGameLikeCurrent
+                        } else {
+                            GameEngineCommand::NewGame(Some(difficulty), None)
+                        });
                     },
                 );
             }
@@ -107,9 +122,16 @@ impl SubmitUI {
                 let media = self.audio_set.random_lose_sound();
                 media.play();
 
+                // the board is full, so the mistake may be a swap or two; let the player look for it
                 NotQuiteRightDialog::new(&self.window, self.game_engine_command_emitter.clone())
+                    .with_keep_editing(true)
                     .show();
             }
+            PuzzleCompletionState::TryAgain { .. } => {
+                // the grid highlights the wrong tiles; the player keeps editing
+                let media = self.audio_set.random_lose_sound();
+                media.play();
+            }
         }
     }
 }
@@ -122,6 +144,18 @@ impl EventHandler<GameEngineEvent> for SubmitUI {
                     CompletionDialog::show(self.submit_dialog.clone());
                 }
             }
+            GameEngineEvent::GameAbandoned(difficulty) => {
+                if let Err(e) = self
+                    .stats_manager
+                    .borrow_mut()
+                    .record_abandoned_game(*difficulty)
+                {
+                    log::error!(target: "window", "Failed to record abandoned game: {}", e)
+                }
+            }
+            GameEngineEvent::PuzzleDifficultyRated(rating) => {
+                self.puzzle_rating = rating.clone();
+            }
             GameEngineEvent::PuzzleCompleted(state) => {
                 self.handle_game_completion(state);
             }
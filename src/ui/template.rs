@@ -68,6 +68,19 @@ impl TemplateParser {
         elements
     }
 
+    /// Render a template as plain text: markup is dropped, tiles become their code (e.g. `[0a]`)
+    /// and icons their name
+    pub fn to_plain_text(template: &str) -> String {
+        TemplateParser::parse_template_elements(template)
+            .into_iter()
+            .map(|element| match element {
+                TemplateElement::Label(text) => strip_markup(&text),
+                TemplateElement::Tile(tile) => format!("[{}]", tile),
+                TemplateElement::Icon(icon_name) => format!("[{}]", icon_name),
+            })
+            .collect()
+    }
+
     pub fn append_to_text_buffer(
         &self,
         text_view: &TextView,
@@ -82,7 +95,7 @@ impl TemplateParser {
                     buffer.insert_markup(pointer, &text);
                 }
                 TemplateElement::Tile(tile) => {
-                    let icon = self.resources.get_solution_icon(&tile).unwrap();
+                    let icon = self.resources.get_solution_icon(&tile);
                     let image = gtk4::Image::from_paintable(Some(icon.as_ref()));
                     let anchor = buffer.create_child_anchor(pointer);
                     image.set_size_request(32, 32);
@@ -110,6 +123,20 @@ impl TemplateParser {
     }
 }
 
+fn strip_markup(text: &str) -> String {
+    let mut plain = String::new();
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => plain.push(c),
+            _ => {}
+        }
+    }
+    plain
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,4 +185,14 @@ mod tests {
         );
         assert!(matches!(elements[2], TemplateElement::Label(ref text) if text == " icon"));
     }
+
+    #[test]
+    fn test_to_plain_text() {
+        let template = "<b>Careful:</b> {tile:0a} is next to {tile:1b} {icon:view-reveal-symbolic}";
+
+        assert_eq!(
+            TemplateParser::to_plain_text(template),
+            "Careful: [0a] is next to [1b] [view-reveal-symbolic]"
+        );
+    }
 }
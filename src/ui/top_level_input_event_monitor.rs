@@ -5,15 +5,18 @@ use gtk4::{gdk, prelude::*, ApplicationWindow, EventControllerKey, GestureClick,
 use crate::{
     destroyable::Destroyable,
     events::{EventEmitter, Unsubscriber},
-    model::{Clickable, InputEvent, LayoutManagerEvent},
+    model::{Clickable, InputEvent, LayoutManagerEvent, SettingsProjection},
 };
 
+use super::keyboard_shortcuts;
+
 pub struct TopLevelInputEventMonitor {
     window: Rc<ApplicationWindow>,
     scrolled_window: ScrolledWindow,
     key_controller: Option<EventControllerKey>,
     click_controller: Option<GestureClick>,
     input_event_emitter: EventEmitter<InputEvent>,
+    settings_projection: Rc<RefCell<SettingsProjection>>,
     layout_subscription: Option<Unsubscriber<LayoutManagerEvent>>,
 }
 
@@ -39,6 +42,7 @@ impl TopLevelInputEventMonitor {
         window: Rc<ApplicationWindow>,
         scrolled_window: ScrolledWindow,
         input_event_emitter: EventEmitter<InputEvent>,
+        settings_projection: Rc<RefCell<SettingsProjection>>,
     ) -> Rc<RefCell<Self>> {
         let game_controls = Rc::new(RefCell::new(Self {
             window: window.clone(),
@@ -46,6 +50,7 @@ impl TopLevelInputEventMonitor {
             key_controller: None,
             click_controller: None,
             input_event_emitter,
+            settings_projection,
             layout_subscription: None,
         }));
 
@@ -125,9 +130,20 @@ impl TopLevelInputEventMonitor {
 
             if let Some(game_controls) = weak_game_controls.upgrade() {
                 let game_controls = game_controls.borrow();
-                game_controls
-                    .input_event_emitter
-                    .emit(InputEvent::KeyPressed(val));
+                let action = keyboard_shortcuts::key_action_for(
+                    &game_controls
+                        .settings_projection
+                        .borrow()
+                        .current_settings()
+                        .keymap,
+                    val,
+                    state,
+                );
+                if let Some(action) = action {
+                    game_controls
+                        .input_event_emitter
+                        .emit(InputEvent::KeyAction(action));
+                }
                 true.into()
             } else {
                 false.into()
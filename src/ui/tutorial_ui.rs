@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashSet, rc::Rc, sync::Arc};
 
 use gtk4::{
     prelude::*, Align, ApplicationWindow, IconTheme, ScrolledWindow, TextBuffer, TextTagTable,
@@ -12,9 +12,9 @@ use crate::{
     game::settings::Settings,
     helpers::Capitalize,
     model::{
-        ClueSelection, ClueWithAddress, Deduction, DeductionKind, Dimensions, GameBoard,
-        GameBoardChangeReason, GameEngineCommand, GameEngineEvent, LayoutConfiguration,
-        LayoutManagerEvent,
+        ClueAddress, ClueSelection, ClueSet, ClueWithAddress, Deduction, DeductionKind, Difficulty,
+        Dimensions, GameBoard, GameBoardChangeReason, GameEngineCommand, GameEngineEvent,
+        LayoutConfiguration, LayoutManagerEvent,
     },
     solver::{
         clue_completion_evaluator::is_clue_fully_completed, deduce_clue, simplify_deductions,
@@ -23,6 +23,7 @@ use crate::{
     ui::{deferred_size_reallocation, ImageSet},
 };
 
+use super::clue_panels_ui::CluePanelsUI;
 use super::template::TemplateParser;
 use fluent_i18n::t;
 
@@ -66,9 +67,13 @@ impl EventHandler<GameEngineEvent> for TutorialUI {
             } => {
                 self.handle_game_board_updated(board, *history_index, change_reason);
             }
+            GameEngineEvent::ClueSetUpdated(clue_set, difficulty, _) => {
+                self.handle_clue_set_updated(clue_set, *difficulty);
+            }
             GameEngineEvent::SettingsChanged(settings) => {
                 self.settings = settings.clone();
                 self.sync_tutorial_text();
+                self.sync_clue_locks();
             }
             _ => {}
         }
@@ -98,6 +103,14 @@ impl TutorialUI {
             .as_ref()
             .map(|cs| cs.is_focused)
             .unwrap_or(false);
+        let focused_address = self
+            .current_clue
+            .as_ref()
+            .filter(|_| is_focused)
+            .map(|clue| clue.address());
+        if let Some(address) = focused_address {
+            self.unlock_clue(address);
+        }
         match &self.current_step {
             TutorialStep::SelectAClue if is_focused => {
                 self.current_step = TutorialStep::PlayToEnd;
@@ -112,6 +125,7 @@ impl TutorialUI {
 
     fn handle_clue_hint_highlighted(&mut self, clue_with_address: &Option<ClueWithAddress>) {
         if let Some(cwa) = clue_with_address {
+            self.unlock_clue(cwa.address());
             match &self.current_step {
                 TutorialStep::HintUsagePhase1 => {
                     self.current_step = TutorialStep::HintUsagePhase2(cwa.clone());
@@ -139,16 +153,17 @@ impl TutorialUI {
         change_reason: &GameBoardChangeReason,
     ) {
         self.current_board = Some(board.clone());
+        let rewound_to_start = history_index == 0
+            && matches!(
+                change_reason,
+                GameBoardChangeReason::Undo | GameBoardChangeReason::HistoryJump
+            );
         match &self.current_step {
-            TutorialStep::HintUsagePhase3Oops(cwa, deduction)
-                if history_index == 0 && *change_reason == GameBoardChangeReason::Undo =>
-            {
+            TutorialStep::HintUsagePhase3Oops(cwa, deduction) if rewound_to_start => {
                 self.current_step = TutorialStep::HintUsagePhase3(cwa.clone(), deduction.clone());
                 self.sync_tutorial_text();
             }
-            TutorialStep::Undo
-                if history_index == 0 && *change_reason == GameBoardChangeReason::Undo =>
-            {
+            TutorialStep::Undo if rewound_to_start => {
                 self.game_engine_command_emitter
                     .emit(GameEngineCommand::ClueFocus(None));
                 self.current_step = TutorialStep::SelectAClue;
@@ -207,6 +222,10 @@ pub struct TutorialUI {
     current_board: Option<GameBoard>,
     current_clue: Option<ClueWithAddress>,
     layout: Dimensions,
+    clue_panels_ui: Rc<RefCell<CluePanelsUI>>,
+    clue_set: Option<Arc<ClueSet>>,
+    current_difficulty: Difficulty,
+    unlocked_clues: HashSet<ClueAddress>,
 }
 
 impl Destroyable for TutorialUI {
@@ -223,6 +242,7 @@ impl TutorialUI {
         resources: &Rc<ImageSet>,
         settings: &Settings,
         layout: &LayoutConfiguration,
+        clue_panels_ui: &Rc<RefCell<CluePanelsUI>>,
     ) -> Rc<RefCell<Self>> {
         let text_tag_table = TextTagTable::new();
         let buffer = TextBuffer::builder().tag_table(&text_tag_table).build();
@@ -262,6 +282,10 @@ impl TutorialUI {
             current_board: None,
             current_clue: None,
             layout: layout.tutorial.clone(),
+            clue_panels_ui: clue_panels_ui.clone(),
+            clue_set: None,
+            current_difficulty: settings.difficulty,
+            unlocked_clues: HashSet::new(),
         }));
 
         // Subscriptions are wired centrally in `wire_event_observers` using
@@ -373,6 +397,38 @@ impl TutorialUI {
         info!("Tutorial step: {:?}", self.current_step);
     }
 
+    fn handle_clue_set_updated(&mut self, clue_set: &Arc<ClueSet>, difficulty: Difficulty) {
+        self.clue_set = Some(clue_set.clone());
+        self.current_difficulty = difficulty;
+        self.unlocked_clues.clear();
+        self.sync_clue_locks();
+    }
+
+    fn progressive_reveal_active(&self) -> bool {
+        self.current_difficulty == Difficulty::Tutorial && self.settings.progressive_clue_reveal
+    }
+
+    fn unlock_clue(&mut self, clue_address: ClueAddress) {
+        if self.unlocked_clues.insert(clue_address) {
+            self.sync_clue_locks();
+        }
+    }
+
+    /// Clues stay locked (greyed shape only) until the player interacts with them
+    fn sync_clue_locks(&self) {
+        let locked_clues: HashSet<ClueAddress> = match &self.clue_set {
+            Some(clue_set) if self.progressive_reveal_active() => clue_set
+                .all_clues()
+                .map(|clue| clue.address())
+                .filter(|address| !self.unlocked_clues.contains(address))
+                .collect(),
+            _ => HashSet::new(),
+        };
+        self.clue_panels_ui
+            .borrow_mut()
+            .set_locked_clues(locked_clues);
+    }
+
     fn handle_layout_changed(&mut self, layout: &LayoutConfiguration) {
         self.layout = layout.tutorial.clone();
         self.sync_layout();
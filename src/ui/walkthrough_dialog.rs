@@ -0,0 +1,171 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use gtk4::{prelude::*, Align, ApplicationWindow, Label, ScrolledWindow};
+
+use crate::{
+    destroyable::Destroyable,
+    events::EventHandler,
+    model::{ClueSet, Deduction, DeductionLogEntry, GameEngineEvent},
+};
+use fluent_i18n::t;
+
+use super::template::TemplateParser;
+
+/// Shows the step-by-step solution walkthrough as plain text the player can copy
+pub struct WalkthroughDialog {
+    window: Rc<ApplicationWindow>,
+    clue_set: Option<Arc<ClueSet>>,
+    dialog: Option<gtk4::Window>,
+}
+
+impl WalkthroughDialog {
+    pub fn new(window: &Rc<ApplicationWindow>) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            window: window.clone(),
+            clue_set: None,
+            dialog: None,
+        }))
+    }
+
+    fn deduction_text(deduction: &Deduction) -> String {
+        let action = if deduction.is_positive() {
+            t!("action-must-be")
+        } else {
+            t!("action-cannot-be")
+        };
+        TemplateParser::to_plain_text(&t!("deduction-log-deduction", {
+            "tile" => deduction.tile_assertion.tile.to_string(),
+            "column" => (deduction.column + 1).to_string(),
+            "action" => action
+        }))
+    }
+
+    fn step_text(&self, step_number: usize, entry: &DeductionLogEntry) -> String {
+        let clue_description = entry
+            .clue
+            .and_then(|address| {
+                self.clue_set
+                    .as_ref()
+                    .and_then(|clue_set| clue_set.get_clue(address))
+            })
+            .map(|clue| clue.clue.description())
+            .unwrap_or_else(|| t!("deduction-log-hidden-set"));
+
+        let mut lines = vec![format!(
+            "{}. {}",
+            step_number,
+            TemplateParser::to_plain_text(&clue_description)
+        )];
+        lines.extend(
+            entry
+                .deductions
+                .iter()
+                .map(|deduction| format!("   {}", Self::deduction_text(deduction))),
+        );
+        lines.join("\n")
+    }
+
+    /// Numbered walkthrough, one step per clue (or hidden set) with its deductions underneath
+    fn render_walkthrough(&self, walkthrough: &[DeductionLogEntry]) -> String {
+        let mut sections = vec![format!("# {}", t!("walkthrough-title"))];
+        if walkthrough.is_empty() {
+            sections.push(t!("walkthrough-empty"));
+        }
+        sections.extend(
+            walkthrough
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| self.step_text(i + 1, entry)),
+        );
+        sections.join("\n\n")
+    }
+
+    fn show_walkthrough(&mut self, walkthrough: &[DeductionLogEntry]) {
+        self.hide_dialog();
+        let walkthrough_text = self.render_walkthrough(walkthrough);
+
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(20)
+            .margin_top(20)
+            .margin_start(30)
+            .margin_end(30)
+            .build();
+
+        let dialog = gtk4::Window::builder()
+            .transient_for(self.window.as_ref())
+            .title(&t!("walkthrough-title"))
+            .child(&content_area)
+            .default_width(500)
+            .default_height(600)
+            .build();
+
+        let label = Label::builder()
+            .label(&walkthrough_text)
+            .selectable(true)
+            .wrap(true)
+            .halign(Align::Start)
+            .valign(Align::Start)
+            .build();
+        let scrolled_window = ScrolledWindow::builder()
+            .child(&label)
+            .vexpand(true)
+            .hscrollbar_policy(gtk4::PolicyType::Never)
+            .vscrollbar_policy(gtk4::PolicyType::Automatic)
+            .build();
+        content_area.append(&scrolled_window);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .spacing(10)
+            .halign(Align::End)
+            .build();
+        let copy_button = gtk4::Button::builder()
+            .label(&t!("walkthrough-copy"))
+            .build();
+        let close_button = gtk4::Button::builder().label(&t!("close")).build();
+        button_box.append(&copy_button);
+        button_box.append(&close_button);
+        content_area.append(&button_box);
+
+        copy_button.connect_clicked(move |button| {
+            button.clipboard().set_text(&walkthrough_text);
+        });
+        close_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        dialog.present();
+        self.dialog = Some(dialog);
+    }
+
+    fn hide_dialog(&mut self) {
+        if let Some(dialog) = self.dialog.take() {
+            dialog.close();
+        }
+    }
+}
+
+impl Destroyable for WalkthroughDialog {
+    fn destroy(&mut self) {
+        self.hide_dialog();
+    }
+}
+
+impl EventHandler<GameEngineEvent> for WalkthroughDialog {
+    fn handle_event(&mut self, event: &GameEngineEvent) {
+        match event {
+            GameEngineEvent::ClueSetUpdated(clue_set, _, _) => {
+                self.clue_set = Some(clue_set.clone());
+            }
+            GameEngineEvent::SolutionWalkthrough(walkthrough) => {
+                self.show_walkthrough(walkthrough);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::rc::Rc;
+
+use glib::Propagation;
+use gtk4::gdk;
+use gtk4::EventControllerKey;
+use gtk4::{prelude::*, ApplicationWindow, Label};
+
+use crate::{
+    events::EventEmitter,
+    model::{Difficulty, GameEngineCommand, SettingsChange},
+};
+use fluent_i18n::t;
+
+/// Greets a new player on the first launch, which starts with the tutorial, and offers to skip it
+pub struct WelcomePrompt;
+
+impl WelcomePrompt {
+    /// Show the prompt. However it's dismissed, it's recorded in the settings so it never shows
+    /// again; skipping also makes Easy the preferred difficulty.
+    pub fn show(
+        window: &Rc<ApplicationWindow>,
+        game_engine_command_emitter: EventEmitter<GameEngineCommand>,
+    ) {
+        let content_area = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(10)
+            .margin_bottom(10)
+            .margin_top(10)
+            .margin_start(20)
+            .margin_end(20)
+            .build();
+
+        let dialog = gtk4::Window::builder()
+            .title(&t!("welcome-title"))
+            .transient_for(window.as_ref())
+            .modal(true)
+            .child(&content_area)
+            .default_width(300)
+            .build();
+
+        let label = Label::new(Some(&t!("welcome-message")));
+        label.set_wrap(true);
+        content_area.append(&label);
+
+        let button_box = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Horizontal)
+            .spacing(10)
+            .halign(gtk4::Align::End)
+            .build();
+        let skip_button = gtk4::Button::builder()
+            .label(&t!("welcome-skip-tutorial"))
+            .build();
+        let start_button = gtk4::Button::builder()
+            .label(&t!("welcome-start-tutorial"))
+            .build();
+        button_box.append(&skip_button);
+        button_box.append(&start_button);
+        content_area.append(&button_box);
+
+        dialog.connect_close_request({
+            let game_engine_command_emitter = game_engine_command_emitter.clone();
+            move |_| {
+                game_engine_command_emitter.emit(GameEngineCommand::ChangeSettings(
+                    SettingsChange {
+                        has_launched_before: Some(true),
+                        ..Default::default()
+                    },
+                ));
+                Propagation::Proceed
+            }
+        });
+
+        start_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                dialog.close();
+            }
+        });
+
+        skip_button.connect_clicked({
+            let dialog = dialog.clone();
+            move |_| {
+                game_engine_command_emitter.emit(GameEngineCommand::ChangeSettings(
+                    SettingsChange {
+                        preferred_difficulty: Some(Difficulty::Easy),
+                        ..Default::default()
+                    },
+                ));
+                game_engine_command_emitter
+                    .emit(GameEngineCommand::NewGame(Some(Difficulty::Easy), None));
+                dialog.close();
+            }
+        });
+
+        let key_controller = EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let dialog = dialog.clone();
+            move |_, keyval, _, _| {
+                if keyval == gdk::Key::Escape {
+                    dialog.close();
+                    return Propagation::Stop;
+                }
+                Propagation::Proceed
+            }
+        });
+        dialog.add_controller(key_controller);
+        dialog.set_default_widget(Some(&start_button));
+        dialog.present();
+    }
+}
@@ -4,8 +4,8 @@ use crate::game::game_engine::GameEngine;
 use crate::game::settings::Settings;
 use crate::game::stats_manager::StatsManager;
 use crate::model::{
-    game_state_snapshot, Difficulty, GameEngineCommand, GameEngineEvent, GameStateSnapshot,
-    InputEvent, LayoutManagerEvent, SettingsProjection,
+    game_state_snapshot, DeductionKind, Difficulty, GameEngineCommand, GameEngineEvent,
+    GameStateSnapshot, InputEvent, LayoutManagerEvent, SettingsChange, SettingsProjection,
 };
 use crate::ui::input_translator::InputTranslator;
 use crate::ui::seed_dialog::SeedDialog;
@@ -14,39 +14,74 @@ use crate::ui::stats_dialog::StatsDialog;
 use crate::ui::submit_ui::SubmitUI;
 use crate::ui::timer_button_ui::TimerButtonUI;
 use crate::ui::top_level_input_event_monitor::TopLevelInputEventMonitor;
-use fluent_i18n::t;
+use fluent_i18n::{get_locale, t};
 use gio::{Menu, SimpleAction};
 use gtk4::gdk::{Display, Monitor};
 use gtk4::{
     prelude::*, AboutDialog, Application, ApplicationWindow, Button, CssProvider, HeaderBar, Label,
-    License, MenuButton, Orientation, STYLE_PROVIDER_PRIORITY_APPLICATION,
+    License, MenuButton, Orientation, Overlay, STYLE_PROVIDER_PRIORITY_APPLICATION,
 };
 use std::cell::RefCell;
 use std::env;
 use std::rc::Rc;
 
+use super::achievement_toast::AchievementToast;
+use super::archive_dialog::ArchiveDialog;
+use super::autosave_timer::AutosaveTimer;
+use super::clipboard_puzzle_prompt::ClipboardPuzzlePrompt;
+use super::clue_editor_dialog::ClueEditorDialog;
 use super::clue_panels_ui::CluePanelsUI;
+use super::clue_weights_dialog::ClueWeightsDialog;
+use super::daily_puzzle_ui::DailyPuzzleUI;
+use super::deduction_log_ui::DeductionLogUI;
+use super::favorites_dialog::FavoritesDialog;
 use super::game_info_ui::GameInfoUI;
 use super::hint_button_ui::HintButtonUI;
 use super::history_controls_ui::HistoryControlsUI;
+use super::keyboard_shortcuts::{self, KeyboardShortcutsDialog};
+use super::keymap_dialog::KeymapDialog;
 use super::layout_manager::{ClueStats, LayoutManager};
+use super::obvious_consequence_ui::ObviousConsequenceUI;
 use super::pause_screen_ui::PauseScreenUI;
 use super::puzzle_generation_dialog::PuzzleGenerationDialog;
 use super::puzzle_grid_ui::PuzzleGridUI;
 use super::resource_manager::ResourceManager;
+use super::sandbox_banner_ui::SandboxBannerUI;
+use super::solution_preview_ui::SolutionPreviewUI;
+use super::solvability_check_ui::SolvabilityCheckUI;
 use super::tutorial_ui::TutorialUI;
+use super::walkthrough_dialog::WalkthroughDialog;
+use super::welcome_prompt::WelcomePrompt;
 
 const APP_VERSION: &str = env!("APP_VERSION");
 
 pub fn load_settings_and_game_state() -> (Settings, Option<GameStateSnapshot>) {
-    let mut initial_settings = Settings::load();
-    let saved_game_state = game_state_snapshot::load_game_state_snapshot();
+    let initial_settings = Settings::load();
+    let saved_game_state = if initial_settings.resume_last_game {
+        game_state_snapshot::load_game_state_snapshot()
+    } else {
+        log::info!(target: "window", "Not resuming; starting at preferred difficulty");
+        None
+    };
+    resolve_startup_state(initial_settings, saved_game_state)
+}
+
+/// A resumed save dictates the difficulty; otherwise the first game uses the preferred one, or the
+/// tutorial on the very first launch
+fn resolve_startup_state(
+    mut initial_settings: Settings,
+    saved_game_state: Option<GameStateSnapshot>,
+) -> (Settings, Option<GameStateSnapshot>) {
     if let Some(save_state) = &saved_game_state {
         log::info!(target: "window", "Loaded saved game state");
         // if these disagree, then bad things happen.
         initial_settings.difficulty = save_state.board.solution.difficulty;
+    } else if !initial_settings.has_launched_before {
+        log::info!(target: "window", "First launch; starting the tutorial");
+        initial_settings.difficulty = Difficulty::Tutorial;
     } else {
         log::info!(target: "window", "No saved game state found");
+        initial_settings.difficulty = initial_settings.preferred_difficulty;
     }
     (initial_settings, saved_game_state)
 }
@@ -85,6 +120,7 @@ struct Components {
     resource_manager: Rc<RefCell<ResourceManager>>,
     puzzle_grid_ui: Rc<RefCell<PuzzleGridUI>>,
     game_state: Rc<RefCell<GameEngine>>,
+    autosave_timer: Rc<RefCell<AutosaveTimer>>,
     hint_button_ui: Rc<RefCell<HintButtonUI>>,
     tutorial_ui: Rc<RefCell<TutorialUI>>,
     layout_manager: Rc<RefCell<LayoutManager>>,
@@ -95,10 +131,22 @@ struct Components {
     history_controls_ui: Rc<RefCell<HistoryControlsUI>>,
     stats_manager: Rc<RefCell<StatsManager>>,
     submit_ui: Rc<RefCell<SubmitUI>>,
+    achievement_toast: Rc<RefCell<AchievementToast>>,
     input_translator: Rc<RefCell<InputTranslator>>,
     timer_button: Rc<RefCell<TimerButtonUI>>,
     seed_dialog: Rc<RefCell<SeedDialog>>,
     puzzle_generation_dialog: Rc<RefCell<PuzzleGenerationDialog>>,
+    deduction_log_ui: Rc<RefCell<DeductionLogUI>>,
+    walkthrough_dialog: Rc<RefCell<WalkthroughDialog>>,
+    clue_editor_dialog: Rc<RefCell<ClueEditorDialog>>,
+    favorites_dialog: Rc<RefCell<FavoritesDialog>>,
+    keymap_dialog: Rc<RefCell<KeymapDialog>>,
+    clue_weights_dialog: Rc<RefCell<ClueWeightsDialog>>,
+    solution_preview_ui: Rc<RefCell<SolutionPreviewUI>>,
+    solvability_check_ui: Rc<RefCell<SolvabilityCheckUI>>,
+    obvious_consequence_ui: Rc<RefCell<ObviousConsequenceUI>>,
+    sandbox_banner_ui: Rc<RefCell<SandboxBannerUI>>,
+    daily_puzzle_ui: Rc<RefCell<DailyPuzzleUI>>,
     settings_projection: Rc<RefCell<SettingsProjection>>,
 }
 
@@ -108,10 +156,14 @@ impl Components {
         channels: &Channels,
         initial_settings: &Settings,
     ) -> Self {
-        let resource_manager = ResourceManager::new(channels.layout_manager.emitter.clone());
+        let resource_manager = ResourceManager::new(
+            channels.layout_manager.emitter.clone(),
+            initial_settings.featured_icon_category,
+        );
         let default_layout = LayoutManager::calculate_layout(
             initial_settings.difficulty,
             Some(ClueStats::default()),
+            initial_settings.candidate_display,
         );
         let image_set = resource_manager.borrow().get_image_set();
         let audio_set = resource_manager.borrow().get_audio_set();
@@ -136,6 +188,8 @@ impl Components {
             initial_settings.clone(),
         );
 
+        let autosave_timer = AutosaveTimer::new(&game_state, initial_settings);
+
         // Create hint button UI
         let hint_button_ui = HintButtonUI::new(
             channels.game_engine_command.emitter.clone(),
@@ -151,12 +205,15 @@ impl Components {
             &image_set,
             initial_settings,
             &default_layout,
+            &clue_panels_ui,
         );
 
         let layout_manager = LayoutManager::new(
             window.clone(),
             channels.layout_manager.emitter.clone(),
             initial_settings.difficulty,
+            initial_settings.collapse_completed_clues,
+            initial_settings.candidate_display,
         );
 
         // Create pause screen UI
@@ -168,27 +225,33 @@ impl Components {
             channels.game_engine_command.emitter.clone(),
             initial_settings.clone(),
         );
-        let game_info_ui =
-            GameInfoUI::new(Rc::new(pause_screen_ui.borrow().pause_screen_box.clone()));
+        let game_info_ui = GameInfoUI::new(
+            Rc::new(pause_screen_ui.borrow().pause_screen_box.clone()),
+            initial_settings,
+        );
+        let settings_projection = SettingsProjection::new(&initial_settings);
+
         // Initialize game controls
         let game_controls = TopLevelInputEventMonitor::new(
             window.clone(),
             layout_manager.borrow().scrolled_window.clone(),
             channels.input.emitter.clone(),
+            settings_projection.clone(),
         );
-        let history_controls_ui = HistoryControlsUI::new();
+        let history_controls_ui =
+            HistoryControlsUI::new(channels.game_engine_command.emitter.clone());
 
         // Remove the old button_box since controls are now in header
         let stats_manager = Rc::new(RefCell::new(StatsManager::new()));
 
+        let achievement_toast = AchievementToast::new();
         let submit_ui = SubmitUI::new(
             channels.game_engine_command.emitter.clone(),
             &stats_manager,
             &audio_set,
             &window,
+            &achievement_toast,
         );
-        let settings_projection = SettingsProjection::new(&initial_settings);
-
         // Initialize input translator
         let input_translator = InputTranslator::new(
             channels.game_engine_command.emitter.clone(),
@@ -199,12 +262,39 @@ impl Components {
 
         let seed_dialog = SeedDialog::new(&window, channels.game_engine_command.emitter.clone());
         let puzzle_generation_dialog = PuzzleGenerationDialog::new(&window);
+        let deduction_log_ui = DeductionLogUI::new(&window, &image_set, initial_settings);
+        let walkthrough_dialog = WalkthroughDialog::new(&window);
+        let clue_editor_dialog = ClueEditorDialog::new(&window);
+        let favorites_dialog = FavoritesDialog::new(
+            &window,
+            channels.game_engine_command.emitter.clone(),
+            &stats_manager,
+        );
+        let keymap_dialog = KeymapDialog::new(
+            &window,
+            channels.game_engine_command.emitter.clone(),
+            initial_settings,
+        );
+        let clue_weights_dialog = ClueWeightsDialog::new(
+            &window,
+            channels.game_engine_command.emitter.clone(),
+            initial_settings,
+        );
+        let solution_preview_ui = SolutionPreviewUI::new(&image_set);
+        let solvability_check_ui =
+            SolvabilityCheckUI::new(&window, channels.game_engine_command.emitter.clone());
+        let obvious_consequence_ui =
+            ObviousConsequenceUI::new(&image_set, channels.game_engine_command.emitter.clone());
+        let sandbox_banner_ui = SandboxBannerUI::new(channels.game_engine_command.emitter.clone());
+        let daily_puzzle_ui =
+            DailyPuzzleUI::new(channels.game_engine_command.emitter.clone(), &stats_manager);
 
         Self {
             clue_panels_ui,
             resource_manager,
             puzzle_grid_ui,
             game_state,
+            autosave_timer,
             hint_button_ui,
             tutorial_ui,
             layout_manager,
@@ -215,10 +305,22 @@ impl Components {
             history_controls_ui,
             stats_manager,
             submit_ui,
+            achievement_toast,
             input_translator,
             timer_button,
             seed_dialog,
             puzzle_generation_dialog,
+            deduction_log_ui,
+            walkthrough_dialog,
+            clue_editor_dialog,
+            favorites_dialog,
+            keymap_dialog,
+            clue_weights_dialog,
+            solution_preview_ui,
+            solvability_check_ui,
+            obvious_consequence_ui,
+            sandbox_banner_ui,
+            daily_puzzle_ui,
             settings_projection,
         }
     }
@@ -228,16 +330,29 @@ impl Destroyable for Components {
     fn destroy(&mut self) {
         self.history_controls_ui.borrow_mut().destroy();
         self.game_state.borrow_mut().destroy();
+        self.autosave_timer.borrow_mut().destroy();
         self.game_info_ui.borrow_mut().destroy();
         self.hint_button_ui.borrow_mut().destroy();
         self.pause_screen_ui.borrow_mut().destroy();
         self.submit_ui.borrow_mut().destroy();
+        self.achievement_toast.borrow_mut().destroy();
         self.puzzle_grid_ui.borrow_mut().destroy();
         self.clue_panels_ui.borrow_mut().destroy();
         self.timer_button.borrow_mut().destroy();
         self.layout_manager.borrow_mut().destroy();
         self.seed_dialog.borrow_mut().destroy();
         self.puzzle_generation_dialog.borrow_mut().destroy();
+        self.deduction_log_ui.borrow_mut().destroy();
+        self.walkthrough_dialog.borrow_mut().destroy();
+        self.clue_editor_dialog.borrow_mut().destroy();
+        self.favorites_dialog.borrow_mut().destroy();
+        self.keymap_dialog.borrow_mut().destroy();
+        self.clue_weights_dialog.borrow_mut().destroy();
+        self.solution_preview_ui.borrow_mut().destroy();
+        self.solvability_check_ui.borrow_mut().destroy();
+        self.obvious_consequence_ui.borrow_mut().destroy();
+        self.sandbox_banner_ui.borrow_mut().destroy();
+        self.daily_puzzle_ui.borrow_mut().destroy();
         self.settings_menu_ui.borrow_mut().destroy();
         self.game_controls.borrow_mut().destroy();
         self.input_translator.borrow_mut().destroy();
@@ -290,6 +405,8 @@ fn wire_event_observers(channels: &Channels, components: &Components) {
 
     layout_event_observer
         .subscribe_component(&(components.resource_manager.clone() as EHLayoutEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.resource_manager.clone() as EHGameEvent));
 
     // Subscribe SubmitUI to GameEngineEvent via centralized subscription
     game_engine_event_observer.subscribe_component(&(components.submit_ui.clone() as EHGameEvent));
@@ -299,12 +416,45 @@ fn wire_event_observers(channels: &Channels, components: &Components) {
         .subscribe_component(&(components.seed_dialog.clone() as EHGameEvent));
     game_engine_event_observer
         .subscribe_component(&(components.puzzle_generation_dialog.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.deduction_log_ui.clone() as EHGameEvent));
+    layout_event_observer
+        .subscribe_component(&(components.deduction_log_ui.clone() as EHLayoutEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.walkthrough_dialog.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.clue_editor_dialog.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.favorites_dialog.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.keymap_dialog.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.clue_weights_dialog.clone() as EHGameEvent));
+    if Settings::is_debug_mode() {
+        game_engine_event_observer
+            .subscribe_component(&(components.solution_preview_ui.clone() as EHGameEvent));
+        layout_event_observer
+            .subscribe_component(&(components.solution_preview_ui.clone() as EHLayoutEvent));
+    }
+    game_engine_event_observer
+        .subscribe_component(&(components.solvability_check_ui.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.obvious_consequence_ui.clone() as EHGameEvent));
+    layout_event_observer
+        .subscribe_component(&(components.obvious_consequence_ui.clone() as EHLayoutEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.sandbox_banner_ui.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.daily_puzzle_ui.clone() as EHGameEvent));
+    game_engine_event_observer
+        .subscribe_component(&(components.autosave_timer.clone() as EHGameEvent));
 
-    // InputTranslator handles InputEvent
+    // InputTranslator handles InputEvent; the puzzle grid moves its keyboard cursor
     type EHInputEvent = Rc<RefCell<dyn EventHandler<InputEvent>>>;
     let input_event_observer = &channels.input.observer;
     input_event_observer
         .subscribe_component(&(components.input_translator.clone() as EHInputEvent));
+    input_event_observer.subscribe_component(&(components.puzzle_grid_ui.clone() as EHInputEvent));
 
     // SettingsProjection listens for GameEngineEvent (SettingsChanged)
     game_engine_event_observer
@@ -321,11 +471,15 @@ pub fn build_ui(app: &Application) {
         .and_then(|m| m.downcast::<Monitor>().ok())
         .expect("No monitors found");
     let monitor_geometry = monitor.geometry();
-    let monitor_width = monitor_geometry.width();
-    let monitor_height = monitor_geometry.height();
-    let desired_height = (monitor_height * 8) / 10;
-    let desired_width = (monitor_height * 4) / 3;
-    let max_desired_width = (monitor_width * 8) / 10;
+    let max_width = (monitor_geometry.width() * 8) / 10;
+    let max_height = (monitor_geometry.height() * 8) / 10;
+    // size the window for the startup puzzle, shrinking it (keeping the aspect ratio) to fit
+    let estimated = LayoutManager::estimate_window_size(initial_settings.difficulty);
+    let fit = (max_width as f32 / estimated.width as f32)
+        .min(max_height as f32 / estimated.height as f32)
+        .min(1.0);
+    let desired_width = (estimated.width as f32 * fit) as i32;
+    let desired_height = (estimated.height as f32 * fit) as i32;
 
     let window = Rc::new(
         ApplicationWindow::builder()
@@ -334,8 +488,8 @@ pub fn build_ui(app: &Application) {
             .icon_name("io.github.timcharper.EmojiClu")
             .resizable(true)
             .decorated(true)
-            .default_height(desired_height as i32)
-            .default_width(desired_width.min(max_desired_width) as i32)
+            .default_height(desired_height)
+            .default_width(desired_width)
             .build(),
     );
     let channels = Channels::new();
@@ -346,24 +500,74 @@ pub fn build_ui(app: &Application) {
     let game_engine_command_emitter = channels.game_engine_command.emitter.clone();
 
     // Set up keyboard shortcuts
-    app.set_accels_for_action("win.undo", &["<Control>z"]);
-    app.set_accels_for_action("win.redo", &["<Control><Shift>z"]);
-    app.set_accels_for_action("win.new-game", &["<Control>n"]);
-    app.set_accels_for_action("win.pause", &["space"]);
-    app.set_accels_for_action("win.restart", &["<Control>r"]);
+    keyboard_shortcuts::register_accels(app, &initial_settings.keymap);
 
     // Create menu model for hamburger menu
     let menu = Menu::new();
 
     // Add all menu items
     menu.append(Some(&t!("menu-new-game")), Some("win.new-game"));
+    let new_game_difficulty_menu = Menu::new();
+    for difficulty in Difficulty::all() {
+        new_game_difficulty_menu.append(
+            Some(&difficulty.to_string()),
+            Some(&format!("win.new-game-difficulty::{}", difficulty.index())),
+        );
+    }
+    menu.append_submenu(
+        Some(&t!("menu-new-game-difficulty")),
+        &new_game_difficulty_menu,
+    );
+    menu.append(
+        Some(&t!("menu-new-game-like-current")),
+        Some("win.new-game-like-current"),
+    );
+    menu.append(
+        Some(&t!("menu-restore-previous-game")),
+        Some("win.restore-previous-game"),
+    );
     menu.append(Some(&t!("menu-restart")), Some("win.restart"));
     menu.append(Some(&t!("menu-statistics")), Some("win.statistics"));
+    menu.append(Some(&t!("menu-daily-puzzle")), Some("win.daily-puzzle"));
+    menu.append(Some(&t!("menu-puzzle-archive")), Some("win.puzzle-archive"));
+    menu.append(Some(&t!("menu-favorites")), Some("win.favorites"));
+    menu.append(Some(&t!("menu-add-favorite")), Some("win.add-favorite"));
     menu.append(Some(&t!("menu-seed")), Some("win.seed"));
+    menu.append(Some(&t!("menu-copy-puzzle")), Some("win.copy-puzzle"));
+    menu.append(Some(&t!("menu-begin-sandbox")), Some("win.begin-sandbox"));
+    menu.append(
+        Some(&t!("menu-solution-walkthrough")),
+        Some("win.solution-walkthrough"),
+    );
+    menu.append(
+        Some(&t!("menu-toggle-clue-visibility")),
+        Some("win.toggle-clue-visibility"),
+    );
+    menu.append(
+        Some(&t!("menu-complete-satisfied-clues")),
+        Some("win.complete-satisfied-clues"),
+    );
+    menu.append(
+        Some(&t!("menu-keyboard-shortcuts")),
+        Some("win.keyboard-shortcuts"),
+    );
+    menu.append(Some(&t!("menu-key-bindings")), Some("win.key-bindings"));
+    menu.append(Some(&t!("menu-clue-weights")), Some("win.clue-weights"));
     menu.append_submenu(
         Some(&t!("menu-settings")),
         components.settings_menu_ui.borrow().get_menu(),
     );
+    if Settings::is_debug_mode() {
+        let technique_menu = Menu::new();
+        for (index, deduction_kind) in DeductionKind::all().iter().enumerate() {
+            technique_menu.append(
+                Some(&format!("{:?}", deduction_kind)),
+                Some(&format!("win.find-technique-puzzle::{}", index)),
+            );
+        }
+        menu.append_submenu(Some("Find Puzzle Using Technique"), &technique_menu);
+        menu.append(Some("Clue Editor"), Some("win.clue-editor"));
+    }
     menu.append(Some(&t!("menu-about")), Some("win.about"));
 
     // Add menu button to header bar
@@ -399,17 +603,37 @@ pub fn build_ui(app: &Application) {
     let current_difficulty = initial_settings.difficulty;
     difficulty_selector.set_selected(current_difficulty.index() as u32);
 
-    // Handle difficulty changes
-    let game_engine_command_emitter_new_game = game_engine_command_emitter.clone();
-    difficulty_selector.connect_selected_notify(move |selector| {
-        let new_difficulty = Difficulty::from_index(selector.selected() as usize);
-        game_engine_command_emitter_new_game
-            .emit(GameEngineCommand::NewGame(Some(new_difficulty), None));
+    // Handle difficulty changes; unless the setting says otherwise, picking one starts a new game
+    difficulty_selector.connect_selected_notify({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        let settings = components.settings_projection.clone();
+        move |selector| {
+            let new_difficulty = Difficulty::from_index(selector.selected() as usize);
+            if settings
+                .borrow()
+                .current_settings()
+                .difficulty_dropdown_immediate
+            {
+                game_engine_command_emitter
+                    .emit(GameEngineCommand::NewGame(Some(new_difficulty), None));
+            } else {
+                game_engine_command_emitter.emit(GameEngineCommand::ChangeSettings(
+                    SettingsChange {
+                        next_difficulty: Some(Some(new_difficulty)),
+                        ..Default::default()
+                    },
+                ));
+            }
+        }
     });
 
     header_bar.pack_start(&difficulty_box);
 
     let solve_button = Button::with_label(&t!("solve-button"));
+    let step_button = Button::builder()
+        .label(&t!("step-button"))
+        .tooltip_text(&t!("step-button-tooltip"))
+        .build();
 
     // Create left side box for timer and hints
     let left_box = gtk4::Box::builder()
@@ -422,10 +646,15 @@ pub fn build_ui(app: &Application) {
     left_box.append(&components.timer_button.borrow().button);
     left_box.append(&components.game_info_ui.borrow().timer_label);
     left_box.append(&components.hint_button_ui.borrow().hint_button);
+    left_box.append(&step_button);
+    left_box.append(&components.solvability_check_ui.borrow().check_button);
+    left_box.append(&components.obvious_consequence_ui.borrow().button);
     let hints_label = Label::new(Some(&t!("hints-label")));
     hints_label.set_css_classes(&["hints-label"]);
     left_box.append(&hints_label);
     left_box.append(&components.game_info_ui.borrow().hints_label);
+    left_box.append(&components.game_info_ui.borrow().rating_label);
+    left_box.append(&components.game_info_ui.borrow().completed_label);
 
     header_bar.pack_start(&left_box);
 
@@ -438,10 +667,13 @@ pub fn build_ui(app: &Application) {
         .build();
 
     // Create buttons first
+    right_box.append(&components.daily_puzzle_ui.borrow().button);
+    right_box.append(&components.history_controls_ui.borrow().history_scale);
     right_box.append(components.history_controls_ui.borrow().undo_button.as_ref());
     right_box.append(components.history_controls_ui.borrow().redo_button.as_ref());
     if Settings::is_debug_mode() {
         right_box.append(&solve_button);
+        right_box.append(&components.solution_preview_ui.borrow().button);
     }
 
     let menu_button = MenuButton::builder()
@@ -466,6 +698,11 @@ pub fn build_ui(app: &Application) {
         game_engine_command_emitter_solve.emit(GameEngineCommand::Solve);
     });
 
+    let game_engine_command_emitter_step = game_engine_command_emitter.clone();
+    step_button.connect_clicked(move |_| {
+        game_engine_command_emitter_step.emit(GameEngineCommand::ApplyNextDeduction);
+    });
+
     // Add CSS for selected cells
     let provider = CssProvider::new();
     provider.load_from_resource("/org/emojiclu/style.css");
@@ -487,12 +724,13 @@ pub fn build_ui(app: &Application) {
     // Assemble the UI
     puzzle_vertical_box.append(&puzzle_background);
     puzzle_vertical_box.append(&components.tutorial_ui.borrow().scrolled_window);
-    puzzle_vertical_box.append(&components.clue_panels_ui.borrow().vertical_grid);
+    puzzle_vertical_box.append(&components.clue_panels_ui.borrow().vertical_panel);
     puzzle_vertical_box.set_hexpand(false);
 
     let game_box = components.game_info_ui.borrow().game_box.clone();
     game_box.append(&puzzle_vertical_box);
-    game_box.append(&components.clue_panels_ui.borrow().horizontal_grid);
+    game_box.append(&components.clue_panels_ui.borrow().horizontal_panel);
+    game_box.append(&components.deduction_log_ui.borrow().panel);
 
     let top_level_box = gtk4::Box::builder()
         .name("top-level-box")
@@ -505,15 +743,38 @@ pub fn build_ui(app: &Application) {
         .css_classes(["app-background"])
         .build();
 
+    top_level_box.append(&components.sandbox_banner_ui.borrow().banner);
     top_level_box.append(game_box.as_ref());
     top_level_box.append(&components.pause_screen_ui.borrow().pause_screen_box);
 
     scrolled_window.set_child(Some(&top_level_box));
     // window.set_child(Some(&top_level_box));
-    window.set_child(Some(&scrolled_window));
+    // the toast floats over the board rather than scrolling with it
+    let window_overlay = Overlay::builder().child(&scrolled_window).build();
+    window_overlay.add_overlay(&components.achievement_toast.borrow().revealer);
+    window.set_child(Some(&window_overlay));
 
     window.present();
 
+    // Let the engine pause when the player switches to another app. Checked once focus has
+    // settled, so moving to one of our own dialogs doesn't count.
+    window.connect_is_active_notify({
+        let app = app.clone();
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |window| {
+            if window.is_active() {
+                return;
+            }
+            let app = app.clone();
+            let game_engine_command_emitter = game_engine_command_emitter.clone();
+            glib::idle_add_local_once(move || {
+                if !app.windows().iter().any(|window| window.is_active()) {
+                    game_engine_command_emitter.emit(GameEngineCommand::FocusLost);
+                }
+            });
+        }
+    });
+
     // Add actions for keyboard shortcuts and menu items
     let action_undo = SimpleAction::new("undo", None);
     let game_engine_command_emitter_undo = game_engine_command_emitter.clone();
@@ -529,6 +790,17 @@ pub fn build_ui(app: &Application) {
     });
     window.add_action(&action_redo);
 
+    // no default key; players can bind one in the keymap
+    let action_hint = SimpleAction::new("hint", None);
+    action_hint.connect_activate({
+        let hint_button = components.hint_button_ui.borrow().hint_button.clone();
+        move |_, _| {
+            // activating the button respects its cooldown after a hint
+            hint_button.activate();
+        }
+    });
+    window.add_action(&action_hint);
+
     // Add new game action that uses current difficulty
     let action_new_game = SimpleAction::new("new-game", None);
     action_new_game.connect_activate({
@@ -539,6 +811,62 @@ pub fn build_ui(app: &Application) {
     });
     window.add_action(&action_new_game);
 
+    let action_new_game_like_current = SimpleAction::new("new-game-like-current", None);
+    action_new_game_like_current.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::NewGameLikeCurrent);
+        }
+    });
+    window.add_action(&action_new_game_like_current);
+
+    let action_restore_previous_game = SimpleAction::new("restore-previous-game", None);
+    action_restore_previous_game.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::RestorePreviousGame);
+        }
+    });
+    window.add_action(&action_restore_previous_game);
+
+    // New game at a chosen difficulty; goes through the dropdown so it stays in sync
+    let action_new_game_difficulty =
+        SimpleAction::new("new-game-difficulty", Some(glib::VariantTy::STRING));
+    action_new_game_difficulty.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        let difficulty_selector = difficulty_selector.clone();
+        let settings = components.settings_projection.clone();
+        move |_, parameter| {
+            let index = parameter
+                .and_then(|parameter| parameter.get::<String>())
+                .and_then(|index| index.parse::<usize>().ok());
+            let Some(index) = index else {
+                return;
+            };
+            let dropdown_immediate = settings
+                .borrow()
+                .current_settings()
+                .difficulty_dropdown_immediate;
+            if !dropdown_immediate {
+                // the dropdown only picks the next game's difficulty, so start the game here
+                difficulty_selector.set_selected(index as u32);
+                game_engine_command_emitter.emit(GameEngineCommand::NewGame(
+                    Some(Difficulty::from_index(index)),
+                    None,
+                ));
+            } else if difficulty_selector.selected() == index as u32 {
+                // reselecting doesn't notify, so start the game here
+                game_engine_command_emitter.emit(GameEngineCommand::NewGame(
+                    Some(Difficulty::from_index(index)),
+                    None,
+                ));
+            } else {
+                difficulty_selector.set_selected(index as u32);
+            }
+        }
+    });
+    window.add_action(&action_new_game_difficulty);
+
     let action_statistics = SimpleAction::new("statistics", None);
     let stats_manager_stats = Rc::clone(&components.stats_manager);
 
@@ -551,13 +879,74 @@ pub fn build_ui(app: &Application) {
                 settings.borrow().current_settings().difficulty,
                 &stats_manager_stats.borrow_mut(),
                 None,
-                || {},
+                None,
+                |_| {},
             );
         }
     });
 
     window.add_action(&action_statistics);
 
+    let action_daily_puzzle = SimpleAction::new("daily-puzzle", None);
+    action_daily_puzzle.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::NewDailyPuzzle);
+        }
+    });
+    window.add_action(&action_daily_puzzle);
+
+    let action_puzzle_archive = SimpleAction::new("puzzle-archive", None);
+    action_puzzle_archive.connect_activate({
+        let stats_manager = Rc::clone(&components.stats_manager);
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        let window = window.clone();
+        move |_, _| {
+            ArchiveDialog::show(
+                &window,
+                &stats_manager.borrow(),
+                &game_engine_command_emitter,
+            );
+        }
+    });
+    window.add_action(&action_puzzle_archive);
+
+    let action_favorites = SimpleAction::new("favorites", None);
+    action_favorites.connect_activate({
+        let favorites_dialog = components.favorites_dialog.clone();
+        move |_, _| {
+            favorites_dialog.borrow().show();
+        }
+    });
+    window.add_action(&action_favorites);
+
+    let action_add_favorite = SimpleAction::new("add-favorite", None);
+    action_add_favorite.connect_activate({
+        let favorites_dialog = components.favorites_dialog.clone();
+        move |_, _| {
+            favorites_dialog.borrow().add_current();
+        }
+    });
+    window.add_action(&action_add_favorite);
+
+    let action_solution_walkthrough = SimpleAction::new("solution-walkthrough", None);
+    action_solution_walkthrough.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::ShowSolutionWalkthrough);
+        }
+    });
+    window.add_action(&action_solution_walkthrough);
+
+    let action_begin_sandbox = SimpleAction::new("begin-sandbox", None);
+    action_begin_sandbox.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::BeginSandbox);
+        }
+    });
+    window.add_action(&action_begin_sandbox);
+
     let action_about = SimpleAction::new("about", None);
     action_about.connect_activate(move |_, _| {
         let dialog = AboutDialog::builder()
@@ -567,11 +956,52 @@ pub fn build_ui(app: &Application) {
             .website(&t!("about-website"))
             .website_label(&t!("about-website-label"))
             .license_type(License::MitX11)
+            .comments(&t!("about-locale", { "locale" => get_locale().to_string() }))
             .build();
+        // credit entries of the form "name url" are rendered as links
+        dialog.add_credit_section(
+            &t!("about-translations-section"),
+            &[&format!(
+                "{} {}",
+                t!("about-help-translate"),
+                t!("about-translations-url")
+            )],
+        );
         dialog.present();
     });
     window.add_action(&action_about);
+
+    let action_keyboard_shortcuts = SimpleAction::new("keyboard-shortcuts", None);
+    action_keyboard_shortcuts.connect_activate({
+        let window = window.clone();
+        let settings = components.settings_projection.clone();
+        move |_, _| {
+            KeyboardShortcutsDialog::show(&window, &settings.borrow().current_settings().keymap);
+        }
+    });
+    window.add_action(&action_keyboard_shortcuts);
+
+    let action_key_bindings = SimpleAction::new("key-bindings", None);
+    action_key_bindings.connect_activate({
+        let keymap_dialog = components.keymap_dialog.clone();
+        move |_, _| {
+            keymap_dialog.borrow().show();
+        }
+    });
+    window.add_action(&action_key_bindings);
+
+    let action_clue_weights = SimpleAction::new("clue-weights", None);
+    action_clue_weights.connect_activate({
+        let clue_weights_dialog = components.clue_weights_dialog.clone();
+        move |_, _| {
+            clue_weights_dialog.borrow().show();
+        }
+    });
+    window.add_action(&action_clue_weights);
     // Initialize game with saved difficulty
+    let current_puzzle_code = saved_game_state
+        .as_ref()
+        .and_then(|save_state| save_state.board.solution.puzzle_code());
     match saved_game_state {
         Some(save_state) => {
             game_engine_command_emitter.emit(GameEngineCommand::LoadState(save_state));
@@ -583,6 +1013,15 @@ pub fn build_ui(app: &Application) {
             ));
         }
     }
+    if initial_settings.has_launched_before {
+        ClipboardPuzzlePrompt::check(
+            &window,
+            game_engine_command_emitter.clone(),
+            current_puzzle_code,
+        );
+    } else {
+        WelcomePrompt::show(&window, game_engine_command_emitter.clone());
+    }
     // delete me
     // game_engine_command_emitter.emit(GameEngineCommand::ChangeSettings(settings.borrow().clone()));
 
@@ -596,6 +1035,37 @@ pub fn build_ui(app: &Application) {
     });
     window.add_action(&action_seed);
 
+    // unlike the puzzle code, the share string carries the progress made so far
+    let action_copy_puzzle = SimpleAction::new("copy-puzzle", None);
+    action_copy_puzzle.connect_activate({
+        let window = window.clone();
+        let game_state = components.game_state.clone();
+        move |_, _| {
+            if let Some(share_string) = game_state.borrow().get_game_save_state().to_share_string()
+            {
+                WidgetExt::clipboard(window.as_ref()).set_text(&share_string);
+            }
+        }
+    });
+    // puzzles generated with custom clue weights have no code to share them by
+    action_copy_puzzle.set_enabled(
+        components
+            .game_state
+            .borrow()
+            .get_game_save_state()
+            .to_share_string()
+            .is_some(),
+    );
+    channels.game_engine_event.observer.subscribe({
+        let action_copy_puzzle = action_copy_puzzle.clone();
+        move |event| {
+            if let GameEngineEvent::GameBoardUpdated { board, .. } = event {
+                action_copy_puzzle.set_enabled(board.solution.puzzle_code().is_some());
+            }
+        }
+    });
+    window.add_action(&action_copy_puzzle);
+
     // Add restart action
     let action_restart = SimpleAction::new("restart", None);
     action_restart.connect_activate({
@@ -606,10 +1076,57 @@ pub fn build_ui(app: &Application) {
     });
     window.add_action(&action_restart);
 
+    let action_toggle_clue_visibility = SimpleAction::new("toggle-clue-visibility", None);
+    action_toggle_clue_visibility.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::ToggleClueVisibility);
+        }
+    });
+    window.add_action(&action_toggle_clue_visibility);
+
+    let action_complete_satisfied_clues = SimpleAction::new("complete-satisfied-clues", None);
+    action_complete_satisfied_clues.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, _| {
+            game_engine_command_emitter.emit(GameEngineCommand::CompleteAllSatisfiedClues);
+        }
+    });
+    window.add_action(&action_complete_satisfied_clues);
+
+    // Debug designer tool: the parameter is an index into `DeductionKind::all()`
+    let action_find_technique_puzzle =
+        SimpleAction::new("find-technique-puzzle", Some(glib::VariantTy::STRING));
+    action_find_technique_puzzle.connect_activate({
+        let game_engine_command_emitter = game_engine_command_emitter.clone();
+        move |_, parameter| {
+            let deduction_kind = parameter
+                .and_then(|parameter| parameter.get::<String>())
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| DeductionKind::all().get(index).cloned());
+            if let Some(deduction_kind) = deduction_kind {
+                game_engine_command_emitter
+                    .emit(GameEngineCommand::NewGameRequiringTechnique(deduction_kind));
+            }
+        }
+    });
+    window.add_action(&action_find_technique_puzzle);
+
+    // Debug designer tool for writing clue sets by hand
+    let action_clue_editor = SimpleAction::new("clue-editor", None);
+    action_clue_editor.connect_activate({
+        let clue_editor_dialog = components.clue_editor_dialog.clone();
+        move |_, _| {
+            clue_editor_dialog.borrow_mut().show();
+        }
+    });
+    window.add_action(&action_clue_editor);
+
     window.connect_close_request({
         let components = Rc::new(RefCell::new(components));
         move |_| {
             log::info!(target: "window", "{}", t!("destroying-window"));
+            components.borrow().game_state.borrow().cancel_generation();
             if !components
                 .borrow()
                 .game_state
@@ -625,3 +1142,41 @@ pub fn build_ui(app: &Application) {
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saved_game_takes_precedence() {
+        let mut settings = Settings::default();
+        settings.preferred_difficulty = Difficulty::Easy;
+        let save_state = GameStateSnapshot::generate_new(Difficulty::Moderate, Some(42));
+
+        let (settings, save_state) = resolve_startup_state(settings, Some(save_state));
+
+        assert!(save_state.is_some());
+        assert_eq!(settings.difficulty, Difficulty::Moderate);
+    }
+
+    #[test]
+    fn test_preferred_difficulty_without_save() {
+        let mut settings = Settings::default();
+        settings.difficulty = Difficulty::Veteran;
+        settings.preferred_difficulty = Difficulty::Easy;
+
+        let (settings, save_state) = resolve_startup_state(settings, None);
+
+        assert!(save_state.is_none());
+        assert_eq!(settings.difficulty, Difficulty::Easy);
+    }
+
+    #[test]
+    fn test_first_launch_starts_tutorial() {
+        let mut settings = Settings::default();
+        settings.preferred_difficulty = Difficulty::Hard;
+
+        let (settings, _) = resolve_startup_state(settings, None);
+        assert_eq!(settings.difficulty, Difficulty::Tutorial);
+    }
+}